@@ -1,4 +1,5 @@
 use crate::commands::shared::{EnvArgs, OutputArgs, SourceArgs};
+use crate::core::exit_code::ExitCode;
 use crate::core::formatter::OutputFormat;
 use clap::{Args, Subcommand};
 use rq_lib::RqClient;
@@ -27,6 +28,8 @@ struct AuthDetailsJsonView {
     environment: Option<String>,
     #[serde(rename = "Fields")]
     fields: HashMap<String, String>,
+    #[serde(rename = "Defaulted Fields")]
+    defaulted_fields: Vec<String>,
     file: String,
     line: usize,
     character: usize,
@@ -45,6 +48,31 @@ pub enum AuthSubcommand {
     List(ListArgs),
     #[command(about = "Show authentication details")]
     Show(ShowArgs),
+    #[command(about = "Validate every auth configuration without resolving variables")]
+    Validate(ValidateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ValidateArgs {
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Serialize)]
+struct AuthValidationError {
+    file: String,
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct AuthValidationView {
+    valid: Vec<rq_lib::client::models::AuthListEntry>,
+    errors: Vec<AuthValidationError>,
 }
 
 #[derive(Debug, Args)]
@@ -75,6 +103,12 @@ pub struct ShowArgs {
     #[arg(long = "no-var-interpolation", help = "Skip variable interpolation")]
     pub no_var_interpolation: bool,
 
+    #[arg(
+        long = "no-defaults",
+        help = "Hide fields that rq fills in when the auth block does not set them"
+    )]
+    pub no_defaults: bool,
+
     #[command(flatten)]
     pub output: OutputArgs,
 }
@@ -85,10 +119,7 @@ pub fn execute_list(args: &ListArgs) -> Result<(), Box<dyn std::error::Error>> {
 
     match args.output.output {
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&auth_list).unwrap_or("[]".to_string())
-            );
+            println!("{}", crate::core::json::render(&auth_list));
         }
         OutputFormat::Text => {
             if auth_list.is_empty() {
@@ -108,13 +139,17 @@ pub fn execute_list(args: &ListArgs) -> Result<(), Box<dyn std::error::Error>> {
 pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
     let source_path = Path::new(&args.source.source);
 
-    let (auth_name, auth_type_str, fields, file, line, character) = RqClient::default()
-        .get_auth_details(
+    let (auth_name, auth_type_str, mut fields, mut defaulted, file, line, character) =
+        RqClient::default().get_auth_details(
             source_path,
             &args.name,
             args.env_args.environment.as_deref(),
             !args.no_var_interpolation,
         )?;
+    if args.no_defaults {
+        fields.retain(|field, _| !defaulted.contains(field));
+        defaulted.clear();
+    }
 
     match args.output.output {
         OutputFormat::Json => {
@@ -123,17 +158,20 @@ pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
                 auth_type: auth_type_str,
                 environment: args.env_args.environment.clone(),
                 fields,
+                defaulted_fields: defaulted,
                 file,
                 line,
                 character,
             };
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&view).unwrap_or_default()
-            );
+            println!("{}", crate::core::json::render(&view));
         }
         OutputFormat::Text => {
             let formatter = crate::core::formatter::get_formatter(&args.output.output);
+            for field in &defaulted {
+                if let Some(value) = fields.get_mut(field) {
+                    value.push_str(" (default)");
+                }
+            }
             let view = AuthDetailsView {
                 name: auth_name,
                 auth_type: auth_type_str,
@@ -146,3 +184,55 @@ pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+pub fn execute_validate(args: &ValidateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let source_path = Path::new(&args.source.source);
+    let (valid, errors) = RqClient::default().validate_auth(source_path)?;
+    let view = AuthValidationView {
+        valid,
+        errors: errors
+            .into_iter()
+            .map(|e| AuthValidationError {
+                file: e
+                    .file_path
+                    .as_deref()
+                    .map(rq_lib::paths::clean_path_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                line: e.line,
+                column: e.column,
+                message: e.message,
+            })
+            .collect(),
+    };
+
+    match args.output.output {
+        OutputFormat::Json => {
+            println!("{}", crate::core::json::render(&view));
+        }
+        OutputFormat::Text => {
+            if view.valid.is_empty() && view.errors.is_empty() {
+                println!("No auth configurations found");
+            }
+            for error in &view.errors {
+                println!(
+                    "{}:{}:{}: {}",
+                    error.file, error.line, error.column, error.message
+                );
+            }
+            if !view.valid.is_empty() || !view.errors.is_empty() {
+                println!(
+                    "{} valid, {} invalid auth configuration(s)",
+                    view.valid.len(),
+                    view.errors.len()
+                );
+            }
+        }
+    }
+
+    if !view.errors.is_empty() {
+        std::process::exit(ExitCode::ConfigError.code());
+    }
+
+    Ok(())
+}
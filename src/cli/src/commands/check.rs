@@ -33,11 +33,32 @@ struct CheckError {
 #[derive(Serialize)]
 struct CheckResult {
     errors: Vec<CheckError>,
+    warnings: Vec<CheckError>,
 }
 
 pub fn execute(args: &CheckArgs) -> Result<(), Box<dyn std::error::Error>> {
     let path = std::path::Path::new(&args.source);
-    let errors = RqClient::default().check_path(path, args.env.as_deref())?;
+    let client = RqClient::default();
+    let errors = client.check_path(path, args.env.as_deref())?;
+    let collisions = client
+        .environment_collisions(path)?
+        .into_iter()
+        .map(|c| CheckError {
+            file: c.file,
+            line: c.line + 1,
+            column: c.character + 1,
+            message: c.message,
+        });
+    let unused_templates = client
+        .unused_endpoint_templates(path)?
+        .into_iter()
+        .map(|t| CheckError {
+            file: t.file,
+            line: t.line + 1,
+            column: t.character + 1,
+            message: t.message,
+        });
+    let warnings = collisions.chain(unused_templates).collect();
 
     let check_errors: Vec<CheckError> = errors
         .into_iter()
@@ -58,8 +79,9 @@ pub fn execute(args: &CheckArgs) -> Result<(), Box<dyn std::error::Error>> {
     let has_errors = !check_errors.is_empty();
     let result = CheckResult {
         errors: check_errors,
+        warnings,
     };
-    println!("{}", serde_json::to_string_pretty(&result)?);
+    println!("{}", crate::core::json::render(&result));
 
     if has_errors {
         std::process::exit(1);
@@ -24,6 +24,9 @@ pub struct ListArgs {
     #[command(flatten)]
     pub source: SourceArgs,
 
+    #[arg(long = "verbose", help = "Show the files that define each environment")]
+    pub verbose: bool,
+
     #[command(flatten)]
     pub output: OutputArgs,
 }
@@ -45,25 +48,28 @@ pub struct ShowArgs {
 
 pub fn execute_list(args: &ListArgs) -> Result<(), Box<dyn std::error::Error>> {
     let path = std::path::Path::new(&args.source.source);
-    let env_list = RqClient::default().list_environments(path)?;
+    let entries = RqClient::default().list_environment_files(path)?;
 
     match args.output.output {
         OutputFormat::Json => {
-            let entries: Vec<serde_json::Value> = env_list
-                .iter()
-                .map(|name| serde_json::json!({ "name": name }))
-                .collect();
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string())
-            );
+            println!("{}", crate::core::json::render(&entries));
         }
         OutputFormat::Text => {
             let formatter = crate::core::formatter::get_formatter(&args.output.output);
+            let lines: Vec<String> = entries
+                .iter()
+                .map(|e| {
+                    if args.verbose {
+                        format!("{} ({})", e.name, e.files.join(", "))
+                    } else {
+                        e.name.clone()
+                    }
+                })
+                .collect();
             print!(
                 "{}",
                 formatter.format_list(
-                    &env_list,
+                    &lines,
                     "Environments found:",
                     "No environments found in .rq files"
                 )
@@ -79,10 +85,7 @@ pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
     let entry = RqClient::default().get_environment(path, &args.name)?;
     match args.output.output {
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&entry).unwrap_or_default()
-            );
+            println!("{}", crate::core::json::render(&entry));
         }
         OutputFormat::Text => {
             let formatter = crate::core::formatter::get_formatter(&args.output.output);
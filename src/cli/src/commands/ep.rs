@@ -74,10 +74,7 @@ pub fn execute_list(args: &ListArgs) -> Result<(), Box<dyn std::error::Error>> {
 
     match args.output.output {
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string())
-            );
+            println!("{}", crate::core::json::render(&entries));
         }
         OutputFormat::Text => {
             let formatter = crate::core::formatter::get_formatter(&args.output.output);
@@ -101,10 +98,7 @@ pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
     let entry = RqClient::default().get_endpoint(path, &args.name)?;
     match args.output.output {
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&entry).unwrap_or_default()
-            );
+            println!("{}", crate::core::json::render(&entry));
         }
         OutputFormat::Text => {
             let formatter = crate::core::formatter::get_formatter(&args.output.output);
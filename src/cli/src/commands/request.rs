@@ -1,11 +1,21 @@
-use crate::commands::shared::{EnvArgs, OutputArgs, SourceArgs};
+use crate::commands::shared::{EnvArgs, OutputArgs, RunSourceArgs, SourceArgs};
 use crate::commands::validators;
+use crate::core::error::RqError;
+use crate::core::formatter::OutputTemplate;
 use crate::core::logger::Logger;
+use crate::core::resume::ResumeState;
+use crate::core::sort::{sort_results, SortBy};
+use crate::core::transform::ResponseTransform;
 use clap::{Args, Subcommand};
-use rq_lib::{RequestExecutionResult, RqClient};
+use rq_lib::client::models::{AuthFieldOverride, ConfirmMutation, RequestCompleted, SkipRequest};
+use rq_lib::native::{PoolOptions, ReqwestHttpClient};
+use rq_lib::{PingResult, RequestExecutionResult, RqClient, RunOptions, RunSummary};
 use serde::Serialize;
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Serialize)]
 pub struct AuthConfigView {
@@ -28,6 +38,8 @@ pub struct RequestDetailsView {
     pub body: Option<String>,
     #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
     pub timeout: Option<String>,
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     #[serde(rename = "Auth", skip_serializing_if = "Option::is_none")]
     pub auth: Option<AuthConfigView>,
 }
@@ -46,6 +58,8 @@ struct RequestDetailsJsonView {
     body: Option<String>,
     #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
     timeout: Option<String>,
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
     #[serde(rename = "Auth", skip_serializing_if = "Option::is_none")]
     auth: Option<AuthConfigView>,
     file: String,
@@ -53,9 +67,24 @@ struct RequestDetailsJsonView {
     character: usize,
 }
 
+#[derive(Serialize)]
+struct AuthHeadersView<'a> {
+    request: &'a str,
+    method: &'a str,
+    url: &'a str,
+    headers: BTreeMap<&'a str, &'a str>,
+}
+
 #[derive(Serialize)]
 pub struct ExecutionResultsView {
     pub results: Vec<RequestExecutionResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<RunSummary>,
+}
+
+#[derive(Serialize)]
+pub struct PingResultsView {
+    pub results: Vec<PingResult>,
 }
 
 #[derive(Debug, Args)]
@@ -72,7 +101,32 @@ pub enum RequestSubcommand {
     #[command(about = "Show request details")]
     Show(ShowArgs),
     #[command(about = "Run a request")]
-    Run(RunArgs),
+    Run(Box<RunArgs>),
+    #[command(about = "Check that a request's host is reachable without running it")]
+    Ping(PingArgs),
+    #[command(about = "Export requests to another format")]
+    Export(ExportCommand),
+}
+
+#[derive(Debug, Args)]
+pub struct ExportCommand {
+    #[command(subcommand)]
+    pub format: ExportFormat,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ExportFormat {
+    #[command(about = "Print an OpenAPI 3 document describing each request")]
+    Openapi(ExportOpenApiArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ExportOpenApiArgs {
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    #[command(flatten)]
+    pub env_args: EnvArgs,
 }
 
 #[derive(Debug, Args)]
@@ -115,6 +169,229 @@ pub struct ShowArgs {
 
 #[derive(Debug, Args)]
 pub struct RunArgs {
+    #[command(flatten)]
+    pub source: RunSourceArgs,
+
+    #[command(flatten)]
+    pub request_name_args: RequestNameArgs,
+
+    #[command(flatten)]
+    pub env_args: EnvArgs,
+
+    #[arg(
+        long = "profile",
+        value_name = "NAME",
+        help = "Apply a variable profile on top of the environment (can be repeated)",
+        value_parser = validators::validate_name
+    )]
+    pub profile: Vec<String>,
+
+    #[arg(
+        short = 'v',
+        long = "variable",
+        value_name = "NAME[:TYPE]=VALUE",
+        help = "Override requests variables",
+        value_parser = validators::validate_variable
+    )]
+    pub variable: Vec<String>,
+
+    #[arg(
+        long = "var-file",
+        value_name = "FILE",
+        help = "Load variables from a JSON object file (can be repeated, -v takes precedence)"
+    )]
+    pub var_file: Vec<PathBuf>,
+
+    #[arg(
+        long = "timeout",
+        value_name = "DURATION",
+        help = "Default timeout for requests without a timeout attribute (e.g. 1500ms, 30s, 2m)",
+        value_parser = validators::parse_duration
+    )]
+    pub timeout: Option<Duration>,
+
+    #[arg(
+        long = "max-redirects",
+        value_name = "N",
+        help = "Maximum number of redirects to follow [default: 10]"
+    )]
+    pub max_redirects: Option<usize>,
+
+    #[arg(
+        long = "pool-max-idle",
+        value_name = "N",
+        help = "Maximum idle keep-alive connections kept per host (0 disables reuse) [default: unlimited]"
+    )]
+    pub pool_max_idle: Option<usize>,
+
+    #[arg(
+        long = "pool-idle-timeout",
+        value_name = "DURATION",
+        help = "Close keep-alive connections idle for longer than this (e.g. 500ms, 30s) [default: 90s]",
+        value_parser = validators::parse_duration
+    )]
+    pub pool_idle_timeout: Option<Duration>,
+
+    #[arg(
+        long = "show-redirects",
+        help = "Print the redirect chain leading to each response"
+    )]
+    pub show_redirects: bool,
+
+    #[arg(
+        long = "deadline",
+        value_name = "DURATION",
+        help = "Abort the whole run once it takes longer than this (e.g. 500ms, 30s, 2m)",
+        value_parser = validators::parse_duration
+    )]
+    pub deadline: Option<Duration>,
+
+    #[arg(
+        long = "junit",
+        value_name = "FILE",
+        help = "Write a JUnit XML report with one test case per request"
+    )]
+    pub junit: Option<PathBuf>,
+
+    #[arg(
+        long = "confirm-mutations",
+        help = "Ask for confirmation before sending POST, PUT, PATCH or DELETE requests"
+    )]
+    pub confirm_mutations: bool,
+
+    #[arg(
+        short = 'y',
+        long = "yes",
+        requires = "confirm_mutations",
+        help = "Answer yes to every --confirm-mutations prompt"
+    )]
+    pub yes: bool,
+
+    #[arg(
+        long = "allow-insecure-auth",
+        help = "Do not warn when credentials are sent over plain HTTP to a non-local host"
+    )]
+    pub allow_insecure_auth: bool,
+
+    #[arg(
+        long = "skip-auth",
+        help = "Send requests without applying their auth configuration"
+    )]
+    pub skip_auth: bool,
+
+    #[arg(
+        long = "only-auth",
+        conflicts_with_all = ["skip_auth", "resume", "print_resolved_file"],
+        help = "Apply auth and print the resulting request headers without sending requests"
+    )]
+    pub only_auth: bool,
+
+    #[arg(
+        long = "auth-field",
+        value_name = "[AUTH.]FIELD=VALUE",
+        help = "Override an auth configuration field for this run (can be repeated)",
+        value_parser = AuthFieldOverride::parse
+    )]
+    pub auth_field: Vec<AuthFieldOverride>,
+
+    #[arg(
+        long = "reauth",
+        conflicts_with = "skip_auth",
+        help = "Re-run auth and retry once when an authenticated request returns 401"
+    )]
+    pub reauth: bool,
+
+    #[arg(
+        long = "no-token-cache",
+        help = "Do not read or write the OAuth2 client credentials token cache"
+    )]
+    pub no_token_cache: bool,
+
+    #[arg(
+        long = "dump-tokens-redacted",
+        help = "Print a redacted fingerprint of each OAuth2 access token to stderr"
+    )]
+    pub dump_tokens_redacted: bool,
+
+    #[arg(
+        long = "interactive",
+        help = "Sign in through the browser for OAuth2 authorization code auth"
+    )]
+    pub interactive: bool,
+
+    #[arg(
+        long = "explain-auth",
+        help = "Print which auth provider each request used, the path it took and the headers it injected"
+    )]
+    pub explain_auth: bool,
+
+    #[arg(
+        long = "no-env-default",
+        help = "Fail when no environment is selected but the source defines environments"
+    )]
+    pub no_env_default: bool,
+
+    #[arg(
+        long = "ws-frames",
+        value_name = "N",
+        help = "Stop reading a WebSocket (ws:// or wss://) request after N received frames"
+    )]
+    pub ws_frames: Option<usize>,
+
+    #[arg(
+        long = "fail-if-empty",
+        help = "Exit with an error when no request was executed"
+    )]
+    pub fail_if_empty: bool,
+
+    #[arg(
+        long = "resume",
+        help = "Skip requests that succeeded in an earlier unfinished run of the same source"
+    )]
+    pub resume: bool,
+
+    #[arg(
+        long = "print-resolved-file",
+        conflicts_with = "resume",
+        help = "Print the file with every variable and function resolved instead of sending requests"
+    )]
+    pub print_resolved_file: bool,
+
+    #[arg(
+        long = "summary",
+        help = "Print a summary of request counts, total time and status codes after the run"
+    )]
+    pub summary: bool,
+
+    #[arg(
+        long = "output-template",
+        value_name = "TEMPLATE",
+        help = "Print each result through a template, e.g. '{status} {url} {time_ms}ms'",
+        value_parser = OutputTemplate::parse
+    )]
+    pub output_template: Option<OutputTemplate>,
+
+    #[arg(
+        long = "transform",
+        value_name = "FILTER",
+        help = "Apply a jq-style filter to each JSON response body, e.g. '.[] | .name'",
+        value_parser = ResponseTransform::parse
+    )]
+    pub transform: Option<ResponseTransform>,
+
+    #[arg(
+        long = "sort-by",
+        value_name = "KEY",
+        help = "Print results ordered by status (highest first), time (slowest first) or name"
+    )]
+    pub sort_by: Option<SortBy>,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct PingArgs {
     #[command(flatten)]
     pub source: SourceArgs,
 
@@ -127,7 +404,7 @@ pub struct RunArgs {
     #[arg(
         short = 'v',
         long = "variable",
-        value_name = "NAME=VALUE",
+        value_name = "NAME[:TYPE]=VALUE",
         help = "Override requests variables",
         value_parser = validators::validate_variable
     )]
@@ -144,7 +421,7 @@ pub fn execute_list(args: &ListArgs) -> Result<(), Box<dyn std::error::Error>> {
     for e in &parse_errors {
         match args.output.output {
             crate::core::formatter::OutputFormat::Json => {
-                eprintln!("{}", crate::core::error::error_to_json(e));
+                eprintln!("{}", crate::core::json::render_error(e));
             }
             crate::core::formatter::OutputFormat::Text => {
                 eprintln!("Warning: Failed to parse: {e}");
@@ -203,15 +480,13 @@ pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
                 headers: headers_map,
                 body: details.body,
                 timeout: details.timeout,
+                description: details.description,
                 auth,
                 file: details.file,
                 line: details.line,
                 character: details.character,
             };
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&view).unwrap_or_default()
-            );
+            println!("{}", crate::core::json::render(&view));
         }
         crate::core::formatter::OutputFormat::Text => {
             let formatter = crate::core::formatter::get_formatter(&args.output.output);
@@ -222,6 +497,7 @@ pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
                 headers: headers_map,
                 body: details.body,
                 timeout: details.timeout,
+                description: details.description,
                 auth,
             };
             print!("{}", formatter.format(&view));
@@ -231,26 +507,33 @@ pub fn execute_show(args: &ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+pub async fn execute_default_run(mut args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if args.request_name_args.name.is_none() && args.source.source != rq_lib::client::STDIN_SOURCE {
+        let source_path = Path::new(&args.source.source);
+        if let Some(name) = RqClient::default().default_request(source_path)? {
+            Logger::debug(&format!("Running default request '{name}'"));
+            args.request_name_args.name = Some(name);
+        }
+    }
+    execute_run(&args).await
+}
+
 pub async fn execute_run(args: &RunArgs) -> Result<(), Box<dyn std::error::Error>> {
-    let source_path = Path::new(&args.source.source);
-    let request_name = args
-        .request_name_args
-        .name
-        .as_deref()
-        .map(|n| n.replace('.', "/"));
-    let (results, parse_warnings) = RqClient::default()
-        .run(
-            source_path,
-            request_name.as_deref(),
-            args.env_args.environment.as_deref(),
-            &args.variable,
-        )
-        .await?;
+    if args.print_resolved_file {
+        return print_resolved_file(args);
+    }
+    let resume = load_resume_state(args)?;
+    let (mut results, parse_warnings) = run_requests(args, resume.clone()).await?;
+    if let Some(state) = &resume {
+        if results.iter().all(RequestExecutionResult::succeeded) {
+            state.clear()?;
+        }
+    }
 
     for w in &parse_warnings {
         match args.output.output {
             crate::core::formatter::OutputFormat::Json => {
-                eprintln!("{}", crate::core::error::error_to_json(w));
+                eprintln!("{}", crate::core::json::render_error(w));
             }
             crate::core::formatter::OutputFormat::Text => {
                 eprintln!("Warning: Failed to parse: {w}");
@@ -258,13 +541,35 @@ pub async fn execute_run(args: &RunArgs) -> Result<(), Box<dyn std::error::Error
         }
     }
 
+    if args.only_auth {
+        print_auth_headers(&results, &args.output.output);
+        return Ok(());
+    }
+
+    if args.fail_if_empty && results.is_empty() {
+        return Err(RqError::RequestNotFound(format!(
+            "no request was executed from '{}' and --fail-if-empty is set",
+            args.source.source
+        ))
+        .into());
+    }
+
+    if let Some(transform) = &args.transform {
+        transform_bodies(transform, &mut results)?;
+    }
+
     for result in &results {
+        print_redirect_chain(result, args.show_redirects);
+        print_response_warnings(result, &args.output.output);
         let elapsed_str = format!("{} ms", result.elapsed_ms);
         Logger::debug("\n--- HTTP Response ---");
         Logger::debug(&format!(
             "Response status: {} ({})",
             result.status, elapsed_str
         ));
+        if let Some(retries) = &result.retries {
+            Logger::debug(&format!("Retries: {retries}"));
+        }
         Logger::debug("Response Headers:");
         for (key, value) in &result.response_headers {
             Logger::debug(&format!("  {key}: {value}"));
@@ -273,8 +578,385 @@ pub async fn execute_run(args: &RunArgs) -> Result<(), Box<dyn std::error::Error
         Logger::debug("--- End Response ---\n");
     }
 
+    if let Some(junit_path) = &args.junit {
+        write_junit_report(junit_path, &args.source.source, &results)?;
+    }
+
+    if let Some(sort_by) = args.sort_by {
+        sort_results(&mut results, sort_by);
+    }
+    let summary = args.summary.then(|| RunSummary::from_results(&results));
+    let assertion_error = assertion_error(&results);
+    if let Some(template) = &args.output_template {
+        for result in &results {
+            println!("{}", template.render(result));
+        }
+        if let Some(summary) = summary {
+            println!("{summary}");
+        }
+    } else {
+        print_results(results, args.output.output, summary);
+    }
+
+    match assertion_error {
+        Some(error) => Err(error.into()),
+        None => Ok(()),
+    }
+}
+
+fn assertion_error(results: &[RequestExecutionResult]) -> Option<RqError> {
+    let lines: Vec<String> = results
+        .iter()
+        .flat_map(|result| {
+            result
+                .assertion_failures
+                .iter()
+                .map(|failure| format!("Assertion failed in '{}': {failure}", result.request_name))
+        })
+        .collect();
+    (!lines.is_empty()).then(|| RqError::AssertionFailed(lines.join("\n")))
+}
+
+fn print_results(
+    results: Vec<RequestExecutionResult>,
+    default_format: crate::core::formatter::OutputFormat,
+    summary: Option<RunSummary>,
+) {
+    let format_of = |result: &RequestExecutionResult| {
+        result
+            .output
+            .map(crate::core::formatter::OutputFormat::from)
+            .unwrap_or(default_format)
+    };
+    let mut groups: Vec<(
+        crate::core::formatter::OutputFormat,
+        Vec<RequestExecutionResult>,
+    )> = Vec::new();
+    for result in results {
+        let format = format_of(&result);
+        match groups.last_mut() {
+            Some((last, group)) if *last == format => group.push(result),
+            _ => groups.push((format, vec![result])),
+        }
+    }
+    if groups.is_empty() {
+        groups.push((default_format, Vec::new()));
+    }
+    let last = groups.len() - 1;
+    let mut summary_line = None;
+    for (i, (format, results)) in groups.into_iter().enumerate() {
+        let mut view = ExecutionResultsView {
+            results,
+            summary: None,
+        };
+        if i == last {
+            match format {
+                crate::core::formatter::OutputFormat::Json => view.summary = summary.clone(),
+                crate::core::formatter::OutputFormat::Text => summary_line = summary.clone(),
+            }
+        }
+        let formatter = crate::core::formatter::get_formatter(&format);
+        print!("{}", formatter.format(&view));
+    }
+    if let Some(summary) = summary_line {
+        println!("{summary}");
+    }
+}
+
+fn load_resume_state(args: &RunArgs) -> Result<Option<Arc<ResumeState>>, RqError> {
+    if !args.resume {
+        return Ok(None);
+    }
+    if args.source.source == rq_lib::client::STDIN_SOURCE {
+        return Err(RqError::Validation(
+            "--resume cannot be used when reading requests from stdin".to_string(),
+        ));
+    }
+    let state = ResumeState::load(Path::new(&args.source.source))?;
+    let completed = state.completed_count();
+    if completed > 0 && args.output.output == crate::core::formatter::OutputFormat::Text {
+        eprintln!("Resuming: skipping {completed} request(s) that succeeded in a previous run");
+    }
+    Ok(Some(Arc::new(state)))
+}
+
+fn resume_callbacks(
+    resume: Option<Arc<ResumeState>>,
+) -> (Option<Arc<SkipRequest>>, Option<Arc<RequestCompleted>>) {
+    let Some(state) = resume else {
+        return (None, None);
+    };
+    let skip_state = state.clone();
+    let skip: Arc<SkipRequest> = Arc::new(move |file, name| skip_state.is_completed(file, name));
+    let completed: Arc<RequestCompleted> = Arc::new(move |file, result| {
+        if result.succeeded() {
+            if let Err(e) = state.record(file, &result.request_name) {
+                eprintln!("Warning: Failed to save resume state: {e}");
+            }
+        }
+    });
+    (Some(skip), Some(completed))
+}
+
+fn print_auth_headers(
+    results: &[RequestExecutionResult],
+    output: &crate::core::formatter::OutputFormat,
+) {
+    let views: Vec<AuthHeadersView> = results
+        .iter()
+        .map(|result| AuthHeadersView {
+            request: &result.request_name,
+            method: &result.method,
+            url: &result.url,
+            headers: result
+                .request_headers
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect(),
+        })
+        .collect();
+    let formatter = crate::core::formatter::get_formatter(output);
+    print!("{}", formatter.format_list(&views, "", "No requests found"));
+}
+
+fn print_resolved_file(args: &RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let request_name = args
+        .request_name_args
+        .name
+        .as_deref()
+        .map(|n| n.replace('.', "/"));
+    let mut client = RqClient::default();
+    if args.source.source == rq_lib::client::STDIN_SOURCE {
+        client = client.with_stdin_document(std::io::read_to_string(std::io::stdin())?);
+    }
+    let options = RunOptions {
+        profiles: args.profile.clone(),
+        variable_files: args.var_file.clone(),
+        ..RunOptions::default()
+    };
+    print!(
+        "{}",
+        client.resolve_file(
+            Path::new(&args.source.source),
+            request_name.as_deref(),
+            args.env_args.environment.as_deref(),
+            &args.variable,
+            &options,
+        )?
+    );
+    Ok(())
+}
+
+async fn run_requests(
+    args: &RunArgs,
+    resume: Option<Arc<ResumeState>>,
+) -> Result<(Vec<RequestExecutionResult>, Vec<RqError>), RqError> {
+    let source_path = Path::new(&args.source.source);
+    let request_name = args
+        .request_name_args
+        .name
+        .as_deref()
+        .map(|n| n.replace('.', "/"));
+    let pool = PoolOptions {
+        max_idle_per_host: args.pool_max_idle,
+        idle_timeout: args.pool_idle_timeout,
+    };
+    let mut client =
+        RqClient::default().with_http_client(Arc::new(ReqwestHttpClient::with_pool(pool)));
+    if args.source.source == rq_lib::client::STDIN_SOURCE {
+        client = client.with_stdin_document(std::io::read_to_string(std::io::stdin())?);
+    }
+    let (skip_request, on_request_completed) = resume_callbacks(resume);
+    let options = RunOptions {
+        max_redirects: args.max_redirects,
+        timeout: args.timeout.map(|t| format!("{}ms", t.as_millis())),
+        profiles: args.profile.clone(),
+        variable_files: args.var_file.clone(),
+        confirm_mutation: args
+            .confirm_mutations
+            .then(|| confirm_mutation_prompt(args.yes)),
+        allow_insecure_auth: args.allow_insecure_auth,
+        skip_request,
+        on_request_completed,
+        skip_auth: args.skip_auth,
+        only_auth: args.only_auth,
+        auth_fields: args.auth_field.clone(),
+        ws_max_frames: args.ws_frames,
+        reauth: args.reauth,
+        require_environment: args.no_env_default,
+        token_cache: (!args.no_token_cache)
+            .then(crate::core::paths::cache_dir)
+            .flatten()
+            .map(|dir| dir.join("tokens")),
+        dump_tokens_redacted: args.dump_tokens_redacted,
+        interactive: args.interactive,
+        explain_auth: args.explain_auth,
+    };
+    let run = client.run(
+        source_path,
+        request_name.as_deref(),
+        args.env_args.environment.as_deref(),
+        &args.variable,
+        &options,
+    );
+    match args.deadline {
+        Some(deadline) => tokio::time::timeout(deadline, run)
+            .await
+            .map_err(|_| RqError::DeadlineExceeded(format!("{deadline:?}")))?,
+        None => run.await,
+    }
+}
+
+fn confirm_mutation_prompt(assume_yes: bool) -> Arc<ConfirmMutation> {
+    Arc::new(move |method, url| {
+        if assume_yes {
+            return Ok(());
+        }
+        let stdin = std::io::stdin();
+        if !stdin.is_terminal() {
+            return Err(format!(
+                "{method} {url} needs confirmation but no terminal is attached (pass --yes to send it)"
+            ));
+        }
+        eprint!("About to send {method} {url}. Continue? [y/N] ");
+        let mut answer = String::new();
+        stdin.read_line(&mut answer).map_err(|e| e.to_string())?;
+        match answer.trim().to_ascii_lowercase().as_str() {
+            "y" | "yes" => Ok(()),
+            _ => Err(format!("{method} {url} was declined")),
+        }
+    })
+}
+
+fn transform_bodies(
+    transform: &ResponseTransform,
+    results: &mut [RequestExecutionResult],
+) -> Result<(), RqError> {
+    for result in results {
+        result.body = transform.apply(&result.body).map_err(|e| {
+            RqError::Validation(format!(
+                "Failed to transform response of '{}': {e}",
+                result.request_name
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+fn write_junit_report(
+    path: &Path,
+    source: &str,
+    results: &[RequestExecutionResult],
+) -> Result<(), String> {
+    let report = crate::core::junit::render(source, results);
+    std::fs::write(path, report)
+        .map_err(|e| format!("Failed to write JUnit report to {}: {e}", path.display()))
+}
+
+fn print_response_warnings(
+    result: &RequestExecutionResult,
+    output: &crate::core::formatter::OutputFormat,
+) {
+    for warning in &result.warnings {
+        match output {
+            crate::core::formatter::OutputFormat::Json => {
+                eprintln!("{}", crate::core::json::render_warning(warning));
+            }
+            crate::core::formatter::OutputFormat::Text => {
+                eprintln!("Warning: {}: {warning}", result.request_name)
+            }
+        }
+    }
+}
+
+fn print_redirect_chain(result: &RequestExecutionResult, show: bool) {
+    if result.redirects.is_empty() {
+        return;
+    }
+    let mut lines = vec![format!("Redirect chain for '{}':", result.request_name)];
+    for hop in &result.redirects {
+        lines.push(format!("  {} {} -> {}", hop.status, hop.url, hop.location));
+    }
+    if let Some(last) = result.redirects.last() {
+        lines.push(format!("  {} {}", result.status, last.location));
+    }
+    for line in &lines {
+        if show {
+            eprintln!("{line}");
+        } else {
+            Logger::debug(line);
+        }
+    }
+}
+
+pub fn execute_export(command: &ExportCommand) -> Result<(), Box<dyn std::error::Error>> {
+    match &command.format {
+        ExportFormat::Openapi(args) => execute_export_openapi(args),
+    }
+}
+
+fn execute_export_openapi(args: &ExportOpenApiArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let source_path = Path::new(&args.source.source);
+    let client = RqClient::default();
+    let (requests, parse_errors) = client.list_requests(source_path)?;
+    for e in &parse_errors {
+        eprintln!("Warning: Failed to parse: {e}");
+    }
+
+    let mut details = Vec::with_capacity(requests.len());
+    for request in &requests {
+        match client.get_request_details(
+            Path::new(&request.file),
+            &request.name,
+            args.env_args.environment.as_deref(),
+            true,
+            true,
+            &[],
+        ) {
+            Ok(detail) => details.push(detail),
+            Err(e) => eprintln!("Warning: Skipped {}: {e}", request.name),
+        }
+    }
+
+    let title = std::fs::canonicalize(source_path)
+        .ok()
+        .as_deref()
+        .and_then(Path::file_stem)
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "rq".to_string());
+    let rendered = crate::core::openapi::render(&title, &details);
+    for skipped in &rendered.skipped {
+        eprintln!("Warning: Skipped {skipped}");
+    }
+    let output = if crate::core::json::is_compact() {
+        serde_json::to_string(&rendered.document)?
+    } else {
+        serde_json::to_string_pretty(&rendered.document)?
+    };
+    println!("{output}");
+    Ok(())
+}
+
+pub async fn execute_ping(args: &PingArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let source_path = Path::new(&args.source.source);
+    let name = args
+        .request_name_args
+        .name
+        .as_deref()
+        .ok_or("Request name is required")?
+        .replace('.', "/");
+
+    let results = RqClient::default()
+        .ping(
+            source_path,
+            &name,
+            args.env_args.environment.as_deref(),
+            &args.variable,
+        )
+        .await?;
+
     let formatter = crate::core::formatter::get_formatter(&args.output.output);
-    let view = ExecutionResultsView { results };
+    let view = PingResultsView { results };
     print!("{}", formatter.format(&view));
 
     Ok(())
@@ -27,6 +27,18 @@ pub struct SourceArgs {
     pub source: String,
 }
 
+#[derive(Debug, Args)]
+pub struct RunSourceArgs {
+    #[arg(
+        short = 's',
+        long = "source",
+        default_value = ".",
+        help = "Path to the .rq file or directory, or '-' to read a .rq document from stdin",
+        value_parser = validators::validate_source_or_stdin
+    )]
+    pub source: String,
+}
+
 #[derive(Debug, Args)]
 pub struct EnvArgs {
     #[arg(
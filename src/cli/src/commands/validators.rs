@@ -1,6 +1,8 @@
 use lazy_static::lazy_static;
 use regex::Regex;
+use rq_lib::syntax::duration;
 use std::path::Path;
+use std::time::Duration;
 
 lazy_static! {
     static ref NAME_REGEX: Regex = Regex::new(r"^[a-zA-Z_][a-zA-Z0-9_./-]*$").unwrap();
@@ -14,6 +16,13 @@ pub fn validate_path_exists(path: &str) -> Result<String, String> {
     }
 }
 
+pub fn validate_source_or_stdin(path: &str) -> Result<String, String> {
+    if path == rq_lib::client::STDIN_SOURCE {
+        return Ok(path.to_string());
+    }
+    validate_path_exists(path)
+}
+
 pub fn validate_name(name: &str) -> Result<String, String> {
     if name.len() > 50 {
         return Err("Name must be 50 characters or less".to_string());
@@ -25,19 +34,23 @@ pub fn validate_name(name: &str) -> Result<String, String> {
 }
 
 pub fn validate_variable(variable: &str) -> Result<String, String> {
-    let parts: Vec<&str> = variable.splitn(2, '=').collect();
-    if parts.len() != 2 {
-        return Err("Variable must be in format NAME=VALUE".to_string());
-    }
+    let Some((spec, _)) = variable.split_once('=') else {
+        return Err("Variable must be in format NAME=VALUE or NAME:TYPE=VALUE".to_string());
+    };
 
-    let name = parts[0];
+    let name = spec.split_once(':').map_or(spec, |(name, _)| name);
     if let Err(e) = validate_name(name) {
         return Err(format!("Invalid variable name: {e}"));
     }
+    rq_lib::client::cli_variables::parse_cli_variable(variable)?;
 
     Ok(variable.to_string())
 }
 
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    duration::parse_duration(value).map_err(|e| format!("Invalid duration '{value}': {e}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,6 +23,8 @@ pub enum ExitCode {
     AuthError = 7,
     /// Variable resolution error
     VariableError = 8,
+    DeadlineError = 9,
+    AssertionError = 10,
 }
 
 impl ExitCode {
@@ -43,7 +45,10 @@ impl From<&Box<dyn std::error::Error>> for ExitCode {
                 RqError::NotADirectory(_) => ExitCode::FileError,
                 RqError::RequestNotFound(_) => ExitCode::NotFoundError,
                 RqError::EnvironmentNotFound(_) => ExitCode::ConfigError,
+                RqError::ProfileNotFound(_) => ExitCode::ConfigError,
                 RqError::Network(_) => ExitCode::NetworkError,
+                RqError::DeadlineExceeded(_) => ExitCode::DeadlineError,
+                RqError::AssertionFailed(_) => ExitCode::AssertionError,
                 RqError::Generic(_) => ExitCode::GeneralError,
             }
         } else {
@@ -1,8 +1,9 @@
 use clap::ValueEnum;
+use rq_lib::RequestExecutionResult;
 use serde::Serialize;
 use serde_json::Value;
 
-#[derive(Debug, Clone, Copy, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
 pub enum OutputFormat {
     Text,
     Json,
@@ -17,6 +18,15 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+impl From<rq_lib::syntax::parse_result::OutputFormat> for OutputFormat {
+    fn from(format: rq_lib::syntax::parse_result::OutputFormat) -> Self {
+        match format {
+            rq_lib::syntax::parse_result::OutputFormat::Text => OutputFormat::Text,
+            rq_lib::syntax::parse_result::OutputFormat::Json => OutputFormat::Json,
+        }
+    }
+}
+
 fn render_text_from_model<T: Serialize + ?Sized>(model: &T) -> String {
     let value = serde_json::to_value(model).unwrap_or(Value::Null);
     render_value(&value, 0)
@@ -96,7 +106,7 @@ impl Formatter {
     pub fn format<T: Serialize>(&self, model: &T) -> String {
         match self.engine {
             OutputFormat::Text => render_text_from_model(model),
-            OutputFormat::Json => serde_json::to_string_pretty(model).unwrap_or_default(),
+            OutputFormat::Json => super::json::render(model),
         }
     }
 
@@ -114,9 +124,7 @@ impl Formatter {
                     }
                 }
             }
-            OutputFormat::Json => {
-                serde_json::to_string_pretty(list).unwrap_or_else(|_| "[]".to_string())
-            }
+            OutputFormat::Json => super::json::render(list),
         }
     }
 }
@@ -124,3 +132,180 @@ impl Formatter {
 pub fn get_formatter(output_format: &OutputFormat) -> Formatter {
     Formatter::new(*output_format)
 }
+
+const TEMPLATE_FIELDS: &str = "{status}, {method}, {url}, {time_ms}, {body}, {name}, {header.Name}";
+
+#[derive(Debug, Clone, PartialEq)]
+enum TemplateField {
+    Status,
+    Method,
+    Url,
+    TimeMs,
+    Body,
+    Name,
+    Header(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TemplatePart {
+    Literal(String),
+    Field(TemplateField),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputTemplate {
+    parts: Vec<TemplatePart>,
+}
+
+impl OutputTemplate {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let name = read_placeholder(&mut chars)?;
+                    if !literal.is_empty() {
+                        parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(TemplatePart::Field(parse_template_field(&name)?));
+                }
+                '}' => {
+                    return Err(
+                        "Unmatched '}' in output template (use '}}' for a literal brace)"
+                            .to_string(),
+                    )
+                }
+                _ => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(TemplatePart::Literal(literal));
+        }
+        Ok(Self { parts })
+    }
+
+    pub fn render(&self, result: &RequestExecutionResult) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                TemplatePart::Literal(text) => text.clone(),
+                TemplatePart::Field(field) => render_template_field(field, result),
+            })
+            .collect()
+    }
+}
+
+fn read_placeholder(chars: &mut impl Iterator<Item = char>) -> Result<String, String> {
+    let mut name = String::new();
+    for c in chars {
+        if c == '}' {
+            return Ok(name);
+        }
+        name.push(c);
+    }
+    Err(format!(
+        "Unclosed placeholder '{{{name}' in output template"
+    ))
+}
+
+fn parse_template_field(name: &str) -> Result<TemplateField, String> {
+    match name.trim() {
+        "status" => Ok(TemplateField::Status),
+        "method" => Ok(TemplateField::Method),
+        "url" => Ok(TemplateField::Url),
+        "time_ms" => Ok(TemplateField::TimeMs),
+        "body" => Ok(TemplateField::Body),
+        "name" => Ok(TemplateField::Name),
+        other => match other.strip_prefix("header.") {
+            Some(header) if !header.is_empty() => Ok(TemplateField::Header(header.to_string())),
+            _ => Err(format!(
+                "Unknown placeholder '{{{other}}}' in output template. Expected one of: {TEMPLATE_FIELDS}"
+            )),
+        },
+    }
+}
+
+fn render_template_field(field: &TemplateField, result: &RequestExecutionResult) -> String {
+    match field {
+        TemplateField::Status => result.status.to_string(),
+        TemplateField::Method => result.method.clone(),
+        TemplateField::Url => result.url.clone(),
+        TemplateField::TimeMs => result.elapsed_ms.to_string(),
+        TemplateField::Body => result.body.clone(),
+        TemplateField::Name => result.request_name.clone(),
+        TemplateField::Header(name) => result
+            .response_headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn result() -> RequestExecutionResult {
+        RequestExecutionResult {
+            request_name: "get_user".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/users/1".to_string(),
+            status: 200,
+            elapsed_ms: 42,
+            request_headers: HashMap::new(),
+            response_headers: HashMap::from([(
+                "content-type".to_string(),
+                "application/json".to_string(),
+            )]),
+            body: "{\"id\":1}".to_string(),
+            redirects: Vec::new(),
+            warnings: Vec::new(),
+            output: None,
+            assertion_failures: Vec::new(),
+            retries: None,
+        }
+    }
+
+    #[test]
+    fn test_render_template_fields() {
+        let template =
+            OutputTemplate::parse("{name}: {status} {method} {url} {time_ms}ms").unwrap();
+        assert_eq!(
+            template.render(&result()),
+            "get_user: 200 GET http://localhost/users/1 42ms"
+        );
+    }
+
+    #[test]
+    fn test_render_template_header_and_body() {
+        let template =
+            OutputTemplate::parse("{header.Content-Type}|{header.X-Missing}|{body}").unwrap();
+        assert_eq!(template.render(&result()), "application/json||{\"id\":1}");
+    }
+
+    #[test]
+    fn test_render_template_escaped_braces() {
+        let template = OutputTemplate::parse("{{{status}}}").unwrap();
+        assert_eq!(template.render(&result()), "{200}");
+    }
+
+    #[test]
+    fn test_parse_template_rejects_unknown_placeholder() {
+        let err = OutputTemplate::parse("{code}").unwrap_err();
+        assert!(err.contains("Unknown placeholder '{code}'"));
+        assert!(OutputTemplate::parse("{status}}").is_err());
+        assert!(OutputTemplate::parse("{status").is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::sync::OnceLock;
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+static COMPACT: OnceLock<bool> = OnceLock::new();
+
+#[derive(Serialize)]
+struct Envelope<'a, T: Serialize + ?Sized> {
+    schema_version: u32,
+    data: &'a T,
+}
+
+pub fn init(compact: bool) {
+    let _ = COMPACT.get_or_init(|| compact);
+}
+
+pub fn is_compact() -> bool {
+    COMPACT.get().copied().unwrap_or(false)
+}
+
+pub fn render<T: Serialize + ?Sized>(data: &T) -> String {
+    let envelope = Envelope {
+        schema_version: SCHEMA_VERSION,
+        data,
+    };
+    let rendered = if is_compact() {
+        serde_json::to_string(&envelope)
+    } else {
+        serde_json::to_string_pretty(&envelope)
+    };
+    rendered.unwrap_or_else(|e| {
+        render_error_value(json!({ "type": "generic", "message": e.to_string() }))
+    })
+}
+
+pub fn render_error(error: &(dyn std::error::Error + 'static)) -> String {
+    render_error_value(rq_lib::error::error_to_json_value(error))
+}
+
+pub fn render_warning(message: &str) -> String {
+    json!({ "schema_version": SCHEMA_VERSION, "warning": { "message": message } }).to_string()
+}
+
+fn render_error_value(detail: Value) -> String {
+    json!({ "schema_version": SCHEMA_VERSION, "error": detail }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rq_lib::error::RqError;
+
+    #[test]
+    fn test_render_wraps_data_in_envelope() {
+        let rendered: Value = serde_json::from_str(&render(&vec!["a", "b"])).unwrap();
+        assert_eq!(
+            rendered,
+            json!({ "schema_version": SCHEMA_VERSION, "data": ["a", "b"] })
+        );
+    }
+
+    #[test]
+    fn test_render_error_includes_schema_version() {
+        let error = RqError::Validation("bad input".to_string());
+        let rendered: Value = serde_json::from_str(&render_error(&error)).unwrap();
+        assert_eq!(rendered["schema_version"], SCHEMA_VERSION);
+        assert_eq!(rendered["error"]["type"], "validation");
+        assert_eq!(rendered["error"]["message"], "bad input");
+    }
+}
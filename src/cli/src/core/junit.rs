@@ -0,0 +1,135 @@
+use rq_lib::RequestExecutionResult;
+
+pub fn render(suite_name: &str, results: &[RequestExecutionResult]) -> String {
+    let failures = results
+        .iter()
+        .filter(|r| failure_message(r).is_some())
+        .count();
+    let total_time = results.iter().map(|r| r.elapsed_ms).sum::<u64>();
+    let suite_attrs = format!(
+        "name=\"{}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" time=\"{}\"",
+        escape(suite_name),
+        results.len(),
+        seconds(total_time)
+    );
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!("<testsuites {suite_attrs}>\n"));
+    xml.push_str(&format!("  <testsuite {suite_attrs}>\n"));
+    for result in results {
+        xml.push_str(&render_test_case(suite_name, result));
+    }
+    xml.push_str("  </testsuite>\n");
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn render_test_case(suite_name: &str, result: &RequestExecutionResult) -> String {
+    let open = format!(
+        "    <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+        escape(&result.request_name),
+        escape(suite_name),
+        seconds(result.elapsed_ms)
+    );
+    match failure_message(result) {
+        Some((message, kind)) => format!(
+            "{open}>\n      <failure message=\"{}\" type=\"{kind}\">{} {}</failure>\n    </testcase>\n",
+            escape(&message),
+            escape(&result.method),
+            escape(&result.url)
+        ),
+        None => format!("{open}/>\n"),
+    }
+}
+
+fn failure_message(result: &RequestExecutionResult) -> Option<(String, &'static str)> {
+    if !result.assertion_failures.is_empty() {
+        let failures: Vec<String> = result
+            .assertion_failures
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        return Some((
+            format!("Assertion failed: {}", failures.join("; ")),
+            "assertion",
+        ));
+    }
+    (result.status >= 400).then(|| (format!("Unexpected status {}", result.status), "status"))
+}
+
+fn seconds(ms: u64) -> String {
+    format!("{:.3}", ms as f64 / 1000.0)
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rq_lib::AssertionFailure;
+    use std::collections::HashMap;
+
+    fn result(name: &str, status: u16, elapsed_ms: u64) -> RequestExecutionResult {
+        RequestExecutionResult {
+            request_name: name.to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/items?a=1&b=2".to_string(),
+            status,
+            elapsed_ms,
+            request_headers: HashMap::new(),
+            response_headers: HashMap::new(),
+            body: String::new(),
+            redirects: Vec::new(),
+            warnings: Vec::new(),
+            output: None,
+            assertion_failures: Vec::new(),
+            retries: None,
+        }
+    }
+
+    #[test]
+    fn test_render_counts_failures_and_time() {
+        let xml = render(
+            "api.rq",
+            &[result("ok", 200, 120), result("broken", 500, 30)],
+        );
+
+        assert!(xml.contains(
+            "<testsuite name=\"api.rq\" tests=\"2\" failures=\"1\" errors=\"0\" time=\"0.150\">"
+        ));
+        assert!(xml.contains("<testcase name=\"ok\" classname=\"api.rq\" time=\"0.120\"/>"));
+        assert!(xml.contains("<failure message=\"Unexpected status 500\" type=\"status\">"));
+    }
+
+    #[test]
+    fn test_render_reports_assertion_failures() {
+        let mut failed = result("checked", 200, 10);
+        failed.assertion_failures.push(AssertionFailure {
+            assertion: "body.$.id == 1".to_string(),
+            expected: "1".to_string(),
+            actual: "2".to_string(),
+        });
+        let xml = render("api.rq", &[failed]);
+
+        assert!(xml.contains("failures=\"1\""));
+        assert!(xml.contains(
+            "<failure message=\"Assertion failed: body.$.id == 1 (expected 1, got 2)\" type=\"assertion\">"
+        ));
+    }
+
+    #[test]
+    fn test_render_escapes_xml() {
+        let xml = render("a<b>", &[result("x\"y", 404, 0)]);
+
+        assert!(xml.contains("name=\"a&lt;b&gt;\""));
+        assert!(xml.contains("name=\"x&quot;y\""));
+        assert!(xml.contains("http://localhost/items?a=1&amp;b=2"));
+    }
+}
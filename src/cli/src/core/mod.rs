@@ -1,5 +1,12 @@
 pub mod error;
 pub mod exit_code;
 pub mod formatter;
+pub mod json;
+pub mod junit;
 pub mod logger;
+pub mod openapi;
+pub mod paths;
+pub mod resume;
+pub mod sort;
+pub mod transform;
 pub mod version;
@@ -0,0 +1,216 @@
+use rq_lib::client::models::RequestDetails;
+use serde_json::{json, Map, Value};
+
+const OPENAPI_VERSION: &str = "3.0.3";
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+const RESERVED_HEADERS: [&str; 3] = ["accept", "content-type", "authorization"];
+
+pub struct OpenApiDocument {
+    pub document: Value,
+    pub skipped: Vec<String>,
+}
+
+struct UrlParts<'a> {
+    server: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+pub fn render(title: &str, requests: &[RequestDetails]) -> OpenApiDocument {
+    let mut servers: Vec<&str> = Vec::new();
+    let mut paths = Map::new();
+    let mut skipped = Vec::new();
+
+    for request in requests {
+        let method = request.method.to_lowercase();
+        if !METHODS.contains(&method.as_str()) {
+            skipped.push(format!(
+                "{}: method {} is not supported by OpenAPI",
+                request.name, request.method
+            ));
+            continue;
+        }
+        let parts = split_url(&request.url);
+        if let Some(server) = parts.server.filter(|s| !servers.contains(s)) {
+            servers.push(server);
+        }
+        let item = paths
+            .entry(parts.path.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        let Value::Object(item) = item else {
+            continue;
+        };
+        if let Some(existing) = item.get(&method).and_then(|op| op["operationId"].as_str()) {
+            skipped.push(format!(
+                "{}: {} {} is already described by {existing}",
+                request.name, request.method, parts.path
+            ));
+            continue;
+        }
+        item.insert(method, operation(request, parts.query));
+    }
+
+    let servers: Vec<Value> = servers.iter().map(|url| json!({ "url": url })).collect();
+    let mut document = json!({
+        "openapi": OPENAPI_VERSION,
+        "info": { "title": title, "version": "1.0.0" },
+        "paths": paths,
+    });
+    if !servers.is_empty() {
+        document["servers"] = Value::Array(servers);
+    }
+    OpenApiDocument { document, skipped }
+}
+
+fn operation(request: &RequestDetails, query: Option<&str>) -> Value {
+    let mut parameters: Vec<Value> = query
+        .into_iter()
+        .flat_map(|q| q.split('&'))
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            json!({ "name": name, "in": "query", "example": value })
+        })
+        .collect();
+    parameters.extend(
+        request
+            .headers
+            .iter()
+            .filter(|(name, _)| !RESERVED_HEADERS.contains(&name.to_lowercase().as_str()))
+            .map(|(name, value)| json!({ "name": name, "in": "header", "example": value })),
+    );
+
+    let mut operation = json!({
+        "operationId": request.name,
+        "responses": { "default": { "description": "Response" } },
+    });
+    if let Some(description) = &request.description {
+        operation["summary"] = json!(description);
+    }
+    if !parameters.is_empty() {
+        operation["parameters"] = Value::Array(parameters);
+    }
+    if let Some(body) = &request.body {
+        operation["requestBody"] = request_body(body, &request.headers);
+    }
+    operation
+}
+
+fn request_body(body: &str, headers: &[(String, String)]) -> Value {
+    let parsed = serde_json::from_str::<Value>(body).ok();
+    let content_type = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| value.clone())
+        .unwrap_or_else(|| match parsed {
+            Some(_) => "application/json".to_string(),
+            None => "text/plain".to_string(),
+        });
+    let example = parsed.unwrap_or_else(|| Value::String(body.to_string()));
+    json!({ "content": { content_type: { "example": example } } })
+}
+
+fn split_url(url: &str) -> UrlParts<'_> {
+    let (url, query) = match url.split_once('?') {
+        Some((url, query)) => (url, Some(query)),
+        None => (url, None),
+    };
+    let Some(scheme_end) = url.find("://").map(|i| i + 3) else {
+        return UrlParts {
+            server: None,
+            path: if url.is_empty() { "/" } else { url },
+            query,
+        };
+    };
+    match url[scheme_end..].find('/') {
+        Some(slash) => UrlParts {
+            server: Some(&url[..scheme_end + slash]),
+            path: &url[scheme_end + slash..],
+            query,
+        },
+        None => UrlParts {
+            server: Some(url),
+            path: "/",
+            query,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(name: &str, method: &str, url: &str) -> RequestDetails {
+        RequestDetails {
+            name: name.to_string(),
+            auth_name: None,
+            auth_type: None,
+            url: url.to_string(),
+            headers: vec![
+                ("Accept".to_string(), "application/json".to_string()),
+                ("X-Trace".to_string(), "abc".to_string()),
+            ],
+            method: method.to_string(),
+            body: None,
+            timeout: None,
+            description: Some("List users".to_string()),
+            required_variables: Vec::new(),
+            file: "users.rq".to_string(),
+            line: 0,
+            character: 0,
+        }
+    }
+
+    #[test]
+    fn test_render_single_get() {
+        let rendered = render(
+            "users",
+            &[request(
+                "list_users",
+                "GET",
+                "https://api.example.com/v1/users?page=2",
+            )],
+        );
+
+        assert!(rendered.skipped.is_empty());
+        assert_eq!(
+            rendered.document,
+            json!({
+                "openapi": "3.0.3",
+                "info": { "title": "users", "version": "1.0.0" },
+                "servers": [{ "url": "https://api.example.com" }],
+                "paths": {
+                    "/v1/users": {
+                        "get": {
+                            "operationId": "list_users",
+                            "summary": "List users",
+                            "parameters": [
+                                { "name": "page", "in": "query", "example": "2" },
+                                { "name": "X-Trace", "in": "header", "example": "abc" }
+                            ],
+                            "responses": { "default": { "description": "Response" } }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_render_skips_duplicate_operation() {
+        let rendered = render(
+            "users",
+            &[
+                request("first", "GET", "http://localhost/users"),
+                request("second", "GET", "http://localhost/users"),
+            ],
+        );
+
+        assert_eq!(
+            rendered.skipped,
+            vec!["second: GET /users is already described by first"]
+        );
+    }
+}
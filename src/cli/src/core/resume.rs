@@ -0,0 +1,112 @@
+use crate::core::error::RqError;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+#[derive(Serialize, Deserialize, Default)]
+struct StateFile {
+    source: String,
+    completed: BTreeSet<String>,
+}
+
+pub struct ResumeState {
+    path: PathBuf,
+    state: Mutex<StateFile>,
+}
+
+impl ResumeState {
+    pub fn load(source: &Path) -> Result<Self, RqError> {
+        let dir = crate::core::paths::cache_dir()
+            .ok_or_else(|| {
+                RqError::Validation(
+                    "--resume needs a cache directory, but none could be determined".to_string(),
+                )
+            })?
+            .join("resume");
+        let source = std::fs::canonicalize(source)
+            .unwrap_or_else(|_| source.to_path_buf())
+            .display()
+            .to_string();
+        Self::load_from(&dir, source)
+    }
+
+    fn load_from(dir: &Path, source: String) -> Result<Self, RqError> {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        let path = dir.join(format!("{:016x}.json", hasher.finish()));
+        let state = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str::<StateFile>(&content)
+                .ok()
+                .filter(|state| state.source == source)
+                .unwrap_or_default(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => StateFile::default(),
+            Err(e) => return Err(RqError::Io(e)),
+        };
+        Ok(Self {
+            path,
+            state: Mutex::new(StateFile { source, ..state }),
+        })
+    }
+
+    pub fn is_completed(&self, file: &Path, request_name: &str) -> bool {
+        self.state
+            .lock()
+            .is_ok_and(|state| state.completed.contains(&key(file, request_name)))
+    }
+
+    pub fn completed_count(&self) -> usize {
+        self.state.lock().map_or(0, |state| state.completed.len())
+    }
+
+    pub fn record(&self, file: &Path, request_name: &str) -> Result<(), String> {
+        let mut state = self.state.lock().map_err(|e| e.to_string())?;
+        state.completed.insert(key(file, request_name));
+        let content = serde_json::to_string_pretty(&*state).map_err(|e| e.to_string())?;
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&self.path, content)
+            .map_err(|e| format!("Failed to write {}: {e}", self.path.display()))
+    }
+
+    pub fn clear(&self) -> Result<(), String> {
+        match std::fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(format!("Failed to remove {}: {e}", self.path.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn key(file: &Path, request_name: &str) -> String {
+    format!("{}::{request_name}", rq_lib::paths::clean_path(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_persists_and_clear_removes() {
+        let dir = std::env::temp_dir().join("rq_resume_state_unit");
+        let _ = std::fs::remove_dir_all(&dir);
+        let file = Path::new("api/users.rq");
+
+        let state = ResumeState::load_from(&dir, "/work/api".to_string()).unwrap();
+        assert!(!state.is_completed(file, "list"));
+        state.record(file, "list").unwrap();
+
+        let reloaded = ResumeState::load_from(&dir, "/work/api".to_string()).unwrap();
+        assert!(reloaded.is_completed(file, "list"));
+        assert!(!reloaded.is_completed(file, "create"));
+        assert_eq!(reloaded.completed_count(), 1);
+
+        reloaded.clear().unwrap();
+        let cleared = ResumeState::load_from(&dir, "/work/api".to_string()).unwrap();
+        assert_eq!(cleared.completed_count(), 0);
+    }
+}
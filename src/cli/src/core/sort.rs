@@ -0,0 +1,68 @@
+use clap::ValueEnum;
+use rq_lib::RequestExecutionResult;
+use std::cmp::Reverse;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum SortBy {
+    Status,
+    Time,
+    Name,
+}
+
+pub fn sort_results(results: &mut [RequestExecutionResult], by: SortBy) {
+    match by {
+        SortBy::Status => results.sort_by_key(|r| Reverse(r.status)),
+        SortBy::Time => results.sort_by_key(|r| Reverse(r.elapsed_ms)),
+        SortBy::Name => results.sort_by(|a, b| a.request_name.cmp(&b.request_name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn result(name: &str, status: u16, elapsed_ms: u64) -> RequestExecutionResult {
+        RequestExecutionResult {
+            request_name: name.to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/".to_string(),
+            status,
+            elapsed_ms,
+            request_headers: HashMap::new(),
+            response_headers: HashMap::new(),
+            body: String::new(),
+            redirects: Vec::new(),
+            warnings: Vec::new(),
+            output: None,
+            assertion_failures: Vec::new(),
+            retries: None,
+        }
+    }
+
+    fn sorted(by: SortBy) -> Vec<String> {
+        let mut results = vec![
+            result("list", 200, 40),
+            result("create", 500, 10),
+            result("show", 404, 90),
+            result("delete", 200, 40),
+        ];
+        sort_results(&mut results, by);
+        results.into_iter().map(|r| r.request_name).collect()
+    }
+
+    #[test]
+    fn test_sort_by_status_puts_failures_first() {
+        assert_eq!(sorted(SortBy::Status), ["create", "show", "list", "delete"]);
+    }
+
+    #[test]
+    fn test_sort_by_time_puts_slowest_first() {
+        assert_eq!(sorted(SortBy::Time), ["show", "list", "delete", "create"]);
+    }
+
+    #[test]
+    fn test_sort_by_name() {
+        assert_eq!(sorted(SortBy::Name), ["create", "delete", "list", "show"]);
+    }
+}
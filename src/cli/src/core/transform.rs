@@ -0,0 +1,234 @@
+use serde_json::Value;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(i64),
+    Iterate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseTransform {
+    stages: Vec<Vec<Segment>>,
+}
+
+impl ResponseTransform {
+    pub fn parse(filter: &str) -> Result<Self, String> {
+        let stages = split_pipeline(filter)?
+            .iter()
+            .map(|stage| parse_stage(stage))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { stages })
+    }
+
+    pub fn apply(&self, body: &str) -> Result<String, String> {
+        let input: Value = serde_json::from_str(body)
+            .map_err(|e| format!("response body is not valid JSON: {e}"))?;
+        let mut values = vec![input];
+        for stage in &self.stages {
+            let mut next = Vec::new();
+            for value in values {
+                next.extend(apply_stage(stage, value)?);
+            }
+            values = next;
+        }
+        Ok(values
+            .iter()
+            .map(render_output)
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+fn split_pipeline(filter: &str) -> Result<Vec<String>, String> {
+    let mut stages = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    for c in filter.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '[' if !in_string => depth += 1,
+            ']' if !in_string => depth = depth.saturating_sub(1),
+            '|' if !in_string && depth == 0 => {
+                stages.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    stages.push(current);
+    if stages.iter().any(|stage| stage.trim().is_empty()) {
+        return Err(format!(
+            "Invalid transform '{filter}': empty filter in pipeline"
+        ));
+    }
+    Ok(stages)
+}
+
+fn parse_stage(stage: &str) -> Result<Vec<Segment>, String> {
+    let stage = stage.trim();
+    let invalid = |reason: &str| format!("Invalid transform '{stage}': {reason}");
+    let mut chars = stage.chars().peekable();
+    if chars.next() != Some('.') {
+        return Err(invalid("a filter must start with '.'"));
+    }
+    let mut segments = Vec::new();
+    if chars.peek().is_some_and(|c| is_identifier_char(*c)) {
+        segments.push(Segment::Field(read_identifier(&mut chars)));
+    }
+    while let Some(c) = chars.next() {
+        match c {
+            '.' if chars.peek() == Some(&'[') => {}
+            '.' if chars.peek().is_some_and(|c| is_identifier_char(*c)) => {
+                segments.push(Segment::Field(read_identifier(&mut chars)));
+            }
+            '[' => segments.push(read_bracket(&mut chars).map_err(|e| invalid(&e))?),
+            other => return Err(invalid(&format!("unexpected '{other}'"))),
+        }
+    }
+    Ok(segments)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn read_identifier(chars: &mut Peekable<Chars>) -> String {
+    let mut name = String::new();
+    while let Some(c) = chars.next_if(|c| is_identifier_char(*c)) {
+        name.push(c);
+    }
+    name
+}
+
+fn read_bracket(chars: &mut Peekable<Chars>) -> Result<Segment, String> {
+    let mut inner = String::new();
+    let mut in_string = false;
+    for c in chars.by_ref() {
+        match c {
+            '"' => in_string = !in_string,
+            ']' if !in_string => return parse_bracket(inner.trim()),
+            _ => {}
+        }
+        inner.push(c);
+    }
+    Err("unclosed '['".to_string())
+}
+
+fn parse_bracket(inner: &str) -> Result<Segment, String> {
+    if inner.is_empty() {
+        return Ok(Segment::Iterate);
+    }
+    if let Some(name) = inner
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        return Ok(Segment::Field(name.to_string()));
+    }
+    inner
+        .parse::<i64>()
+        .map(Segment::Index)
+        .map_err(|_| format!("expected an index, a quoted key or nothing inside '[{inner}]'"))
+}
+
+fn apply_stage(segments: &[Segment], input: Value) -> Result<Vec<Value>, String> {
+    let mut values = vec![input];
+    for segment in segments {
+        let mut next = Vec::new();
+        for value in values {
+            next.extend(apply_segment(segment, value)?);
+        }
+        values = next;
+    }
+    Ok(values)
+}
+
+fn apply_segment(segment: &Segment, value: Value) -> Result<Vec<Value>, String> {
+    match (segment, value) {
+        (Segment::Field(_) | Segment::Index(_), Value::Null) => Ok(vec![Value::Null]),
+        (Segment::Field(name), Value::Object(mut map)) => {
+            Ok(vec![map.remove(name).unwrap_or(Value::Null)])
+        }
+        (Segment::Index(index), Value::Array(mut items)) => {
+            let len = items.len() as i64;
+            let position = if *index < 0 { len + index } else { *index };
+            if (0..len).contains(&position) {
+                Ok(vec![items.swap_remove(position as usize)])
+            } else {
+                Ok(vec![Value::Null])
+            }
+        }
+        (Segment::Iterate, Value::Array(items)) => Ok(items),
+        (Segment::Iterate, Value::Object(map)) => Ok(map.into_iter().map(|(_, v)| v).collect()),
+        (Segment::Field(name), other) => Err(format!(
+            "cannot index {} with \"{name}\"",
+            type_name(&other)
+        )),
+        (Segment::Index(index), other) => {
+            Err(format!("cannot index {} with {index}", type_name(&other)))
+        }
+        (Segment::Iterate, other) => Err(format!("cannot iterate over {}", type_name(&other))),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn render_output(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => serde_json::to_string_pretty(other).unwrap_or_else(|_| other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERS: &str = r#"[{"name":"alice","tags":["a","b"]},{"name":"bob","tags":[]}]"#;
+
+    fn run(filter: &str, body: &str) -> Result<String, String> {
+        ResponseTransform::parse(filter)?.apply(body)
+    }
+
+    #[test]
+    fn test_transform_iterates_and_projects() {
+        assert_eq!(run(".[] | .name", USERS).unwrap(), "alice\nbob");
+        assert_eq!(run(".[0].tags[-1]", USERS).unwrap(), "b");
+        assert_eq!(run(r#".[1]["name"]"#, USERS).unwrap(), "bob");
+        assert_eq!(run(".[5]", USERS).unwrap(), "null");
+    }
+
+    #[test]
+    fn test_transform_identity_pretty_prints() {
+        assert_eq!(run(".", r#"{"id":1}"#).unwrap(), "{\n  \"id\": 1\n}");
+        assert_eq!(run(".missing.deeper", r#"{"id":1}"#).unwrap(), "null");
+    }
+
+    #[test]
+    fn test_transform_rejects_invalid_filters() {
+        assert!(ResponseTransform::parse("name").is_err());
+        assert!(ResponseTransform::parse(".[0").is_err());
+        assert!(ResponseTransform::parse(".[] |").is_err());
+        assert!(ResponseTransform::parse(".[abc]").is_err());
+    }
+
+    #[test]
+    fn test_transform_reports_type_errors() {
+        let err = run(".[] | .name", r#"{"a":"x"}"#).unwrap_err();
+        assert_eq!(err, "cannot index string with \"name\"");
+        assert!(run(".", "not json").is_err());
+    }
+}
@@ -5,7 +5,6 @@ mod commands;
 mod core;
 
 use commands::Commands;
-use core::error::error_to_json;
 use core::exit_code::ExitCode;
 use core::formatter::OutputFormat;
 
@@ -19,6 +18,13 @@ struct Args {
     #[arg(short, long, help = "Enable debug logging", global = true)]
     debug: bool,
 
+    #[arg(
+        long,
+        help = "Print JSON output on a single line instead of pretty-printed",
+        global = true
+    )]
+    compact_json: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -28,6 +34,13 @@ struct Args {
 struct DefaultArgs {
     #[arg(short, long, help = "Enable debug logging", global = true)]
     debug: bool,
+
+    #[arg(
+        long,
+        help = "Print JSON output on a single line instead of pretty-printed",
+        global = true
+    )]
+    compact_json: bool,
     #[command(flatten)]
     run_args: commands::request::RunArgs,
 }
@@ -37,7 +50,7 @@ async fn main() {
     let output_format = extract_output_format(&std::env::args().collect::<Vec<_>>());
     if let Err(e) = run().await {
         match output_format {
-            OutputFormat::Json => eprintln!("{}", error_to_json(e.as_ref())),
+            OutputFormat::Json => eprintln!("{}", core::json::render_error(e.as_ref())),
             OutputFormat::Text => eprintln!("Error: {e}"),
         }
         let exit_code = ExitCode::from(&e);
@@ -74,6 +87,7 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
     if is_subcommand {
         let args = Args::parse();
         crate::core::logger::Logger::init(args.debug);
+        crate::core::json::init(args.compact_json);
         match args.command {
             Some(Commands::Check(check_args)) => commands::check::execute(&check_args),
             Some(Commands::Env(env_command)) => match env_command.command {
@@ -91,6 +105,9 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
                 commands::auth::AuthSubcommand::Show(show_args) => {
                     commands::auth::execute_show(&show_args)
                 }
+                commands::auth::AuthSubcommand::Validate(validate_args) => {
+                    commands::auth::execute_validate(&validate_args)
+                }
             },
             Some(Commands::Ep(ep_command)) => match ep_command.command {
                 commands::ep::EpSubcommand::List(list_args) => {
@@ -124,6 +141,12 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
                 commands::request::RequestSubcommand::Run(run_args) => {
                     commands::request::execute_run(&run_args).await
                 }
+                commands::request::RequestSubcommand::Ping(ping_args) => {
+                    commands::request::execute_ping(&ping_args).await
+                }
+                commands::request::RequestSubcommand::Export(export_command) => {
+                    commands::request::execute_export(&export_command)
+                }
             },
             None => Ok(()),
         }
@@ -139,7 +162,8 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
                 }
                 let default_args = DefaultArgs::parse();
                 crate::core::logger::Logger::init(default_args.debug);
-                commands::request::execute_run(&default_args.run_args).await
+                crate::core::json::init(default_args.compact_json);
+                commands::request::execute_default_run(default_args.run_args).await
             }
             Err(e)
                 if e.kind() == clap::error::ErrorKind::DisplayHelp
@@ -151,7 +175,8 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
             Err(_) => {
                 let default_args = DefaultArgs::parse();
                 crate::core::logger::Logger::init(default_args.debug);
-                commands::request::execute_run(&default_args.run_args).await
+                crate::core::json::init(default_args.compact_json);
+                commands::request::execute_default_run(default_args.run_args).await
             }
         }
     }
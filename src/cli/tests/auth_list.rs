@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 use std::fs;
 
@@ -53,7 +53,7 @@ fn test_auth_list_json_output() -> Result<(), Box<dyn std::error::Error>> {
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if !json.is_array() {
         return Err(format!("Expected JSON array, got: {json}").into());
@@ -93,7 +93,7 @@ fn test_auth_list_json_case_insensitive() -> Result<(), Box<dyn std::error::Erro
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if !json.is_array() {
         return Err(format!("Expected JSON array, got: {json}").into());
@@ -254,7 +254,7 @@ rq test("http://localhost:8080/test");
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let items = json.as_array().expect("Expected JSON array");
 
@@ -324,7 +324,7 @@ rq test("http://localhost:8080/test");
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let items = json.as_array().expect("Expected JSON array");
 
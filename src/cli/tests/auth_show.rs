@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 use std::fs;
 
@@ -68,7 +68,7 @@ fn test_auth_show_bearer_json() -> Result<(), Box<dyn std::error::Error>> {
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("Auth Configuration").and_then(|v| v.as_str()) != Some("bearer_auth") {
         return Err(format!("Expected name 'bearer_auth', got: {json}").into());
@@ -171,7 +171,7 @@ fn test_auth_show_oauth2_json() -> Result<(), Box<dyn std::error::Error>> {
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("Auth Configuration").and_then(|v| v.as_str()) != Some("github_oauth") {
         return Err(format!("Expected name 'github_oauth', got: {json}").into());
@@ -320,7 +320,7 @@ auth test_bearer(auth_type.bearer) {
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let token = json
         .get("Fields")
@@ -388,7 +388,7 @@ auth local(auth_type.oauth2_authorization_code) {
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let fields = json
         .get("Fields")
@@ -472,7 +472,7 @@ auth test_bearer(auth_type.bearer) {
         .output()?;
 
     let stdout_local = String::from_utf8_lossy(&output_local.stdout);
-    let json_local: Value = serde_json::from_str(&stdout_local)?;
+    let json_local: Value = json_data(&stdout_local)?;
 
     let token_local = json_local
         .get("Fields")
@@ -502,7 +502,7 @@ auth test_bearer(auth_type.bearer) {
     fs::remove_dir_all(&temp_dir).ok();
 
     let stdout_dev = String::from_utf8_lossy(&output_dev.stdout);
-    let json_dev: Value = serde_json::from_str(&stdout_dev)?;
+    let json_dev: Value = json_data(&stdout_dev)?;
 
     let token_dev = json_dev
         .get("Fields")
@@ -554,7 +554,7 @@ fn test_auth_show_unresolved_no_var_interpolation() -> Result<(), Box<dyn std::e
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let token = json
         .get("Fields")
@@ -661,7 +661,7 @@ auth minimal_oauth(auth_type.oauth2_authorization_code) {
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let fields = json
         .get("Fields")
@@ -669,10 +669,10 @@ auth minimal_oauth(auth_type.oauth2_authorization_code) {
 
     // Check that defaults were applied
     if fields.get("redirect_uri").and_then(|v| v.as_str())
-        != Some("vscode://rq-lang.rq-language/oauth-callback")
+        != Some("http://127.0.0.1:8765/oauth-callback")
     {
         return Err(format!(
-            "Expected default redirect_uri 'vscode://rq-lang.rq-language/oauth-callback', got: {:?}",
+            "Expected default redirect_uri 'http://127.0.0.1:8765/oauth-callback', got: {:?}",
             fields.get("redirect_uri")
         )
         .into());
@@ -732,7 +732,7 @@ auth custom_oauth(auth_type.oauth2_authorization_code) {
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let fields = json
         .get("Fields")
@@ -783,7 +783,7 @@ fn test_auth_show_interpolation_from_let() -> Result<(), Box<dyn std::error::Err
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let token = json
         .get("Fields")
@@ -825,7 +825,7 @@ fn test_auth_show_interpolation_from_env_file() -> Result<(), Box<dyn std::error
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let token = json
         .get("Fields")
@@ -867,7 +867,7 @@ fn test_auth_show_interpolation_combined() -> Result<(), Box<dyn std::error::Err
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let token = json
         .get("Fields")
@@ -909,7 +909,7 @@ fn test_auth_show_bare_identifier_reference() -> Result<(), Box<dyn std::error::
         .into());
     }
 
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let token = json
         .get("Fields")
@@ -1042,3 +1042,55 @@ fn test_error_line_number_bare_variable() -> Result<(), Box<dyn std::error::Erro
 
     Ok(())
 }
+
+#[test]
+fn test_auth_show_marks_defaulted_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = std::env::temp_dir().join(format!("rq_test_defaulted_{}", std::process::id()));
+    fs::create_dir_all(&temp_dir)?;
+
+    fs::write(
+        temp_dir.join("test.rq"),
+        r#"
+auth partial_oauth(auth_type.oauth2_authorization_code) {
+    client_id: "test-client-id",
+    authorization_url: "https://auth.example.com/authorize",
+    token_url: "https://auth.example.com/token",
+    code_challenge_method: "plain"
+}
+"#,
+    )?;
+    let source = temp_dir.to_str().ok_or("invalid temp dir")?;
+    let show = |extra: &[&str]| {
+        rq_cmd()
+            .args(["auth", "show", "-n", "partial_oauth", "-s", source])
+            .args(extra)
+            .output()
+    };
+
+    let json_output = show(&["-o", "json"])?;
+    let text_output = show(&[])?;
+    let hidden_output = show(&["--no-defaults", "-o", "json"])?;
+    fs::remove_dir_all(&temp_dir).ok();
+
+    let json: Value = json_data(&String::from_utf8_lossy(&json_output.stdout))?;
+    if json["Defaulted Fields"] != serde_json::json!(["redirect_uri"]) {
+        return Err(format!("Expected only redirect_uri to be defaulted, got: {json}").into());
+    }
+
+    let text = String::from_utf8_lossy(&text_output.stdout);
+    if !text.contains("redirect_uri: http://127.0.0.1:8765/oauth-callback (default)")
+        || text.contains("plain (default)")
+    {
+        return Err(format!("Expected only redirect_uri marked as default, got: {text}").into());
+    }
+
+    let hidden: Value = json_data(&String::from_utf8_lossy(&hidden_output.stdout))?;
+    if hidden["Fields"].get("redirect_uri").is_some()
+        || hidden["Fields"]["code_challenge_method"] != "plain"
+        || hidden["Defaulted Fields"] != serde_json::json!([])
+    {
+        return Err(format!("Expected defaulted fields to be hidden, got: {hidden}").into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+mod common;
+use common::{json_data, rq_cmd};
+
+#[test]
+fn test_auth_validate_valid_directory() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args(["auth", "validate", "-s", "tests/auth/validate/input/valid"])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+    assert_eq!(stdout.trim(), "2 valid, 0 invalid auth configuration(s)");
+
+    Ok(())
+}
+
+#[test]
+fn test_auth_validate_reports_every_error_with_location() -> Result<(), Box<dyn std::error::Error>>
+{
+    let output = rq_cmd()
+        .args([
+            "auth",
+            "validate",
+            "-s",
+            "tests/auth/validate/input/invalid",
+            "-o",
+            "json",
+        ])
+        .output()?;
+
+    assert_eq!(output.status.code(), Some(3));
+    let json = json_data(&String::from_utf8_lossy(&output.stdout))?;
+    assert_eq!(json["valid"][0]["name"], "good_token");
+
+    let errors: Vec<(String, u64, String)> = json["errors"]
+        .as_array()
+        .ok_or("missing errors array")?
+        .iter()
+        .map(|e| {
+            let file = e["file"].as_str().unwrap_or_default();
+            let file = file.rsplit(['/', '\\']).next().unwrap_or_default();
+            (
+                file.to_string(),
+                e["line"].as_u64().unwrap_or_default(),
+                e["message"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            (
+                "bearer.rq".to_string(),
+                6,
+                "Bearer auth 'empty_token' has empty 'token' field".to_string()
+            ),
+            (
+                "oauth.rq".to_string(),
+                1,
+                "OAuth2 Client Credentials auth 'cc' missing required field 'token_url'. Set it explicitly or set 'issuer' to discover it".to_string()
+            ),
+            (
+                "oauth.rq".to_string(),
+                8,
+                "Bearer auth 'both_tokens' cannot set both 'token' and 'token_file'".to_string()
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_auth_validate_does_not_need_environment() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args(["auth", "validate", "-s", "tests/request/run/input/auth"])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "stdout: {stdout}, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains(" valid, 0 invalid auth configuration(s)"));
+
+    Ok(())
+}
@@ -1,11 +1,11 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 fn run_check(args: &[&str]) -> (bool, Value) {
     let output = rq_cmd().args(args).output().unwrap();
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout).unwrap_or(Value::Null);
+    let json: Value = json_data(&stdout).unwrap_or(Value::Null);
     (output.status.success(), json)
 }
 
@@ -94,6 +94,37 @@ fn test_check_valid_variable_reference_chain() {
     assert_eq!(error_count(&json), 0);
 }
 
+#[test]
+fn test_check_valid_captured_variable_used_later() {
+    let (success, json) = run_check(&["check", "-s", "tests/check/input/valid_capture.rq"]);
+    assert!(
+        success,
+        "a variable captured by an earlier request should be defined, got: {:?}",
+        error_messages(&json)
+    );
+    assert_eq!(error_count(&json), 0);
+}
+
+#[test]
+fn test_check_error_captured_variable_used_before_capture() {
+    let (success, json) = run_check(&[
+        "check",
+        "-s",
+        "tests/check/input/err_capture_used_before.rq",
+    ]);
+    assert!(
+        !success,
+        "expected exit 1 for a variable used before it is captured"
+    );
+    assert_eq!(error_count(&json), 1);
+    let msgs = error_messages(&json);
+    assert!(
+        msgs[0].contains("location"),
+        "expected message about 'location', got: {}",
+        msgs[0]
+    );
+}
+
 #[test]
 fn test_check_valid_with_environment_flag() {
     let (success, json) = run_check(&[
@@ -583,3 +614,56 @@ fn test_check_nonexistent_source_exits_nonzero() {
         "expected failure for nonexistent source"
     );
 }
+
+#[test]
+fn test_check_warns_on_environment_defined_differently_across_files() {
+    let (success, json) = run_check(&[
+        "check",
+        "-s",
+        "tests/check/input/env_collision",
+        "-e",
+        "local",
+    ]);
+    assert!(
+        success,
+        "environment collisions are warnings, got errors: {:?}",
+        error_messages(&json)
+    );
+    let warnings = json["warnings"].as_array().cloned().unwrap_or_default();
+    assert_eq!(warnings.len(), 1, "expected one warning, got: {warnings:?}");
+    let message = warnings[0]["message"].as_str().unwrap_or("");
+    assert!(
+        message.contains("'local'") && message.contains("a.rq") && message.contains("b.rq"),
+        "expected collision warning for 'local' listing both files, got: {message}"
+    );
+    assert!(warnings[0]["file"].as_str().unwrap_or("").ends_with("a.rq"));
+    assert_eq!(warnings[0]["line"], 1);
+}
+
+#[test]
+fn test_check_valid_directory_has_no_warnings() {
+    let (_, json) = run_check(&["check", "-s", "tests/check/input/valid_dir"]);
+    assert_eq!(json["warnings"], serde_json::json!([]));
+}
+
+#[test]
+fn test_check_warns_about_unused_endpoint_template() {
+    let (success, json) = run_check(&["check", "-s", "tests/check/input/warn_unused_template.rq"]);
+    assert!(
+        success,
+        "unused templates are warnings, got errors: {:?}",
+        error_messages(&json)
+    );
+    let warnings = json["warnings"].as_array().cloned().unwrap_or_default();
+    assert_eq!(warnings.len(), 1, "expected one warning, got: {warnings:?}");
+    assert_eq!(
+        warnings[0]["message"],
+        "Endpoint template 'legacy' is never used as a parent by another endpoint"
+    );
+    assert!(warnings[0]["file"]
+        .as_str()
+        .unwrap_or("")
+        .ends_with("warn_unused_template.rq"));
+    assert_eq!(warnings[0]["line"], 4);
+    assert_eq!(warnings[0]["column"], 4);
+}
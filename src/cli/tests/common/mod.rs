@@ -1,11 +1,38 @@
 #![allow(dead_code)]
 use serde_json::Value;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub fn rq_cmd() -> Command {
-    Command::new(env!("CARGO_BIN_EXE_rq"))
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_rq"));
+    cmd.env("XDG_CACHE_HOME", isolated_cache_dir());
+    cmd
+}
+
+pub fn write_rq(name: &str, content: impl AsRef<[u8]>) -> std::io::Result<String> {
+    let rq_path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{name}.rq"));
+    if let Some(parent) = rq_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&rq_path, content)?;
+    Ok(rq_path.display().to_string())
+}
+
+fn isolated_cache_dir() -> PathBuf {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("cache")
+        .join(format!(
+            "{}-{nanos}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ))
 }
 
 pub fn json_subset(expected: &Value, actual: &Value) -> bool {
@@ -128,13 +155,24 @@ pub fn validate_json_response(stdout: &str, expected_path: &Path) -> Result<(),
     Ok(())
 }
 
+pub fn json_data(stdout: &str) -> Result<Value, String> {
+    let envelope: Value = serde_json::from_str(stdout)
+        .map_err(|e| format!("Failed to parse actual JSON response: {e}"))?;
+    if envelope.get("schema_version") != Some(&Value::from(1)) {
+        return Err(format!("Missing or unexpected schema_version in: {stdout}"));
+    }
+    envelope
+        .get("data")
+        .cloned()
+        .ok_or_else(|| format!("Missing 'data' in JSON envelope: {stdout}"))
+}
+
 pub fn validate_pure_json_response(stdout: &str, expected_path: &Path) -> Result<(), String> {
     let expected_content = fs::read_to_string(expected_path)
         .map_err(|e| format!("Failed to read expected file: {e}"))?;
     let expected_json: Value = serde_json::from_str(&expected_content)
         .map_err(|e| format!("Failed to parse expected JSON: {e}"))?;
-    let actual_json: Value = serde_json::from_str(stdout)
-        .map_err(|e| format!("Failed to parse actual JSON response: {e}"))?;
+    let actual_json = json_data(stdout)?;
 
     if !json_subset(&expected_json, &actual_json) {
         return Err(format!(
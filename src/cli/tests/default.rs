@@ -129,3 +129,117 @@ rq cli_variable_override("{}/get?color={{{{color}}}}");
 
     Ok(())
 }
+
+fn write_default_request_dir(
+    name: &str,
+    files: &[(&str, String)],
+) -> Result<String, Box<dyn std::error::Error>> {
+    let dir = format!("{}/{name}", env!("CARGO_TARGET_TMPDIR"));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    for (file, content) in files {
+        std::fs::write(format!("{dir}/{file}"), content)?;
+    }
+    Ok(dir)
+}
+
+#[tokio::test]
+async fn test_default_run_uses_default_request_directive() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/smoke"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/other"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let rq_content = format!(
+        "default request smoke;\n\nrq smoke(\"{0}/smoke\");\nrq other(\"{0}/other\");\n",
+        mock_server.uri()
+    );
+    let dir = write_default_request_dir("test_default_request", &[("api.rq", rq_content)])?;
+
+    let bare = rq_cmd().current_dir(&dir).output()?;
+    let stdout = String::from_utf8_lossy(&bare.stdout);
+    assert!(
+        bare.status.success(),
+        "{}",
+        String::from_utf8_lossy(&bare.stderr)
+    );
+    assert!(stdout.contains("request_name: smoke"), "{stdout}");
+    assert!(!stdout.contains("request_name: other"), "{stdout}");
+
+    let named = rq_cmd().args(["-n", "other"]).current_dir(&dir).output()?;
+    assert!(named.status.success());
+    assert!(String::from_utf8_lossy(&named.stdout).contains("request_name: other"));
+
+    let explicit = rq_cmd().args(["request", "run", "-s", &dir]).output()?;
+    assert!(explicit.status.success());
+    let stdout = String::from_utf8_lossy(&explicit.stdout);
+    assert!(
+        stdout.contains("request_name: smoke") && stdout.contains("request_name: other"),
+        "{stdout}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_default_run_rejects_conflicting_default_requests() -> Result<(), Box<dyn std::error::Error>>
+{
+    let dir = write_default_request_dir(
+        "test_default_request_conflict",
+        &[
+            (
+                "a.rq",
+                "default request first;\nrq first(\"http://localhost:8080/get\");\n".to_string(),
+            ),
+            (
+                "b.rq",
+                "default request second;\nrq second(\"http://localhost:8080/get\");\n".to_string(),
+            ),
+        ],
+    )?;
+
+    let output = rq_cmd().current_dir(&dir).output()?;
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Conflicting default requests: 'first' in")
+            && stderr.contains("'second' in"),
+        "{stderr}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_default_run_resolves_endpoint_default_request() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = write_default_request_dir(
+        "test_default_request_endpoint",
+        &[(
+            "api.rq",
+            "default request api.post;\n\nep api(\"http://localhost:8080/api\") {\n    rq get(\"/get\");\n    [method(POST)]\n    rq post(\"/post\");\n}\n"
+                .to_string(),
+        )],
+    )?;
+
+    let output = rq_cmd().args(["-s", &format!("{dir}/api.rq")]).output()?;
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("request_name: api/post"), "{stdout}");
+    assert!(!stdout.contains("request_name: api/get"), "{stdout}");
+    Ok(())
+}
@@ -1,5 +1,5 @@
 mod common;
-use common::{rq_cmd, validate_pure_json_response};
+use common::{json_data, rq_cmd, validate_pure_json_response};
 use serde_json::Value;
 use std::fs;
 use std::path::Path;
@@ -112,7 +112,7 @@ rq test("http://localhost/test");
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected items array in JSON")?;
 
     if !items
@@ -147,7 +147,7 @@ fn test_env_list_json_case_insensitive() -> Result<(), Box<dyn std::error::Error
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     if !json.is_array() {
         return Err(format!("Expected JSON format even with uppercase 'JSON', got: {json}").into());
     }
@@ -182,6 +182,37 @@ fn test_env_list_multiple() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_env_list_compact_json() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "env",
+            "list",
+            "-s",
+            "tests/env/list/input/multiple.rq",
+            "-o",
+            "json",
+            "--compact-json",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim_end().lines().count() != 1 {
+        return Err(format!("Expected single-line JSON, got: {stdout}").into());
+    }
+    validate_pure_json_response(&stdout, Path::new("tests/env/list/expected/multiple.json"))?;
+
+    Ok(())
+}
+
 #[test]
 fn test_env_list_invalid_output() {
     let output = rq_cmd()
@@ -227,7 +258,7 @@ fn test_env_show_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("name").and_then(|v| v.as_str()) != Some("local") {
         return Err(format!("Expected name 'local', got: {json}").into());
@@ -264,3 +295,59 @@ fn test_env_show_not_found() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_env_list_reports_defining_files() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("rq_test_env_files");
+    if temp_dir.exists() {
+        fs::remove_dir_all(&temp_dir)?;
+    }
+    fs::create_dir_all(temp_dir.join("nested"))?;
+    fs::write(
+        temp_dir.join("a.rq"),
+        "env local {\n    base: \"http://a\",\n}\n\nenv dev {\n    base: \"http://d\",\n}\n",
+    )?;
+    fs::write(
+        temp_dir.join("nested/b.rq"),
+        "env local {\n    base: \"http://b\",\n}\n",
+    )?;
+    let source = temp_dir.to_string_lossy().to_string();
+
+    let output = rq_cmd()
+        .args(["env", "list", "-s", &source, "-o", "json"])
+        .output()?;
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json = json_data(&String::from_utf8_lossy(&output.stdout))?;
+    let files = |index: usize| -> Vec<String> {
+        json[index]["files"]
+            .as_array()
+            .map(|files| {
+                files
+                    .iter()
+                    .filter_map(|f| f.as_str())
+                    .map(|f| f.replace('\\', "/"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    assert_eq!(json[0]["name"], "dev");
+    assert_eq!(files(0).len(), 1);
+    assert!(files(0)[0].ends_with("rq_test_env_files/a.rq"));
+    assert_eq!(json[1]["name"], "local");
+    assert_eq!(files(1).len(), 2, "{json}");
+    assert!(files(1)[1].ends_with("rq_test_env_files/nested/b.rq"));
+
+    let output = rq_cmd()
+        .args(["env", "list", "-s", &source, "--verbose"])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("a.rq, ") && stdout.contains("b.rq)"),
+        "Expected defining files in verbose output, got: {stdout}"
+    );
+    Ok(())
+}
@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -27,7 +27,7 @@ fn test_ep_list_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -107,7 +107,7 @@ fn test_ep_list_is_template_false_for_body_ep() -> Result<(), Box<dyn std::error
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -148,7 +148,7 @@ fn test_ep_list_empty() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if !items.is_empty() {
@@ -192,7 +192,7 @@ fn test_ep_list_file_includes_imports() -> Result<(), Box<dyn std::error::Error>
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    let json: serde_json::Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -243,7 +243,7 @@ fn test_ep_list_file_excludes_unimported() -> Result<(), Box<dyn std::error::Err
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    let json: serde_json::Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.len() != 1 {
@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -26,7 +26,7 @@ fn test_ep_refs_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -71,7 +71,7 @@ fn test_ep_refs_multiple_files() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.len() < 2 {
@@ -138,7 +138,7 @@ fn test_ep_refs_no_results() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if !items.is_empty() {
@@ -186,7 +186,7 @@ fn test_ep_refs_definition_included() -> Result<(), Box<dyn std::error::Error>>
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     let has_definition = items.iter().any(|r| {
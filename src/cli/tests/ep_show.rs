@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -26,7 +26,7 @@ fn test_ep_show_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("name").and_then(|v| v.as_str()) != Some("api") {
         return Err(format!("Expected name 'api', got: {json}").into());
@@ -97,7 +97,7 @@ fn test_ep_show_directory_search() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("name").and_then(|v| v.as_str()) != Some("api") {
         return Err(format!("Expected name 'api', got: {json}").into());
@@ -131,7 +131,7 @@ fn test_ep_show_no_var_interpolation() -> Result<(), Box<dyn std::error::Error>>
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("name").and_then(|v| v.as_str()) != Some("api") {
         return Err(format!("Expected name 'api', got: {json}").into());
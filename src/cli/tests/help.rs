@@ -75,6 +75,11 @@ fn test_auth_show_help() -> Result<(), Box<dyn std::error::Error>> {
     verify_help(&["auth", "show", "--help"], "auth_show.txt")
 }
 
+#[test]
+fn test_auth_validate_help() -> Result<(), Box<dyn std::error::Error>> {
+    verify_help(&["auth", "validate", "--help"], "auth_validate.txt")
+}
+
 #[test]
 fn test_ep_help() -> Result<(), Box<dyn std::error::Error>> {
     verify_help(&["ep", "--help"], "ep.txt")?;
@@ -106,6 +111,24 @@ fn test_request_run_help() -> Result<(), Box<dyn std::error::Error>> {
     verify_help(&["request", "run", "--help"], "request_run.txt")
 }
 
+#[test]
+fn test_request_ping_help() -> Result<(), Box<dyn std::error::Error>> {
+    verify_help(&["request", "ping", "--help"], "request_ping.txt")
+}
+
+#[test]
+fn test_request_export_help() -> Result<(), Box<dyn std::error::Error>> {
+    verify_help(&["request", "export", "--help"], "request_export.txt")
+}
+
+#[test]
+fn test_request_export_openapi_help() -> Result<(), Box<dyn std::error::Error>> {
+    verify_help(
+        &["request", "export", "openapi", "--help"],
+        "request_export_openapi.txt",
+    )
+}
+
 #[test]
 fn test_var_help() -> Result<(), Box<dyn std::error::Error>> {
     verify_help(&["var", "--help"], "var.txt")?;
@@ -0,0 +1,58 @@
+mod common;
+use common::rq_cmd;
+use serde_json::Value;
+
+#[test]
+fn test_request_export_openapi() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "export",
+            "openapi",
+            "-s",
+            "tests/request/run/input/endpoint_qs.rq",
+        ])
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let document: Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(document["openapi"], "3.0.3");
+    assert_eq!(document["info"]["title"], "endpoint_qs");
+    assert_eq!(document["servers"][0]["url"], "http://localhost:8080");
+    let operation = &document["paths"]["/api/users"]["get"];
+    assert_eq!(operation["operationId"], "api/get");
+    assert_eq!(operation["parameters"][0]["name"], "api-version");
+    assert_eq!(operation["parameters"][0]["in"], "query");
+    Ok(())
+}
+
+#[test]
+fn test_request_export_openapi_request_body() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "export",
+            "openapi",
+            "-s",
+            "tests/request/run/input/body_object.rq",
+        ])
+        .output()?;
+
+    assert!(output.status.success());
+    let document: Value = serde_json::from_slice(&output.stdout)?;
+    let paths = document["paths"]
+        .as_object()
+        .ok_or("paths is not an object")?;
+    let body = paths
+        .values()
+        .filter_map(|item| item.as_object())
+        .flat_map(|item| item.values())
+        .find_map(|operation| operation.get("requestBody"))
+        .ok_or("no request body exported")?;
+    assert!(body["content"]["application/json"]["example"].is_object());
+    Ok(())
+}
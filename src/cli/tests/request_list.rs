@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -53,7 +53,7 @@ fn test_request_list_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let items = json.as_array().ok_or("JSON missing 'items' array")?;
 
@@ -183,7 +183,7 @@ fn test_request_list_json_endpoint_location() -> Result<(), Box<dyn std::error::
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     let ep_request = items
@@ -226,3 +226,43 @@ fn test_request_list_json_endpoint_location() -> Result<(), Box<dyn std::error::
 
     Ok(())
 }
+
+#[test]
+fn test_request_list_description() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "list",
+            "-s",
+            "tests/request/run/input/description.rq",
+            "-o",
+            "json",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let json: Value = json_data(&String::from_utf8_lossy(&output.stdout))?;
+    let item = json
+        .as_array()
+        .and_then(|items| items.first())
+        .ok_or("No items in array")?;
+    if item["description"].as_str() != Some("Fetches the current user") {
+        return Err(format!("Expected description, got: {item}").into());
+    }
+
+    let output = rq_cmd()
+        .args(["request", "list", "-s", "tests/request/run/input/basic.rq"])
+        .output()?;
+    if String::from_utf8_lossy(&output.stdout).contains("description") {
+        return Err("Requests without a description should not list one".into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+mod common;
+use common::{json_data, rq_cmd};
+use serde_json::Value;
+
+#[test]
+fn test_request_ping_reports_status_and_protocol() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "ping",
+            "-s",
+            "tests/request/run/input/basic.rq",
+            "-n",
+            "basic",
+            "-o",
+            "json",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let json: Value = json_data(&String::from_utf8_lossy(&output.stdout))?;
+    let first = json
+        .get("results")
+        .and_then(|r| r.as_array())
+        .and_then(|r| r.first())
+        .ok_or("Missing results in JSON output")?;
+
+    if first.get("url").and_then(|v| v.as_str()) != Some("http://localhost:8080/get") {
+        return Err(format!("Unexpected url: {:?}", first.get("url")).into());
+    }
+    if first.get("status").and_then(|v| v.as_u64()) != Some(200) {
+        return Err(format!("Unexpected status: {:?}", first.get("status")).into());
+    }
+    if first.get("protocol").and_then(|v| v.as_str()).is_none() {
+        return Err("Missing protocol".into());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_request_ping_requires_name() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args(["request", "ping", "-s", "tests/request/run/input/basic.rq"])
+        .output()?;
+
+    if output.status.success() {
+        return Err("Expected command to fail without a request name".into());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.contains("Request name is required") {
+        return Err(format!("Unexpected error output: {stderr}").into());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_request_ping_connection_refused() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "ping",
+            "-s",
+            "tests/request/run/fixtures/connection_refused/test.rq",
+            "-n",
+            "get",
+        ])
+        .output()?;
+
+    if output.status.code() != Some(6) {
+        return Err(format!("Expected exit code 6, got: {:?}", output.status.code()).into());
+    }
+
+    Ok(())
+}
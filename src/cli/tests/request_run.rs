@@ -1,11 +1,28 @@
 // Integration tests for rq CLI
 use libtest_mimic::{run, Arguments, Failed, Trial};
 use std::fs;
+use std::future::Future;
 use std::path::Path;
 
 mod common;
 use common::{rq_cmd, validate_json_response};
 
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn sync_trial(name: &str, test: fn() -> TestResult) -> Trial {
+    Trial::test(name, move || test().map_err(Failed::from))
+}
+
+fn async_trial<F: Future<Output = TestResult> + 'static>(name: &str, test: fn() -> F) -> Trial {
+    Trial::test(name, move || {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?
+            .block_on(test())
+            .map_err(Failed::from)
+    })
+}
+
 fn main() {
     let args = Arguments::from_args();
 
@@ -57,8 +74,52 @@ fn main() {
             "request_required_variable_satisfied_by_cli",
             test_request_required_variable_satisfied_by_cli,
         ),
+        Trial::test("request_profiles_layered", test_request_profiles_layered),
+        Trial::test("request_profile_not_found", test_request_profile_not_found),
     ];
 
+    trials.extend(redirects::trials());
+    trials.extend(deadline::trials());
+    trials.extend(junit::trials());
+    trials.extend(confirm::trials());
+    trials.extend(timeout::trials());
+    trials.extend(output_template::trials());
+    trials.extend(form::trials());
+    trials.extend(var_file::trials());
+    trials.extend(mtls::trials());
+    trials.extend(ndjson::trials());
+    trials.extend(charset::trials());
+    trials.extend(insecure_auth::trials());
+    trials.extend(retry::trials());
+    trials.extend(idempotency::trials());
+    trials.extend(conditional_headers::trials());
+    trials.extend(stdin::trials());
+    trials.extend(fail_if_empty::trials());
+    trials.extend(transform::trials());
+    trials.extend(output_attribute::trials());
+    trials.extend(summary::trials());
+    trials.extend(body_bytes::trials());
+    trials.extend(pool::trials());
+    #[cfg(unix)]
+    trials.extend(unix_socket::trials());
+    trials.extend(assertions::trials());
+    trials.extend(resume::trials());
+    trials.extend(typed_variables::trials());
+    trials.extend(auth_toggle::trials());
+    trials.extend(auth_field::trials());
+    #[cfg(feature = "websocket")]
+    trials.extend(websocket::trials());
+    trials.extend(reauth::trials());
+    trials.extend(env_get::trials());
+    trials.extend(name_search::trials());
+    trials.extend(token_cache::trials());
+    trials.extend(capture::trials());
+    trials.extend(skip::trials());
+    trials.extend(body_file::trials());
+    trials.extend(sort::trials());
+    trials.extend(multipart::trials());
+    trials.extend(explain_auth::trials());
+
     // Discover tests from organized directories
     let discovered_tests = discover_directory_tests();
     trials.extend(discovered_tests);
@@ -212,6 +273,66 @@ fn test_request_cli_variable_override() -> Result<(), Failed> {
     .map_err(Failed::from)
 }
 
+fn test_request_profiles_layered() -> Result<(), Failed> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "run",
+            "-s",
+            "tests/request/run/fixtures/profiles/profiles.rq",
+            "-e",
+            "local",
+            "--profile",
+            "admin",
+            "--profile",
+            "acme",
+        ])
+        .output()
+        .map_err(|e| format!("Failed to execute command: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    validate_json_response(
+        &stdout,
+        Path::new("tests/request/run/fixtures/profiles/profiles.json"),
+    )
+    .map_err(Failed::from)
+}
+
+fn test_request_profile_not_found() -> Result<(), Failed> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "run",
+            "-s",
+            "tests/request/run/fixtures/profiles/profiles.rq",
+            "-e",
+            "local",
+            "--profile",
+            "ghost",
+        ])
+        .output()
+        .map_err(|e| format!("Failed to execute command: {e}"))?;
+
+    if output.status.code() != Some(3) {
+        return Err(format!("Expected exit code 3, got: {:?}", output.status.code()).into());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.contains("Profile not found: ghost") {
+        return Err(format!("Unexpected error output: {stderr}").into());
+    }
+
+    Ok(())
+}
+
 fn test_request_dotenv() -> Result<(), Failed> {
     let output = rq_cmd()
         .args([
@@ -391,6 +512,10 @@ fn test_request_run_connection_refused() -> Result<(), Failed> {
     let parsed: serde_json::Value = serde_json::from_str(stderr.trim())
         .map_err(|e| format!("stderr is not valid JSON: {e}\nstderr: {stderr}"))?;
 
+    if parsed.get("schema_version") != Some(&serde_json::Value::from(1)) {
+        return Err(format!("Missing schema_version in JSON error: {stderr}").into());
+    }
+
     let error = parsed
         .get("error")
         .ok_or("Missing 'error' key in JSON output")?;
@@ -511,6 +636,8 @@ fn run_directory_test(dir_name: &str, file_name: &str) -> Result<(), Failed> {
     let env_name = extract_env_from_name(file_name);
     let request_name = extract_request_from_name(file_name);
     let use_dir_source = file_name.contains("__dir__");
+    let print_resolved = file_name.contains("__print_resolved__");
+    let no_env_default = file_name.contains("__no_env_default__");
 
     let mut cmd = rq_cmd();
 
@@ -535,6 +662,14 @@ fn run_directory_test(dir_name: &str, file_name: &str) -> Result<(), Failed> {
         cmd.args(["--name", req]);
     }
 
+    if print_resolved {
+        cmd.arg("--print-resolved-file");
+    }
+
+    if no_env_default {
+        cmd.arg("--no-env-default");
+    }
+
     let output = cmd
         .output()
         .map_err(|e| format!("Failed to execute command: {e}"))?;
@@ -620,3 +755,4348 @@ fn extract_request_from_name(test_name: &str) -> Option<String> {
     }
     None
 }
+
+mod redirects {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{json_data, rq_cmd};
+    use libtest_mimic::Trial;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn spawn_redirect_server() -> Result<String, Box<dyn std::error::Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(&stream);
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+                let hops_left: usize = path.trim_start_matches("/hop/").parse().unwrap_or_default();
+                let response = if hops_left == 0 {
+                    "HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{}"
+                        .to_string()
+                } else {
+                    format!(
+                    "HTTP/1.1 302 Found\r\nlocation: /hop/{}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    hops_left - 1
+                )
+                };
+                let mut stream = stream;
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        Ok(base_url)
+    }
+
+    fn run_redirects(
+        extra_args: &[&str],
+    ) -> Result<std::process::Output, Box<dyn std::error::Error>> {
+        let base_url = spawn_redirect_server()?;
+        let mut args = vec![
+            "request".to_string(),
+            "run".to_string(),
+            "-s".to_string(),
+            "tests/request/run/fixtures/redirects/test.rq".to_string(),
+            "-v".to_string(),
+            format!("base_url={base_url}"),
+        ];
+        args.extend(extra_args.iter().map(|a| a.to_string()));
+        Ok(rq_cmd().args(&args).output()?)
+    }
+
+    fn test_request_run_show_redirects_prints_chain() -> TestResult {
+        let output = run_redirects(&["--show-redirects"])?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if !output.status.success() {
+            return Err(format!("Command failed: {stderr}").into());
+        }
+        if !stderr.contains("Redirect chain for 'two_hops':") {
+            return Err(format!("Missing redirect chain header: {stderr}").into());
+        }
+        if !stderr.contains("/hop/2 -> ") || !stderr.contains("/hop/1 -> ") {
+            return Err(format!("Missing intermediate hops: {stderr}").into());
+        }
+        if !stderr.lines().any(|l| l.trim_start().starts_with("200 ")) {
+            return Err(format!("Missing final response line: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_request_run_redirect_chain_hidden_by_default() -> TestResult {
+        let output = run_redirects(&[])?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if !output.status.success() {
+            return Err(format!("Command failed: {stderr}").into());
+        }
+        if stderr.contains("Redirect chain") {
+            return Err(format!("Redirect chain printed without flag: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_request_run_max_redirects_exceeded() -> TestResult {
+        let output = run_redirects(&["--max-redirects", "1"])?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(6) {
+            return Err(format!("Expected exit code 6, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("too many redirects (max 1)") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn mount_redirect(mock_server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/old"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", "/new"))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/new"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("moved"))
+            .mount(mock_server)
+            .await;
+    }
+
+    fn run_with_redirects_attribute(
+        mock_server: &MockServer,
+        attribute: &str,
+        name: &str,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!("{attribute}\nrq old(\"{}/old\");\n", mock_server.uri()),
+        )?;
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+        Ok(json_data(&stdout)?["results"][0].clone())
+    }
+
+    async fn test_redirects_attribute_follow_false_returns_location() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_redirect(&mock_server).await;
+
+        let result = run_with_redirects_attribute(
+            &mock_server,
+            "[redirects(follow=false)]",
+            "test_redirects_no_follow",
+        )?;
+
+        assert_eq!(result["status"], 302, "{result}");
+        assert_eq!(result["response_headers"]["location"], "/new", "{result}");
+
+        Ok(())
+    }
+
+    async fn test_redirects_attribute_max_follows() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_redirect(&mock_server).await;
+
+        let result =
+            run_with_redirects_attribute(&mock_server, "[redirects(max=1)]", "test_redirects_max")?;
+
+        assert_eq!(result["status"], 200, "{result}");
+        assert_eq!(result["body"], "moved", "{result}");
+
+        Ok(())
+    }
+
+    fn test_redirects_attribute_overrides_max_redirects_flag() -> TestResult {
+        let base_url = spawn_redirect_server()?;
+        let rq_path = format!("{}/test_redirects_override.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!("[redirects(max=2)]\nrq two_hops(\"{base_url}/hop/2\");\n"),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--max-redirects", "1"])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Expected the attribute to allow two hops: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            sync_trial(
+                "request_run_show_redirects_prints_chain",
+                test_request_run_show_redirects_prints_chain,
+            ),
+            sync_trial(
+                "request_run_redirect_chain_hidden_by_default",
+                test_request_run_redirect_chain_hidden_by_default,
+            ),
+            sync_trial(
+                "request_run_max_redirects_exceeded",
+                test_request_run_max_redirects_exceeded,
+            ),
+            async_trial(
+                "redirects_attribute_follow_false_returns_location",
+                test_redirects_attribute_follow_false_returns_location,
+            ),
+            async_trial(
+                "redirects_attribute_max_follows",
+                test_redirects_attribute_max_follows,
+            ),
+            sync_trial(
+                "redirects_attribute_overrides_max_redirects_flag",
+                test_redirects_attribute_overrides_max_redirects_flag,
+            ),
+        ]
+    }
+}
+
+mod deadline {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn write_slow_request(file_name: &str) -> Result<(MockServer, String), std::io::Error> {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/fast"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+            "rq fast(\"{uri}/fast\");\nrq slow(\"{uri}/slow\");\n",
+            uri = mock_server.uri()
+        );
+        let rq_path = format!("{}/{file_name}", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+        Ok((mock_server, rq_path))
+    }
+
+    async fn test_request_run_deadline_exceeded() -> TestResult {
+        let (_server, rq_path) = write_slow_request("test_deadline_exceeded.rq").await?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--deadline", "300ms"])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(9) {
+            return Err(format!("Expected exit code 9, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("Deadline exceeded: run did not finish within 300ms") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_request_run_within_deadline() -> TestResult {
+        let (_server, rq_path) = write_slow_request("test_within_deadline.rq").await?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-n",
+                "fast",
+                "--deadline",
+                "30s",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn test_request_run_deadline_rejects_invalid_duration() -> TestResult {
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input/basic.rq",
+                "--deadline",
+                "soon",
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.success() {
+            return Err("Expected command to fail with an invalid duration".into());
+        }
+        if !stderr.contains("Invalid duration 'soon'") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "request_run_deadline_exceeded",
+                test_request_run_deadline_exceeded,
+            ),
+            async_trial(
+                "request_run_within_deadline",
+                test_request_run_within_deadline,
+            ),
+            sync_trial(
+                "request_run_deadline_rejects_invalid_duration",
+                test_request_run_deadline_rejects_invalid_duration,
+            ),
+        ]
+    }
+}
+
+mod junit {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_request_run_writes_junit_report() -> TestResult {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/healthy"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/broken"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+            "rq healthy(\"{uri}/healthy\");\nrq broken(\"{uri}/broken\");\n",
+            uri = mock_server.uri()
+        );
+        let tmp_dir = env!("CARGO_TARGET_TMPDIR");
+        let rq_path = format!("{tmp_dir}/test_junit_report.rq");
+        let report_path = format!("{tmp_dir}/test_junit_report.xml");
+        std::fs::write(&rq_path, rq_content)?;
+        let _ = std::fs::remove_file(&report_path);
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--junit", &report_path])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        let report = std::fs::read_to_string(&report_path)?;
+        if !report.contains("tests=\"2\" failures=\"1\"") {
+            return Err(format!("Unexpected test counts in report:\n{report}").into());
+        }
+        if !report.contains("<testcase name=\"healthy\"") {
+            return Err(format!("Missing passing test case in report:\n{report}").into());
+        }
+        if !report.contains("<failure message=\"Unexpected status 503\"") {
+            return Err(format!("Missing failure for broken request in report:\n{report}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![async_trial(
+            "request_run_writes_junit_report",
+            test_request_run_writes_junit_report,
+        )]
+    }
+}
+
+mod confirm {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::process::Stdio;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn write_requests(mock_server: &MockServer, file_name: &str) -> std::io::Result<String> {
+        let rq_content = format!(
+            "rq read(\"{uri}/items\");\n[method(DELETE)]\nrq remove(\"{uri}/items\");\n",
+            uri = mock_server.uri()
+        );
+        let rq_path = format!("{}/{file_name}", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+        Ok(rq_path)
+    }
+
+    async fn test_confirm_mutations_aborts_without_tty() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_requests(&mock_server, "test_confirm_no_tty.rq").await?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-n",
+                "remove",
+                "--confirm-mutations",
+            ])
+            .stdin(Stdio::null())
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(3) {
+            return Err(format!("Expected exit code 3, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("Request 'remove' was not sent") || !stderr.contains("pass --yes") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_confirm_mutations_yes_sends_request() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_requests(&mock_server, "test_confirm_yes.rq").await?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-n",
+                "remove",
+                "--confirm-mutations",
+                "--yes",
+            ])
+            .stdin(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_confirm_mutations_skips_safe_methods() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_requests(&mock_server, "test_confirm_safe.rq").await?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-n",
+                "read",
+                "--confirm-mutations",
+            ])
+            .stdin(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_declined_mutation_skips_auth() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "issued-token",
+                "token_type": "Bearer"
+            })))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let rq_path = format!("{}/test_confirm_auth.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+        &rq_path,
+        format!(
+            "auth cc_auth(auth_type.oauth2_client_credentials) {{\n    client_id: \"test-client\",\n    client_secret: \"test-secret\",\n    token_url: \"{uri}/token\"\n}}\n\n[auth(\"cc_auth\")]\n[method(DELETE)]\nrq remove(\"{uri}/items\");\n",
+            uri = mock_server.uri()
+        ),
+    )?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--no-token-cache",
+                "--confirm-mutations",
+            ])
+            .stdin(Stdio::null())
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(3) {
+            return Err(format!("Expected exit code 3, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("Request 'remove' was not sent") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_yes_requires_confirm_mutations() -> TestResult {
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input/basic.rq",
+                "--yes",
+            ])
+            .output()?;
+
+        if output.status.success() {
+            return Err("Expected --yes without --confirm-mutations to be rejected".into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "confirm_mutations_aborts_without_tty",
+                test_confirm_mutations_aborts_without_tty,
+            ),
+            async_trial(
+                "confirm_mutations_yes_sends_request",
+                test_confirm_mutations_yes_sends_request,
+            ),
+            async_trial(
+                "confirm_mutations_skips_safe_methods",
+                test_confirm_mutations_skips_safe_methods,
+            ),
+            async_trial(
+                "declined_mutation_skips_auth",
+                test_declined_mutation_skips_auth,
+            ),
+            sync_trial(
+                "yes_requires_confirm_mutations",
+                test_yes_requires_confirm_mutations,
+            ),
+        ]
+    }
+}
+
+mod timeout {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn write_slow_requests(file_name: &str) -> Result<(MockServer, String), std::io::Error> {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(1)))
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+            "rq default_timeout(\"{uri}/slow\");\n[timeout(5s)]\nrq own_timeout(\"{uri}/slow\");\n",
+            uri = mock_server.uri()
+        );
+        let rq_path = format!("{}/{file_name}", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+        Ok((mock_server, rq_path))
+    }
+
+    async fn test_cli_timeout_applies_to_requests_without_attribute() -> TestResult {
+        let (_server, rq_path) = write_slow_requests("test_cli_timeout_default.rq").await?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-n",
+                "default_timeout",
+                "--timeout",
+                "200ms",
+            ])
+            .output()?;
+
+        if output.status.success() {
+            return Err("Expected request to time out with --timeout 200ms".into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_timeout_attribute_overrides_cli_timeout() -> TestResult {
+        let (_server, rq_path) = write_slow_requests("test_cli_timeout_override.rq").await?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-n",
+                "own_timeout",
+                "--timeout",
+                "200ms",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn test_cli_timeout_rejects_invalid_duration() -> TestResult {
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input/basic.rq",
+                "--timeout",
+                "30x",
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.success() {
+            return Err("Expected command to fail with an invalid timeout".into());
+        }
+        if !stderr.contains("Invalid duration '30x': unknown unit 'x'") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "cli_timeout_applies_to_requests_without_attribute",
+                test_cli_timeout_applies_to_requests_without_attribute,
+            ),
+            async_trial(
+                "timeout_attribute_overrides_cli_timeout",
+                test_timeout_attribute_overrides_cli_timeout,
+            ),
+            sync_trial(
+                "cli_timeout_rejects_invalid_duration",
+                test_cli_timeout_rejects_invalid_duration,
+            ),
+        ]
+    }
+}
+
+mod output_template {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_request_run_output_template() -> TestResult {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-request-id", "abc-123")
+                    .set_body_string("[]"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+            "rq users(\"{uri}/users\");\nrq missing(\"{uri}/missing\");\n",
+            uri = mock_server.uri()
+        );
+        let rq_path = format!("{}/test_output_template.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--output-template",
+                "{name} {status} {method} {header.X-Request-Id} {body}",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let expected = "users 200 GET abc-123 []\nmissing 404 GET  \n";
+        if stdout != expected {
+            return Err(format!("Expected:\n{expected}\nGot:\n{stdout}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_request_run_output_template_rejects_unknown_placeholder() -> TestResult {
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input/basic.rq",
+                "--output-template",
+                "{code}",
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.success() {
+            return Err("Expected command to fail with an unknown placeholder".into());
+        }
+        if !stderr.contains("Unknown placeholder '{code}'") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "request_run_output_template",
+                test_request_run_output_template,
+            ),
+            sync_trial(
+                "request_run_output_template_rejects_unknown_placeholder",
+                test_request_run_output_template_rejects_unknown_placeholder,
+            ),
+        ]
+    }
+}
+
+mod form {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{body_string, body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_form_body_repeats_array_keys() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/submit"))
+            .and(header("content-type", "application/x-www-form-urlencoded"))
+            .and(body_string(
+                "tags%5B%5D=red&tags%5B%5D=blue&name=Ann+%26+Lee",
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+        "let tags = [\"red\", \"blue\"];\n\n[method(POST)]\nrq submit(\"{uri}/submit\", body: form[\"tags[]\": tags, \"name\": \"Ann & Lee\"]);\n",
+        uri = mock_server.uri()
+    );
+        let rq_path = format!("{}/test_form_arrays.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_form_body_interpolates_and_encodes_values() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/login"))
+            .and(header("content-type", "application/x-www-form-urlencoded"))
+            .and(body_string_contains("user=ann%40example.com"))
+            .and(body_string_contains("note=50%25+off%3F"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+        "let user = \"ann@example.com\";\n\n[method(POST)]\nrq login(\"{uri}/login\", body: form[\"user\": user, \"note\": \"{{{{discount}}}} off?\"]);\n",
+        uri = mock_server.uri()
+    );
+        let rq_path = format!("{}/test_form_interpolation.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-v", "discount=50%"])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        Ok(())
+    }
+
+    async fn test_form_body_keeps_explicit_content_type() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/submit"))
+            .and(header(
+                "content-type",
+                "application/x-www-form-urlencoded; charset=utf-8",
+            ))
+            .and(body_string_contains("name=Ann"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+        "[method(POST)]\nrq submit(\"{uri}/submit\", $[\"Content-Type\": \"application/x-www-form-urlencoded; charset=utf-8\"], form[\"name\": \"Ann\"]);\n",
+        uri = mock_server.uri()
+    );
+        let rq_path = format!("{}/test_form_content_type.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "form_body_repeats_array_keys",
+                test_form_body_repeats_array_keys,
+            ),
+            async_trial(
+                "form_body_interpolates_and_encodes_values",
+                test_form_body_interpolates_and_encodes_values,
+            ),
+            async_trial(
+                "form_body_keeps_explicit_content_type",
+                test_form_body_keeps_explicit_content_type,
+            ),
+        ]
+    }
+}
+
+mod var_file {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{body_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn write_files(
+        mock_server: &MockServer,
+        name: &str,
+        vars: &str,
+    ) -> std::io::Result<(String, String)> {
+        let rq_content = format!(
+        "[method(POST)]\nrq create(\"{uri}/{{{{resource}}}}\", $[\"X-Env\": \"{{{{env_name}}}}\"], user);\n",
+        uri = mock_server.uri()
+    );
+        let tmp_dir = env!("CARGO_TARGET_TMPDIR");
+        let rq_path = format!("{tmp_dir}/{name}.rq");
+        let vars_path = format!("{tmp_dir}/{name}.json");
+        std::fs::write(&rq_path, rq_content)?;
+        std::fs::write(&vars_path, vars)?;
+        Ok((rq_path, vars_path))
+    }
+
+    async fn test_var_file_provides_variables() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/users"))
+            .and(header("X-Env", "staging"))
+            .and(body_json(serde_json::json!({"name": "Ada", "admin": true})))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let (rq_path, vars_path) = write_files(
+        &mock_server,
+        "test_var_file",
+        r#"{"resource": "users", "env_name": "staging", "user": {"name": "Ada", "admin": true}}"#,
+    )
+    .await?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--var-file", &vars_path])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_cli_variable_overrides_var_file() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/users"))
+            .and(header("X-Env", "production"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let (rq_path, vars_path) = write_files(
+            &mock_server,
+            "test_var_file_override",
+            r#"{"resource": "users", "env_name": "staging", "user": {}}"#,
+        )
+        .await?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--var-file",
+                &vars_path,
+                "-v",
+                "env_name=production",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn test_var_file_rejects_non_object() -> TestResult {
+        let vars_path = format!("{}/test_var_file_array.json", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&vars_path, "[1, 2]")?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input/basic.rq",
+                "--var-file",
+                &vars_path,
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(3) {
+            return Err(format!("Expected exit code 3, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("must contain a JSON object") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "var_file_provides_variables",
+                test_var_file_provides_variables,
+            ),
+            async_trial(
+                "cli_variable_overrides_var_file",
+                test_cli_variable_overrides_var_file,
+            ),
+            sync_trial(
+                "var_file_rejects_non_object",
+                test_var_file_rejects_non_object,
+            ),
+        ]
+    }
+}
+
+mod mtls {
+    use super::{sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use openssl::asn1::Asn1Time;
+    use openssl::hash::MessageDigest;
+    use openssl::nid::Nid;
+    use openssl::pkey::PKey;
+    use openssl::rsa::Rsa;
+    use openssl::ssl::{SslAcceptor, SslMethod, SslVerifyMode};
+    use openssl::x509::extension::SubjectAlternativeName;
+    use openssl::x509::{X509NameBuilder, X509};
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+
+    struct TlsServer {
+        port: u16,
+        ca_file: String,
+    }
+
+    fn server_identity() -> Result<(PKey<openssl::pkey::Private>, X509), Box<dyn std::error::Error>>
+    {
+        let key = PKey::from_rsa(Rsa::generate(2048)?)?;
+        let mut name = X509NameBuilder::new()?;
+        name.append_entry_by_nid(Nid::COMMONNAME, "localhost")?;
+        let name = name.build();
+        let mut cert = X509::builder()?;
+        cert.set_version(2)?;
+        cert.set_subject_name(&name)?;
+        cert.set_issuer_name(&name)?;
+        cert.set_pubkey(&key)?;
+        cert.set_not_before(Asn1Time::days_from_now(0)?.as_ref())?;
+        cert.set_not_after(Asn1Time::days_from_now(1)?.as_ref())?;
+        let san = SubjectAlternativeName::new()
+            .dns("localhost")
+            .build(&cert.x509v3_context(None, None))?;
+        cert.append_extension(san)?;
+        cert.sign(&key, MessageDigest::sha256())?;
+        Ok((key, cert.build()))
+    }
+
+    fn start_mtls_server(name: &str) -> Result<TlsServer, Box<dyn std::error::Error>> {
+        let (key, cert) = server_identity()?;
+        let ca_file = format!("{}/{name}_ca.pem", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&ca_file, cert.to_pem()?)?;
+
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
+        acceptor.set_private_key(&key)?;
+        acceptor.set_certificate(&cert)?;
+        acceptor.set_verify_callback(
+            SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT,
+            |_, _| true,
+        );
+        let acceptor = Arc::new(acceptor.build());
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let Ok(mut tls) = acceptor.accept(stream) else {
+                    continue;
+                };
+                let client = tls
+                    .ssl()
+                    .peer_certificate()
+                    .and_then(|c| {
+                        c.subject_name()
+                            .entries_by_nid(Nid::COMMONNAME)
+                            .next()
+                            .and_then(|e| e.data().as_utf8().ok().map(|s| s.to_string()))
+                    })
+                    .unwrap_or_default();
+                let mut buf = [0u8; 4096];
+                let _ = tls.read(&mut buf);
+                let body = format!("{{\"client\": \"{client}\"}}");
+                let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+                let _ = tls.write_all(response.as_bytes());
+                let _ = tls.shutdown();
+            }
+        });
+
+        Ok(TlsServer { port, ca_file })
+    }
+
+    fn write_request(server: &TlsServer, name: &str, attribute: &str) -> std::io::Result<String> {
+        let rq_content = format!(
+            "{attribute}rq secure(\"https://localhost:{}/secure\");\n",
+            server.port
+        );
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+        Ok(rq_path)
+    }
+
+    fn copy_fixture_cert(file: &str, name: &str) -> std::io::Result<()> {
+        std::fs::copy(
+            format!("{}/tests/fixtures/certs/{file}", env!("CARGO_MANIFEST_DIR")),
+            format!("{}/{name}", env!("CARGO_TARGET_TMPDIR")),
+        )?;
+        Ok(())
+    }
+
+    fn test_cert_attribute_presents_pkcs12_identity() -> TestResult {
+        let server = start_mtls_server("mtls_p12")?;
+        copy_fixture_cert("client.p12", "mtls_client.p12")?;
+        let rq_path = write_request(
+            &server,
+            "test_mtls_p12",
+            "[cert(\"mtls_client.p12\", \"testpass\")]\n",
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .env("SSL_CERT_FILE", &server.ca_file)
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+        if !stdout.contains("test-client") {
+            return Err(format!("Server did not see the client certificate: {stdout}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_cert_attribute_presents_pem_identity() -> TestResult {
+        let server = start_mtls_server("mtls_pem")?;
+        copy_fixture_cert("client.pem", "mtls_client.pem")?;
+        let rq_path = write_request(&server, "test_mtls_pem", "[cert(\"mtls_client.pem\")]\n")?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .env("SSL_CERT_FILE", &server.ca_file)
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        if !output.status.success() || !stdout.contains("test-client") {
+            return Err(format!(
+                "Expected the PEM identity to be accepted: {stdout}{}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn test_request_without_cert_is_rejected_by_mtls_server() -> TestResult {
+        let server = start_mtls_server("mtls_none")?;
+        let rq_path = write_request(&server, "test_mtls_none", "")?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path])
+            .env("SSL_CERT_FILE", &server.ca_file)
+            .output()?;
+
+        if output.status.code() != Some(6) {
+            return Err(format!(
+                "Expected a network error without a client certificate, got {:?}",
+                output.status.code()
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn test_cert_attribute_reports_missing_file() -> TestResult {
+        let server = start_mtls_server("mtls_missing")?;
+        let rq_path = write_request(
+            &server,
+            "test_mtls_missing",
+            "[cert(\"does-not-exist.p12\")]\n",
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(3) {
+            return Err(format!("Expected exit code 3, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("Failed to read client certificate 'does-not-exist.p12'") {
+            return Err(format!("Unexpected error output: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_cert_attribute_on_imported_endpoint_template() -> TestResult {
+        let server = start_mtls_server("mtls_ep")?;
+        let dir = format!("{}/mtls_ep", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::create_dir_all(format!("{dir}/shared"))?;
+        copy_fixture_cert("client.p12", "mtls_ep/shared/client.p12")?;
+        std::fs::write(
+            format!("{dir}/shared/base.rq"),
+            format!(
+                "[cert(\"client.p12\", \"testpass\")]\nep secure_base(\"https://localhost:{}\");\n",
+                server.port
+            ),
+        )?;
+        let rq_path = format!("{dir}/api.rq");
+        std::fs::write(
+            &rq_path,
+            "import \"shared/base\";\n\nep api<secure_base>(\"/secure\") {\n    rq secure();\n}\n",
+        )?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-n",
+                "api/secure",
+                "-o",
+                "json",
+            ])
+            .env("SSL_CERT_FILE", &server.ca_file)
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        if !output.status.success() || !stdout.contains("test-client") {
+            return Err(format!(
+                "Expected the endpoint certificate to be presented: {stdout}{}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            sync_trial(
+                "cert_attribute_presents_pkcs12_identity",
+                test_cert_attribute_presents_pkcs12_identity,
+            ),
+            sync_trial(
+                "cert_attribute_presents_pem_identity",
+                test_cert_attribute_presents_pem_identity,
+            ),
+            sync_trial(
+                "request_without_cert_is_rejected_by_mtls_server",
+                test_request_without_cert_is_rejected_by_mtls_server,
+            ),
+            sync_trial(
+                "cert_attribute_reports_missing_file",
+                test_cert_attribute_reports_missing_file,
+            ),
+            sync_trial(
+                "cert_attribute_on_imported_endpoint_template",
+                test_cert_attribute_on_imported_endpoint_template,
+            ),
+        ]
+    }
+}
+
+mod ndjson {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{body_string, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_body_lines_from_var_file_objects() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/_bulk"))
+            .and(header("content-type", "application/x-ndjson"))
+            .and(body_string(
+                "{\"index\":{\"_index\":\"logs\"}}\n{\"level\":\"info\",\"message\":\"started\"}\n",
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let tmp_dir = env!("CARGO_TARGET_TMPDIR");
+        let rq_path = format!("{tmp_dir}/test_body_lines.rq");
+        let vars_path = format!("{tmp_dir}/test_body_lines.json");
+        std::fs::write(
+            &rq_path,
+            format!(
+                "[method(POST)]\nrq bulk(\"{uri}/_bulk\", body_lines: records);\n",
+                uri = mock_server.uri()
+            ),
+        )?;
+        std::fs::write(
+            &vars_path,
+            r#"{"records": [{"index": {"_index": "logs"}}, {"level": "info", "message": "started"}]}"#,
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--var-file", &vars_path])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![async_trial(
+            "body_lines_from_var_file_objects",
+            test_body_lines_from_var_file_objects,
+        )]
+    }
+}
+
+mod charset {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn run_with_response(
+        content_type: &str,
+        body: &[u8],
+        name: &str,
+    ) -> Result<std::process::Output, Box<dyn std::error::Error>> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/text"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body.to_vec(), content_type))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!("rq text(\"{uri}/text\");\n", uri = mock_server.uri()),
+        )?;
+
+        Ok(rq_cmd().args(["request", "run", "-s", &rq_path]).output()?)
+    }
+
+    async fn test_latin1_response_is_decoded() -> TestResult {
+        let output = run_with_response(
+            "text/plain; charset=ISO-8859-1",
+            b"Caf\xE9 cr\xE8me",
+            "test_charset_latin1",
+        )
+        .await?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if !output.status.success() {
+            return Err(format!("Command failed: {stderr}").into());
+        }
+        if !stdout.contains("Café crème") {
+            return Err(format!("Expected decoded latin1 body, got: {stdout}").into());
+        }
+        if stderr.contains("Warning") {
+            return Err(format!("Unexpected warning: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_unsupported_charset_warns() -> TestResult {
+        let output = run_with_response(
+            "text/plain; charset=x-unknown",
+            b"hello",
+            "test_charset_unknown",
+        )
+        .await?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if !output.status.success() {
+            return Err(format!("Command failed: {stderr}").into());
+        }
+        if !stderr.contains("Warning: text: Response charset 'x-unknown' is not supported") {
+            return Err(format!("Expected charset warning, got: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "latin1_response_is_decoded",
+                test_latin1_response_is_decoded,
+            ),
+            async_trial("unsupported_charset_warns", test_unsupported_charset_warns),
+        ]
+    }
+}
+
+mod insecure_auth {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_loopback_http_auth_does_not_warn() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/me"))
+            .and(header("authorization", "Bearer local-token"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = format!("{}/test_insecure_auth.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!(
+                "rq me(\"{uri}/me\", $[\"Authorization\": \"Bearer local-token\"]);\n",
+                uri = mock_server.uri()
+            ),
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if !output.status.success() {
+            return Err(format!("Command failed: {stderr}").into());
+        }
+        if stderr.contains("plain HTTP") {
+            return Err(format!("Unexpected insecure auth warning: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![async_trial(
+            "loopback_http_auth_does_not_warn",
+            test_loopback_http_auth_does_not_warn,
+        )]
+    }
+}
+
+mod retry {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{json_data, rq_cmd};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_failures(mock_server: &MockServer, status: u16, failures: u64, successes: u64) {
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(status))
+            .up_to_n_times(failures)
+            .expect(failures)
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(successes)
+            .mount(mock_server)
+            .await;
+    }
+
+    fn run_with_attribute(
+        mock_server: &MockServer,
+        attribute: &str,
+        name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!(
+                "{attribute}\nrq flaky(\"{uri}/flaky\");\n",
+                uri = mock_server.uri()
+            ),
+        )?;
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    async fn test_retry_default_statuses_until_success() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_failures(&mock_server, 503, 2, 1).await;
+
+        let stdout = run_with_attribute(&mock_server, "[retry(count=3)]", "test_retry_default")?;
+
+        if !stdout.contains("\"status\": 200") {
+            return Err(format!("Expected final status 200, got: {stdout}").into());
+        }
+        let retries = &json_data(&stdout)?["results"][0]["retries"];
+        assert_eq!(retries["attempts"], 2, "{stdout}");
+        assert_eq!(retries["rate_limited"], false, "{stdout}");
+        assert!(retries["wait_ms"].is_u64(), "{stdout}");
+
+        Ok(())
+    }
+
+    async fn test_retry_only_listed_statuses() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_failures(&mock_server, 503, 1, 0).await;
+
+        let stdout = run_with_attribute(
+            &mock_server,
+            "[retry(count=3, on=[502, 429])]",
+            "test_retry_listed",
+        )?;
+
+        if !stdout.contains("\"status\": 503") {
+            return Err(format!("Expected 503 to be returned without retry, got: {stdout}").into());
+        }
+        let result = &json_data(&stdout)?["results"][0];
+        assert!(result.get("retries").is_none(), "{stdout}");
+
+        Ok(())
+    }
+
+    async fn test_retry_gives_up_after_count() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_failures(&mock_server, 429, 3, 0).await;
+
+        let stdout = run_with_attribute(&mock_server, "[retry(count=2)]", "test_retry_exhausted")?;
+
+        if !stdout.contains("\"status\": 429") {
+            return Err(format!("Expected last 429 response, got: {stdout}").into());
+        }
+        let retries = &json_data(&stdout)?["results"][0]["retries"];
+        assert_eq!(retries["attempts"], 2, "{stdout}");
+        assert_eq!(retries["rate_limited"], true, "{stdout}");
+
+        Ok(())
+    }
+
+    async fn test_retry_backoff_waits_between_attempts() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_failures(&mock_server, 503, 2, 1).await;
+
+        let stdout = run_with_attribute(
+            &mock_server,
+            "[retry(count=3, backoff=100ms)]",
+            "test_retry_backoff",
+        )?;
+
+        let retries = &json_data(&stdout)?["results"][0]["retries"];
+        assert_eq!(retries["attempts"], 2, "{stdout}");
+        let wait_ms = retries["wait_ms"].as_u64().unwrap_or_default();
+        assert!(
+            (300..=450).contains(&wait_ms),
+            "expected 100ms + 200ms of backoff plus jitter: {stdout}"
+        );
+
+        Ok(())
+    }
+
+    fn test_retry_connection_errors() -> TestResult {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")?
+            .local_addr()?
+            .port();
+        let rq_path = format!("{}/test_retry_connection.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!("[retry(count=2, backoff=10ms)]\nrq offline(\"http://127.0.0.1:{port}/\");\n"),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--debug"])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert_eq!(output.status.code(), Some(6), "{stderr}");
+        assert!(stderr.contains("retrying in"), "{stderr}");
+        assert!(stderr.contains("(2/2)"), "{stderr}");
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "retry_default_statuses_until_success",
+                test_retry_default_statuses_until_success,
+            ),
+            async_trial(
+                "retry_only_listed_statuses",
+                test_retry_only_listed_statuses,
+            ),
+            async_trial(
+                "retry_gives_up_after_count",
+                test_retry_gives_up_after_count,
+            ),
+            async_trial(
+                "retry_backoff_waits_between_attempts",
+                test_retry_backoff_waits_between_attempts,
+            ),
+            sync_trial("retry_connection_errors", test_retry_connection_errors),
+        ]
+    }
+}
+
+mod idempotency {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn run_post(
+        mock_server: &MockServer,
+        attributes: &str,
+        name: &str,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!(
+                "[method(POST)]\n{attributes}\nrq create(\"{uri}/orders\", body: \"{{}}\");\n",
+                uri = mock_server.uri()
+            ),
+        )?;
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+        let requests = mock_server
+            .received_requests()
+            .await
+            .ok_or("Request recording is disabled")?;
+        Ok(requests
+            .iter()
+            .map(|r| {
+                r.headers
+                    .get("idempotency-key")
+                    .or_else(|| r.headers.get("x-request-id"))
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string()
+            })
+            .collect())
+    }
+
+    async fn test_idempotency_key_is_stable_across_retries() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/orders"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/orders"))
+            .respond_with(ResponseTemplate::new(201))
+            .mount(&mock_server)
+            .await;
+
+        let keys = run_post(
+            &mock_server,
+            "[retry(count=3)]\n[idempotency]",
+            "test_idempotency_retry",
+        )
+        .await?;
+
+        if keys.len() != 3 {
+            return Err(format!("Expected 3 attempts, got: {keys:?}").into());
+        }
+        if keys[0].len() != 36 || keys.iter().any(|k| k != &keys[0]) {
+            return Err(format!("Expected one UUID reused on every attempt, got: {keys:?}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_idempotency_custom_header_name() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/orders"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let keys = run_post(
+            &mock_server,
+            "[idempotency(\"X-Request-Id\")]",
+            "test_idempotency_custom_header",
+        )
+        .await?;
+
+        if keys.first().is_none_or(|k| k.len() != 36) {
+            return Err(format!("Expected X-Request-Id to carry a UUID, got: {keys:?}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "idempotency_key_is_stable_across_retries",
+                test_idempotency_key_is_stable_across_retries,
+            ),
+            async_trial(
+                "idempotency_custom_header_name",
+                test_idempotency_custom_header_name,
+            ),
+        ]
+    }
+}
+
+mod conditional_headers {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::any;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn received_content_types(
+        source: &str,
+        name: &str,
+    ) -> Result<Vec<(String, Option<String>)>, Box<dyn std::error::Error>> {
+        let mock_server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, source.replace("{uri}", &mock_server.uri()))?;
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        let requests = mock_server
+            .received_requests()
+            .await
+            .ok_or("Request recording is disabled")?;
+        Ok(requests
+            .iter()
+            .map(|r| {
+                (
+                    r.method.to_string(),
+                    r.headers
+                        .get("content-type")
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_string),
+                )
+            })
+            .collect())
+    }
+
+    async fn test_when_body_header_skipped_on_requests_without_body() -> TestResult {
+        let source = r#"ep api("{uri}", headers: $["Content-Type": "application/json" when_body, "X-Api": "1"]) {
+    rq list("/items");
+    [method(POST)]
+    rq create("/items", body: "{\"name\": \"a\"}");
+}
+"#;
+
+        let received = received_content_types(source, "test_when_body_endpoint").await?;
+
+        let get = received.iter().find(|(m, _)| m == "GET");
+        let post = received.iter().find(|(m, _)| m == "POST");
+        if get.is_none_or(|(_, ct)| ct.is_some()) {
+            return Err(format!("Expected GET without content-type, got: {received:?}").into());
+        }
+        if post.is_none_or(|(_, ct)| ct.as_deref() != Some("application/json")) {
+            return Err(format!("Expected POST with content-type, got: {received:?}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_when_body_header_from_headers_variable() -> TestResult {
+        let source = r#"let common = $["Content-Type": "text/plain" when_body];
+
+rq ping("{uri}/ping", common);
+[method(PUT)]
+rq upload("{uri}/upload", common, body: "plain");
+"#;
+
+        let received = received_content_types(source, "test_when_body_variable").await?;
+
+        let expected = vec![
+            ("GET".to_string(), None),
+            ("PUT".to_string(), Some("text/plain".to_string())),
+        ];
+        if received != expected {
+            return Err(format!("Expected {expected:?}, got: {received:?}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_request_header_overrides_when_body_header() -> TestResult {
+        let source = r#"ep api("{uri}", headers: $["Content-Type": "application/json" when_body]) {
+    rq legacy("/legacy", $["Content-Type": "application/xml"]);
+}
+"#;
+
+        let received = received_content_types(source, "test_when_body_override").await?;
+
+        if received.first().and_then(|(_, ct)| ct.as_deref()) != Some("application/xml") {
+            return Err(format!("Expected request header to win, got: {received:?}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "when_body_header_skipped_on_requests_without_body",
+                test_when_body_header_skipped_on_requests_without_body,
+            ),
+            async_trial(
+                "when_body_header_from_headers_variable",
+                test_when_body_header_from_headers_variable,
+            ),
+            async_trial(
+                "request_header_overrides_when_body_header",
+                test_request_header_overrides_when_body_header,
+            ),
+        ]
+    }
+}
+
+mod stdin {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::io::Write;
+    use std::process::{Output, Stdio};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn run_from_stdin(args: &[&str], document: &str) -> Result<Output, Box<dyn std::error::Error>> {
+        let mut child = rq_cmd()
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .ok_or("stdin was not captured")?
+            .write_all(document.as_bytes())?;
+        Ok(child.wait_with_output()?)
+    }
+
+    async fn test_run_document_from_stdin() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/piped"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("from stdin"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let document = format!("rq piped(\"{}/piped\");\n", mock_server.uri());
+        let output = run_from_stdin(&["request", "run", "-s", "-"], &document)?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+        if !stdout.contains("request_name: piped") || !stdout.contains("from stdin") {
+            return Err(format!("Expected piped request output, got: {stdout}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_stdin_syntax_error_uses_synthetic_path() -> TestResult {
+        let output = run_from_stdin(&["-s", "-"], "rq broken(\"http://localhost:8080\")\n")?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(2) {
+            return Err(format!("Expected exit code 2, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("Syntax error in <stdin> at line 1") {
+            return Err(format!("Expected error located in <stdin>, got: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial("run_document_from_stdin", test_run_document_from_stdin),
+            sync_trial(
+                "stdin_syntax_error_uses_synthetic_path",
+                test_stdin_syntax_error_uses_synthetic_path,
+            ),
+        ]
+    }
+}
+
+mod fail_if_empty {
+    use super::{sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::path::PathBuf;
+
+    fn directory_without_requests(name: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+            dir.join("vars.rq"),
+            "let base_url = \"http://localhost:8080\";\n",
+        )?;
+        Ok(dir)
+    }
+
+    fn test_fail_if_empty_exits_with_not_found() -> TestResult {
+        let dir = directory_without_requests("test_fail_if_empty")?;
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &dir.to_string_lossy(),
+                "--fail-if-empty",
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(5) {
+            return Err(format!("Expected exit code 5, got: {:?}", output.status.code()).into());
+        }
+        if !stderr.contains("no request was executed") {
+            return Err(format!("Expected empty-run error, got: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_empty_run_succeeds_without_flag() -> TestResult {
+        let dir = directory_without_requests("test_empty_run_default")?;
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &dir.to_string_lossy()])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Expected empty run to succeed by default, got: {:?}",
+                output.status.code()
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            sync_trial(
+                "fail_if_empty_exits_with_not_found",
+                test_fail_if_empty_exits_with_not_found,
+            ),
+            sync_trial(
+                "empty_run_succeeds_without_flag",
+                test_empty_run_succeeds_without_flag,
+            ),
+        ]
+    }
+}
+
+mod transform {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn users_server() -> MockServer {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"[{"name":"alice","id":1},{"name":"bob","id":2}]"#),
+            )
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    fn source(uri: &str) -> String {
+        format!("rq users(\"{uri}/users\");\n")
+    }
+
+    async fn test_request_run_transform_projects_body() -> TestResult {
+        let mock_server = users_server().await;
+        let rq_path = write_rq("test_transform_projects", source(&mock_server.uri()))?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--transform",
+                ".[] | .name",
+                "--output-template",
+                "{body}",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout != "alice\nbob\n" {
+            return Err(format!("Expected projected names, got: {stdout}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_request_run_transform_type_error() -> TestResult {
+        let mock_server = users_server().await;
+        let rq_path = write_rq("test_transform_type_error", source(&mock_server.uri()))?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--transform", ".name"])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.code() != Some(3) {
+            return Err(format!("Expected exit code 3, got: {:?}", output.status.code()).into());
+        }
+        if !stderr
+            .contains("Failed to transform response of 'users': cannot index array with \"name\"")
+        {
+            return Err(format!("Expected transform error, got: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    fn test_request_run_transform_rejects_invalid_filter() -> TestResult {
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input",
+                "--transform",
+                "name",
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.success() {
+            return Err("Expected an invalid filter to be rejected".into());
+        }
+        if !stderr.contains("Invalid transform 'name': a filter must start with '.'") {
+            return Err(format!("Expected invalid filter error, got: {stderr}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "request_run_transform_projects_body",
+                test_request_run_transform_projects_body,
+            ),
+            async_trial(
+                "request_run_transform_type_error",
+                test_request_run_transform_type_error,
+            ),
+            sync_trial(
+                "request_run_transform_rejects_invalid_filter",
+                test_request_run_transform_rejects_invalid_filter,
+            ),
+        ]
+    }
+}
+
+mod output_attribute {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_output_attribute_overrides_cli_format() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id":1}"#))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/text"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("plain"))
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+            "[output(json)]\nrq as_json(\"{uri}/json\");\nrq as_default(\"{uri}/text\");\n",
+            uri = mock_server.uri()
+        );
+        let rq_path = format!("{}/test_output_attribute.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "text"])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !stdout.contains("\"request_name\": \"as_json\"") {
+            return Err(format!("Expected JSON output for 'as_json', got: {stdout}").into());
+        }
+        if !stdout.contains("request_name: as_default") || stdout.contains("\"as_default\"") {
+            return Err(format!("Expected text output for 'as_default', got: {stdout}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![async_trial(
+            "output_attribute_overrides_cli_format",
+            test_output_attribute_overrides_cli_format,
+        )]
+    }
+}
+
+mod summary {
+    use super::{async_trial, TestResult};
+    use crate::common::{json_data, rq_cmd};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn write_mixed_run(
+        name: &str,
+    ) -> Result<(MockServer, String), Box<dyn std::error::Error>> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ok"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let rq_content = format!(
+            "rq first(\"{uri}/ok\");\nrq second(\"{uri}/ok\");\nrq third(\"{uri}/missing\");\n",
+            uri = mock_server.uri()
+        );
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, rq_content)?;
+        Ok((mock_server, rq_path))
+    }
+
+    async fn test_summary_line_in_text_output() -> TestResult {
+        let (_server, rq_path) = write_mixed_run("test_summary_text").await?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--summary"])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let last_line = stdout.lines().last().unwrap_or_default();
+        if !last_line.starts_with("Summary: 3 requests, 2 succeeded, 1 failed in ")
+            || !last_line.ends_with(" ms (200: 2, 404: 1)")
+        {
+            return Err(format!("Unexpected summary line: {last_line}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_summary_object_in_json_output() -> TestResult {
+        let (_server, rq_path) = write_mixed_run("test_summary_json").await?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--summary", "-o", "json"])
+            .output()?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.contains("Summary:") {
+            return Err(format!("Expected no summary line in JSON output, got: {stdout}").into());
+        }
+        let data = json_data(&stdout)?;
+        let summary = &data["summary"];
+        if summary["total"] != 3 || summary["succeeded"] != 2 || summary["failed"] != 1 {
+            return Err(format!("Unexpected summary counts: {summary}").into());
+        }
+        if summary["status_codes"]["200"] != 2 || summary["status_codes"]["404"] != 1 {
+            return Err(format!("Unexpected status histogram: {summary}").into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_no_summary_without_flag() -> TestResult {
+        let (_server, rq_path) = write_mixed_run("test_summary_absent").await?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+
+        let data = json_data(&String::from_utf8_lossy(&output.stdout))?;
+        if data.get("summary").is_some() {
+            return Err(format!("Expected no summary without --summary, got: {data}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "summary_line_in_text_output",
+                test_summary_line_in_text_output,
+            ),
+            async_trial(
+                "summary_object_in_json_output",
+                test_summary_object_in_json_output,
+            ),
+            async_trial("no_summary_without_flag", test_no_summary_without_flag),
+        ]
+    }
+}
+
+mod body_bytes {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{body_bytes, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const PAYLOAD: &[u8] = &[0x08, 0x96, 0x01, 0xff, 0x00, 0x0d, 0x0a, 0xc3];
+
+    fn write_files(name: &str, source: String) -> Result<String, Box<dyn std::error::Error>> {
+        let tmp_dir = env!("CARGO_TARGET_TMPDIR");
+        let rq_path = format!("{tmp_dir}/{name}.rq");
+        std::fs::write(format!("{tmp_dir}/{name}.bin"), PAYLOAD)?;
+        std::fs::write(&rq_path, source)?;
+        Ok(rq_path)
+    }
+
+    async fn test_read_bytes_sends_file_verbatim() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/upload"))
+            .and(header("content-type", "application/octet-stream"))
+            .and(body_bytes(PAYLOAD))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = write_files(
+        "test_read_bytes_verbatim",
+        format!(
+            "let file = \"test_read_bytes_verbatim.bin\";\n\n[method(POST)]\nrq upload(\"{uri}/upload\", body: io.read_bytes(file));\n",
+            uri = mock_server.uri()
+        ),
+    )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_read_bytes_keeps_explicit_content_type() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/upload"))
+            .and(header("content-type", "application/x-protobuf"))
+            .and(body_bytes(PAYLOAD))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = write_files(
+        "test_read_bytes_content_type",
+        format!(
+            "[method(POST)]\nrq upload(\"{uri}/upload\", $[\"Content-Type\": \"application/x-protobuf\"], io.read_bytes(\"test_read_bytes_content_type.bin\"));\n",
+            uri = mock_server.uri()
+        ),
+    )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "read_bytes_sends_file_verbatim",
+                test_read_bytes_sends_file_verbatim,
+            ),
+            async_trial(
+                "read_bytes_keeps_explicit_content_type",
+                test_read_bytes_keeps_explicit_content_type,
+            ),
+        ]
+    }
+}
+
+mod pool {
+    use super::{sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    fn serve_keep_alive(stream: TcpStream) {
+        let Ok(mut writer) = stream.try_clone() else {
+            return;
+        };
+        let mut reader = BufReader::new(stream);
+        loop {
+            let mut line = String::new();
+            loop {
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) | Err(_) => return,
+                    Ok(_) if line == "\r\n" => break,
+                    Ok(_) => {}
+                }
+            }
+            let response = "HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\nok";
+            if writer.write_all(response.as_bytes()).is_err() {
+                return;
+            }
+        }
+    }
+
+    fn start_counting_server() -> Result<(String, Arc<AtomicUsize>), Box<dyn std::error::Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let uri = format!("http://{}", listener.local_addr()?);
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                thread::spawn(move || serve_keep_alive(stream));
+            }
+        });
+        Ok((uri, connections))
+    }
+
+    fn run_three_requests(
+        name: &str,
+        extra_args: &[&str],
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        let (uri, connections) = start_counting_server()?;
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!("rq first(\"{uri}/a\");\nrq second(\"{uri}/b\");\nrq third(\"{uri}/c\");\n"),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path])
+            .args(extra_args)
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(connections.load(Ordering::SeqCst))
+    }
+
+    fn test_request_run_reuses_connections_by_default() -> TestResult {
+        let connections = run_three_requests("test_pool_default", &[])?;
+        if connections != 1 {
+            return Err(format!("Expected 1 reused connection, got {connections}").into());
+        }
+        Ok(())
+    }
+
+    fn test_request_run_pool_max_idle_zero_disables_reuse() -> TestResult {
+        let connections = run_three_requests("test_pool_no_idle", &["--pool-max-idle", "0"])?;
+        if connections != 3 {
+            return Err(format!("Expected 3 connections, got {connections}").into());
+        }
+        Ok(())
+    }
+
+    fn test_request_run_pool_idle_timeout_invalid() -> TestResult {
+        let output = rq_cmd()
+            .args(["request", "run", "-s", ".", "--pool-idle-timeout", "soon"])
+            .output()?;
+
+        if output.status.code() != Some(2) {
+            return Err(format!("Expected exit code 2, got {:?}", output.status.code()).into());
+        }
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            sync_trial(
+                "request_run_reuses_connections_by_default",
+                test_request_run_reuses_connections_by_default,
+            ),
+            sync_trial(
+                "request_run_pool_max_idle_zero_disables_reuse",
+                test_request_run_pool_max_idle_zero_disables_reuse,
+            ),
+            sync_trial(
+                "request_run_pool_idle_timeout_invalid",
+                test_request_run_pool_idle_timeout_invalid,
+            ),
+        ]
+    }
+}
+
+#[cfg(unix)]
+mod unix_socket {
+    use super::{sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    fn serve_once(listener: UnixListener, request_head: mpsc::Sender<String>) {
+        let Ok((stream, _)) = listener.accept() else {
+            return;
+        };
+        let Ok(mut writer) = stream.try_clone() else {
+            return;
+        };
+        let mut reader = BufReader::new(stream);
+        let mut head = String::new();
+        let mut line = String::new();
+        while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+            head.push_str(&line);
+            line.clear();
+        }
+        let _ = request_head.send(head);
+        let body = r#"{"ServerVersion":"27.0.0"}"#;
+        let _ = write!(
+        writer,
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    }
+
+    fn test_request_run_over_unix_socket() -> TestResult {
+        let tmp_dir = env!("CARGO_TARGET_TMPDIR");
+        let socket_path = format!("{tmp_dir}/test_unix_socket.sock");
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path)?;
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || serve_once(listener, sender));
+
+        let rq_path = format!("{tmp_dir}/test_unix_socket.rq");
+        std::fs::write(
+            &rq_path,
+            format!("[unix(\"{socket_path}\")]\nrq info(\"http://docker/v1.43/info\");\n"),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        let head = receiver.recv_timeout(std::time::Duration::from_secs(5))?;
+        if !head.starts_with("GET /v1.43/info HTTP/1.1\r\n") {
+            return Err(format!("Unexpected request line: {head}").into());
+        }
+        if !head.to_lowercase().contains("host: docker\r\n") {
+            return Err(format!("Expected Host header from the URL, got: {head}").into());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !stdout.contains("ServerVersion") {
+            return Err(format!("Expected response body in output, got: {stdout}").into());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![sync_trial(
+            "request_run_over_unix_socket",
+            test_request_run_over_unix_socket,
+        )]
+    }
+}
+
+mod assertions {
+    use super::{async_trial, TestResult};
+    use crate::common::{rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const ASSERTIONS: &str = r#"[expect(status == 200)]
+[expect(time_ms < 10000)]
+[expect(header.Content-Type contains "json")]
+[expect(body.$.user.id == 7)]
+[expect(body.$.user.roles[0] == "admin")]
+[expect(body.$.user.name matches "^Ali")]
+"#;
+
+    async fn user_server(status: u16) -> MockServer {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(status).set_body_raw(
+                r#"{"user": {"id": 7, "name": "Alice", "roles": ["admin"]}}"#,
+                "application/json",
+            ))
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    async fn test_expect_passes_when_assertions_hold() -> TestResult {
+        let mock_server = user_server(200).await;
+        let rq_path = write_rq(
+            "test_expect_passes",
+            format!("{ASSERTIONS}rq get_user(\"{}/user\");\n", mock_server.uri()),
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_expect_failure_reports_actual_and_expected() -> TestResult {
+        let mock_server = user_server(503).await;
+        let rq_path = write_rq(
+        "test_expect_failure",
+        format!(
+            "[expect(status == 200)]\n[expect(body.$.user.id != 7)]\n[expect(body.$.user.email contains \"@\")]\nrq get_user(\"{}/user\");\n",
+            mock_server.uri()
+        ),
+    )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert_eq!(output.status.code(), Some(10), "stderr: {stderr}");
+        assert!(
+            stdout.contains("Alice"),
+            "response should still be printed: {stdout}"
+        );
+        assert!(stderr
+            .contains("Assertion failed in 'get_user': status == 200 (expected 200, got 503)"));
+        assert!(stderr.contains(
+            "Assertion failed in 'get_user': body.$.user.id != 7 (expected != 7, got 7)"
+        ));
+        assert!(stderr.contains(
+        "Assertion failed in 'get_user': body.$.user.email contains \"@\" (expected contains \"@\", got <missing>)"
+    ));
+
+        Ok(())
+    }
+
+    async fn test_expect_failure_as_json_error() -> TestResult {
+        let mock_server = user_server(200).await;
+        let rq_path = write_rq(
+            "test_expect_failure_json",
+            format!(
+                "[expect(body.$.user.roles contains \"owner\")]\nrq get_user(\"{}/user\");\n",
+                mock_server.uri()
+            ),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+        let stdout: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert_eq!(output.status.code(), Some(10), "stderr: {stderr}");
+        assert_eq!(
+            stdout["data"]["results"][0]["assertion_failures"][0]["actual"],
+            "[\"admin\"]"
+        );
+        assert!(
+            stderr.contains("\"type\":\"assertion\""),
+            "stderr: {stderr}"
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "expect_passes_when_assertions_hold",
+                test_expect_passes_when_assertions_hold,
+            ),
+            async_trial(
+                "expect_failure_reports_actual_and_expected",
+                test_expect_failure_reports_actual_and_expected,
+            ),
+            async_trial(
+                "expect_failure_as_json_error",
+                test_expect_failure_as_json_error,
+            ),
+        ]
+    }
+}
+
+mod resume {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::path::Path;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount(server: &MockServer, route: &str, calls: u64) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(calls)
+            .mount(server)
+            .await;
+    }
+
+    fn run(dir: &Path, cache: &Path, extra: &[&str]) -> std::process::Output {
+        let source = dir.display().to_string();
+        let mut args = vec!["request", "run", "-s", &source, "--resume"];
+        args.extend_from_slice(extra);
+        rq_cmd()
+            .env("XDG_CACHE_HOME", cache)
+            .args(args)
+            .output()
+            .expect("rq runs")
+    }
+
+    async fn test_resume_skips_requests_completed_before_failure() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount(&mock_server, "/first", 2).await;
+        Mock::given(method("GET"))
+            .and(path("/second"))
+            .and(header("authorization", "Bearer abc"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        mount(&mock_server, "/third", 2).await;
+
+        let tmp_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("test_resume_skips");
+        let dir = tmp_dir.join("requests");
+        let cache = tmp_dir.join("cache");
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+        dir.join("api.rq"),
+        format!(
+            "rq first(\"{uri}/first\");\n\n[required(token)]\nrq second(\"{uri}/second\", $[\"Authorization\": \"Bearer {{{{token}}}}\"]);\n\nrq third(\"{uri}/third\");\n",
+            uri = mock_server.uri()
+        ),
+    )?;
+
+        let interrupted = run(&dir, &cache, &[]);
+        assert!(!interrupted.status.success());
+
+        let resumed = run(&dir, &cache, &["-v", "token=abc"]);
+        let stderr = String::from_utf8_lossy(&resumed.stderr);
+        assert!(resumed.status.success(), "stderr: {stderr}");
+        assert!(stderr.contains("Resuming: skipping 1 request(s) that succeeded in a previous run"));
+        let stdout = String::from_utf8_lossy(&resumed.stdout);
+        assert!(!stdout.contains("request_name: first"), "stdout: {stdout}");
+        assert!(stdout.contains("request_name: second"), "stdout: {stdout}");
+
+        let fresh = run(&dir, &cache, &["-v", "token=abc"]);
+        assert!(fresh.status.success());
+        assert!(!String::from_utf8_lossy(&fresh.stderr).contains("Resuming"));
+
+        Ok(())
+    }
+
+    async fn test_resume_keeps_state_when_a_request_fails() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount(&mock_server, "/ok", 1).await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let tmp_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("test_resume_failed_status");
+        let dir = tmp_dir.join("requests");
+        let cache = tmp_dir.join("cache");
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+            dir.join("api.rq"),
+            format!(
+                "rq ok(\"{uri}/ok\");\nrq flaky(\"{uri}/flaky\");\n",
+                uri = mock_server.uri()
+            ),
+        )?;
+
+        assert!(run(&dir, &cache, &[]).status.success());
+        let rerun = run(&dir, &cache, &[]);
+        let stdout = String::from_utf8_lossy(&rerun.stdout);
+        assert!(!stdout.contains("request_name: ok"), "stdout: {stdout}");
+        assert!(stdout.contains("request_name: flaky"), "stdout: {stdout}");
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "resume_skips_requests_completed_before_failure",
+                test_resume_skips_requests_completed_before_failure,
+            ),
+            async_trial(
+                "resume_keeps_state_when_a_request_fails",
+                test_resume_keeps_state_when_a_request_fails,
+            ),
+        ]
+    }
+}
+
+mod typed_variables {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_typed_variables_interpolate_into_json_body() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/services"))
+            .and(body_json(serde_json::json!({
+                "port": 8080,
+                "ratio": 0.5,
+                "enabled": false,
+                "name": "8080"
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = write_rq(
+        "test_typed_variables",
+        format!(
+            "[method(POST)]\nrq create(\"{}/services\", body: ${{\"port\": {{{{port}}}}, \"ratio\": {{{{ratio}}}}, \"enabled\": {{{{enabled}}}}, \"name\": \"{{{{name}}}}\"}});\n",
+            mock_server.uri()
+        ),
+    )?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "-v",
+                "port:int=8080",
+                "-v",
+                "ratio:float=0.5",
+                "-v",
+                "enabled:bool=false",
+                "-v",
+                "name=8080",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Command failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn test_typed_variable_must_match_its_type() -> TestResult {
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input",
+                "-v",
+                "port:int=80a",
+            ])
+            .output()?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(2), "stderr: {stderr}");
+        assert!(
+            stderr.contains("Variable 'port' is declared as int, but '80a' is not an integer"),
+            "stderr: {stderr}"
+        );
+
+        Ok(())
+    }
+
+    fn test_typed_variable_unknown_type() -> TestResult {
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                "tests/request/run/input",
+                "-v",
+                "when:date=2024-01-01",
+            ])
+            .output()?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(2), "stderr: {stderr}");
+        assert!(
+            stderr.contains(
+                "Unknown type 'date' for variable 'when'; expected string, int, float or bool"
+            ),
+            "stderr: {stderr}"
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "typed_variables_interpolate_into_json_body",
+                test_typed_variables_interpolate_into_json_body,
+            ),
+            sync_trial(
+                "typed_variable_must_match_its_type",
+                test_typed_variable_must_match_its_type,
+            ),
+            sync_trial(
+                "typed_variable_unknown_type",
+                test_typed_variable_unknown_type,
+            ),
+        ]
+    }
+}
+
+mod auth_toggle {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{json_data, rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn source(base_url: &str) -> String {
+        format!("auth tok(auth_type.bearer) {{\n    token: \"secret-token\"\n}}\n\n[auth(\"tok\")]\nrq secured(\"{base_url}/secured\");\n")
+    }
+
+    async fn test_skip_auth_sends_request_without_credentials() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/secured"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_rq("test_skip_auth", source(&mock_server.uri()))?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--skip-auth"])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let received = mock_server
+            .received_requests()
+            .await
+            .ok_or("request recording is disabled")?;
+        assert_eq!(received.len(), 1);
+        assert!(!received[0].headers.contains_key("authorization"));
+        Ok(())
+    }
+
+    async fn test_only_auth_prints_headers_without_sending() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_rq("test_only_auth", source(&mock_server.uri()))?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--only-auth",
+                "-o",
+                "json",
+            ])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let data = json_data(&String::from_utf8_lossy(&output.stdout))?;
+        let entry = &data[0];
+        assert_eq!(entry["request"], "secured");
+        assert_eq!(entry["url"], format!("{}/secured", mock_server.uri()));
+        assert_eq!(entry["headers"]["authorization"], "Bearer secret-token");
+        Ok(())
+    }
+
+    fn test_only_auth_conflicts_with_skip_auth() -> TestResult {
+        let rq_path = write_rq("test_only_auth_conflict", source("http://localhost:1"))?;
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--only-auth",
+                "--skip-auth",
+            ])
+            .output()?;
+        assert_eq!(output.status.code(), Some(2));
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "skip_auth_sends_request_without_credentials",
+                test_skip_auth_sends_request_without_credentials,
+            ),
+            async_trial(
+                "only_auth_prints_headers_without_sending",
+                test_only_auth_prints_headers_without_sending,
+            ),
+            sync_trial(
+                "only_auth_conflicts_with_skip_auth",
+                test_only_auth_conflicts_with_skip_auth,
+            ),
+        ]
+    }
+}
+
+mod auth_field {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{json_data, rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn source(base_url: &str) -> String {
+        format!("auth tok(auth_type.bearer) {{\n    token: \"from-file\"\n}}\n\n[auth(\"tok\")]\nrq secured(\"{base_url}/secured\");\n")
+    }
+
+    async fn test_auth_field_overrides_token() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/secured"))
+            .and(header("authorization", "Bearer from-cli"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_rq("test_auth_field_override", source(&mock_server.uri()))?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--auth-field",
+                "tok.token=from-cli",
+            ])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(())
+    }
+
+    fn test_auth_field_for_other_auth_is_ignored() -> TestResult {
+        let rq_path = write_rq("test_auth_field_other", source("http://localhost:1"))?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--only-auth",
+                "-o",
+                "json",
+                "--auth-field",
+                "other.token=from-cli",
+            ])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let data = json_data(&String::from_utf8_lossy(&output.stdout))?;
+        assert_eq!(data[0]["headers"]["authorization"], "Bearer from-file");
+        Ok(())
+    }
+
+    fn test_auth_field_rejects_unknown_field() -> TestResult {
+        let rq_path = write_rq("test_auth_field_unknown", source("http://localhost:1"))?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--auth-field",
+                "client_secret=xyz",
+            ])
+            .output()?;
+
+        assert_eq!(output.status.code(), Some(3));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("Cannot override field 'client_secret' of bearer auth 'tok'"),
+            "{stderr}"
+        );
+        Ok(())
+    }
+
+    fn test_auth_field_rejects_malformed_value() -> TestResult {
+        let output = rq_cmd()
+            .args(["request", "run", "--auth-field", "token"])
+            .output()?;
+
+        assert_eq!(output.status.code(), Some(2));
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "auth_field_overrides_token",
+                test_auth_field_overrides_token,
+            ),
+            sync_trial(
+                "auth_field_for_other_auth_is_ignored",
+                test_auth_field_for_other_auth_is_ignored,
+            ),
+            sync_trial(
+                "auth_field_rejects_unknown_field",
+                test_auth_field_rejects_unknown_field,
+            ),
+            sync_trial(
+                "auth_field_rejects_malformed_value",
+                test_auth_field_rejects_malformed_value,
+            ),
+        ]
+    }
+}
+
+#[cfg(feature = "websocket")]
+mod websocket {
+    use super::{sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+    use tungstenite::handshake::server::{Request, Response};
+    use tungstenite::Message;
+
+    #[allow(clippy::result_large_err)]
+    fn serve_once(
+        listener: TcpListener,
+        token: mpsc::Sender<String>,
+        extra_frames: &'static [&str],
+    ) {
+        let Ok((stream, _)) = listener.accept() else {
+            return;
+        };
+        let callback = |request: &Request, response: Response| {
+            let header = request
+                .headers()
+                .get("x-token")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default();
+            let _ = token.send(header.to_string());
+            Ok(response)
+        };
+        let Ok(mut socket) = tungstenite::accept_hdr(stream, callback) else {
+            return;
+        };
+        for frame in extra_frames {
+            let _ = socket.send(Message::text(*frame));
+        }
+        while let Ok(message) = socket.read() {
+            if message.is_text() || message.is_binary() {
+                let _ = socket.send(message);
+            } else if message.is_close() {
+                break;
+            }
+        }
+    }
+
+    fn start_server(
+        extra_frames: &'static [&str],
+    ) -> std::io::Result<(String, mpsc::Receiver<String>)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("ws://{}", listener.local_addr()?);
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || serve_once(listener, sender, extra_frames));
+        Ok((url, receiver))
+    }
+
+    fn test_websocket_sends_body_and_prints_frames() -> TestResult {
+        let (url, token) = start_server(&["welcome"])?;
+        let rq_path = format!("{}/test_websocket_echo.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!(
+                "rq chat(\"{url}/chat\", headers: $[\"X-Token\": \"abc\"], body: \"hello\");\n"
+            ),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--ws-frames", "2"])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            token.recv_timeout(std::time::Duration::from_secs(5))?,
+            "abc"
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("101"), "expected 101 status, got: {stdout}");
+        assert!(
+            stdout.contains("welcome\nhello"),
+            "expected received frames in output, got: {stdout}"
+        );
+        Ok(())
+    }
+
+    fn test_websocket_stops_at_timeout() -> TestResult {
+        let (url, _token) = start_server(&["one", "two"])?;
+        let rq_path = format!("{}/test_websocket_timeout.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!("[timeout(500ms)]\nrq feed(\"{url}/feed\");\n"),
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains("one\ntwo"),
+            "expected frames received before the timeout, got: {stdout}"
+        );
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            sync_trial(
+                "websocket_sends_body_and_prints_frames",
+                test_websocket_sends_body_and_prints_frames,
+            ),
+            sync_trial(
+                "websocket_stops_at_timeout",
+                test_websocket_stops_at_timeout,
+            ),
+        ]
+    }
+}
+
+mod reauth {
+    use super::{async_trial, TestResult};
+    use crate::common::{json_data, rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn start_server() -> MockServer {
+        let mock_server = MockServer::start().await;
+        for token in ["expired-token", "fresh-token"] {
+            Mock::given(method("POST"))
+                .and(path("/token"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "access_token": token,
+                    "token_type": "Bearer",
+                    "expires_in": 3600
+                })))
+                .up_to_n_times(1)
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/api/resource"))
+            .and(header("Authorization", "Bearer fresh-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("secure_data"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/resource"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    fn source(base_url: &str, attribute: &str) -> String {
+        format!("auth cc(auth_type.oauth2_client_credentials) {{\n    client_id: \"test-client\",\n    client_secret: \"test-secret\",\n    token_url: \"{base_url}/token\"\n}}\n\n[auth(\"cc\")]\n{attribute}rq get_resource(\"{base_url}/api/resource\");\n")
+    }
+
+    async fn token_requests(mock_server: &MockServer) -> Result<usize, Box<dyn std::error::Error>> {
+        let received = mock_server
+            .received_requests()
+            .await
+            .ok_or("request recording is disabled")?;
+        Ok(received.iter().filter(|r| r.url.path() == "/token").count())
+    }
+
+    async fn test_reauth_attribute_retries_with_fresh_token() -> TestResult {
+        let mock_server = start_server().await;
+        let rq_path = write_rq(
+            "test_reauth_attribute",
+            source(&mock_server.uri(), "[reauth]\n"),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let data = json_data(&String::from_utf8_lossy(&output.stdout))?;
+        assert_eq!(data["results"][0]["status"], 200, "{data}");
+        assert_eq!(token_requests(&mock_server).await?, 2);
+        Ok(())
+    }
+
+    async fn test_without_reauth_401_is_returned() -> TestResult {
+        let mock_server = start_server().await;
+        let rq_path = write_rq("test_reauth_disabled", source(&mock_server.uri(), ""))?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+
+        let data = json_data(&String::from_utf8_lossy(&output.stdout))?;
+        assert_eq!(data["results"][0]["status"], 401, "{data}");
+        assert_eq!(token_requests(&mock_server).await?, 1);
+        Ok(())
+    }
+
+    async fn test_reauth_flag_applies_to_every_request() -> TestResult {
+        let mock_server = start_server().await;
+        let rq_path = write_rq("test_reauth_flag", source(&mock_server.uri(), ""))?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json", "--reauth"])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let data = json_data(&String::from_utf8_lossy(&output.stdout))?;
+        assert_eq!(data["results"][0]["status"], 200, "{data}");
+        assert_eq!(token_requests(&mock_server).await?, 2);
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "reauth_attribute_retries_with_fresh_token",
+                test_reauth_attribute_retries_with_fresh_token,
+            ),
+            async_trial(
+                "without_reauth_401_is_returned",
+                test_without_reauth_401_is_returned,
+            ),
+            async_trial(
+                "reauth_flag_applies_to_every_request",
+                test_reauth_flag_applies_to_every_request,
+            ),
+        ]
+    }
+}
+
+mod env_get {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_env_get_reads_os_environment_variable() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/items"))
+            .and(header("Authorization", "Bearer os-token"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = format!("{}/test_env_get.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+        &rq_path,
+        "rq items(\"{{env.get(\\\"RQ_TEST_BASE_URL\\\")}}/api/items\", $[\"Authorization\": \"Bearer {{env.get(\\\"RQ_TEST_TOKEN\\\")}}\"]);\n",
+    )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path])
+            .env("RQ_TEST_BASE_URL", mock_server.uri())
+            .env("RQ_TEST_TOKEN", "os-token")
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![async_trial(
+            "env_get_reads_os_environment_variable",
+            test_env_get_reads_os_environment_variable,
+        )]
+    }
+}
+
+mod name_search {
+    use super::{sync_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+
+    fn write_dir(name: &str, files: &[(&str, &str)]) -> Result<String, Box<dyn std::error::Error>> {
+        let dir = format!("{}/{name}", env!("CARGO_TARGET_TMPDIR"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)?;
+        for (file, content) in files {
+            std::fs::write(format!("{dir}/{file}"), content)?;
+        }
+        Ok(dir)
+    }
+
+    const BROKEN: &str = "rq broken(\"http://localhost:8080/get\"\n";
+    const TARGET: &str = "rq target(\"http://localhost:8080/get\");\n";
+
+    fn test_name_search_skips_broken_sibling_file() -> TestResult {
+        let dir = write_dir(
+            "test_name_search_found",
+            &[("a_broken.rq", BROKEN), ("b_target.rq", TARGET)],
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &dir, "-n", "target"])
+            .output()?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "{stderr}");
+        assert!(
+            stderr.contains("Warning: Failed to parse: Syntax error in")
+                && stderr.contains("a_broken.rq"),
+            "expected a parse warning for the broken file, got: {stderr}"
+        );
+        assert!(String::from_utf8_lossy(&output.stdout).contains("status: 200"));
+        Ok(())
+    }
+
+    fn test_name_search_reports_not_found_over_parse_errors() -> TestResult {
+        let dir = write_dir(
+            "test_name_search_missing",
+            &[("a_broken.rq", BROKEN), ("b_target.rq", TARGET)],
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &dir, "-n", "missing"])
+            .output()?;
+
+        assert_eq!(output.status.code(), Some(5));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("Request 'missing' not found in directory"),
+            "unexpected stderr: {stderr}"
+        );
+        assert!(
+            stderr.contains("Skipped 1 file(s) that failed to parse:")
+                && stderr.contains("a_broken.rq"),
+            "expected the broken file to be listed, got: {stderr}"
+        );
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            sync_trial(
+                "name_search_skips_broken_sibling_file",
+                test_name_search_skips_broken_sibling_file,
+            ),
+            sync_trial(
+                "name_search_reports_not_found_over_parse_errors",
+                test_name_search_reports_not_found_over_parse_errors,
+            ),
+        ]
+    }
+}
+
+mod token_cache {
+    use super::{async_trial, TestResult};
+    use crate::common::{rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use std::path::{Path, PathBuf};
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn start_server(token_requests: u64, resource_requests: u64) -> MockServer {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "cached-token",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            })))
+            .expect(token_requests)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/resource"))
+            .and(header("Authorization", "Bearer cached-token"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(resource_requests)
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    fn source(base_url: &str) -> String {
+        format!("auth cc(auth_type.oauth2_client_credentials) {{\n    client_id: \"test-client\",\n    client_secret: \"test-secret\",\n    token_url: \"{base_url}/token\",\n    scope: \"read\"\n}}\n\n[auth(\"cc\")]\nrq get_resource(\"{base_url}/api/resource\");\n")
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join(name)
+            .join("cache");
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn run(rq_path: &str, cache: &Path, extra: &[&str]) -> TestResult {
+        let output = rq_cmd()
+            .env("XDG_CACHE_HOME", cache)
+            .args(["request", "run", "-s", rq_path])
+            .args(extra)
+            .output()?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).into());
+        }
+        Ok(())
+    }
+
+    async fn test_token_cache_reuses_token_across_runs() -> TestResult {
+        let mock_server = start_server(1, 2).await;
+        let rq_path = write_rq("token_cache_reuse", source(&mock_server.uri()))?;
+        let cache = cache_dir("token_cache_reuse");
+
+        run(&rq_path, &cache, &[])?;
+        run(&rq_path, &cache, &[])?;
+
+        assert_eq!(
+            std::fs::read_dir(cache.join("rq").join("tokens"))?.count(),
+            1
+        );
+        Ok(())
+    }
+
+    async fn test_no_token_cache_requests_a_token_every_run() -> TestResult {
+        let mock_server = start_server(2, 2).await;
+        let rq_path = write_rq("token_cache_disabled", source(&mock_server.uri()))?;
+        let cache = cache_dir("token_cache_disabled");
+
+        run(&rq_path, &cache, &["--no-token-cache"])?;
+        run(&rq_path, &cache, &["--no-token-cache"])?;
+
+        assert!(!cache.join("rq").join("tokens").exists());
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "token_cache_reuses_token_across_runs",
+                test_token_cache_reuses_token_across_runs,
+            ),
+            async_trial(
+                "no_token_cache_requests_a_token_every_run",
+                test_no_token_cache_requests_a_token_every_run,
+            ),
+        ]
+    }
+}
+
+mod capture {
+    use super::{async_trial, TestResult};
+    use crate::common::{rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn test_capture_header_feeds_next_request() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/orders"))
+            .respond_with(ResponseTemplate::new(201).insert_header("Location", "/orders/42"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/orders/42"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("order 42"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = write_rq(
+        "test_capture_location",
+        format!(
+            "let base = \"{uri}\";\n\n[method(POST)]\n[capture(location = header.Location)]\nrq post_order(\"{{{{base}}}}/orders\", body: \"{{}}\");\n\nrq get_order(\"{{{{base}}}}{{{{location}}}}\");\n",
+            uri = mock_server.uri()
+        ),
+    )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() || !stdout.contains("order 42") {
+            return Err(format!(
+                "Expected the captured Location to be followed: {stdout}{}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn test_capture_of_missing_header_stops_the_run() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/orders"))
+            .respond_with(ResponseTemplate::new(202))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let rq_path = write_rq(
+        "test_capture_missing",
+        format!(
+            "[method(POST)]\n[capture(location = header.Location)]\nrq post_order(\"{uri}/orders\", body: \"{{}}\");\n\nrq get_order(\"{uri}{{{{location}}}}\");\n",
+            uri = mock_server.uri()
+        ),
+    )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if output.status.code() != Some(3) {
+            return Err(format!(
+                "Expected exit code 3, got {:?}: {stderr}",
+                output.status.code()
+            )
+            .into());
+        }
+        if !stderr.contains(
+        "Cannot capture 'location' from request 'post_order': the response has no 'Location' header",
+    ) {
+        return Err(format!("Unexpected error output: {stderr}").into());
+    }
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "capture_header_feeds_next_request",
+                test_capture_header_feeds_next_request,
+            ),
+            async_trial(
+                "capture_of_missing_header_stops_the_run",
+                test_capture_of_missing_header_stops_the_run,
+            ),
+        ]
+    }
+}
+
+mod skip {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{json_data, rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn source(base_url: &str) -> String {
+        format!("rq active(\"{base_url}/active\");\n\n[skip]\nrq legacy(\"{base_url}/legacy\");\n")
+    }
+
+    async fn test_skip_attribute_excludes_request_from_run() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/active"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/legacy"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_rq("test_skip_run", source(&mock_server.uri()))?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(output.status.success(), "{stderr}");
+        assert!(
+            stderr.contains("Skipped 'legacy': marked with [skip]"),
+            "{stderr}"
+        );
+        let results = &json_data(&stdout)?["results"];
+        assert_eq!(results.as_array().map(Vec::len), Some(1), "{stdout}");
+        assert_eq!(results[0]["request_name"], "active", "{stdout}");
+
+        Ok(())
+    }
+
+    fn test_skip_attribute_keeps_request_listed() -> TestResult {
+        let rq_path = write_rq("test_skip_list", source("http://localhost:8080"))?;
+
+        let output = rq_cmd()
+            .args(["request", "list", "-s", &rq_path, "-o", "json"])
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        assert!(output.status.success());
+        let items = json_data(&stdout)?;
+        let items = items.as_array().ok_or("expected a JSON array")?;
+        assert_eq!(items.len(), 2, "{stdout}");
+        assert!(items[0].get("skipped").is_none(), "{stdout}");
+        assert_eq!(items[1]["name"], "legacy", "{stdout}");
+        assert_eq!(items[1]["skipped"], true, "{stdout}");
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "skip_attribute_excludes_request_from_run",
+                test_skip_attribute_excludes_request_from_run,
+            ),
+            sync_trial(
+                "skip_attribute_keeps_request_listed",
+                test_skip_attribute_keeps_request_listed,
+            ),
+        ]
+    }
+}
+
+mod body_file {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{body_string, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn write_fixture(name: &str, params: &str, uri: &str) -> std::io::Result<String> {
+        let dir = format!("{}/{name}", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::create_dir_all(format!("{dir}/bodies"))?;
+        std::fs::write(
+            format!("{dir}/bodies/user.json"),
+            "{\"name\": \"{{user}}\", \"team\": \"{{team}}\"}",
+        )?;
+        write_rq(
+            &format!("{name}/api"),
+            format!(
+                "let team = \"core\";\n\n[method(POST)]\nrq create(\"{uri}/users\", {params});\n"
+            ),
+        )
+    }
+
+    async fn test_body_file_is_read_and_interpolated() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/users"))
+            .and(header("content-type", "application/json"))
+            .and(body_string("{\"name\": \"Ann\", \"team\": \"core\"}"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_fixture(
+            "body_file_interpolated",
+            "body_file: \"bodies/user.json\"",
+            &mock_server.uri(),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-v", "user=Ann"])
+            .output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        Ok(())
+    }
+
+    async fn test_body_file_raw_skips_interpolation() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/users"))
+            .and(body_string(
+                "{\"name\": \"{{user}}\", \"team\": \"{{team}}\"}",
+            ))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_fixture(
+            "body_file_raw",
+            "body_file: \"bodies/user.json\", raw: true",
+            &mock_server.uri(),
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        Ok(())
+    }
+
+    fn test_body_file_reports_missing_file() -> TestResult {
+        let rq_path = write_fixture(
+            "body_file_missing",
+            "body_file: \"bodies/missing.json\"",
+            "http://localhost:8080",
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert_eq!(output.status.code(), Some(2), "{stderr}");
+        assert!(
+            stderr.contains("Error reading file bodies/missing.json"),
+            "{stderr}"
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "body_file_is_read_and_interpolated",
+                test_body_file_is_read_and_interpolated,
+            ),
+            async_trial(
+                "body_file_raw_skips_interpolation",
+                test_body_file_raw_skips_interpolation,
+            ),
+            sync_trial(
+                "body_file_reports_missing_file",
+                test_body_file_reports_missing_file,
+            ),
+        ]
+    }
+}
+
+mod sort {
+    use super::{async_trial, TestResult};
+    use crate::common::{json_data, rq_cmd};
+    use libtest_mimic::Trial;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn start_server() -> MockServer {
+        let mock_server = MockServer::start().await;
+        for (route, response) in [
+            ("/fast", ResponseTemplate::new(200)),
+            ("/missing", ResponseTemplate::new(404)),
+            (
+                "/slow",
+                ResponseTemplate::new(200).set_delay(Duration::from_millis(300)),
+            ),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(route))
+                .respond_with(response)
+                .mount(&mock_server)
+                .await;
+        }
+        mock_server
+    }
+
+    fn run_sorted(
+        mock_server: &MockServer,
+        extra_args: &[&str],
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let rq_path = format!("{}/test_sort_by.rq", env!("CARGO_TARGET_TMPDIR"));
+        let uri = mock_server.uri();
+        std::fs::write(
+            &rq_path,
+            format!(
+            "rq fast(\"{uri}/fast\");\nrq missing(\"{uri}/missing\");\nrq slow(\"{uri}/slow\");\n"
+        ),
+        )?;
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "-o", "json"])
+            .args(extra_args)
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let results = json_data(&stdout)?["results"].clone();
+        Ok(results
+            .as_array()
+            .ok_or("expected a results array")?
+            .iter()
+            .map(|r| r["request_name"].as_str().unwrap_or_default().to_string())
+            .collect())
+    }
+
+    async fn test_sort_by_orders_printed_results() -> TestResult {
+        let mock_server = start_server().await;
+
+        assert_eq!(run_sorted(&mock_server, &[])?, ["fast", "missing", "slow"]);
+        assert_eq!(
+            run_sorted(&mock_server, &["--sort-by", "status"])?,
+            ["missing", "fast", "slow"]
+        );
+        assert_eq!(run_sorted(&mock_server, &["--sort-by", "time"])?[0], "slow");
+        assert_eq!(
+            run_sorted(&mock_server, &["--sort-by", "name"])?,
+            ["fast", "missing", "slow"]
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![async_trial(
+            "sort_by_orders_printed_results",
+            test_sort_by_orders_printed_results,
+        )]
+    }
+}
+
+mod multipart {
+    use super::{async_trial, sync_trial, TestResult};
+    use crate::common::{rq_cmd, write_rq};
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn write_fixture(name: &str, parts: &str, uri: &str) -> std::io::Result<String> {
+        let dir = format!("{}/{name}", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::create_dir_all(format!("{dir}/files"))?;
+        std::fs::write(format!("{dir}/files/report.txt"), "quarterly numbers")?;
+        write_rq(
+            &format!("{name}/api"),
+            format!("let title = \"Q3\";\n\n[method(POST)]\nrq upload(\"{uri}/upload\", body: multipart[{parts}]);\n"),
+        )
+    }
+
+    async fn test_multipart_body_sends_text_and_file_parts() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/upload"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let rq_path = write_fixture(
+            "multipart_parts",
+            "\"title\": title, \"report\": @\"files/report.txt\"",
+            &mock_server.uri(),
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let requests = mock_server
+            .received_requests()
+            .await
+            .ok_or("request recording is disabled")?;
+        let request = requests.first().ok_or("no request received")?;
+        let content_type = request
+            .headers
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        assert!(
+            content_type.starts_with("multipart/form-data; boundary="),
+            "{content_type}"
+        );
+        let body = String::from_utf8_lossy(&request.body);
+        assert!(
+            body.contains("Content-Disposition: form-data; name=\"title\"\r\n\r\nQ3\r\n"),
+            "{body}"
+        );
+        assert!(
+            body.contains(
+                "Content-Disposition: form-data; name=\"report\"; filename=\"report.txt\"\r\n"
+            ),
+            "{body}"
+        );
+        assert!(body.contains("\r\n\r\nquarterly numbers\r\n"), "{body}");
+
+        Ok(())
+    }
+
+    fn test_multipart_body_reports_missing_file() -> TestResult {
+        let rq_path = write_fixture(
+            "multipart_missing",
+            "\"report\": @\"files/missing.txt\"",
+            "http://localhost:8080",
+        )?;
+
+        let output = rq_cmd().args(["request", "run", "-s", &rq_path]).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert_eq!(output.status.code(), Some(2), "{stderr}");
+        assert!(stderr.contains("Multipart part 'report'"), "{stderr}");
+        assert!(stderr.contains("files/missing.txt"), "{stderr}");
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "multipart_body_sends_text_and_file_parts",
+                test_multipart_body_sends_text_and_file_parts,
+            ),
+            sync_trial(
+                "multipart_body_reports_missing_file",
+                test_multipart_body_reports_missing_file,
+            ),
+        ]
+    }
+}
+
+mod explain_auth {
+    use super::{async_trial, TestResult};
+    use crate::common::rq_cmd;
+    use libtest_mimic::Trial;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_client_credentials(mock_server: &MockServer, bearer: &str) {
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "issued-token",
+                "token_type": "Bearer"
+            })))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/resource"))
+            .and(header("Authorization", format!("Bearer {bearer}").as_str()))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    fn write_client_credentials(name: &str, uri: &str) -> std::io::Result<String> {
+        let template = std::fs::read_to_string(format!(
+            "{}/tests/fixtures/templates/auth_oauth2_cc.rq.template",
+            env!("CARGO_MANIFEST_DIR")
+        ))?;
+        let rq_path = format!("{}/{name}.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(&rq_path, template.replace("{{MOCK_URL}}", uri))?;
+        Ok(rq_path)
+    }
+
+    async fn test_explain_auth_traces_token_endpoint() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_client_credentials(&mock_server, "issued-token").await;
+        let rq_path = write_client_credentials("explain_auth_token_endpoint", &mock_server.uri())?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--no-token-cache",
+                "--explain-auth",
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(output.status.success(), "{stderr}");
+        assert!(
+            stderr.contains(
+                "Request 'get_resource': selected auth 'cc_auth' (oauth2_client_credentials)"
+            ),
+            "{stderr}"
+        );
+        assert!(
+        stderr.contains(&format!(
+            "Auth 'cc_auth': requesting a token from token endpoint {}/token with the client secret",
+            mock_server.uri()
+        )),
+        "{stderr}"
+    );
+        assert!(
+            stderr
+                .contains("Request 'get_resource': auth 'cc_auth' injected header 'authorization'"),
+            "{stderr}"
+        );
+        assert!(!stderr.contains("issued-token"), "{stderr}");
+
+        Ok(())
+    }
+
+    async fn test_explain_auth_traces_variable_fallback() -> TestResult {
+        let mock_server = MockServer::start().await;
+        mount_client_credentials(&mock_server, "from-variable").await;
+        let rq_path = write_client_credentials("explain_auth_fallback", &mock_server.uri())?;
+
+        let output = rq_cmd()
+            .args([
+                "request",
+                "run",
+                "-s",
+                &rq_path,
+                "--no-token-cache",
+                "--explain-auth",
+                "-v",
+                "auth_token=from-variable",
+            ])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(output.status.success(), "{stderr}");
+        assert!(
+            stderr.contains(
+                "Auth 'cc_auth': using the 'auth_token' variable instead of running the flow"
+            ),
+            "{stderr}"
+        );
+        assert!(!stderr.contains("token endpoint"), "{stderr}");
+
+        Ok(())
+    }
+
+    async fn test_explain_auth_reports_requests_without_auth() -> TestResult {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/public"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        let rq_path = format!("{}/explain_auth_none.rq", env!("CARGO_TARGET_TMPDIR"));
+        std::fs::write(
+            &rq_path,
+            format!("rq public(\"{}/public\");\n", mock_server.uri()),
+        )?;
+
+        let output = rq_cmd()
+            .args(["request", "run", "-s", &rq_path, "--explain-auth"])
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(output.status.success(), "{stderr}");
+        assert!(
+            stderr.contains("Request 'public': no auth configured"),
+            "{stderr}"
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn trials() -> Vec<Trial> {
+        vec![
+            async_trial(
+                "explain_auth_traces_token_endpoint",
+                test_explain_auth_traces_token_endpoint,
+            ),
+            async_trial(
+                "explain_auth_traces_variable_fallback",
+                test_explain_auth_traces_variable_fallback,
+            ),
+            async_trial(
+                "explain_auth_reports_requests_without_auth",
+                test_explain_auth_reports_requests_without_auth,
+            ),
+        ]
+    }
+}
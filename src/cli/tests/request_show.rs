@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -128,7 +128,7 @@ fn test_request_show_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("Request").and_then(|v| v.as_str()) != Some("simple_auth") {
         return Err("JSON missing or incorrect 'name' field".into());
@@ -404,7 +404,7 @@ fn test_request_show_unresolved_no_var_interpolation() -> Result<(), Box<dyn std
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let url = json
         .get("URL")
@@ -473,7 +473,7 @@ fn test_request_show_timeout_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    let json: serde_json::Value = json_data(&stdout)?;
 
     if json.get("Timeout").and_then(|v| v.as_str()) != Some("10") {
         return Err(format!("Expected Timeout '10', got: {json}").into());
@@ -506,7 +506,7 @@ fn test_request_show_resolved_variables_json() -> Result<(), Box<dyn std::error:
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json["URL"] != "https://api.example.com/resource" {
         return Err(format!(
@@ -534,3 +534,121 @@ fn test_request_show_resolved_variables_json() -> Result<(), Box<dyn std::error:
 
     Ok(())
 }
+
+#[test]
+fn test_request_show_resolves_against_environment() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "show",
+            "-s",
+            "tests/fixtures/request_show_env.rq",
+            "-n",
+            "get_status",
+            "-e",
+            "prod",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !stdout.contains("URL: https://prod.example.com/status") {
+        return Err(format!("Expected URL resolved against 'prod', got: {stdout}").into());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_request_show_environment_no_var_interpolation() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "show",
+            "-s",
+            "tests/fixtures/request_show_env.rq",
+            "-n",
+            "get_status",
+            "-e",
+            "prod",
+            "--no-var-interpolation",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !stdout.contains("URL: {{host}}/status") {
+        return Err(format!("Expected raw URL template, got: {stdout}").into());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_request_show_unknown_environment() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "show",
+            "-s",
+            "tests/fixtures/request_show_env.rq",
+            "-n",
+            "get_status",
+            "-e",
+            "missing",
+        ])
+        .output()?;
+
+    if output.status.success() {
+        return Err("Expected unknown environment to fail".into());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.contains("Environment not found: missing") {
+        return Err(format!("Unexpected error output: {stderr}").into());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_request_show_description() -> Result<(), Box<dyn std::error::Error>> {
+    let output = rq_cmd()
+        .args([
+            "request",
+            "show",
+            "-s",
+            "tests/request/run/input/description.rq",
+            "-n",
+            "described",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !stdout.contains("Description: Fetches the current user") {
+        return Err(format!("Output missing description: {stdout}").into());
+    }
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -34,7 +34,7 @@ fn test_var_list_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -84,7 +84,7 @@ fn test_var_list_empty() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if !items.is_empty() {
@@ -128,7 +128,7 @@ fn test_var_list_file_includes_imports() -> Result<(), Box<dyn std::error::Error
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -179,7 +179,7 @@ fn test_var_list_file_excludes_unimported() -> Result<(), Box<dyn std::error::Er
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.len() != 1 {
@@ -225,7 +225,7 @@ fn test_var_list_partial_syntax_error() -> Result<(), Box<dyn std::error::Error>
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -26,7 +26,7 @@ fn test_var_refs_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
@@ -111,7 +111,7 @@ fn test_var_refs_across_directory() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.len() < 2 {
@@ -149,7 +149,7 @@ fn test_var_refs_no_results() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if !items.is_empty() {
@@ -194,7 +194,7 @@ fn test_var_refs_env_declaration_included() -> Result<(), Box<dyn std::error::Er
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    let json: serde_json::Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     let has_env_decl = items.iter().any(|r| r["line"].as_u64() == Some(1));
@@ -244,7 +244,7 @@ fn test_var_refs_interpolation_character_position() -> Result<(), Box<dyn std::e
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if items.is_empty() {
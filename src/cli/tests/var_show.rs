@@ -1,5 +1,5 @@
 mod common;
-use common::rq_cmd;
+use common::{json_data, rq_cmd};
 use serde_json::Value;
 
 #[test]
@@ -26,7 +26,7 @@ fn test_var_show_let_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("name").and_then(|v| v.as_str()) != Some("base_url") {
         return Err(format!("Expected name 'base_url', got: {json}").into());
@@ -102,7 +102,7 @@ fn test_var_show_env_variable_json() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("name").and_then(|v| v.as_str()) != Some("base_url") {
         return Err(format!("Expected name 'base_url', got: {json}").into());
@@ -164,7 +164,7 @@ env dev {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     if json.get("source").and_then(|v| v.as_str()) != Some("env:dev") {
         return Err(format!("Expected env var to take precedence, got: {json}").into());
@@ -254,7 +254,7 @@ fn test_var_show_unresolved_reference_no_var_interpolation(
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
 
     let value = json
         .get("value")
@@ -290,7 +290,7 @@ fn test_var_list_let_variables() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if !items.iter().any(|v| v["name"].as_str() == Some("base_url")) {
@@ -330,7 +330,7 @@ fn test_var_list_with_env() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Value = serde_json::from_str(&stdout)?;
+    let json: Value = json_data(&stdout)?;
     let items = json.as_array().ok_or("Expected JSON array")?;
 
     if !items.iter().any(|v| v["name"].as_str() == Some("base_url")) {
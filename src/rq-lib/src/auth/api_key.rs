@@ -0,0 +1,211 @@
+use super::auth_provider::{explain, AuthFuture, AuthProvider, ConfiguredRequest};
+use crate::syntax::auth::Config;
+use crate::syntax::error::AuthError;
+
+pub const KEY_FIELD: &str = "key";
+pub const NAME_FIELD: &str = "name";
+pub const LOCATION_FIELD: &str = "location";
+pub const LOCATION_HEADER: &str = "header";
+pub const LOCATION_QUERY: &str = "query";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+}
+
+impl ApiKeyLocation {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            LOCATION_HEADER => Some(Self::Header),
+            LOCATION_QUERY => Some(Self::Query),
+            _ => None,
+        }
+    }
+}
+
+pub struct ApiKeyAuthProvider {
+    explain: bool,
+}
+
+impl ApiKeyAuthProvider {
+    pub fn new() -> Self {
+        Self { explain: false }
+    }
+
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    pub fn apply(
+        auth_config: &Config,
+        url: String,
+        mut headers: Vec<(String, String)>,
+    ) -> Result<ConfiguredRequest, AuthError> {
+        let field = |name: &str| {
+            auth_config
+                .fields
+                .get(name)
+                .map(|t| t.value.as_str())
+                .ok_or_else(|| {
+                    AuthError::new(format!(
+                        "API key auth '{}' requires '{name}'",
+                        auth_config.name
+                    ))
+                })
+        };
+        let key = field(KEY_FIELD)?;
+        let name = field(NAME_FIELD)?;
+        let location = match auth_config.fields.get(LOCATION_FIELD) {
+            Some(token) => ApiKeyLocation::parse(&token.value).ok_or_else(|| {
+                AuthError::new(format!(
+                    "API key auth '{}' has invalid '{LOCATION_FIELD}' '{}'. Expected '{LOCATION_HEADER}' or '{LOCATION_QUERY}'",
+                    auth_config.name, token.value
+                ))
+            })?,
+            None => ApiKeyLocation::Header,
+        };
+
+        match location {
+            ApiKeyLocation::Header => {
+                headers.retain(|(k, _)| !k.eq_ignore_ascii_case(name));
+                headers.push((name.to_string(), key.to_string()));
+                Ok((url, headers))
+            }
+            ApiKeyLocation::Query => Ok((append_query_param(url, name, key), headers)),
+        }
+    }
+}
+
+fn append_query_param(url: String, name: &str, value: &str) -> String {
+    let (base, fragment) = match url.split_once('#') {
+        Some((base, fragment)) => (base.to_string(), Some(fragment.to_string())),
+        None => (url, None),
+    };
+    let separator = match base.find('?') {
+        None => "?",
+        Some(_) if base.ends_with('?') || base.ends_with('&') => "",
+        Some(_) => "&",
+    };
+    let pair = form_urlencoded::Serializer::new(String::new())
+        .append_pair(name, value)
+        .finish();
+    match fragment {
+        Some(fragment) => format!("{base}{separator}{pair}#{fragment}"),
+        None => format!("{base}{separator}{pair}"),
+    }
+}
+
+impl Default for ApiKeyAuthProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthProvider for ApiKeyAuthProvider {
+    fn auth_type(&self) -> &str {
+        "api_key"
+    }
+
+    fn configure<'a>(
+        &'a self,
+        auth_config: &'a Config,
+        _context: &'a crate::syntax::variable_context::VariableContext,
+        url: String,
+        headers: Vec<(String, String)>,
+    ) -> AuthFuture<'a> {
+        Box::pin(async move {
+            let field = |name: &str| auth_config.fields.get(name).map(|t| t.value.as_str());
+            explain(
+                self.explain,
+                &auth_config.name,
+                &format!(
+                    "sending the key as {} '{}'",
+                    field(LOCATION_FIELD).unwrap_or(LOCATION_HEADER),
+                    field(NAME_FIELD).unwrap_or_default()
+                ),
+            );
+            Ok(Self::apply(auth_config, url, headers)?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::auth::AuthType;
+    use crate::syntax::token::{Token, TokenType};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn config(fields: &[(&str, &str)]) -> Config {
+        Config {
+            name: "api".to_string(),
+            auth_type: AuthType::ApiKey,
+            fields: fields
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.to_string(),
+                        Token {
+                            token_type: TokenType::String,
+                            value: v.to_string(),
+                            span: 0..0,
+                        },
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+            file_path: PathBuf::new(),
+            line: 0,
+            character: 0,
+        }
+    }
+
+    #[test]
+    fn test_api_key_defaults_to_header() {
+        let headers = vec![("x-api-key".to_string(), "stale".to_string())];
+        let (url, headers) = ApiKeyAuthProvider::apply(
+            &config(&[("key", "secret"), ("name", "X-API-Key")]),
+            "http://localhost/items".to_string(),
+            headers,
+        )
+        .unwrap();
+        assert_eq!(url, "http://localhost/items");
+        assert_eq!(
+            headers,
+            vec![("X-API-Key".to_string(), "secret".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_api_key_query_respects_existing_separators() {
+        let auth = config(&[("key", "a b&c"), ("name", "api_key"), ("location", "query")]);
+        let apply = |url: &str| {
+            ApiKeyAuthProvider::apply(&auth, url.to_string(), Vec::new())
+                .unwrap()
+                .0
+        };
+        assert_eq!(apply("http://h/items"), "http://h/items?api_key=a+b%26c");
+        assert_eq!(
+            apply("http://h/items?page=2"),
+            "http://h/items?page=2&api_key=a+b%26c"
+        );
+        assert_eq!(apply("http://h/items?"), "http://h/items?api_key=a+b%26c");
+        assert_eq!(
+            apply("http://h/items?page=2#top"),
+            "http://h/items?page=2&api_key=a+b%26c#top"
+        );
+    }
+
+    #[test]
+    fn test_api_key_rejects_unknown_location() {
+        let err = ApiKeyAuthProvider::apply(
+            &config(&[("key", "secret"), ("name", "key"), ("location", "cookie")]),
+            "http://h".to_string(),
+            Vec::new(),
+        )
+        .unwrap_err();
+        assert!(err.message.contains("invalid 'location' 'cookie'"), "{err}");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::syntax::auth::Config;
+use crate::syntax::error::AuthError;
 use crate::syntax::variable_context::VariableContext;
 use std::future::Future;
 use std::pin::Pin;
@@ -14,8 +15,16 @@ pub type AuthFuture<'a> = Pin<
 pub type AuthFuture<'a> =
     Pin<Box<dyn Future<Output = Result<ConfiguredRequest, Box<dyn std::error::Error>>> + 'a>>;
 
+pub(crate) const FALLBACK_VARIABLE_STEP: &str =
+    "using the 'auth_token' variable instead of running the flow";
+
+pub(crate) fn explain(enabled: bool, auth_name: &str, step: &str) {
+    if enabled {
+        eprintln!("Auth '{auth_name}': {step}");
+    }
+}
+
 pub trait AuthProvider: Send + Sync {
-    #[allow(dead_code)]
     fn auth_type(&self) -> &str;
 
     fn configure<'a>(
@@ -25,4 +34,14 @@ pub trait AuthProvider: Send + Sync {
         url: String,
         headers: Vec<(String, String)>,
     ) -> AuthFuture<'a>;
+
+    fn respond_to_challenge(
+        &self,
+        _auth_config: &Config,
+        _method: &str,
+        _url: &str,
+        _challenge: &str,
+    ) -> Result<Option<String>, AuthError> {
+        Ok(None)
+    }
 }
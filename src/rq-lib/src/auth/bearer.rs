@@ -1,13 +1,24 @@
-use super::auth_provider::{AuthFuture, AuthProvider};
+use super::auth_provider::{explain, AuthFuture, AuthProvider};
+use crate::syntax::auth::Config;
+use crate::syntax::error::AuthError;
 use crate::syntax::variable_context::{Variable, VariableValue};
 
 const TOKEN_FIELD: &str = "token";
+const TOKEN_FILE_FIELD: &str = "token_file";
+const TOKEN_KEYCHAIN_FIELD: &str = "token_keychain";
 
-pub struct BearerProvider;
+pub struct BearerProvider {
+    explain: bool,
+}
 
 impl BearerProvider {
     pub fn new() -> Self {
-        BearerProvider
+        BearerProvider { explain: false }
+    }
+
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
     }
 
     pub fn apply_from_variables(
@@ -33,6 +44,103 @@ impl BearerProvider {
         None
     }
 
+    pub fn resolve_token(auth_config: &Config) -> Result<String, AuthError> {
+        if let Some(token) = auth_config.fields.get(TOKEN_FIELD) {
+            return Ok(token.value.clone());
+        }
+        if let Some(entry) = auth_config.fields.get(TOKEN_KEYCHAIN_FIELD) {
+            return Self::read_keychain_token(auth_config, &entry.value);
+        }
+        let token_file = auth_config.fields.get(TOKEN_FILE_FIELD).ok_or_else(|| {
+            AuthError::new(format!(
+                "Bearer auth '{}' requires one of '{TOKEN_FIELD}', '{TOKEN_FILE_FIELD}' or '{TOKEN_KEYCHAIN_FIELD}'",
+                auth_config.name
+            ))
+        })?;
+        Self::read_token_file(auth_config, &token_file.value)
+    }
+
+    fn token_source(auth_config: &Config) -> String {
+        let field = |name: &str| auth_config.fields.get(name).map(|t| t.value.as_str());
+        if field(TOKEN_FIELD).is_some() {
+            return format!("using the '{TOKEN_FIELD}' field");
+        }
+        if let Some(entry) = field(TOKEN_KEYCHAIN_FIELD) {
+            return format!("reading the token from keychain entry '{entry}'");
+        }
+        match field(TOKEN_FILE_FIELD) {
+            Some(path) => format!("reading the token from file '{path}'"),
+            None => "no token source configured".to_string(),
+        }
+    }
+
+    pub fn parse_keychain_entry(value: &str) -> Option<(&str, &str)> {
+        value
+            .rsplit_once('/')
+            .filter(|(service, account)| !service.trim().is_empty() && !account.trim().is_empty())
+    }
+
+    #[cfg(feature = "keychain")]
+    fn read_keychain_token(auth_config: &Config, entry: &str) -> Result<String, AuthError> {
+        let (service, account) = Self::parse_keychain_entry(entry).ok_or_else(|| {
+            AuthError::new(format!(
+                "Bearer auth '{}': '{TOKEN_KEYCHAIN_FIELD}' must be \"service/account\", got '{entry}'",
+                auth_config.name
+            ))
+        })?;
+        let token = keyring::Entry::new(service, account)
+            .and_then(|e| e.get_password())
+            .map_err(|e| {
+                AuthError::new(format!(
+                    "Failed to read keychain entry '{entry}' for bearer auth '{}': {e}",
+                    auth_config.name
+                ))
+            })?;
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(AuthError::new(format!("Keychain entry '{entry}' is empty")));
+        }
+        Ok(token.to_string())
+    }
+
+    #[cfg(not(feature = "keychain"))]
+    fn read_keychain_token(auth_config: &Config, _entry: &str) -> Result<String, AuthError> {
+        Err(AuthError::new(format!(
+            "Bearer auth '{}': '{TOKEN_KEYCHAIN_FIELD}' is not supported in this build",
+            auth_config.name
+        )))
+    }
+
+    #[cfg(feature = "native")]
+    fn read_token_file(auth_config: &Config, token_file: &str) -> Result<String, AuthError> {
+        let path = std::path::Path::new(token_file);
+        let resolved_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else if let Some(parent) = auth_config.file_path.parent() {
+            parent.join(path)
+        } else {
+            path.to_path_buf()
+        };
+        let content = std::fs::read_to_string(&resolved_path).map_err(|e| {
+            AuthError::new(format!("Failed to read token file '{token_file}': {e}"))
+        })?;
+        let token = content.trim();
+        if token.is_empty() {
+            return Err(AuthError::new(format!(
+                "Token file '{token_file}' is empty"
+            )));
+        }
+        Ok(token.to_string())
+    }
+
+    #[cfg(not(feature = "native"))]
+    fn read_token_file(auth_config: &Config, _token_file: &str) -> Result<String, AuthError> {
+        Err(AuthError::new(format!(
+            "Bearer auth '{}': '{TOKEN_FILE_FIELD}' is not supported in WASM builds",
+            auth_config.name
+        )))
+    }
+
     pub fn add_bearer_header(headers: &mut Vec<(String, String)>, token: &str) {
         let auth_key = reqwest::header::AUTHORIZATION.as_str();
         headers.retain(|(k, _)| !k.eq_ignore_ascii_case(auth_key));
@@ -53,14 +161,19 @@ impl AuthProvider for BearerProvider {
 
     fn configure<'a>(
         &'a self,
-        auth_config: &'a crate::syntax::auth::Config,
+        auth_config: &'a Config,
         _context: &'a crate::syntax::variable_context::VariableContext,
         url: String,
         mut headers: Vec<(String, String)>,
     ) -> AuthFuture<'a> {
         Box::pin(async move {
-            let token = &auth_config.fields[TOKEN_FIELD].value;
-            Self::add_bearer_header(&mut headers, token);
+            explain(
+                self.explain,
+                &auth_config.name,
+                &Self::token_source(auth_config),
+            );
+            let token = Self::resolve_token(auth_config)?;
+            Self::add_bearer_header(&mut headers, &token);
             Ok((url, headers))
         })
     }
@@ -77,6 +190,21 @@ mod tests {
         assert_eq!(executor.auth_type(), "bearer");
     }
 
+    #[test]
+    fn test_parse_keychain_entry() {
+        assert_eq!(
+            BearerProvider::parse_keychain_entry("github/ann"),
+            Some(("github", "ann"))
+        );
+        assert_eq!(
+            BearerProvider::parse_keychain_entry("api.example.com/v2/ann@example.com"),
+            Some(("api.example.com/v2", "ann@example.com"))
+        );
+        assert_eq!(BearerProvider::parse_keychain_entry("github"), None);
+        assert_eq!(BearerProvider::parse_keychain_entry("github/"), None);
+        assert_eq!(BearerProvider::parse_keychain_entry("/ann"), None);
+    }
+
     #[test]
     fn test_apply_from_variables_with_auth_token() {
         let variables = vec![
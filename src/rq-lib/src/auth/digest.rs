@@ -0,0 +1,328 @@
+use super::auth_provider::{explain, AuthFuture, AuthProvider};
+use crate::syntax::auth::Config;
+use crate::syntax::error::AuthError;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+pub const USERNAME_FIELD: &str = "username";
+pub const PASSWORD_FIELD: &str = "password";
+
+const SCHEME: &str = "digest";
+const QOP_AUTH: &str = "auth";
+const NONCE_COUNT: &str = "00000001";
+
+pub struct DigestAuthProvider {
+    explain: bool,
+}
+
+impl DigestAuthProvider {
+    pub fn new() -> Self {
+        Self { explain: false }
+    }
+
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+}
+
+impl Default for DigestAuthProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthProvider for DigestAuthProvider {
+    fn auth_type(&self) -> &str {
+        "digest"
+    }
+
+    fn configure<'a>(
+        &'a self,
+        auth_config: &'a Config,
+        _context: &'a crate::syntax::variable_context::VariableContext,
+        url: String,
+        headers: Vec<(String, String)>,
+    ) -> AuthFuture<'a> {
+        Box::pin(async move {
+            explain(
+                self.explain,
+                &auth_config.name,
+                "sending without credentials; the header is built from a 401 challenge",
+            );
+            Ok((url, headers))
+        })
+    }
+
+    fn respond_to_challenge(
+        &self,
+        auth_config: &Config,
+        method: &str,
+        url: &str,
+        challenge: &str,
+    ) -> Result<Option<String>, AuthError> {
+        let Some(challenge) = DigestChallenge::parse(challenge) else {
+            return Ok(None);
+        };
+        let field = |name: &str| {
+            auth_config
+                .fields
+                .get(name)
+                .map(|t| t.value.as_str())
+                .ok_or_else(|| {
+                    AuthError::new(format!(
+                        "Digest auth '{}' requires '{name}'",
+                        auth_config.name
+                    ))
+                })
+        };
+        let credentials = DigestCredentials {
+            username: field(USERNAME_FIELD)?,
+            password: field(PASSWORD_FIELD)?,
+            method,
+            uri: &request_uri(url),
+            cnonce: &uuid::Uuid::new_v4().simple().to_string(),
+        };
+        challenge
+            .authorization(&credentials)
+            .map(Some)
+            .map_err(|e| AuthError::new(format!("Digest auth '{}': {e}", auth_config.name)))
+    }
+}
+
+struct DigestCredentials<'a> {
+    username: &'a str,
+    password: &'a str,
+    method: &'a str,
+    uri: &'a str,
+    cnonce: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DigestChallenge {
+    realm: String,
+    nonce: String,
+    opaque: Option<String>,
+    algorithm: Option<String>,
+    qop: Option<String>,
+}
+
+impl DigestChallenge {
+    fn parse(header: &str) -> Option<Self> {
+        let start = header.to_ascii_lowercase().find(&format!("{SCHEME} "))?;
+        let mut params = parse_params(&header[start + SCHEME.len()..]);
+        Some(Self {
+            realm: params.remove("realm").unwrap_or_default(),
+            nonce: params.remove("nonce")?,
+            opaque: params.remove("opaque"),
+            algorithm: params.remove("algorithm"),
+            qop: params.remove("qop"),
+        })
+    }
+
+    fn authorization(&self, credentials: &DigestCredentials) -> Result<String, String> {
+        let algorithm = self.algorithm.as_deref().unwrap_or("MD5");
+        let qop = match &self.qop {
+            Some(offered) if offered.split(',').any(|q| q.trim() == QOP_AUTH) => Some(QOP_AUTH),
+            Some(offered) => return Err(format!("unsupported qop '{offered}'")),
+            None => None,
+        };
+        let mut ha1 = hash(
+            algorithm,
+            &format!(
+                "{}:{}:{}",
+                credentials.username, self.realm, credentials.password
+            ),
+        )?;
+        if algorithm.to_ascii_lowercase().ends_with("-sess") {
+            ha1 = hash(
+                algorithm,
+                &format!("{ha1}:{}:{}", self.nonce, credentials.cnonce),
+            )?;
+        }
+        let ha2 = hash(
+            algorithm,
+            &format!("{}:{}", credentials.method, credentials.uri),
+        )?;
+        let response = match qop {
+            Some(qop) => hash(
+                algorithm,
+                &format!(
+                    "{ha1}:{}:{NONCE_COUNT}:{}:{qop}:{ha2}",
+                    self.nonce, credentials.cnonce
+                ),
+            )?,
+            None => hash(algorithm, &format!("{ha1}:{}:{ha2}", self.nonce))?,
+        };
+
+        let mut parts = vec![
+            format!("username=\"{}\"", credentials.username),
+            format!("realm=\"{}\"", self.realm),
+            format!("nonce=\"{}\"", self.nonce),
+            format!("uri=\"{}\"", credentials.uri),
+            format!("algorithm={algorithm}"),
+            format!("response=\"{response}\""),
+        ];
+        if let Some(qop) = qop {
+            parts.push(format!("qop={qop}"));
+            parts.push(format!("nc={NONCE_COUNT}"));
+            parts.push(format!("cnonce=\"{}\"", credentials.cnonce));
+        }
+        if let Some(opaque) = &self.opaque {
+            parts.push(format!("opaque=\"{opaque}\""));
+        }
+        Ok(format!("Digest {}", parts.join(", ")))
+    }
+}
+
+fn parse_params(input: &str) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    let mut rest = input.trim_start();
+    while let Some((key, after)) = rest.split_once('=') {
+        let key = key.trim_matches([',', ' ']).to_ascii_lowercase();
+        let after = after.trim_start();
+        let (value, remaining) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                (&quoted[..end], quoted.get(end + 1..).unwrap_or_default())
+            }
+            None => {
+                let end = after.find(',').unwrap_or(after.len());
+                (after[..end].trim(), &after[end..])
+            }
+        };
+        params.insert(key, value.to_string());
+        rest = remaining.trim_start_matches([',', ' ']);
+    }
+    params
+}
+
+fn request_uri(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match parsed.query() {
+            Some(query) => format!("{}?{query}", parsed.path()),
+            None => parsed.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+fn hash(algorithm: &str, data: &str) -> Result<String, String> {
+    let base = algorithm.to_ascii_uppercase();
+    match base.strip_suffix("-SESS").unwrap_or(&base) {
+        "MD5" => md5_hex(data),
+        "SHA-256" => Ok(hex(&Sha256::digest(data.as_bytes()))),
+        _ => Err(format!("unsupported algorithm '{algorithm}'")),
+    }
+}
+
+#[cfg(feature = "native")]
+fn md5_hex(data: &str) -> Result<String, String> {
+    openssl::hash::hash(openssl::hash::MessageDigest::md5(), data.as_bytes())
+        .map(|digest| hex(&digest))
+        .map_err(|e| format!("MD5 is unavailable: {e}"))
+}
+
+#[cfg(not(feature = "native"))]
+fn md5_hex(_data: &str) -> Result<String, String> {
+    Err("algorithm 'MD5' is not supported in WASM builds".to_string())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials<'a>(method: &'a str, uri: &'a str) -> DigestCredentials<'a> {
+        DigestCredentials {
+            username: "Mufasa",
+            password: "Circle Of Life",
+            method,
+            uri,
+            cnonce: "0a4f113b",
+        }
+    }
+
+    #[test]
+    fn test_parse_digest_challenge() {
+        let challenge = DigestChallenge::parse(
+            r#"Digest realm="testrealm@host.com", qop="auth,auth-int", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", opaque="5ccc069c403ebaf9f0171e9517f40e41""#,
+        )
+        .unwrap();
+        assert_eq!(challenge.realm, "testrealm@host.com");
+        assert_eq!(challenge.nonce, "dcd98b7102dd2f0e8b11d0f600bfb0c093");
+        assert_eq!(challenge.qop.as_deref(), Some("auth,auth-int"));
+        assert_eq!(
+            challenge.opaque.as_deref(),
+            Some("5ccc069c403ebaf9f0171e9517f40e41")
+        );
+        assert_eq!(challenge.algorithm, None);
+        assert_eq!(DigestChallenge::parse(r#"Basic realm="x""#), None);
+    }
+
+    #[test]
+    fn test_md5_authorization_matches_rfc_2617_example() {
+        let challenge = DigestChallenge::parse(
+            r#"Digest realm="testrealm@host.com", qop="auth,auth-int", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", opaque="5ccc069c403ebaf9f0171e9517f40e41""#,
+        )
+        .unwrap();
+        let header = challenge
+            .authorization(&credentials("GET", "/dir/index.html"))
+            .unwrap();
+        assert_eq!(
+            header,
+            r#"Digest username="Mufasa", realm="testrealm@host.com", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", uri="/dir/index.html", algorithm=MD5, response="6629fae49393a05397450978507c4ef1", qop=auth, nc=00000001, cnonce="0a4f113b", opaque="5ccc069c403ebaf9f0171e9517f40e41""#
+        );
+    }
+
+    #[test]
+    fn test_sha256_authorization_matches_rfc_7616_example() {
+        let challenge = DigestChallenge::parse(
+            r#"Digest realm="http-auth@example.org", qop="auth, auth-int", algorithm=SHA-256, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#,
+        )
+        .unwrap();
+        let header = challenge
+            .authorization(&DigestCredentials {
+                username: "Mufasa",
+                password: "Circle of Life",
+                method: "GET",
+                uri: "/dir/index.html",
+                cnonce: "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ",
+            })
+            .unwrap();
+        assert!(
+            header.contains(
+                r#"response="753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1""#
+            ),
+            "{header}"
+        );
+    }
+
+    #[test]
+    fn test_authorization_rejects_unsupported_qop_and_algorithm() {
+        let mut challenge = DigestChallenge::parse(r#"Digest realm="r", nonce="n""#).unwrap();
+        challenge.qop = Some("auth-int".to_string());
+        assert_eq!(
+            challenge.authorization(&credentials("GET", "/")),
+            Err("unsupported qop 'auth-int'".to_string())
+        );
+        challenge.qop = None;
+        challenge.algorithm = Some("SHA-512-256".to_string());
+        assert_eq!(
+            challenge.authorization(&credentials("GET", "/")),
+            Err("unsupported algorithm 'SHA-512-256'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_request_uri_keeps_path_and_query() {
+        assert_eq!(
+            request_uri("https://h.example.com/dir/index.html?a=1&b=2"),
+            "/dir/index.html?a=1&b=2"
+        );
+        assert_eq!(request_uri("https://h.example.com"), "/");
+    }
+}
@@ -0,0 +1,204 @@
+use crate::syntax::auth::{AuthType, Config, ISSUER_FIELD};
+use crate::syntax::error::AuthError;
+use crate::syntax::token::{Token, TokenType};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+const AUTHORIZATION_URL_FIELD: &str = "authorization_url";
+const TOKEN_URL_FIELD: &str = "token_url";
+const WELL_KNOWN_PATH: &str = "/.well-known/openid-configuration";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DiscoveryDocument {
+    pub authorization_endpoint: Option<String>,
+    pub token_endpoint: Option<String>,
+}
+
+impl DiscoveryDocument {
+    fn endpoint(&self, key: &str) -> Option<&str> {
+        match key {
+            "authorization_endpoint" => self.authorization_endpoint.as_deref(),
+            "token_endpoint" => self.token_endpoint.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DiscoveryCache {
+    documents: Mutex<HashMap<String, DiscoveryDocument>>,
+}
+
+impl DiscoveryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn apply(&self, config: &mut Config) -> Result<(), AuthError> {
+        let Some(issuer) = config.fields.get(ISSUER_FIELD).map(|t| t.value.clone()) else {
+            return Ok(());
+        };
+        let missing: Vec<(&str, &str)> = endpoint_fields(&config.auth_type)
+            .iter()
+            .copied()
+            .filter(|(field, _)| !config.fields.contains_key(*field))
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        let document = self.document(&issuer).await?;
+        for (field, key) in missing {
+            let value = document.endpoint(key).ok_or_else(|| {
+                AuthError::new(format!(
+                    "Discovery document for issuer '{issuer}' has no '{key}'"
+                ))
+            })?;
+            config.fields.insert(
+                field.to_string(),
+                Token {
+                    token_type: TokenType::String,
+                    value: value.to_string(),
+                    span: 0..0,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    async fn document(&self, issuer: &str) -> Result<DiscoveryDocument, AuthError> {
+        if let Some(document) = self
+            .documents
+            .lock()
+            .ok()
+            .and_then(|documents| documents.get(issuer).cloned())
+        {
+            return Ok(document);
+        }
+        let document = fetch_document(issuer).await?;
+        if let Ok(mut documents) = self.documents.lock() {
+            documents.insert(issuer.to_string(), document.clone());
+        }
+        Ok(document)
+    }
+}
+
+pub fn discovery_url(issuer: &str) -> String {
+    format!("{}{WELL_KNOWN_PATH}", issuer.trim_end_matches('/'))
+}
+
+fn endpoint_fields(auth_type: &AuthType) -> &'static [(&'static str, &'static str)] {
+    match auth_type {
+        AuthType::Bearer | AuthType::ApiKey | AuthType::Digest => &[],
+        AuthType::OAuth2AuthorizationCode => &[
+            (AUTHORIZATION_URL_FIELD, "authorization_endpoint"),
+            (TOKEN_URL_FIELD, "token_endpoint"),
+        ],
+        AuthType::OAuth2ClientCredentials | AuthType::OAuth2RefreshToken => {
+            &[(TOKEN_URL_FIELD, "token_endpoint")]
+        }
+        AuthType::OAuth2Implicit => &[(AUTHORIZATION_URL_FIELD, "authorization_endpoint")],
+    }
+}
+
+async fn fetch_document(issuer: &str) -> Result<DiscoveryDocument, AuthError> {
+    let url = discovery_url(issuer);
+    let response =
+        reqwest::Client::new().get(&url).send().await.map_err(|e| {
+            AuthError::new(format!("Failed to fetch discovery document '{url}': {e}"))
+        })?;
+    if !response.status().is_success() {
+        return Err(AuthError::new(format!(
+            "Discovery document '{url}' returned status {}",
+            response.status()
+        )));
+    }
+    response.json().await.map_err(|e| {
+        AuthError::new(format!(
+            "Discovery document '{url}' is not a valid OpenID configuration: {e}"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn config(auth_type: AuthType, fields: &[(&str, &str)]) -> Config {
+        Config {
+            name: "oidc".to_string(),
+            auth_type,
+            fields: fields
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.to_string(),
+                        Token {
+                            token_type: TokenType::String,
+                            value: v.to_string(),
+                            span: 0..0,
+                        },
+                    )
+                })
+                .collect(),
+            file_path: PathBuf::new(),
+            line: 0,
+            character: 0,
+        }
+    }
+
+    #[test]
+    fn test_discovery_url_trims_trailing_slash() {
+        assert_eq!(
+            discovery_url("https://login.example.com/tenant/"),
+            "https://login.example.com/tenant/.well-known/openid-configuration"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_uses_cached_document() {
+        let cache = DiscoveryCache::new();
+        if let Ok(mut documents) = cache.documents.lock() {
+            documents.insert(
+                "https://issuer.test".to_string(),
+                DiscoveryDocument {
+                    authorization_endpoint: Some("https://issuer.test/authorize".to_string()),
+                    token_endpoint: Some("https://issuer.test/token".to_string()),
+                },
+            );
+        }
+        let mut config = config(
+            AuthType::OAuth2AuthorizationCode,
+            &[
+                ("issuer", "https://issuer.test"),
+                ("token_url", "https://override.test/token"),
+            ],
+        );
+
+        cache.apply(&mut config).await.expect("discovery applies");
+
+        assert_eq!(
+            config.fields["authorization_url"].value,
+            "https://issuer.test/authorize"
+        );
+        assert_eq!(
+            config.fields["token_url"].value,
+            "https://override.test/token"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_skips_configs_without_issuer() {
+        let cache = DiscoveryCache::new();
+        let mut config = config(
+            AuthType::OAuth2ClientCredentials,
+            &[("token_url", "https://auth.test/token")],
+        );
+
+        cache.apply(&mut config).await.expect("nothing to discover");
+
+        assert_eq!(config.fields.len(), 1);
+    }
+}
@@ -1,22 +1,63 @@
+pub mod api_key;
 pub mod auth_provider;
 pub mod bearer;
+pub mod digest;
+pub mod discovery;
 pub mod oauth2_authorization_code;
 pub mod oauth2_client_credentials;
 pub mod oauth2_implicit;
+pub mod oauth2_refresh_token;
+pub mod pkce;
+pub mod token_cache;
+mod token_request;
 
+pub use api_key::ApiKeyAuthProvider;
 pub use auth_provider::{AuthFuture, AuthProvider, ConfiguredRequest};
 pub use bearer::BearerProvider;
+pub use digest::DigestAuthProvider;
+pub use discovery::DiscoveryCache;
 pub use oauth2_authorization_code::OAuth2AuthorizationCodeProvider;
 pub use oauth2_client_credentials::OAuth2ClientCredentialsProvider;
 pub use oauth2_implicit::OAuth2ImplicitProvider;
+pub use oauth2_refresh_token::OAuth2RefreshTokenProvider;
+pub use token_cache::{TokenCache, TokenCacheKey};
 
 pub use crate::syntax::auth::{AuthType, Config};
 
-pub fn get_provider(auth_type: &AuthType) -> Box<dyn AuthProvider> {
+#[derive(Debug, Clone, Default)]
+pub struct ProviderOptions {
+    pub token_cache: Option<TokenCache>,
+    pub dump_tokens_redacted: bool,
+    pub interactive: bool,
+    pub explain: bool,
+}
+
+pub fn get_provider(auth_type: &AuthType, options: ProviderOptions) -> Box<dyn AuthProvider> {
+    let explain = options.explain;
     match auth_type {
-        AuthType::Bearer => Box::new(BearerProvider::new()),
-        AuthType::OAuth2AuthorizationCode => Box::new(OAuth2AuthorizationCodeProvider::new()),
-        AuthType::OAuth2ClientCredentials => Box::new(OAuth2ClientCredentialsProvider::new()),
-        AuthType::OAuth2Implicit => Box::new(OAuth2ImplicitProvider::new()),
+        AuthType::Bearer => Box::new(BearerProvider::new().with_explain(explain)),
+        AuthType::OAuth2AuthorizationCode => Box::new(
+            OAuth2AuthorizationCodeProvider::new()
+                .with_interactive(options.interactive)
+                .with_token_dump(options.dump_tokens_redacted)
+                .with_explain(explain),
+        ),
+        AuthType::OAuth2ClientCredentials => {
+            let mut provider = OAuth2ClientCredentialsProvider::new()
+                .with_token_dump(options.dump_tokens_redacted)
+                .with_explain(explain);
+            if let Some(cache) = options.token_cache {
+                provider = provider.with_token_cache(cache);
+            }
+            Box::new(provider)
+        }
+        AuthType::OAuth2Implicit => Box::new(OAuth2ImplicitProvider::new().with_explain(explain)),
+        AuthType::OAuth2RefreshToken => Box::new(
+            OAuth2RefreshTokenProvider::new()
+                .with_token_dump(options.dump_tokens_redacted)
+                .with_explain(explain),
+        ),
+        AuthType::ApiKey => Box::new(ApiKeyAuthProvider::new().with_explain(explain)),
+        AuthType::Digest => Box::new(DigestAuthProvider::new().with_explain(explain)),
     }
 }
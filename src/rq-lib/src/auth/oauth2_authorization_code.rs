@@ -1,12 +1,94 @@
-use super::auth_provider::{AuthFuture, AuthProvider};
+use super::auth_provider::{explain, AuthFuture, AuthProvider, FALLBACK_VARIABLE_STEP};
 use super::bearer::BearerProvider;
+#[cfg(feature = "native")]
+use super::pkce::{generate_state, Pkce, S256};
+#[cfg(feature = "native")]
+use super::token_request::{access_token, post_token_request};
+use super::token_request::{bearer_header, dump_token};
+#[cfg(feature = "native")]
+use crate::syntax::auth::CLI_REDIRECT_URI;
 use crate::syntax::error::AuthError;
+#[cfg(feature = "native")]
+use std::collections::HashMap;
 
-pub struct OAuth2AuthorizationCodeProvider;
+const LABEL: &str = "OAuth2 Authorization Code";
+#[cfg(feature = "native")]
+const CALLBACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+pub struct OAuth2AuthorizationCodeProvider {
+    interactive: bool,
+    dump_tokens: bool,
+    explain: bool,
+}
 
 impl OAuth2AuthorizationCodeProvider {
     pub fn new() -> Self {
-        Self
+        Self {
+            interactive: false,
+            dump_tokens: false,
+            explain: false,
+        }
+    }
+
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    pub fn with_interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn with_token_dump(mut self, dump_tokens: bool) -> Self {
+        self.dump_tokens = dump_tokens;
+        self
+    }
+
+    #[cfg(feature = "native")]
+    async fn authorize(
+        &self,
+        auth_config: &crate::syntax::auth::Config,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let name = &auth_config.name;
+        let flow = FlowFields::new(auth_config)?;
+        let listener = crate::native::CallbackListener::bind(&flow.redirect_uri)
+            .map_err(|e| AuthError::new(format!("{LABEL} auth '{name}': {e}")))?;
+        let pkce = flow
+            .use_pkce
+            .then(|| Pkce::generate(&flow.code_challenge_method));
+        let state = flow.use_state.then(generate_state);
+        let authorization_url = flow.authorization_url(pkce.as_ref(), state.as_deref())?;
+
+        eprintln!("Open this URL to authorize auth '{name}':\n  {authorization_url}");
+        open_browser(&authorization_url);
+        let params = tokio::task::spawn_blocking(move || listener.wait(CALLBACK_TIMEOUT))
+            .await
+            .map_err(|e| AuthError::new(format!("{LABEL} auth '{name}': {e}")))?
+            .map_err(|e| AuthError::new(format!("{LABEL} auth '{name}': {e}")))?;
+        let code = callback_code(name, &params, state.as_deref())?;
+
+        let token_response = post_token_request(
+            LABEL,
+            name,
+            &flow.token_url,
+            &flow.token_params(code, pkce),
+            "",
+        )
+        .await?;
+        Ok(access_token(&token_response)?.to_string())
+    }
+
+    #[cfg(not(feature = "native"))]
+    async fn authorize(
+        &self,
+        auth_config: &crate::syntax::auth::Config,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Err(AuthError::new(format!(
+            "{LABEL} auth '{}': interactive authentication is not supported in WASM builds",
+            auth_config.name
+        ))
+        .into())
     }
 }
 
@@ -30,27 +112,281 @@ impl AuthProvider for OAuth2AuthorizationCodeProvider {
     ) -> AuthFuture<'a> {
         Box::pin(async move {
             let variables = context.all_variables();
-            let (modified_headers, applied) =
-                BearerProvider::apply_from_variables(&variables, headers);
+            let (mut headers, applied) = BearerProvider::apply_from_variables(&variables, headers);
 
             if applied {
-                return Ok((url, modified_headers));
+                explain(self.explain, &auth_config.name, FALLBACK_VARIABLE_STEP);
+                return Ok((url, headers));
             }
 
-            Err(AuthError::new(format!(
-                "OAuth2 Authorization Code auth '{}' requires interactive authentication. This feature is not yet implemented.",
-                auth_config.name
-            ))
-            .into())
+            if !self.interactive {
+                explain(
+                    self.explain,
+                    &auth_config.name,
+                    "no 'auth_token' variable and --interactive is off",
+                );
+                return Err(AuthError::new(format!(
+                    "{LABEL} auth '{}' requires interactive authentication. Run with --interactive to sign in through the browser, or set the 'auth_token' variable.",
+                    auth_config.name
+                ))
+                .into());
+            }
+
+            explain(
+                self.explain,
+                &auth_config.name,
+                "signing in through the browser and the token endpoint",
+            );
+            let access_token = self.authorize(auth_config).await?;
+            if self.dump_tokens {
+                dump_token(&auth_config.name, &access_token);
+            }
+            headers.push(bearer_header(&access_token));
+            Ok((url, headers))
+        })
+    }
+}
+
+#[cfg(feature = "native")]
+struct FlowFields {
+    client_id: String,
+    client_secret: Option<String>,
+    authorization_url: String,
+    token_url: String,
+    redirect_uri: String,
+    scope: Option<String>,
+    code_challenge_method: String,
+    use_pkce: bool,
+    use_state: bool,
+}
+
+#[cfg(feature = "native")]
+impl FlowFields {
+    fn new(auth_config: &crate::syntax::auth::Config) -> Result<Self, AuthError> {
+        let field = |name: &str| auth_config.fields.get(name).map(|t| t.value.clone());
+        let required = |name: &str| {
+            field(name).ok_or_else(|| {
+                AuthError::new(format!(
+                    "{LABEL} auth '{}' requires '{name}'",
+                    auth_config.name
+                ))
+            })
+        };
+        Ok(Self {
+            client_id: required("client_id")?,
+            client_secret: field("client_secret"),
+            authorization_url: required("authorization_url")?,
+            token_url: required("token_url")?,
+            redirect_uri: field("redirect_uri").unwrap_or_else(|| CLI_REDIRECT_URI.to_string()),
+            scope: field("scope"),
+            code_challenge_method: field("code_challenge_method")
+                .unwrap_or_else(|| S256.to_string()),
+            use_pkce: field("use_pkce").is_none_or(|v| v != "false"),
+            use_state: field("use_state").is_none_or(|v| v != "false"),
         })
     }
+
+    fn authorization_url(
+        &self,
+        pkce: Option<&Pkce>,
+        state: Option<&str>,
+    ) -> Result<String, AuthError> {
+        let mut url = reqwest::Url::parse(&self.authorization_url).map_err(|e| {
+            AuthError::new(format!(
+                "{LABEL} has invalid authorization_url '{}': {e}",
+                self.authorization_url
+            ))
+        })?;
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &self.client_id)
+                .append_pair("redirect_uri", &self.redirect_uri);
+            if let Some(scope) = &self.scope {
+                query.append_pair("scope", scope);
+            }
+            if let Some(pkce) = pkce {
+                query
+                    .append_pair("code_challenge", &pkce.challenge)
+                    .append_pair("code_challenge_method", &pkce.method);
+            }
+            if let Some(state) = state {
+                query.append_pair("state", state);
+            }
+        }
+        Ok(url.to_string())
+    }
+
+    fn token_params(&self, code: String, pkce: Option<Pkce>) -> HashMap<&'static str, String> {
+        let mut params = HashMap::from([
+            ("grant_type", "authorization_code".to_string()),
+            ("code", code),
+            ("redirect_uri", self.redirect_uri.clone()),
+            ("client_id", self.client_id.clone()),
+        ]);
+        if let Some(pkce) = pkce {
+            params.insert("code_verifier", pkce.verifier);
+        }
+        if let Some(secret) = &self.client_secret {
+            params.insert("client_secret", secret.clone());
+        }
+        params
+    }
+}
+
+#[cfg(feature = "native")]
+fn callback_code(
+    auth_name: &str,
+    params: &HashMap<String, String>,
+    expected_state: Option<&str>,
+) -> Result<String, AuthError> {
+    if let Some(error) = params.get("error") {
+        let detail = params
+            .get("error_description")
+            .map(|d| format!(": {d}"))
+            .unwrap_or_default();
+        return Err(AuthError::new(format!(
+            "{LABEL} auth '{auth_name}' was not authorized: {error}{detail}"
+        )));
+    }
+    if let Some(expected) = expected_state {
+        if params.get("state").map(String::as_str) != Some(expected) {
+            return Err(AuthError::new(format!(
+                "{LABEL} auth '{auth_name}' received a callback with a mismatched 'state'; it may not come from this sign-in"
+            )));
+        }
+    }
+    params.get("code").cloned().ok_or_else(|| {
+        AuthError::new(format!(
+            "{LABEL} auth '{auth_name}' received a callback without an authorization code"
+        ))
+    })
+}
+
+#[cfg(feature = "native")]
+fn open_browser(url: &str) {
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = std::process::Command::new("xdg-open");
+
+    if let Err(e) = command.arg(url).spawn() {
+        crate::logger::Logger::debug(&format!("Failed to open a browser: {e}"));
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::syntax::token::{Token, TokenType};
     use crate::syntax::variable_context::{Variable, VariableContext, VariableValue};
 
+    fn params(values: &[(&str, &str)]) -> HashMap<String, String> {
+        values
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn flow_fields(values: &[(&str, &str)]) -> FlowFields {
+        let config = crate::syntax::auth::Config {
+            name: "login".to_string(),
+            auth_type: crate::syntax::auth::AuthType::OAuth2AuthorizationCode,
+            fields: values
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.to_string(),
+                        Token {
+                            token_type: TokenType::String,
+                            value: v.to_string(),
+                            span: 0..0,
+                        },
+                    )
+                })
+                .collect(),
+            file_path: std::path::PathBuf::new(),
+            line: 0,
+            character: 0,
+        };
+        FlowFields::new(&config).unwrap()
+    }
+
+    #[test]
+    fn test_authorization_url_carries_pkce_challenge_and_state() {
+        let flow = flow_fields(&[
+            ("client_id", "app"),
+            (
+                "authorization_url",
+                "https://login.example.com/authorize?tenant=t1",
+            ),
+            ("token_url", "https://login.example.com/token"),
+            ("scope", "openid profile"),
+        ]);
+        let pkce = Pkce::from_verifier("verifier".to_string(), "plain");
+        let url = flow.authorization_url(Some(&pkce), Some("s1")).unwrap();
+        assert_eq!(
+            url,
+            "https://login.example.com/authorize?tenant=t1&response_type=code&client_id=app&redirect_uri=http%3A%2F%2F127.0.0.1%3A8765%2Foauth-callback&scope=openid+profile&code_challenge=verifier&code_challenge_method=plain&state=s1"
+        );
+    }
+
+    #[test]
+    fn test_token_params_send_code_verifier() {
+        let flow = flow_fields(&[
+            ("client_id", "app"),
+            ("authorization_url", "https://h/authorize"),
+            ("token_url", "https://h/token"),
+            ("redirect_uri", "http://localhost:9000/cb"),
+            ("use_state", "false"),
+        ]);
+        assert!(!flow.use_state && flow.use_pkce);
+        let pkce = Pkce::from_verifier("verifier".to_string(), S256);
+        let params = flow.token_params("code-1".to_string(), Some(pkce));
+        assert_eq!(params["grant_type"], "authorization_code");
+        assert_eq!(params["code"], "code-1");
+        assert_eq!(params["code_verifier"], "verifier");
+        assert_eq!(params["redirect_uri"], "http://localhost:9000/cb");
+        assert!(!params.contains_key("client_secret"));
+    }
+
+    #[test]
+    fn test_callback_code_verifies_state() {
+        let ok = params(&[("code", "c1"), ("state", "s1")]);
+        assert_eq!(callback_code("login", &ok, Some("s1")).unwrap(), "c1");
+        assert_eq!(callback_code("login", &ok, None).unwrap(), "c1");
+
+        let err = callback_code("login", &ok, Some("s2")).unwrap_err();
+        assert!(err.message.contains("mismatched 'state'"), "{err}");
+        let err = callback_code("login", &params(&[("code", "c1")]), Some("s1")).unwrap_err();
+        assert!(err.message.contains("mismatched 'state'"), "{err}");
+    }
+
+    #[test]
+    fn test_callback_code_surfaces_provider_error() {
+        let denied = params(&[
+            ("error", "access_denied"),
+            ("error_description", "User cancelled"),
+        ]);
+        let err = callback_code("login", &denied, None).unwrap_err();
+        assert_eq!(
+            err.message,
+            "OAuth2 Authorization Code auth 'login' was not authorized: access_denied: User cancelled"
+        );
+        let err = callback_code("login", &params(&[]), None).unwrap_err();
+        assert!(
+            err.message.contains("without an authorization code"),
+            "{err}"
+        );
+    }
+
     #[tokio::test]
     async fn test_configure_fallback_to_bearer_with_auth_token() {
         let executor = OAuth2AuthorizationCodeProvider::new();
@@ -68,6 +404,7 @@ mod tests {
                 value: VariableValue::String("fallback-bearer-token".to_string()),
             }],
             environment_variables: vec![],
+            profile_variables: vec![],
             secret_variables: vec![],
             endpoint_variables: vec![],
             request_variables: vec![],
@@ -108,6 +445,7 @@ mod tests {
                 value: VariableValue::String("other-value".to_string()),
             }],
             environment_variables: vec![],
+            profile_variables: vec![],
             secret_variables: vec![],
             endpoint_variables: vec![],
             request_variables: vec![],
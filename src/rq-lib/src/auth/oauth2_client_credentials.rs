@@ -1,5 +1,10 @@
-use super::auth_provider::{AuthFuture, AuthProvider};
+use super::auth_provider::{explain, AuthFuture, AuthProvider, FALLBACK_VARIABLE_STEP};
 use super::bearer::BearerProvider;
+use super::token_cache::{TokenCache, TokenCacheKey};
+use super::token_request::{
+    access_token, bearer_header, dump_token, expires_in, post_token_request,
+};
+use crate::logger::Logger;
 use crate::syntax::error::AuthError;
 use std::collections::HashMap;
 
@@ -22,11 +27,34 @@ const CERT_FILE_FIELD: &str = "cert_file";
 #[cfg(feature = "native")]
 const CERT_PASSWORD_FIELD: &str = "cert_password";
 
-pub struct OAuth2ClientCredentialsProvider;
+pub struct OAuth2ClientCredentialsProvider {
+    token_cache: Option<TokenCache>,
+    dump_tokens: bool,
+    explain: bool,
+}
 
 impl OAuth2ClientCredentialsProvider {
     pub fn new() -> Self {
-        Self
+        Self {
+            token_cache: None,
+            dump_tokens: false,
+            explain: false,
+        }
+    }
+
+    pub fn with_token_cache(mut self, token_cache: TokenCache) -> Self {
+        self.token_cache = Some(token_cache);
+        self
+    }
+
+    pub fn with_token_dump(mut self, dump_tokens: bool) -> Self {
+        self.dump_tokens = dump_tokens;
+        self
+    }
+
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
     }
 }
 
@@ -52,6 +80,7 @@ impl AuthProvider for OAuth2ClientCredentialsProvider {
             let variables = context.all_variables();
             let (mut headers, applied) = BearerProvider::apply_from_variables(&variables, headers);
             if applied {
+                explain(self.explain, &auth_config.name, FALLBACK_VARIABLE_STEP);
                 return Ok((url, headers));
             }
 
@@ -64,6 +93,28 @@ impl AuthProvider for OAuth2ClientCredentialsProvider {
                 .map(|t| &t.value);
             let cert_file = auth_config.fields.get(CERT_FILE_FIELD).map(|t| &t.value);
 
+            let cache_key = TokenCacheKey {
+                client_id: client_id.clone(),
+                token_url: token_url.clone(),
+                scope: scope.cloned(),
+            };
+            if let Some(access_token) = self.token_cache.as_ref().and_then(|c| c.get(&cache_key)) {
+                Logger::debug(&format!(
+                    "Using cached token for auth '{}'",
+                    auth_config.name
+                ));
+                explain(
+                    self.explain,
+                    &auth_config.name,
+                    "using a cached token from the token cache",
+                );
+                if self.dump_tokens {
+                    dump_token(&auth_config.name, &access_token);
+                }
+                headers.push(bearer_header(&access_token));
+                return Ok((url, headers));
+            }
+
             #[cfg(not(feature = "native"))]
             if cert_file.is_some() {
                 return Err(AuthError::new(format!(
@@ -190,15 +241,6 @@ impl AuthProvider for OAuth2ClientCredentialsProvider {
                 jwt_assertion = Some(token);
             }
 
-            #[cfg(not(target_arch = "wasm32"))]
-            let client = reqwest::Client::builder()
-                .user_agent("rq-cli")
-                .build()
-                .map_err(|e| AuthError::new(format!("Failed to build http client: {e}")))?;
-
-            #[cfg(target_arch = "wasm32")]
-            let client = reqwest::Client::new();
-
             let mut params = HashMap::new();
             params.insert("grant_type", "client_credentials".to_string());
             params.insert("client_id", client_id.clone());
@@ -223,34 +265,42 @@ impl AuthProvider for OAuth2ClientCredentialsProvider {
                 params.insert("scope", s.clone());
             }
 
-            let response = client.post(token_url).form(&params).send().await?;
-
-            if !response.status().is_success() {
-                let status = response.status();
-                let text = response.text().await.unwrap_or_default();
-                #[cfg(feature = "native")]
-                let x5t_info = x5t_thumbprint
-                    .map(|t| format!(" (x5t used: {t})"))
-                    .unwrap_or_default();
-                #[cfg(not(feature = "native"))]
-                let x5t_info = String::new();
-
-                return Err(AuthError::new(format!(
-                    "OAuth2 Client Credentials failed.\n  URL: {token_url}\n  Client ID: {client_id}\n  Status: {status}\n  Response: {text}{x5t_info}"
-                ))
-                .into());
+            #[cfg(feature = "native")]
+            let failure_note = x5t_thumbprint
+                .map(|t| format!(" (x5t used: {t})"))
+                .unwrap_or_default();
+            #[cfg(not(feature = "native"))]
+            let failure_note = String::new();
+
+            let credential = if cert_file.is_some() {
+                "a certificate-signed JWT assertion"
+            } else if client_secret.is_some() {
+                "the client secret"
+            } else {
+                "the client id only"
+            };
+            explain(
+                self.explain,
+                &auth_config.name,
+                &format!("requesting a token from token endpoint {token_url} with {credential}"),
+            );
+            let token_response = post_token_request(
+                "OAuth2 Client Credentials",
+                &auth_config.name,
+                token_url,
+                &params,
+                &failure_note,
+            )
+            .await?;
+            let access_token = access_token(&token_response)?;
+            if self.dump_tokens {
+                dump_token(&auth_config.name, access_token);
             }
 
-            let token_response: serde_json::Value = response.json().await?;
-
-            let access_token = token_response["access_token"]
-                .as_str()
-                .ok_or_else(|| AuthError::new("No access_token in response".to_string()))?;
-
-            headers.push((
-                reqwest::header::AUTHORIZATION.as_str().to_string(),
-                format!("Bearer {access_token}"),
-            ));
+            if let Some(cache) = &self.token_cache {
+                cache.store(&cache_key, access_token, expires_in(&token_response));
+            }
+            headers.push(bearer_header(access_token));
 
             Ok((url, headers))
         })
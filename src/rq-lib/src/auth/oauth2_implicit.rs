@@ -1,12 +1,19 @@
-use super::auth_provider::{AuthFuture, AuthProvider};
+use super::auth_provider::{explain, AuthFuture, AuthProvider, FALLBACK_VARIABLE_STEP};
 use super::bearer::BearerProvider;
 use crate::syntax::error::AuthError;
 
-pub struct OAuth2ImplicitProvider;
+pub struct OAuth2ImplicitProvider {
+    explain: bool,
+}
 
 impl OAuth2ImplicitProvider {
     pub fn new() -> Self {
-        Self
+        Self { explain: false }
+    }
+
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
     }
 }
 
@@ -34,8 +41,14 @@ impl AuthProvider for OAuth2ImplicitProvider {
                 BearerProvider::apply_from_variables(&variables, headers);
 
             if applied {
+                explain(self.explain, &auth_config.name, FALLBACK_VARIABLE_STEP);
                 return Ok((url, modified_headers));
             }
+            explain(
+                self.explain,
+                &auth_config.name,
+                "no 'auth_token' variable; the implicit flow is not implemented",
+            );
 
             Err(AuthError::new(format!(
                 "OAuth2 Implicit auth '{}' requires interactive authentication. This feature is not yet implemented.",
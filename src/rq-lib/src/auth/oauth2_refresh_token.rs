@@ -0,0 +1,178 @@
+use super::auth_provider::{explain, AuthFuture, AuthProvider, FALLBACK_VARIABLE_STEP};
+use super::bearer::BearerProvider;
+use super::token_request::{access_token, bearer_header, dump_token, post_token_request};
+use crate::syntax::error::AuthError;
+use std::collections::HashMap;
+
+pub const CLIENT_ID_FIELD: &str = "client_id";
+pub const CLIENT_SECRET_FIELD: &str = "client_secret";
+pub const TOKEN_URL_FIELD: &str = "token_url";
+pub const REFRESH_TOKEN_FIELD: &str = "refresh_token";
+pub const SCOPE_FIELD: &str = "scope";
+
+pub struct OAuth2RefreshTokenProvider {
+    dump_tokens: bool,
+    explain: bool,
+}
+
+impl OAuth2RefreshTokenProvider {
+    pub fn new() -> Self {
+        Self {
+            dump_tokens: false,
+            explain: false,
+        }
+    }
+
+    pub fn with_token_dump(mut self, dump_tokens: bool) -> Self {
+        self.dump_tokens = dump_tokens;
+        self
+    }
+
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    fn params(
+        auth_config: &crate::syntax::auth::Config,
+    ) -> Result<HashMap<&'static str, String>, AuthError> {
+        let field = |name: &str| auth_config.fields.get(name).map(|t| t.value.clone());
+        let required = |name: &str| {
+            field(name).ok_or_else(|| {
+                AuthError::new(format!(
+                    "OAuth2 Refresh Token auth '{}' requires '{name}'",
+                    auth_config.name
+                ))
+            })
+        };
+        let mut params = HashMap::from([
+            ("grant_type", "refresh_token".to_string()),
+            (CLIENT_ID_FIELD, required(CLIENT_ID_FIELD)?),
+            (REFRESH_TOKEN_FIELD, required(REFRESH_TOKEN_FIELD)?),
+        ]);
+        for name in [CLIENT_SECRET_FIELD, SCOPE_FIELD] {
+            if let Some(value) = field(name) {
+                params.insert(name, value);
+            }
+        }
+        Ok(params)
+    }
+}
+
+impl Default for OAuth2RefreshTokenProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthProvider for OAuth2RefreshTokenProvider {
+    fn auth_type(&self) -> &str {
+        "oauth2_refresh_token"
+    }
+
+    fn configure<'a>(
+        &'a self,
+        auth_config: &'a crate::syntax::auth::Config,
+        context: &'a crate::syntax::variable_context::VariableContext,
+        url: String,
+        headers: Vec<(String, String)>,
+    ) -> AuthFuture<'a> {
+        Box::pin(async move {
+            let variables = context.all_variables();
+            let (mut headers, applied) = BearerProvider::apply_from_variables(&variables, headers);
+            if applied {
+                explain(self.explain, &auth_config.name, FALLBACK_VARIABLE_STEP);
+                return Ok((url, headers));
+            }
+
+            let token_url = auth_config
+                .fields
+                .get(TOKEN_URL_FIELD)
+                .map(|t| t.value.as_str())
+                .ok_or_else(|| {
+                    AuthError::new(format!(
+                        "OAuth2 Refresh Token auth '{}' requires '{TOKEN_URL_FIELD}'",
+                        auth_config.name
+                    ))
+                })?;
+            let params = Self::params(auth_config)?;
+            explain(
+                self.explain,
+                &auth_config.name,
+                &format!("exchanging the refresh token at token endpoint {token_url}"),
+            );
+            let token_response = post_token_request(
+                "OAuth2 Refresh Token",
+                &auth_config.name,
+                token_url,
+                &params,
+                "",
+            )
+            .await?;
+            let access_token = access_token(&token_response)?;
+            if self.dump_tokens {
+                dump_token(&auth_config.name, access_token);
+            }
+            headers.push(bearer_header(access_token));
+
+            Ok((url, headers))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::auth::{AuthType, Config};
+    use crate::syntax::token::{Token, TokenType};
+    use std::path::PathBuf;
+
+    fn config(fields: &[(&str, &str)]) -> Config {
+        Config {
+            name: "refresh".to_string(),
+            auth_type: AuthType::OAuth2RefreshToken,
+            fields: fields
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.to_string(),
+                        Token {
+                            token_type: TokenType::String,
+                            value: v.to_string(),
+                            span: 0..0,
+                        },
+                    )
+                })
+                .collect(),
+            file_path: PathBuf::new(),
+            line: 0,
+            character: 0,
+        }
+    }
+
+    #[test]
+    fn test_refresh_token_params() {
+        let params = OAuth2RefreshTokenProvider::params(&config(&[
+            ("client_id", "app"),
+            ("refresh_token", "r1"),
+            ("scope", "read"),
+            ("token_url", "https://h/token"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            params,
+            HashMap::from([
+                ("grant_type", "refresh_token".to_string()),
+                ("client_id", "app".to_string()),
+                ("refresh_token", "r1".to_string()),
+                ("scope", "read".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_refresh_token_params_require_refresh_token() {
+        let err = OAuth2RefreshTokenProvider::params(&config(&[("client_id", "app")])).unwrap_err();
+        assert!(err.message.contains("requires 'refresh_token'"), "{err}");
+    }
+}
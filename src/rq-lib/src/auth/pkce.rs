@@ -0,0 +1,85 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use sha2::{Digest, Sha256};
+
+const UNRESERVED_CHARS: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+const VERIFIER_LEN: usize = 64;
+const STATE_LEN: usize = 32;
+
+pub const S256: &str = "S256";
+pub const PLAIN: &str = "plain";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pkce {
+    pub verifier: String,
+    pub challenge: String,
+    pub method: String,
+}
+
+impl Pkce {
+    pub fn generate(method: &str) -> Self {
+        Self::from_verifier(random_string(VERIFIER_LEN), method)
+    }
+
+    pub fn from_verifier(verifier: String, method: &str) -> Self {
+        Self {
+            challenge: code_challenge(&verifier, method),
+            verifier,
+            method: method.to_string(),
+        }
+    }
+}
+
+pub fn code_challenge(verifier: &str, method: &str) -> String {
+    if method == PLAIN {
+        return verifier.to_string();
+    }
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+pub fn generate_state() -> String {
+    random_string(STATE_LEN)
+}
+
+fn random_string(len: usize) -> String {
+    (0..len)
+        .map(|_| UNRESERVED_CHARS[rand::random_range(0..UNRESERVED_CHARS.len())] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_s256_challenge_is_base64url_sha256_of_verifier() {
+        let pkce = Pkce::from_verifier(
+            "dBjftJeZ4CVP-mJ92K5vTqHzLlL0p5Dq8dPzIuZtQfA".to_string(),
+            S256,
+        );
+        assert_eq!(
+            pkce.challenge,
+            "WqVV6h5zrfw8tO2KXNTZ7-PAXMkfv2uz8LiBMWNMWDM"
+        );
+        assert_eq!(pkce.method, "S256");
+    }
+
+    #[test]
+    fn test_plain_challenge_is_the_verifier() {
+        assert_eq!(code_challenge("verifier-123", PLAIN), "verifier-123");
+    }
+
+    #[test]
+    fn test_generated_verifier_uses_unreserved_characters() {
+        let first = Pkce::generate(S256);
+        let second = Pkce::generate(S256);
+        assert_eq!(first.verifier.len(), VERIFIER_LEN);
+        assert!(first
+            .verifier
+            .bytes()
+            .all(|b| UNRESERVED_CHARS.contains(&b)));
+        assert_ne!(first.verifier, second.verifier);
+        assert_eq!(first.challenge, code_challenge(&first.verifier, S256));
+        assert_ne!(generate_state(), generate_state());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::logger::Logger;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const EXPIRY_MARGIN_SECS: u64 = 60;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenCacheKey {
+    pub client_id: String,
+    pub token_url: String,
+    pub scope: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedToken {
+    key: TokenCacheKey,
+    access_token: String,
+    expires_at: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TokenCache {
+    dir: PathBuf,
+    refresh: bool,
+}
+
+impl TokenCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            refresh: false,
+        }
+    }
+
+    pub fn refreshing(mut self) -> Self {
+        self.refresh = true;
+        self
+    }
+
+    pub fn get(&self, key: &TokenCacheKey) -> Option<String> {
+        if self.refresh {
+            return None;
+        }
+        let content = std::fs::read_to_string(self.path(key)).ok()?;
+        let cached: CachedToken = serde_json::from_str(&content).ok()?;
+        (cached.key == *key && now_secs() + EXPIRY_MARGIN_SECS < cached.expires_at)
+            .then_some(cached.access_token)
+    }
+
+    pub fn store(&self, key: &TokenCacheKey, access_token: &str, expires_in: Option<u64>) {
+        let Some(expires_in) = expires_in.filter(|secs| *secs > EXPIRY_MARGIN_SECS) else {
+            return;
+        };
+        let cached = CachedToken {
+            key: key.clone(),
+            access_token: access_token.to_string(),
+            expires_at: now_secs() + expires_in,
+        };
+        let path = self.path(key);
+        let written = serde_json::to_string(&cached)
+            .map_err(|e| e.to_string())
+            .and_then(|content| write_private(&path, &content).map_err(|e| e.to_string()));
+        if let Err(e) = written {
+            Logger::debug(&format!("Failed to cache token in {}: {e}", path.display()));
+        }
+    }
+
+    fn path(&self, key: &TokenCacheKey) -> PathBuf {
+        let id = serde_json::to_string(key).unwrap_or_default();
+        let digest = Sha256::digest(id.as_bytes());
+        let name: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+        self.dir.join(format!("{name}.json"))
+    }
+}
+
+fn write_private(path: &Path, content: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(content.as_bytes())
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(scope: Option<&str>) -> TokenCacheKey {
+        TokenCacheKey {
+            client_id: "app".to_string(),
+            token_url: "https://login.example.com/token".to_string(),
+            scope: scope.map(str::to_string),
+        }
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rq_token_cache_{name}_{}", now_secs()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_token_cache_round_trip_per_key() {
+        let cache = TokenCache::new(cache_dir("round_trip"));
+        cache.store(&key(Some("read")), "token-read", Some(3600));
+
+        assert_eq!(
+            cache.get(&key(Some("read"))),
+            Some("token-read".to_string())
+        );
+        assert_eq!(cache.get(&key(Some("write"))), None);
+        assert_eq!(cache.get(&key(None)), None);
+        assert_eq!(cache.clone().refreshing().get(&key(Some("read"))), None);
+    }
+
+    #[test]
+    fn test_token_cache_skips_short_or_unknown_lifetimes() {
+        let cache = TokenCache::new(cache_dir("lifetimes"));
+        cache.store(&key(None), "short", Some(EXPIRY_MARGIN_SECS));
+        assert_eq!(cache.get(&key(None)), None);
+        cache.store(&key(None), "unknown", None);
+        assert_eq!(cache.get(&key(None)), None);
+    }
+
+    #[test]
+    fn test_token_cache_ignores_expired_entries() {
+        let cache = TokenCache::new(cache_dir("expired"));
+        let expired = CachedToken {
+            key: key(None),
+            access_token: "old".to_string(),
+            expires_at: now_secs() + EXPIRY_MARGIN_SECS / 2,
+        };
+        let content = serde_json::to_string(&expired).unwrap();
+        write_private(&cache.path(&key(None)), &content).unwrap();
+        assert_eq!(cache.get(&key(None)), None);
+    }
+}
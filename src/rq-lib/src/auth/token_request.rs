@@ -0,0 +1,209 @@
+use crate::logger::Logger;
+use crate::syntax::error::AuthError;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+const REDACTED_PARAMS: &[&str] = &[
+    "client_secret",
+    "client_assertion",
+    "refresh_token",
+    "code",
+    "code_verifier",
+];
+const FINGERPRINT_EDGE_CHARS: usize = 4;
+const FINGERPRINT_MIN_CHARS: usize = 16;
+
+pub(crate) async fn post_token_request(
+    flow: &str,
+    auth_name: &str,
+    token_url: &str,
+    params: &HashMap<&str, String>,
+    failure_note: &str,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let client = reqwest::Client::builder()
+        .user_agent("rq-cli")
+        .build()
+        .map_err(|e| AuthError::new(format!("Failed to build http client: {e}")))?;
+
+    #[cfg(target_arch = "wasm32")]
+    let client = reqwest::Client::new();
+
+    Logger::debug(&format!(
+        "Token request for auth '{auth_name}':\n{}",
+        curl_command(token_url, params)
+    ));
+    let response = client.post(token_url).form(params).send().await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        let client_id = params.get("client_id").map_or("", String::as_str);
+        return Err(AuthError::new(format!(
+            "{flow} failed.\n  URL: {token_url}\n  Client ID: {client_id}\n  Status: {status}\n  Response: {text}{failure_note}"
+        ))
+        .into());
+    }
+
+    let token_response: Value = response.json().await?;
+    check_token_error(flow, &token_response)?;
+    Ok(token_response)
+}
+
+fn check_token_error(flow: &str, token_response: &Value) -> Result<(), AuthError> {
+    let Some(error) = token_response.get("error").filter(|e| !e.is_null()) else {
+        return Ok(());
+    };
+    let error = error
+        .as_str()
+        .map_or_else(|| error.to_string(), str::to_string);
+    let message = match token_response["error_description"].as_str() {
+        Some(description) => format!("{flow} failed: {error}: {description}"),
+        None => format!("{flow} failed: {error}"),
+    };
+    Err(AuthError::new(message))
+}
+
+pub(crate) fn access_token(token_response: &Value) -> Result<&str, AuthError> {
+    token_response["access_token"]
+        .as_str()
+        .ok_or_else(|| AuthError::new("No access_token in response".to_string()))
+}
+
+pub(crate) fn expires_in(token_response: &Value) -> Option<u64> {
+    match &token_response["expires_in"] {
+        Value::Number(secs) => secs.as_u64(),
+        Value::String(secs) => secs.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+pub(crate) fn bearer_header(access_token: &str) -> (String, String) {
+    (
+        reqwest::header::AUTHORIZATION.as_str().to_string(),
+        format!("Bearer {access_token}"),
+    )
+}
+
+pub(crate) fn dump_token(auth_name: &str, access_token: &str) {
+    eprintln!(
+        "Token for auth '{auth_name}': {}",
+        token_fingerprint(access_token)
+    );
+}
+
+fn token_fingerprint(access_token: &str) -> String {
+    let chars: Vec<char> = access_token.chars().collect();
+    let digest = Sha256::digest(access_token.as_bytes());
+    let hash: String = digest.iter().take(6).map(|b| format!("{b:02x}")).collect();
+    let summary = format!("{} chars, sha256 {hash}", chars.len());
+    if chars.len() < FINGERPRINT_MIN_CHARS {
+        return summary;
+    }
+    let head: String = chars[..FINGERPRINT_EDGE_CHARS].iter().collect();
+    let tail: String = chars[chars.len() - FINGERPRINT_EDGE_CHARS..]
+        .iter()
+        .collect();
+    format!("{head}...{tail} ({summary})")
+}
+
+fn curl_command(token_url: &str, params: &HashMap<&str, String>) -> String {
+    let mut keys: Vec<&&str> = params.keys().collect();
+    keys.sort();
+    let mut lines = vec![
+        format!("curl -X POST {}", shell_quote(token_url)),
+        "  -H 'Content-Type: application/x-www-form-urlencoded'".to_string(),
+    ];
+    for key in keys {
+        let value = if REDACTED_PARAMS.contains(key) {
+            "***"
+        } else {
+            params[*key].as_str()
+        };
+        lines.push(format!(
+            "  --data-urlencode {}",
+            shell_quote(&format!("{key}={value}"))
+        ));
+    }
+    lines.join(" \\\n")
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curl_command_redacts_secrets() {
+        let params = HashMap::from([
+            ("grant_type", "client_credentials".to_string()),
+            ("client_id", "app".to_string()),
+            ("client_secret", "s3cr3t".to_string()),
+            ("scope", "it's read".to_string()),
+        ]);
+        assert_eq!(
+            curl_command("https://login.example.com/token", &params),
+            concat!(
+                "curl -X POST 'https://login.example.com/token' \\\n",
+                "  -H 'Content-Type: application/x-www-form-urlencoded' \\\n",
+                "  --data-urlencode 'client_id=app' \\\n",
+                "  --data-urlencode 'client_secret=***' \\\n",
+                "  --data-urlencode 'grant_type=client_credentials' \\\n",
+                "  --data-urlencode 'scope=it'\\''s read'",
+            )
+        );
+    }
+
+    #[test]
+    fn test_curl_command_redacts_refresh_token() {
+        let params = HashMap::from([
+            ("grant_type", "refresh_token".to_string()),
+            ("refresh_token", "r3fr3sh".to_string()),
+        ]);
+        assert!(curl_command("https://h/token", &params).contains("'refresh_token=***'"));
+    }
+
+    #[test]
+    fn test_check_token_error_surfaces_provider_error() {
+        let err = check_token_error(
+            "OAuth2 Client Credentials",
+            &serde_json::json!({"error": "invalid_grant", "error_description": "Token revoked"}),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.message,
+            "OAuth2 Client Credentials failed: invalid_grant: Token revoked"
+        );
+        let err =
+            check_token_error("flow", &serde_json::json!({"error": "invalid_scope"})).unwrap_err();
+        assert_eq!(err.message, "flow failed: invalid_scope");
+        assert!(check_token_error("flow", &serde_json::json!({"access_token": "t"})).is_ok());
+        assert!(check_token_error("flow", &serde_json::json!({"error": null})).is_ok());
+    }
+
+    #[test]
+    fn test_token_fingerprint_hides_the_middle_of_the_token() {
+        assert_eq!(
+            token_fingerprint("abcd0123456789wxyz"),
+            "abcd...wxyz (18 chars, sha256 f26d1d36a4ff)"
+        );
+        assert_eq!(token_fingerprint("short"), "5 chars, sha256 f9b0078b5df5");
+    }
+
+    #[test]
+    fn test_expires_in_accepts_numbers_and_strings() {
+        assert_eq!(
+            expires_in(&serde_json::json!({"expires_in": 3600})),
+            Some(3600)
+        );
+        assert_eq!(
+            expires_in(&serde_json::json!({"expires_in": "120"})),
+            Some(120)
+        );
+        assert_eq!(expires_in(&serde_json::json!({})), None);
+    }
+}
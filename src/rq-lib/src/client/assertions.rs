@@ -0,0 +1,196 @@
+use crate::client::models::{AssertionFailure, RequestExecutionResult};
+use crate::syntax::assertion::{Assertion, AssertionTarget, Comparator, PathSegment};
+use serde_json::Value;
+
+const MAX_ACTUAL_LEN: usize = 200;
+
+pub fn evaluate(
+    assertions: &[Assertion],
+    result: &RequestExecutionResult,
+) -> Vec<AssertionFailure> {
+    assertions
+        .iter()
+        .filter_map(|assertion| {
+            let actual = actual_value(&assertion.target, result);
+            (!holds(assertion, actual.as_ref())).then(|| AssertionFailure {
+                assertion: assertion.expression.clone(),
+                expected: describe_expected(assertion),
+                actual: actual
+                    .as_ref()
+                    .map(render)
+                    .unwrap_or_else(|| "<missing>".to_string()),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn actual_value(
+    target: &AssertionTarget,
+    result: &RequestExecutionResult,
+) -> Option<Value> {
+    match target {
+        AssertionTarget::Status => Some(Value::from(result.status)),
+        AssertionTarget::TimeMs => Some(Value::from(result.elapsed_ms)),
+        AssertionTarget::Header(name) => result
+            .response_headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| Value::String(value.clone())),
+        AssertionTarget::Body(None) => Some(Value::String(result.body.clone())),
+        AssertionTarget::Body(Some(path)) => {
+            let body: Value = serde_json::from_str(&result.body).ok()?;
+            path.iter()
+                .try_fold(body, |value, segment| match (segment, value) {
+                    (PathSegment::Key(key), Value::Object(mut map)) => map.remove(key),
+                    (PathSegment::Index(index), Value::Array(mut items))
+                        if *index < items.len() =>
+                    {
+                        Some(items.swap_remove(*index))
+                    }
+                    _ => None,
+                })
+        }
+    }
+}
+
+fn holds(assertion: &Assertion, actual: Option<&Value>) -> bool {
+    let expected = &assertion.expected;
+    let Some(actual) = actual else {
+        return assertion.comparator == Comparator::Ne;
+    };
+    match assertion.comparator {
+        Comparator::Eq => values_equal(actual, expected),
+        Comparator::Ne => !values_equal(actual, expected),
+        Comparator::Lt => compare_numbers(actual, expected, |a, e| a < e),
+        Comparator::Gt => compare_numbers(actual, expected, |a, e| a > e),
+        Comparator::Contains => match actual {
+            Value::Array(items) => items.iter().any(|item| values_equal(item, expected)),
+            Value::Object(map) => expected.as_str().is_some_and(|key| map.contains_key(key)),
+            other => text(other).contains(&text(expected)),
+        },
+        Comparator::Matches => expected
+            .as_str()
+            .and_then(|pattern| regex::Regex::new(pattern).ok())
+            .is_some_and(|re| re.is_match(&text(actual))),
+    }
+}
+
+fn values_equal(actual: &Value, expected: &Value) -> bool {
+    match (as_number(actual), expected.as_f64()) {
+        (Some(a), Some(e)) if expected.is_number() => a == e,
+        _ => actual == expected,
+    }
+}
+
+fn compare_numbers(actual: &Value, expected: &Value, cmp: impl Fn(f64, f64) -> bool) -> bool {
+    match (as_number(actual), expected.as_f64()) {
+        (Some(a), Some(e)) => cmp(a, e),
+        _ => false,
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn describe_expected(assertion: &Assertion) -> String {
+    match assertion.comparator {
+        Comparator::Eq => render(&assertion.expected),
+        other => format!("{other} {}", render(&assertion.expected)),
+    }
+}
+
+fn render(value: &Value) -> String {
+    let rendered = value.to_string();
+    if rendered.chars().count() > MAX_ACTUAL_LEN {
+        let truncated: String = rendered.chars().take(MAX_ACTUAL_LEN).collect();
+        format!("{truncated}...")
+    } else {
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn result(status: u16, body: &str) -> RequestExecutionResult {
+        RequestExecutionResult {
+            request_name: "get_user".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/users/1".to_string(),
+            status,
+            elapsed_ms: 120,
+            request_headers: HashMap::new(),
+            response_headers: HashMap::from([(
+                "content-type".to_string(),
+                "application/json; charset=utf-8".to_string(),
+            )]),
+            body: body.to_string(),
+            redirects: Vec::new(),
+            warnings: Vec::new(),
+            output: None,
+            assertion_failures: Vec::new(),
+            retries: None,
+        }
+    }
+
+    fn failures(expressions: &[&str], result: &RequestExecutionResult) -> Vec<AssertionFailure> {
+        let assertions: Vec<Assertion> = expressions
+            .iter()
+            .map(|e| Assertion::parse(e).unwrap())
+            .collect();
+        evaluate(&assertions, result)
+    }
+
+    #[test]
+    fn test_evaluate_passing_assertions() {
+        let result = result(200, r#"{"id": 1, "name": "alice", "tags": ["admin"]}"#);
+        let failed = failures(
+            &[
+                "status == 200",
+                "status != 404",
+                "time_ms < 500",
+                "time_ms > 100",
+                "header.Content-Type contains \"json\"",
+                "body.$.id == 1",
+                "body.$.name matches \"^al\"",
+                "body.$.tags contains \"admin\"",
+                "body.$.missing != 1",
+                "body contains \"alice\"",
+            ],
+            &result,
+        );
+        assert!(failed.is_empty(), "unexpected failures: {failed:?}");
+    }
+
+    #[test]
+    fn test_evaluate_reports_actual_and_expected() {
+        let result = result(404, r#"{"error": "not found"}"#);
+        let failed = failures(
+            &["status == 200", "time_ms < 100", "body.$.id == 1"],
+            &result,
+        );
+        let rendered: Vec<String> = failed.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "status == 200 (expected 200, got 404)",
+                "time_ms < 100 (expected < 100, got 120)",
+                "body.$.id == 1 (expected 1, got <missing>)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use serde_json::Value;
+
+use crate::client::assertions::actual_value;
+use crate::client::models::RequestExecutionResult;
+use crate::error::RqError;
+use crate::syntax::assertion::AssertionTarget;
+use crate::syntax::parse_result::Capture;
+use crate::syntax::{Variable, VariableValue};
+
+pub fn evaluate(
+    captures: &[Capture],
+    result: &RequestExecutionResult,
+) -> Result<Vec<Variable>, RqError> {
+    captures
+        .iter()
+        .map(|capture| {
+            let value = match actual_value(&capture.target, result) {
+                Some(Value::String(value)) => value,
+                Some(value) => value.to_string(),
+                None => return Err(missing(capture, result)),
+            };
+            Ok(Variable {
+                name: capture.variable.clone(),
+                value: VariableValue::String(value),
+            })
+        })
+        .collect()
+}
+
+pub fn store(variables: &mut Vec<Variable>, captured: &[Variable]) {
+    for variable in captured {
+        variables.retain(|v| v.name != variable.name);
+        variables.push(variable.clone());
+    }
+}
+
+fn missing(capture: &Capture, result: &RequestExecutionResult) -> RqError {
+    let reason = match &capture.target {
+        AssertionTarget::Header(name) => format!("the response has no '{name}' header"),
+        _ => format!(
+            "'{}' does not resolve against the response",
+            capture.expression
+        ),
+    };
+    RqError::Validation(format!(
+        "Cannot capture '{}' from request '{}': {reason}",
+        capture.variable, result.request_name
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::assertion::parse_target;
+    use std::collections::HashMap;
+
+    fn result() -> RequestExecutionResult {
+        RequestExecutionResult {
+            request_name: "create_order".to_string(),
+            method: "POST".to_string(),
+            url: "http://localhost/orders".to_string(),
+            status: 201,
+            elapsed_ms: 12,
+            request_headers: HashMap::new(),
+            response_headers: HashMap::from([("location".to_string(), "/orders/42".to_string())]),
+            body: r#"{"id": 42}"#.to_string(),
+            redirects: Vec::new(),
+            warnings: Vec::new(),
+            output: None,
+            assertion_failures: Vec::new(),
+            retries: None,
+        }
+    }
+
+    fn capture(variable: &str, expression: &str) -> Capture {
+        Capture {
+            variable: variable.to_string(),
+            expression: expression.to_string(),
+            target: parse_target(expression).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_reads_header_case_insensitively() {
+        let captured = evaluate(
+            &[
+                capture("location", "header.Location"),
+                capture("id", "body.$.id"),
+            ],
+            &result(),
+        )
+        .unwrap();
+        assert_eq!(
+            captured,
+            vec![
+                Variable {
+                    name: "location".to_string(),
+                    value: VariableValue::String("/orders/42".to_string()),
+                },
+                Variable {
+                    name: "id".to_string(),
+                    value: VariableValue::String("42".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_rejects_missing_header() {
+        let err = evaluate(&[capture("etag", "header.ETag")], &result()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Cannot capture 'etag' from request 'create_order': the response has no 'ETag' header"
+        );
+    }
+
+    #[test]
+    fn test_store_replaces_earlier_value() {
+        let mut variables = vec![Variable {
+            name: "location".to_string(),
+            value: VariableValue::String("/old".to_string()),
+        }];
+        store(
+            &mut variables,
+            &[Variable {
+                name: "location".to_string(),
+                value: VariableValue::String("/new".to_string()),
+            }],
+        );
+        assert_eq!(variables.len(), 1);
+        assert_eq!(
+            variables[0].value,
+            VariableValue::String("/new".to_string())
+        );
+    }
+}
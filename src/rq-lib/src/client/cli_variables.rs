@@ -0,0 +1,88 @@
+use crate::syntax::{Variable, VariableValue};
+
+const TYPES: &str = "string, int, float or bool";
+
+pub fn parse_cli_variable(assignment: &str) -> Result<Variable, String> {
+    let (spec, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| "Variable must be in format NAME=VALUE or NAME:TYPE=VALUE".to_string())?;
+    let (name, kind) = match spec.split_once(':') {
+        Some((name, kind)) => (name.trim(), Some(kind.trim())),
+        None => (spec.trim(), None),
+    };
+    if name.is_empty() {
+        return Err("Variable name must not be empty".to_string());
+    }
+    let value = match kind {
+        None | Some("string") => VariableValue::String(value.to_string()),
+        Some(kind) => VariableValue::Json(typed_literal(name, kind, value.trim())?),
+    };
+    Ok(Variable {
+        name: name.to_string(),
+        value,
+    })
+}
+
+fn typed_literal(name: &str, kind: &str, value: &str) -> Result<String, String> {
+    let invalid = |expected: &str| {
+        format!("Variable '{name}' is declared as {kind}, but '{value}' is not {expected}")
+    };
+    match kind {
+        "int" => value
+            .parse::<i64>()
+            .map(|n| n.to_string())
+            .map_err(|_| invalid("an integer")),
+        "float" => serde_json::from_str::<serde_json::Number>(value)
+            .map(|_| value.to_string())
+            .map_err(|_| invalid("a number")),
+        "bool" => match value {
+            "true" | "false" => Ok(value.to_string()),
+            _ => Err(invalid("true or false")),
+        },
+        other => Err(format!(
+            "Unknown type '{other}' for variable '{name}'; expected {TYPES}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(assignment: &str) -> Result<VariableValue, String> {
+        parse_cli_variable(assignment).map(|v| v.value)
+    }
+
+    #[test]
+    fn test_typed_values_become_json_literals() {
+        assert_eq!(
+            value("port:int=8080"),
+            Ok(VariableValue::Json("8080".into()))
+        );
+        assert_eq!(
+            value("ratio:float=-1.5e3"),
+            Ok(VariableValue::Json("-1.5e3".into()))
+        );
+        assert_eq!(
+            value("enabled:bool=true"),
+            Ok(VariableValue::Json("true".into()))
+        );
+        assert_eq!(
+            value("name:string=a=b"),
+            Ok(VariableValue::String("a=b".into()))
+        );
+        assert_eq!(value("name=8080"), Ok(VariableValue::String("8080".into())));
+    }
+
+    #[test]
+    fn test_values_must_match_declared_type() {
+        assert_eq!(
+            value("port:int=80.5"),
+            Err("Variable 'port' is declared as int, but '80.5' is not an integer".into())
+        );
+        assert!(value("ratio:float=NaN").is_err());
+        assert!(value("enabled:bool=yes").is_err());
+        assert!(value("when:date=2024-01-01").is_err_and(|e| e.contains("Unknown type 'date'")));
+        assert!(value(":int=1").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::client::models::EnvironmentCollision;
+use crate::syntax::{RqFile, Variable};
+use std::collections::BTreeMap;
+
+struct Definition {
+    line: usize,
+    character: usize,
+    variables: Vec<Variable>,
+}
+
+pub fn find(rq_files: &[RqFile]) -> Vec<EnvironmentCollision> {
+    let mut definitions: BTreeMap<&str, BTreeMap<String, Definition>> = BTreeMap::new();
+    for rq_file in rq_files {
+        for (name, (file, line, character)) in &rq_file.environment_locations {
+            let mut variables = rq_file.environments.get(name).cloned().unwrap_or_default();
+            variables.sort_by(|a, b| a.name.cmp(&b.name));
+            definitions.entry(name).or_default().insert(
+                crate::paths::clean_path_str(file).to_string(),
+                Definition {
+                    line: *line,
+                    character: *character,
+                    variables,
+                },
+            );
+        }
+    }
+    definitions
+        .into_iter()
+        .filter_map(|(name, by_file)| collision(name, by_file))
+        .collect()
+}
+
+fn collision(name: &str, by_file: BTreeMap<String, Definition>) -> Option<EnvironmentCollision> {
+    let mut definitions = by_file.values();
+    let first = definitions.next()?;
+    if definitions.all(|d| d.variables == first.variables) {
+        return None;
+    }
+    let files: Vec<String> = by_file.keys().cloned().collect();
+    let (file, first) = by_file.into_iter().next()?;
+    Some(EnvironmentCollision {
+        message: format!(
+            "Environment '{name}' is defined with different variables in {} files: {}",
+            files.len(),
+            files.join(", ")
+        ),
+        name: name.to_string(),
+        file,
+        line: first.line,
+        character: first.character,
+        files,
+    })
+}
@@ -0,0 +1,139 @@
+pub fn merge(
+    var_headers: Vec<(String, String)>,
+    inline_headers: Vec<(String, String)>,
+) -> Vec<(String, String)> {
+    let mut merged = Vec::with_capacity(var_headers.len() + inline_headers.len());
+    let mut placed = vec![false; inline_headers.len()];
+    for (name, value) in var_headers {
+        let overrides: Vec<usize> = inline_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, (inline_name, _))| inline_name.eq_ignore_ascii_case(&name))
+            .map(|(i, _)| i)
+            .collect();
+        if overrides.is_empty() {
+            merged.push((name, value));
+            continue;
+        }
+        for i in overrides {
+            if !placed[i] {
+                placed[i] = true;
+                merged.push(inline_headers[i].clone());
+            }
+        }
+    }
+    merged.extend(
+        inline_headers
+            .into_iter()
+            .zip(placed)
+            .filter(|(_, placed)| !placed)
+            .map(|(header, _)| header),
+    );
+    merged
+}
+
+pub fn layer_auth(
+    before: &[(String, String)],
+    after: Vec<(String, String)>,
+) -> Vec<(String, String)> {
+    let added: Vec<(String, String)> = after
+        .iter()
+        .filter(|header| !before.contains(header))
+        .cloned()
+        .collect();
+    after
+        .into_iter()
+        .filter(|header| {
+            added.contains(header) || !added.iter().any(|(k, _)| k.eq_ignore_ascii_case(&header.0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_disjoint_keeps_var_headers_first() {
+        let merged = merge(
+            headers(&[("Accept", "application/json"), ("X-App", "rq")]),
+            headers(&[("X-Trace", "1"), ("X-Request", "2")]),
+        );
+        assert_eq!(
+            merged,
+            headers(&[
+                ("Accept", "application/json"),
+                ("X-App", "rq"),
+                ("X-Trace", "1"),
+                ("X-Request", "2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_merge_overlap_replaces_in_place_with_inline_casing() {
+        let merged = merge(
+            headers(&[("Accept", "text/plain"), ("X-App", "rq"), ("X-Env", "dev")]),
+            headers(&[("x-env", "prod"), ("ACCEPT", "application/json")]),
+        );
+        assert_eq!(
+            merged,
+            headers(&[
+                ("ACCEPT", "application/json"),
+                ("X-App", "rq"),
+                ("x-env", "prod"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_merge_override_replaces_every_var_duplicate() {
+        let merged = merge(
+            headers(&[("X-Tag", "a"), ("Accept", "*/*"), ("x-tag", "b")]),
+            headers(&[("X-Tag", "c")]),
+        );
+        assert_eq!(merged, headers(&[("X-Tag", "c"), ("Accept", "*/*")]));
+    }
+
+    #[test]
+    fn test_merge_keeps_repeated_inline_headers_together() {
+        let merged = merge(
+            headers(&[("X-Tag", "a"), ("Accept", "*/*")]),
+            headers(&[("X-Tag", "b"), ("X-New", "1"), ("X-Tag", "c")]),
+        );
+        assert_eq!(
+            merged,
+            headers(&[
+                ("X-Tag", "b"),
+                ("X-Tag", "c"),
+                ("Accept", "*/*"),
+                ("X-New", "1"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_layer_auth_replaces_headers_set_by_earlier_layers() {
+        let before = headers(&[("X-Api-Key", "k1"), ("Authorization", "Bearer old")]);
+        let mut after = before.clone();
+        after.push(("authorization".to_string(), "Bearer new".to_string()));
+        assert_eq!(
+            layer_auth(&before, after),
+            headers(&[("X-Api-Key", "k1"), ("authorization", "Bearer new")])
+        );
+    }
+
+    #[test]
+    fn test_layer_auth_keeps_unrelated_duplicate_headers() {
+        let before = headers(&[("Accept", "a"), ("Accept", "b")]);
+        let after = headers(&[("Accept", "a"), ("Accept", "b"), ("X-Api-Key", "k1")]);
+        assert_eq!(layer_auth(&before, after.clone()), after);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::syntax::Request;
+use std::net::IpAddr;
+
+const HTTP_SCHEME: &str = "http://";
+
+pub fn warning(request: &Request) -> Option<String> {
+    let sends_credentials = !request.auth.is_empty()
+        || request
+            .headers
+            .iter()
+            .any(|(k, _)| k.eq_ignore_ascii_case("authorization"));
+    if !sends_credentials {
+        return None;
+    }
+    let host = plain_http_host(&request.url)?;
+    (!is_loopback_host(host)).then(|| {
+        format!(
+            "Request '{}' sends credentials over plain HTTP to '{host}'; use https:// or pass --allow-insecure-auth if this is intended",
+            request.name
+        )
+    })
+}
+
+fn plain_http_host(url: &str) -> Option<&str> {
+    if !url
+        .get(..HTTP_SCHEME.len())?
+        .eq_ignore_ascii_case(HTTP_SCHEME)
+    {
+        return None;
+    }
+    let authority = url[HTTP_SCHEME.len()..]
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default();
+    let host_port = authority.rsplit('@').next().unwrap_or_default();
+    Some(match host_port.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+        None => host_port.split(':').next().unwrap_or_default(),
+    })
+}
+
+fn is_loopback_host(host: &str) -> bool {
+    let host = host.to_ascii_lowercase();
+    host == "localhost"
+        || host.ends_with(".localhost")
+        || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::http_method::HttpMethod;
+
+    fn request(url: &str, auth: Option<&str>, headers: &[(&str, &str)]) -> Request {
+        Request {
+            name: "get_user".to_string(),
+            url: url.to_string(),
+            raw_url: url.to_string(),
+            method: HttpMethod::GET,
+            headers: headers
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: None,
+            form: None,
+            multipart: None,
+            body_lines: None,
+            body_file: None,
+            text_file: None,
+            body_bytes: None,
+            headers_var: None,
+            endpoint: None,
+            suite: None,
+            suite_headers_var: None,
+            auth: auth.map(str::to_string).into_iter().collect(),
+            timeout: None,
+            cert: None,
+            retry: None,
+            redirects: None,
+            idempotency_header: None,
+            output: None,
+            unix_socket: None,
+            description: None,
+            reauth: false,
+            skip: false,
+            assertions: Vec::new(),
+            captures: Vec::new(),
+            max_redirects: None,
+            required_variables: Vec::new(),
+            source_path: None,
+            related_files: Vec::new(),
+            line: 0,
+            character: 0,
+        }
+    }
+
+    #[test]
+    fn test_warns_for_credentials_over_remote_http() {
+        let warning = warning(&request(
+            "HTTP://user@api.example.com:8080/users",
+            None,
+            &[("Authorization", "Bearer x")],
+        ));
+        assert!(warning.is_some_and(|w| w.contains("'api.example.com'")));
+        assert!(super::warning(&request("http://api.example.com", Some("oauth"), &[])).is_some());
+    }
+
+    #[test]
+    fn test_skips_https_loopback_and_anonymous_requests() {
+        let bearer = [("authorization", "Bearer x")];
+        assert!(warning(&request("https://api.example.com", None, &bearer)).is_none());
+        assert!(warning(&request("http://localhost:8080/get", None, &bearer)).is_none());
+        assert!(warning(&request("http://127.0.0.1:3000", None, &bearer)).is_none());
+        assert!(warning(&request("http://[::1]:3000/x", None, &bearer)).is_none());
+        assert!(warning(&request("http://api.example.com", None, &[])).is_none());
+    }
+}
@@ -1,31 +1,77 @@
+mod assertions;
+mod captures;
+pub mod cli_variables;
+mod env_collisions;
+mod header_merge;
+mod insecure_auth;
 pub mod models;
+mod previous;
+mod resolved_file;
+mod rqignore;
+mod unused_templates;
+pub(crate) mod variable_file;
 #[cfg(feature = "native")]
 use crate::native;
 
-use crate::client::models::{RequestDetails, RequestExecutionResult, RequestInfo};
+use crate::auth::{DiscoveryCache, ProviderOptions, TokenCache};
+use crate::client::models::{
+    AuthFieldOverride, EnvironmentCollision, EnvironmentFiles, PingResult, RequestDetails,
+    RequestExecutionResult, RequestInfo, RetryTelemetry, RunOptions, UnusedEndpointTemplate,
+};
+use crate::client::rqignore::{is_ignored, RqIgnore, RQIGNORE_FILE};
+use crate::client::variable_file::parse_variable_file;
 use crate::error::RqError;
-use crate::http::HttpClient;
+use crate::http::{is_websocket_url, HttpClient, HttpResponse};
 use crate::logger::Logger;
+use crate::syntax::auth::AuthContext;
+use crate::syntax::duration::parse_duration;
+use crate::syntax::error::SyntaxError;
+use crate::syntax::parsers::utils::{apply_header_conditions, split_header_entry};
 use crate::syntax::{Fs, Request, RqFile, SecretProvider, Variable, VariableValue};
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
+use uuid::Uuid;
+
+pub const STDIN_SOURCE: &str = "-";
+const STDIN_PATH: &str = "<stdin>";
+const UNAUTHORIZED: u16 = 401;
+const TOO_MANY_REQUESTS: u16 = 429;
 
 type AuthDetails = (
     String,
     String,
     HashMap<String, String>,
+    Vec<String>,
     String,
     usize,
     usize,
 );
 
+struct RunScope<'a> {
+    rq_file: &'a RqFile,
+    source_path: &'a Path,
+    env_vars: Vec<Variable>,
+    profile_vars: Vec<Variable>,
+    secret_vars: Vec<Variable>,
+    cli_vars: Vec<Variable>,
+}
+
+struct AuthScope<'s, 'a> {
+    scope: &'s RunScope<'a>,
+    context: &'s crate::syntax::variable_context::VariableContext,
+    search_paths: &'s [PathBuf],
+}
+
 pub struct RqClient {
     fs: Arc<dyn Fs>,
     secrets: Arc<dyn SecretProvider>,
     http: Arc<dyn HttpClient>,
+    discovery: DiscoveryCache,
+    auth_context: AuthContext,
+    stdin_document: Option<String>,
 }
 
 impl RqClient {
@@ -34,7 +80,29 @@ impl RqClient {
         secrets: Arc<dyn SecretProvider>,
         http: Arc<dyn HttpClient>,
     ) -> Self {
-        Self { fs, secrets, http }
+        Self {
+            fs,
+            secrets,
+            http,
+            discovery: DiscoveryCache::new(),
+            auth_context: AuthContext::default(),
+            stdin_document: None,
+        }
+    }
+
+    pub fn with_auth_context(mut self, auth_context: AuthContext) -> Self {
+        self.auth_context = auth_context;
+        self
+    }
+
+    pub fn with_http_client(mut self, http: Arc<dyn HttpClient>) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub fn with_stdin_document(mut self, content: String) -> Self {
+        self.stdin_document = Some(content);
+        self
     }
 
     pub async fn run(
@@ -43,6 +111,7 @@ impl RqClient {
         request_name: Option<&str>,
         environment: Option<&str>,
         variables: &[String],
+        options: &RunOptions,
     ) -> Result<(Vec<RequestExecutionResult>, Vec<RqError>), RqError> {
         let (rq_files, parse_warnings) = self.get_rq_files_to_process(source_path, request_name)?;
 
@@ -52,24 +121,24 @@ impl RqClient {
                 source_path.display()
             )));
         }
+        if environment.is_none() && options.require_environment {
+            Self::ensure_no_environments(&rq_files)?;
+        }
 
+        let mut cli_vars = self.load_variable_files(&options.variable_files)?;
+        cli_vars.extend(Self::parse_cli_variables(variables)?);
         let mut all_results = Vec::new();
 
-        for rq_file in rq_files {
-            let env_vars = if let Some(env_name) = environment {
-                if let Some(vars) = rq_file.environments.get(env_name) {
-                    vars.clone()
-                } else {
-                    return Err(RqError::EnvironmentNotFound(env_name.to_string()));
-                }
-            } else {
-                Vec::new()
-            };
-
-            let secret_vars = self.collect_secrets_for_env(source_path, environment);
-            let cli_vars = Self::parse_cli_variables(variables)?;
-
-            let filtered_requests = Self::filter_requests(rq_file.requests, request_name);
+        for mut rq_file in rq_files {
+            let requests = std::mem::take(&mut rq_file.requests);
+            let mut scope = self.create_run_scope(
+                &rq_file,
+                source_path,
+                environment,
+                &options.profiles,
+                cli_vars.clone(),
+            )?;
+            let filtered_requests = Self::filter_requests(requests, request_name);
 
             if filtered_requests.is_empty() {
                 if let Some(request_name) = request_name {
@@ -92,142 +161,270 @@ impl RqClient {
                 ));
             }
 
-            let mut results = Vec::new();
-
-            for (i, req_with_vars) in filtered_requests.into_iter().enumerate() {
+            for (i, mut req_with_vars) in filtered_requests.into_iter().enumerate() {
                 Logger::debug(&format!("Request {}: {:?}", i + 1, req_with_vars.request));
+                if req_with_vars.request.skip {
+                    eprintln!(
+                        "Skipped '{}': marked with [skip]",
+                        req_with_vars.request.name
+                    );
+                    continue;
+                }
+                if let Some(skip) = &options.skip_request {
+                    if skip(&rq_file.path, &req_with_vars.request.name) {
+                        continue;
+                    }
+                }
 
-                let context = crate::syntax::variable_context::VariableContext::builder()
-                    .file_variables(rq_file.file_variables.clone())
-                    .environment_variables(env_vars.clone())
-                    .secret_variables(secret_vars.clone())
-                    .endpoint_variables(req_with_vars.endpoint_variables)
-                    .request_variables(req_with_vars.request_variables)
-                    .cli_variables(cli_vars.clone())
-                    .build();
-
-                let mut working = req_with_vars.request;
-
-                let mut seen = std::collections::HashSet::new();
-                let missing: Vec<&str> = working
-                    .required_variables
-                    .iter()
-                    .filter(|name| !cli_vars.iter().any(|v| &v.name == *name))
-                    .map(String::as_str)
-                    .filter(|name| seen.insert(*name))
-                    .collect();
-                if !missing.is_empty() {
-                    return Err(RqError::Validation(format!(
-                        "Required variable(s) not set: {}",
-                        missing.join(", ")
-                    )));
+                previous::substitute(&mut req_with_vars.request, all_results.last())?;
+                let request_captures = std::mem::take(&mut req_with_vars.request.captures);
+                let result = self.run_request(&scope, req_with_vars, options).await?;
+                if let Some(completed) = &options.on_request_completed {
+                    if !options.only_auth {
+                        completed(&rq_file.path, &result);
+                    }
+                }
+                if !options.only_auth {
+                    let captured = captures::evaluate(&request_captures, &result)?;
+                    captures::store(&mut scope.cli_vars, &captured);
+                    captures::store(&mut cli_vars, &captured);
                 }
+                all_results.push(result);
+            }
+        }
 
-                let search_paths = Self::build_search_paths(
-                    &working,
-                    &rq_file.path,
-                    &rq_file.imported_files,
-                    source_path,
+        Ok((all_results, parse_warnings))
+    }
+
+    async fn run_request(
+        &self,
+        scope: &RunScope<'_>,
+        req_with_vars: crate::syntax::parse_result::RequestWithVariables,
+        options: &RunOptions,
+    ) -> Result<RequestExecutionResult, RqError> {
+        let (mut resolved_request, context, search_paths) =
+            self.resolve_request(scope, req_with_vars)?;
+        if options.skip_auth {
+            if options.explain_auth && !resolved_request.auth.is_empty() {
+                eprintln!(
+                    "Request '{}': auth skipped by --skip-auth",
+                    resolved_request.name
                 );
+            }
+            resolved_request.auth.clear();
+        }
+        let reauth_request = (!resolved_request.auth.is_empty()
+            && (resolved_request.reauth || options.reauth))
+            .then(|| resolved_request.clone());
+        if !options.only_auth {
+            Self::confirm_mutation(&resolved_request, options)?;
+        }
+        let auth_scope = AuthScope {
+            scope,
+            context: &context,
+            search_paths: &search_paths,
+        };
+        let mut prepared_request = self
+            .authorize(&auth_scope, resolved_request, options, false)
+            .await?;
+        if options.only_auth {
+            return Ok(unsent_result(&prepared_request));
+        }
+        Self::inject_idempotency_key(&mut prepared_request);
+        let insecure_auth = Self::insecure_auth_warning(&prepared_request, options);
+        let mut result = self
+            .send_answering_challenge(&auth_scope, &mut prepared_request, options)
+            .await?;
+        if let Some(request) = reauth_request.filter(|_| result.status == UNAUTHORIZED) {
+            Logger::debug(&format!(
+                "Request '{}' returned {UNAUTHORIZED}; re-running auth and retrying once",
+                request.name
+            ));
+            prepared_request = self
+                .reauthorize(&auth_scope, request, &prepared_request, options)
+                .await?;
+            result = self.send(&prepared_request, options).await?;
+        }
+        result.warnings.extend(insecure_auth);
+        result.assertion_failures = assertions::evaluate(&prepared_request.assertions, &result);
+        Ok(result)
+    }
 
-                if let Some(header_var) = working.headers_var.clone() {
-                    let headers = std::mem::take(&mut working.headers);
-                    working.headers = Self::apply_headers_var(&header_var, headers, &context)
-                        .map_err(|e| {
-                            let (line, col, path) = crate::syntax::resolve::find_variable_location(
-                                &*self.fs,
-                                &search_paths,
-                                &header_var,
-                            );
-                            RqError::Syntax(crate::syntax::error::SyntaxError::with_file(
-                                e,
-                                line,
-                                col,
-                                0..0,
-                                path.display().to_string(),
-                            ))
-                        })?;
-                }
+    async fn send_answering_challenge(
+        &self,
+        auth_scope: &AuthScope<'_, '_>,
+        request: &mut Request,
+        options: &RunOptions,
+    ) -> Result<RequestExecutionResult, RqError> {
+        let result = self.send(request, options).await?;
+        if result.status != UNAUTHORIZED {
+            return Ok(result);
+        }
+        match self
+            .answer_challenge(auth_scope, request, &result, options)
+            .await?
+        {
+            Some(answered) => {
+                *request = answered;
+                self.send(request, options).await
+            }
+            None => Ok(result),
+        }
+    }
 
-                #[allow(unused_mut)]
-                let mut resolved_request = crate::syntax::resolve::resolve_variables(
-                    working,
-                    &context,
-                    &search_paths,
-                    &*self.fs,
-                )?;
-
-                if let Some(auth_name) = resolved_request.auth.as_deref() {
-                    if !auth_name.trim().is_empty() {
-                        if let Some(auth_provider) = rq_file.auth_providers.get(auth_name) {
-                            let resolved_provider = crate::syntax::resolve::resolve_auth_provider(
-                                auth_provider.clone(),
-                                &context,
-                                &search_paths,
-                                &*self.fs,
-                            )?;
-
-                            let provider = crate::auth::get_provider(&resolved_provider.auth_type);
-                            match provider
-                                .configure(
-                                    &resolved_provider,
-                                    &context,
-                                    resolved_request.url.clone(),
-                                    resolved_request.headers.clone(),
-                                )
-                                .await
-                            {
-                                Ok((modified_url, modified_headers)) => {
-                                    resolved_request.url = modified_url;
-                                    resolved_request.headers = modified_headers;
-                                }
-                                Err(e) => {
-                                    return Err(RqError::Auth(format!(
-                                        "Configuration '{auth_name}' failed: {e}"
-                                    )));
-                                }
-                            }
-                        } else {
-                            return Err(RqError::Validation(format!(
-                                "Auth configuration '{auth_name}' not found"
-                            )));
-                        }
-                    }
+    async fn authorize(
+        &self,
+        auth_scope: &AuthScope<'_, '_>,
+        request: Request,
+        options: &RunOptions,
+        refresh_token: bool,
+    ) -> Result<Request, RqError> {
+        let provider_options = ProviderOptions {
+            token_cache: options.token_cache.as_ref().map(|dir| {
+                let cache = TokenCache::new(dir);
+                if refresh_token {
+                    cache.refreshing()
+                } else {
+                    cache
                 }
+            }),
+            dump_tokens_redacted: options.dump_tokens_redacted,
+            interactive: options.interactive,
+            explain: options.explain_auth,
+        };
+        let mut authorized_request = self
+            .apply_auth(
+                auth_scope.scope,
+                request,
+                auth_scope.context,
+                auth_scope.search_paths,
+                &options.auth_fields,
+                provider_options,
+            )
+            .await?;
+        if authorized_request.timeout.is_none() {
+            authorized_request.timeout = options.timeout.clone();
+        }
+        let mut prepared_request = Self::prepare_request(authorized_request)?;
+        prepared_request.max_redirects = options.max_redirects;
+        Self::ensure_absolute_url(&prepared_request)?;
+        Ok(prepared_request)
+    }
+
+    async fn reauthorize(
+        &self,
+        auth_scope: &AuthScope<'_, '_>,
+        mut request: Request,
+        previous: &Request,
+        options: &RunOptions,
+    ) -> Result<Request, RqError> {
+        if let Some(header) = &previous.idempotency_header {
+            request.headers.extend(
+                previous
+                    .headers
+                    .iter()
+                    .filter(|(k, _)| k.eq_ignore_ascii_case(header))
+                    .cloned(),
+            );
+        }
+        let mut prepared_request = self.authorize(auth_scope, request, options, true).await?;
+        Self::inject_idempotency_key(&mut prepared_request);
+        Ok(prepared_request)
+    }
+
+    async fn send(
+        &self,
+        request: &Request,
+        options: &RunOptions,
+    ) -> Result<RequestExecutionResult, RqError> {
+        if is_websocket_url(&request.url) {
+            self.execute_websocket(request, options.ws_max_frames).await
+        } else {
+            self.execute_with_retry(request).await
+        }
+    }
 
+    pub async fn ping(
+        &self,
+        source_path: &Path,
+        request_name: &str,
+        environment: Option<&str>,
+        variables: &[String],
+    ) -> Result<Vec<PingResult>, RqError> {
+        let (rq_files, _) = self.get_rq_files_to_process(source_path, Some(request_name))?;
+        let cli_vars = Self::parse_cli_variables(variables)?;
+        let mut results = Vec::new();
+
+        for mut rq_file in rq_files {
+            let requests = std::mem::take(&mut rq_file.requests);
+            let scope =
+                self.create_run_scope(&rq_file, source_path, environment, &[], cli_vars.clone())?;
+
+            for req_with_vars in Self::filter_requests(requests, Some(request_name)) {
+                let (resolved_request, _, _) = self.resolve_request(&scope, req_with_vars)?;
                 let prepared_request = Self::prepare_request(resolved_request)?;
 
                 let start_time = Instant::now();
-                match self.http.execute(&prepared_request).await {
-                    Ok(response) => {
-                        let elapsed = start_time.elapsed();
+                let response = self.http.ping(&prepared_request).await?;
+                results.push(PingResult {
+                    request_name: prepared_request.name.clone(),
+                    url: prepared_request.url.clone(),
+                    status: response.status,
+                    protocol: response.protocol,
+                    certificate_expires: response.certificate_expires,
+                    elapsed_ms: start_time.elapsed().as_millis() as u64,
+                });
+            }
+        }
 
-                        let mut request_headers = HashMap::new();
-                        for (key, value) in &prepared_request.headers {
-                            request_headers.insert(key.clone(), value.clone());
-                        }
+        if results.is_empty() {
+            return Err(RqError::RequestNotFound(format!(
+                "Request '{request_name}' not found"
+            )));
+        }
 
-                        results.push(RequestExecutionResult {
-                            request_name: prepared_request.name.clone(),
-                            method: prepared_request.method.as_str().to_string(),
-                            url: prepared_request.url.clone(),
-                            status: response.status,
-                            elapsed_ms: elapsed.as_millis() as u64,
-                            request_headers,
-                            response_headers: response.headers.clone(),
-                            body: response.body.clone(),
-                        });
-                    }
-                    Err(error) => {
-                        return Err(error);
-                    }
-                }
-            }
+        Ok(results)
+    }
 
-            all_results.extend(results);
+    pub fn resolve_file(
+        &self,
+        source_path: &Path,
+        request_name: Option<&str>,
+        environment: Option<&str>,
+        variables: &[String],
+        options: &RunOptions,
+    ) -> Result<String, RqError> {
+        let (rq_files, _) = self.get_rq_files_to_process(source_path, request_name)?;
+        let mut cli_vars = self.load_variable_files(&options.variable_files)?;
+        cli_vars.extend(Self::parse_cli_variables(variables)?);
+        let mut documents = Vec::new();
+
+        for mut rq_file in rq_files {
+            let requests = std::mem::take(&mut rq_file.requests);
+            let scope = self.create_run_scope(
+                &rq_file,
+                source_path,
+                environment,
+                &options.profiles,
+                cli_vars.clone(),
+            )?;
+            let mut resolved = Vec::new();
+            for req_with_vars in Self::filter_requests(requests, request_name) {
+                let (mut request, _, _) = self.resolve_request(&scope, req_with_vars)?;
+                resolve_conditional_headers(&mut request);
+                resolved.push(request);
+            }
+            if !resolved.is_empty() {
+                documents.push(resolved_file::render(&rq_file.path, &resolved));
+            }
         }
 
-        Ok((all_results, parse_warnings))
+        if documents.is_empty() {
+            return Err(RqError::RequestNotFound(
+                request_name.map_or_else(|| source_path.display().to_string(), str::to_string),
+            ));
+        }
+        Ok(documents.join("\n"))
     }
 
     pub fn list_requests(
@@ -271,6 +468,9 @@ impl RqClient {
                 requests.push(RequestInfo {
                     name: req_with_vars.request.name.clone(),
                     endpoint: req_with_vars.request.endpoint.clone(),
+                    suite: req_with_vars.request.suite.clone(),
+                    description: req_with_vars.request.description.clone(),
+                    skipped: req_with_vars.request.skip,
                     file: crate::paths::clean_path(&rq_file.path),
                     endpoint_file,
                     endpoint_line,
@@ -284,6 +484,35 @@ impl RqClient {
         Ok((requests, parse_errors))
     }
 
+    pub fn default_request(&self, source_path: &Path) -> Result<Option<String>, RqError> {
+        let rq_files = if self.fs.is_file(source_path) {
+            vec![self.load_rq_file(source_path)?]
+        } else {
+            let mut rq_files = Vec::new();
+            self.collect_rq_files_parsed(source_path, &mut rq_files)?;
+            rq_files
+        };
+        let defaults: BTreeMap<&str, String> = rq_files
+            .iter()
+            .filter_map(|f| {
+                let name = f.default_request.as_deref()?;
+                Some((name, crate::paths::clean_path(&f.path)))
+            })
+            .collect();
+        match defaults.len() {
+            0 => Ok(None),
+            1 => Ok(defaults.into_keys().next().map(str::to_string)),
+            _ => Err(RqError::Validation(format!(
+                "Conflicting default requests: {}",
+                defaults
+                    .iter()
+                    .map(|(name, file)| format!("'{name}' in {file}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
     pub fn get_request_details(
         &self,
         source_path: &Path,
@@ -396,8 +625,9 @@ impl RqClient {
             .cli_variables(cli_vars)
             .build();
 
+        resolve_conditional_headers(&mut working);
         if !interpolate_variables {
-            let (auth_name, auth_type) = if let Some(auth_name) = working.auth.as_deref() {
+            let (auth_name, auth_type) = if let Some(auth_name) = working.auth.first() {
                 if auth_name.trim().is_empty() {
                     (None, None)
                 } else if let Some(auth_provider) = loaded_auth_providers.get(auth_name) {
@@ -420,6 +650,7 @@ impl RqClient {
                 method: working.method.as_str().to_string(),
                 body: working.body,
                 timeout: working.timeout,
+                description: working.description,
                 required_variables: working.required_variables,
                 file: request_file,
                 line: request_line,
@@ -434,7 +665,11 @@ impl RqClient {
             source_path,
         );
 
-        if let Some(header_var) = working.headers_var.clone() {
+        let header_vars = [
+            working.headers_var.clone(),
+            working.suite_headers_var.clone(),
+        ];
+        for header_var in header_vars.into_iter().flatten() {
             let headers = std::mem::take(&mut working.headers);
             working.headers =
                 Self::apply_headers_var(&header_var, headers, &context).map_err(|e| {
@@ -444,11 +679,12 @@ impl RqClient {
                 })?;
         }
 
-        let resolved =
+        let mut resolved =
             crate::syntax::resolve::resolve_variables(working, &context, &search_paths, &*self.fs)
                 .map_err(|e| RqError::Generic(e.to_string()))?;
+        resolve_conditional_headers(&mut resolved);
 
-        let (auth_name, auth_type) = if let Some(auth_name) = resolved.auth.as_deref() {
+        let (auth_name, auth_type) = if let Some(auth_name) = resolved.auth.first() {
             if auth_name.trim().is_empty() {
                 (None, None)
             } else if let Some(auth_provider) = loaded_auth_providers.get(auth_name) {
@@ -472,6 +708,7 @@ impl RqClient {
             method: resolved.method.as_str().to_string(),
             body: resolved.body,
             timeout: resolved.timeout,
+            description: resolved.description,
             required_variables: resolved.required_variables,
             file: request_file,
             line: request_line,
@@ -512,6 +749,83 @@ impl RqClient {
         Ok(auth_list)
     }
 
+    pub fn validate_auth(
+        &self,
+        source_path: &Path,
+    ) -> Result<(Vec<crate::client::models::AuthListEntry>, Vec<SyntaxError>), RqError> {
+        if !self.fs.exists(source_path) {
+            return Err(RqError::DirectoryNotFound(
+                source_path.display().to_string(),
+            ));
+        }
+        let mut paths = Vec::new();
+        if self.fs.is_file(source_path) {
+            paths.push(source_path.to_path_buf());
+        } else {
+            self.collect_rq_paths(source_path, &mut paths)?;
+        }
+
+        let mut valid = Vec::new();
+        let mut errors = Vec::new();
+        for path in paths {
+            let (providers, parse_errors) = self.parse_auth_blocks(&path)?;
+            errors.extend(parse_errors);
+            for mut provider in providers.into_values() {
+                provider.apply_defaults(self.auth_context);
+                match provider.validate() {
+                    Ok(()) => valid.push(crate::client::models::AuthListEntry {
+                        name: provider.name,
+                        auth_type: provider.auth_type.as_str().to_string(),
+                    }),
+                    Err(mut e) => {
+                        if e.line == 0 {
+                            e.line = provider.line + 1;
+                            e.column = provider.character + 1;
+                        }
+                        e.file_path = Some(provider.file_path.display().to_string());
+                        errors.push(e);
+                    }
+                }
+            }
+        }
+        valid.sort();
+        errors.sort_by(|a, b| (&a.file_path, a.line).cmp(&(&b.file_path, b.line)));
+        Ok((valid, errors))
+    }
+
+    fn parse_auth_blocks(
+        &self,
+        path: &Path,
+    ) -> Result<
+        (
+            HashMap<String, crate::syntax::auth::Config>,
+            Vec<SyntaxError>,
+        ),
+        RqError,
+    > {
+        let canonical = self.fs.canonicalize(path).map_err(RqError::Generic)?;
+        let content = self.fs.read(&canonical).map_err(RqError::Generic)?;
+        let tokens = match crate::syntax::tokenize(&content) {
+            Ok(tokens) => tokens,
+            Err(mut e) => {
+                e.file_path = Some(canonical.display().to_string());
+                return Ok((HashMap::new(), vec![e]));
+            }
+        };
+        let (parse_result, errors) = crate::syntax::analysis::analyze_auth_blocks(
+            &tokens,
+            canonical.clone(),
+            &content,
+            &*self.fs,
+        );
+        let providers = parse_result
+            .auth_providers
+            .into_iter()
+            .filter(|(_, provider)| provider.file_path == canonical)
+            .collect();
+        Ok((providers, errors))
+    }
+
     pub fn get_auth_details(
         &self,
         source_path: &Path,
@@ -539,7 +853,12 @@ impl RqClient {
         let auth_line = auth_provider.line;
         let auth_character = auth_provider.character;
 
-        auth_provider.apply_defaults();
+        let mut defaulted: Vec<String> = auth_provider
+            .apply_defaults(self.auth_context)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        defaulted.sort();
 
         if !interpolate_variables {
             let auth_type_str = auth_provider.auth_type.as_str().to_string();
@@ -552,6 +871,7 @@ impl RqClient {
                 auth_name.to_string(),
                 auth_type_str,
                 fields,
+                defaulted,
                 auth_file,
                 auth_line,
                 auth_character,
@@ -596,6 +916,9 @@ impl RqClient {
             crate::syntax::auth::AuthType::OAuth2AuthorizationCode => "oauth2_authorization_code",
             crate::syntax::auth::AuthType::OAuth2ClientCredentials => "oauth2_client_credentials",
             crate::syntax::auth::AuthType::OAuth2Implicit => "oauth2_implicit",
+            crate::syntax::auth::AuthType::OAuth2RefreshToken => "oauth2_refresh_token",
+            crate::syntax::auth::AuthType::ApiKey => "api_key",
+            crate::syntax::auth::AuthType::Digest => "digest",
         };
 
         Ok((
@@ -606,6 +929,7 @@ impl RqClient {
                 .iter()
                 .map(|(k, v)| (k.clone(), v.value.clone()))
                 .collect(),
+            defaulted,
             auth_file,
             auth_line,
             auth_character,
@@ -643,25 +967,10 @@ impl RqClient {
         &self,
         source_path: &Path,
     ) -> Result<Vec<crate::client::models::EnvironmentEntry>, RqError> {
-        if !self.fs.exists(source_path) {
-            return Err(RqError::DirectoryNotFound(
-                source_path.display().to_string(),
-            ));
-        }
-
         let mut seen = HashSet::new();
         let mut entries: Vec<crate::client::models::EnvironmentEntry> = Vec::new();
 
-        let mut paths = Vec::new();
-        if self.fs.is_file(source_path) {
-            paths.push(source_path.to_path_buf());
-        } else if self.fs.is_dir(source_path) {
-            self.collect_rq_paths(source_path, &mut paths)?;
-        } else {
-            return Err(RqError::NotADirectory(source_path.display().to_string()));
-        }
-
-        for path in paths {
+        for path in self.environment_source_paths(source_path)? {
             if let Ok(rq_file) = self.load_rq_file(&path) {
                 for (name, (file, line, character)) in &rq_file.environment_locations {
                     if seen.insert(name.clone()) {
@@ -680,6 +989,71 @@ impl RqClient {
         Ok(entries)
     }
 
+    pub fn list_environment_files(
+        &self,
+        source_path: &Path,
+    ) -> Result<Vec<EnvironmentFiles>, RqError> {
+        let mut files: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for path in self.environment_source_paths(source_path)? {
+            if let Ok(rq_file) = self.load_rq_file(&path) {
+                for (name, (file, _, _)) in &rq_file.environment_locations {
+                    files
+                        .entry(name.clone())
+                        .or_default()
+                        .insert(crate::paths::clean_path_str(file).to_string());
+                }
+            }
+        }
+        Ok(files
+            .into_iter()
+            .map(|(name, files)| EnvironmentFiles {
+                name,
+                files: files.into_iter().collect(),
+            })
+            .collect())
+    }
+
+    pub fn environment_collisions(
+        &self,
+        source_path: &Path,
+    ) -> Result<Vec<EnvironmentCollision>, RqError> {
+        let rq_files: Vec<RqFile> = self
+            .environment_source_paths(source_path)?
+            .iter()
+            .filter_map(|path| self.load_rq_file(path).ok())
+            .collect();
+        Ok(env_collisions::find(&rq_files))
+    }
+
+    pub fn unused_endpoint_templates(
+        &self,
+        source_path: &Path,
+    ) -> Result<Vec<UnusedEndpointTemplate>, RqError> {
+        let rq_files: Vec<RqFile> = self
+            .environment_source_paths(source_path)?
+            .iter()
+            .filter_map(|path| self.load_rq_file(path).ok())
+            .collect();
+        Ok(unused_templates::find(&rq_files))
+    }
+
+    fn environment_source_paths(&self, source_path: &Path) -> Result<Vec<PathBuf>, RqError> {
+        if !self.fs.exists(source_path) {
+            return Err(RqError::DirectoryNotFound(
+                source_path.display().to_string(),
+            ));
+        }
+        let mut paths = Vec::new();
+        if self.fs.is_file(source_path) {
+            paths.push(source_path.to_path_buf());
+        } else if self.fs.is_dir(source_path) {
+            self.collect_rq_paths(source_path, &mut paths)?;
+        } else {
+            return Err(RqError::NotADirectory(source_path.display().to_string()));
+        }
+        Ok(paths)
+    }
+
     pub fn get_environment(
         &self,
         source_path: &Path,
@@ -1056,7 +1430,11 @@ impl RqClient {
     fn load_rq_file(&self, path: &Path) -> Result<RqFile, RqError> {
         let canonical = self.fs.canonicalize(path).map_err(RqError::Generic)?;
         let content = self.fs.read(&canonical).map_err(RqError::Generic)?;
-        RqFile::from_content(canonical, &content, &*self.fs).map_err(|e| {
+        self.parse_rq_file(canonical, &content)
+    }
+
+    fn parse_rq_file(&self, path: PathBuf, content: &str) -> Result<RqFile, RqError> {
+        RqFile::from_content(path, content, &*self.fs).map_err(|e| {
             if let Some(syntax_err) = e.downcast_ref::<crate::syntax::error::SyntaxError>() {
                 RqError::Syntax(syntax_err.clone())
             } else {
@@ -1072,6 +1450,7 @@ impl RqClient {
     }
 
     fn prepare_request(mut request: Request) -> Result<Request, RqError> {
+        resolve_conditional_headers(&mut request);
         if !request
             .headers
             .iter()
@@ -1083,13 +1462,35 @@ impl RqClient {
             ));
         }
 
+        if let Some(form) = request.form.take() {
+            if !has_header(&request.headers, "content-type") {
+                request.headers.push((
+                    "content-type".to_string(),
+                    "application/x-www-form-urlencoded".to_string(),
+                ));
+            }
+            request.body = Some(encode_form(&form));
+        }
+
+        if let Some(lines) = request.body_lines.take() {
+            if !has_header(&request.headers, "content-type") {
+                request.headers.push((
+                    "content-type".to_string(),
+                    "application/x-ndjson".to_string(),
+                ));
+            }
+            request.body = Some(encode_ndjson(&lines));
+        }
+
+        if request.body_bytes.is_some() && !has_header(&request.headers, "content-type") {
+            request.headers.push((
+                "content-type".to_string(),
+                "application/octet-stream".to_string(),
+            ));
+        }
+
         if let Some(body) = &request.body {
-            if !request
-                .headers
-                .iter()
-                .any(|(k, _)| k.to_lowercase() == "content-type")
-                && is_json_body(body)
-            {
+            if !has_header(&request.headers, "content-type") && is_json_body(body) {
                 request
                     .headers
                     .push(("content-type".to_string(), "application/json".to_string()));
@@ -1097,19 +1498,471 @@ impl RqClient {
         }
 
         if let Some(timeout_str) = &request.timeout {
-            let secs = timeout_str.parse::<f64>().map_err(|_| {
-                RqError::Generic(format!(
-                    "HTTP Error: Timeout value '{timeout_str}' must be a number"
+            parse_duration(timeout_str).map_err(|e| {
+                RqError::Generic(format!("HTTP Error: Invalid timeout '{timeout_str}': {e}"))
+            })?;
+        }
+
+        Ok(request)
+    }
+
+    fn create_run_scope<'a>(
+        &self,
+        rq_file: &'a RqFile,
+        source_path: &'a Path,
+        environment: Option<&str>,
+        profiles: &[String],
+        cli_vars: Vec<Variable>,
+    ) -> Result<RunScope<'a>, RqError> {
+        let env_vars = if let Some(env_name) = environment {
+            if let Some(vars) = rq_file.environments.get(env_name) {
+                vars.clone()
+            } else {
+                return Err(RqError::EnvironmentNotFound(env_name.to_string()));
+            }
+        } else {
+            Vec::new()
+        };
+
+        let mut profile_vars = Vec::new();
+        for profile_name in profiles {
+            match rq_file.profiles.get(profile_name) {
+                Some(vars) => profile_vars.extend(vars.iter().cloned()),
+                None => return Err(RqError::ProfileNotFound(profile_name.to_string())),
+            }
+        }
+
+        Ok(RunScope {
+            rq_file,
+            source_path,
+            env_vars,
+            profile_vars,
+            secret_vars: self.collect_secrets_for_env(source_path, environment),
+            cli_vars,
+        })
+    }
+
+    fn ensure_no_environments(rq_files: &[RqFile]) -> Result<(), RqError> {
+        let names: BTreeSet<&str> = rq_files
+            .iter()
+            .flat_map(|f| f.environments.keys().map(String::as_str))
+            .collect();
+        if names.is_empty() {
+            return Ok(());
+        }
+        Err(RqError::Validation(format!(
+            "No environment selected; pass -e with one of: {}",
+            names.into_iter().collect::<Vec<_>>().join(", ")
+        )))
+    }
+
+    fn load_variable_files(&self, paths: &[PathBuf]) -> Result<Vec<Variable>, RqError> {
+        let mut variables = Vec::new();
+        for path in paths {
+            let content = self.fs.read(path).map_err(|e| {
+                RqError::Validation(format!(
+                    "Failed to read variable file '{}': {e}",
+                    path.display()
                 ))
             })?;
-            if !secs.is_finite() || secs < 0.0 {
-                return Err(RqError::Generic(format!(
-                    "HTTP Error: Timeout value '{timeout_str}' must be a non-negative finite number"
+            variables.extend(parse_variable_file(path, &content)?);
+        }
+        Ok(variables)
+    }
+
+    fn resolve_request(
+        &self,
+        scope: &RunScope,
+        req_with_vars: crate::syntax::parse_result::RequestWithVariables,
+    ) -> Result<
+        (
+            Request,
+            crate::syntax::variable_context::VariableContext,
+            Vec<PathBuf>,
+        ),
+        RqError,
+    > {
+        let context = crate::syntax::variable_context::VariableContext::builder()
+            .file_variables(scope.rq_file.file_variables.clone())
+            .environment_variables(scope.env_vars.clone())
+            .profile_variables(scope.profile_vars.clone())
+            .secret_variables(scope.secret_vars.clone())
+            .endpoint_variables(req_with_vars.endpoint_variables)
+            .request_variables(req_with_vars.request_variables)
+            .cli_variables(scope.cli_vars.clone())
+            .build();
+
+        let mut working = req_with_vars.request;
+        Self::check_required_variables(&working, &scope.cli_vars)?;
+
+        let search_paths = Self::build_search_paths(
+            &working,
+            &scope.rq_file.path,
+            &scope.rq_file.imported_files,
+            scope.source_path,
+        );
+
+        let header_vars = [
+            working.headers_var.clone(),
+            working.suite_headers_var.clone(),
+        ];
+        for header_var in header_vars.into_iter().flatten() {
+            let headers = std::mem::take(&mut working.headers);
+            working.headers =
+                Self::apply_headers_var(&header_var, headers, &context).map_err(|e| {
+                    let (line, col, path) = crate::syntax::resolve::find_variable_location(
+                        &*self.fs,
+                        &search_paths,
+                        &header_var,
+                    );
+                    RqError::Syntax(crate::syntax::error::SyntaxError::with_file(
+                        e,
+                        line,
+                        col,
+                        0..0,
+                        path.display().to_string(),
+                    ))
+                })?;
+        }
+
+        let resolved_request =
+            crate::syntax::resolve::resolve_variables(working, &context, &search_paths, &*self.fs)?;
+        Self::validate_timeout(&resolved_request, &scope.rq_file.path)?;
+
+        Ok((resolved_request, context, search_paths))
+    }
+
+    fn validate_timeout(request: &Request, rq_file_path: &Path) -> Result<(), RqError> {
+        let Some(timeout) = &request.timeout else {
+            return Ok(());
+        };
+        parse_duration(timeout).map(|_| ()).map_err(|e| {
+            let file = request
+                .source_path
+                .clone()
+                .unwrap_or_else(|| rq_file_path.display().to_string());
+            RqError::Syntax(SyntaxError::with_file(
+                format!(
+                    "Request '{}' has invalid timeout '{timeout}': {e}",
+                    request.name
+                ),
+                request.line + 1,
+                request.character + 1,
+                0..0,
+                file,
+            ))
+        })
+    }
+
+    fn check_required_variables(request: &Request, cli_vars: &[Variable]) -> Result<(), RqError> {
+        let mut seen = HashSet::new();
+        let missing: Vec<&str> = request
+            .required_variables
+            .iter()
+            .filter(|name| !cli_vars.iter().any(|v| &v.name == *name))
+            .map(String::as_str)
+            .filter(|name| seen.insert(*name))
+            .collect();
+        if !missing.is_empty() {
+            return Err(RqError::Validation(format!(
+                "Required variable(s) not set: {}",
+                missing.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    async fn apply_auth(
+        &self,
+        scope: &RunScope<'_>,
+        mut request: Request,
+        context: &crate::syntax::variable_context::VariableContext,
+        search_paths: &[PathBuf],
+        overrides: &[AuthFieldOverride],
+        provider_options: ProviderOptions,
+    ) -> Result<Request, RqError> {
+        let explain = provider_options.explain;
+        if explain && request.auth.iter().all(|a| a.trim().is_empty()) {
+            eprintln!("Request '{}': no auth configured", request.name);
+        }
+        for auth_name in request.auth.clone() {
+            if auth_name.trim().is_empty() {
+                continue;
+            }
+            let resolved_provider = self
+                .resolve_auth_config(scope, &auth_name, context, search_paths, overrides)
+                .await?;
+            let provider =
+                crate::auth::get_provider(&resolved_provider.auth_type, provider_options.clone());
+            if explain {
+                eprintln!(
+                    "Request '{}': selected auth '{auth_name}' ({})",
+                    request.name,
+                    provider.auth_type()
+                );
+            }
+            let (modified_url, modified_headers) = provider
+                .configure(
+                    &resolved_provider,
+                    context,
+                    request.url.clone(),
+                    request.headers.clone(),
+                )
+                .await
+                .map_err(|e| RqError::Auth(format!("Configuration '{auth_name}' failed: {e}")))?;
+            if explain {
+                explain_injection(&request, &auth_name, &modified_url, &modified_headers);
+            }
+            request.url = modified_url;
+            request.headers = header_merge::layer_auth(&request.headers, modified_headers);
+        }
+        Ok(request)
+    }
+
+    async fn resolve_auth_config(
+        &self,
+        scope: &RunScope<'_>,
+        auth_name: &str,
+        context: &crate::syntax::variable_context::VariableContext,
+        search_paths: &[PathBuf],
+        overrides: &[AuthFieldOverride],
+    ) -> Result<crate::syntax::auth::Config, RqError> {
+        let auth_provider = scope.rq_file.auth_providers.get(auth_name).ok_or_else(|| {
+            RqError::Validation(format!("Auth configuration '{auth_name}' not found"))
+        })?;
+        let mut resolved_provider = crate::syntax::resolve::resolve_auth_provider(
+            auth_provider.clone(),
+            context,
+            search_paths,
+            &*self.fs,
+        )?;
+        Self::override_auth_fields(&mut resolved_provider, overrides)?;
+        self.discovery
+            .apply(&mut resolved_provider)
+            .await
+            .map_err(|e| RqError::Auth(format!("Configuration '{auth_name}' failed: {e}")))?;
+        Ok(resolved_provider)
+    }
+
+    async fn answer_challenge(
+        &self,
+        auth_scope: &AuthScope<'_, '_>,
+        request: &Request,
+        result: &RequestExecutionResult,
+        options: &RunOptions,
+    ) -> Result<Option<Request>, RqError> {
+        let Some(challenge) = result
+            .response_headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("www-authenticate"))
+            .map(|(_, v)| v)
+        else {
+            return Ok(None);
+        };
+        let mut authorization = None;
+        for auth_name in request.auth.iter().filter(|a| !a.trim().is_empty()) {
+            let config = self
+                .resolve_auth_config(
+                    auth_scope.scope,
+                    auth_name,
+                    auth_scope.context,
+                    auth_scope.search_paths,
+                    &options.auth_fields,
+                )
+                .await?;
+            let provider = crate::auth::get_provider(&config.auth_type, ProviderOptions::default());
+            authorization = provider
+                .respond_to_challenge(&config, request.method.as_str(), &request.url, challenge)
+                .map_err(|e| RqError::Auth(format!("Configuration '{auth_name}' failed: {e}")))?;
+            if authorization.is_some() {
+                if options.explain_auth {
+                    eprintln!(
+                        "Request '{}': auth '{auth_name}' answered the {UNAUTHORIZED} challenge with header 'authorization'",
+                        request.name
+                    );
+                }
+                break;
+            }
+        }
+        Ok(authorization.map(|value| {
+            let mut request = request.clone();
+            request
+                .headers
+                .retain(|(k, _)| !k.eq_ignore_ascii_case("authorization"));
+            request.headers.push(("authorization".to_string(), value));
+            request
+        }))
+    }
+
+    fn override_auth_fields(
+        provider: &mut crate::syntax::auth::Config,
+        overrides: &[AuthFieldOverride],
+    ) -> Result<(), RqError> {
+        let overrides: Vec<&AuthFieldOverride> = overrides
+            .iter()
+            .filter(|o| o.applies_to(&provider.name))
+            .collect();
+        if overrides.is_empty() {
+            return Ok(());
+        }
+        let allowed: Vec<&str> = provider
+            .auth_type
+            .required_fields()
+            .into_iter()
+            .chain(provider.auth_type.optional_fields())
+            .collect();
+        for o in overrides {
+            if !allowed.contains(&o.field.as_str()) {
+                return Err(RqError::Validation(format!(
+                    "Cannot override field '{}' of {} auth '{}'. Expected fields: {}",
+                    o.field,
+                    provider.auth_type.as_str(),
+                    provider.name,
+                    allowed.join(", ")
                 )));
             }
+            provider.fields.insert(
+                o.field.clone(),
+                crate::syntax::token::Token {
+                    token_type: crate::syntax::token::TokenType::String,
+                    value: o.value.clone(),
+                    span: 0..0,
+                },
+            );
         }
+        provider
+            .validate()
+            .map_err(|e| RqError::Validation(e.message))
+    }
 
-        Ok(request)
+    fn ensure_absolute_url(request: &Request) -> Result<(), RqError> {
+        let is_absolute = request.url.split_once("://").is_some_and(|(scheme, rest)| {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                && !rest.is_empty()
+                && !rest.starts_with(['/', '?', '#'])
+        });
+        if is_absolute {
+            return Ok(());
+        }
+        Err(RqError::Validation(format!(
+            "Request '{}' has no absolute URL ('{}'); did you forget an endpoint base or a full URL?",
+            request.name, request.url
+        )))
+    }
+
+    fn confirm_mutation(request: &Request, options: &RunOptions) -> Result<(), RqError> {
+        match &options.confirm_mutation {
+            Some(confirm) if request.method.is_mutating() => {
+                confirm(request.method.as_str(), &request.url).map_err(|reason| {
+                    RqError::Validation(format!(
+                        "Request '{}' was not sent: {reason}",
+                        request.name
+                    ))
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn insecure_auth_warning(request: &Request, options: &RunOptions) -> Option<String> {
+        if options.allow_insecure_auth {
+            return None;
+        }
+        insecure_auth::warning(request)
+    }
+
+    fn inject_idempotency_key(request: &mut Request) {
+        let Some(header) = request.idempotency_header.clone() else {
+            return;
+        };
+        if !has_header(&request.headers, &header) {
+            request.headers.push((header, Uuid::new_v4().to_string()));
+        }
+    }
+
+    async fn execute_with_retry(
+        &self,
+        request: &Request,
+    ) -> Result<RequestExecutionResult, RqError> {
+        let mut outcome = self.execute_request(request).await;
+        let Some(retry) = &request.retry else {
+            return outcome;
+        };
+        let mut telemetry = RetryTelemetry::default();
+        for attempt in 1..=retry.count {
+            let reason = match &outcome {
+                Ok(result) if retry.should_retry(result.status) => {
+                    telemetry.rate_limited |= result.status == TOO_MANY_REQUESTS;
+                    format!("returned {}", result.status)
+                }
+                Err(RqError::Network(message)) => format!("failed ({message})"),
+                _ => break,
+            };
+            let delay = retry.delay(attempt);
+            Logger::debug(&format!(
+                "Request '{}' {reason}; retrying in {}ms ({attempt}/{})",
+                request.name,
+                delay.as_millis(),
+                retry.count
+            ));
+            self.http.sleep(delay).await;
+            telemetry.attempts = attempt;
+            telemetry.wait_ms += delay.as_millis() as u64;
+            outcome = self.execute_request(request).await;
+        }
+        let mut result = outcome?;
+        if telemetry.attempts > 0 {
+            result.retries = Some(telemetry);
+        }
+        Ok(result)
+    }
+
+    async fn execute_request(&self, request: &Request) -> Result<RequestExecutionResult, RqError> {
+        let start_time = Instant::now();
+        let response = self.http.execute(request).await?;
+        Ok(Self::execution_result(request, response, start_time))
+    }
+
+    async fn execute_websocket(
+        &self,
+        request: &Request,
+        max_frames: Option<usize>,
+    ) -> Result<RequestExecutionResult, RqError> {
+        let start_time = Instant::now();
+        let response = self.http.websocket(request, max_frames).await?;
+        Ok(Self::execution_result(request, response, start_time))
+    }
+
+    fn execution_result(
+        request: &Request,
+        response: HttpResponse,
+        start_time: Instant,
+    ) -> RequestExecutionResult {
+        let elapsed = start_time.elapsed();
+
+        let mut request_headers = HashMap::new();
+        for (key, value) in &request.headers {
+            request_headers.insert(key.clone(), value.clone());
+        }
+
+        RequestExecutionResult {
+            request_name: request.name.clone(),
+            method: request.method.as_str().to_string(),
+            url: request.url.clone(),
+            status: response.status,
+            elapsed_ms: elapsed.as_millis() as u64,
+            request_headers,
+            response_headers: response.headers,
+            body: response.body,
+            redirects: response.redirects,
+            warnings: response.warnings,
+            output: request.output,
+            assertion_failures: Vec::new(),
+            retries: None,
+        }
     }
 
     fn collect_secrets_for_env(&self, source_path: &Path, env: Option<&str>) -> Vec<Variable> {
@@ -1150,6 +2003,14 @@ impl RqClient {
         source_path: &Path,
         request_name: Option<&str>,
     ) -> Result<(Vec<RqFile>, Vec<RqError>), RqError> {
+        if source_path == Path::new(STDIN_SOURCE) {
+            let content = self.stdin_document.as_deref().ok_or_else(|| {
+                RqError::Validation("Reading requests from stdin is not supported here".into())
+            })?;
+            let rq_file = self.parse_rq_file(PathBuf::from(STDIN_PATH), content)?;
+            return Ok((vec![rq_file], Vec::new()));
+        }
+
         if self.fs.is_file(source_path) {
             let rq_file = self.load_rq_file(source_path)?;
             return Ok((vec![rq_file], Vec::new()));
@@ -1163,12 +2024,12 @@ impl RqClient {
 
         if let Some(request_name) = request_name {
             match self.find_rq_file_with_request(source_path, request_name)? {
-                Some(rq_file) => Ok((vec![rq_file], Vec::new())),
-                None => Err(RqError::RequestNotFound(format!(
-                    "Request '{}' not found in directory: {}",
+                (Some(rq_file), parse_errors) => Ok((vec![rq_file], parse_errors)),
+                (None, parse_errors) => Err(request_not_found_in_dir(
                     request_name,
-                    source_path.display()
-                ))),
+                    source_path,
+                    &parse_errors,
+                )),
             }
         } else {
             let mut rq_files = Vec::new();
@@ -1178,16 +2039,38 @@ impl RqClient {
     }
 
     fn collect_rq_paths(&self, dir: &Path, paths: &mut Vec<PathBuf>) -> Result<(), RqError> {
+        self.collect_rq_paths_with_ignores(dir, paths, &mut Vec::new())
+    }
+
+    fn collect_rq_paths_with_ignores(
+        &self,
+        dir: &Path,
+        paths: &mut Vec<PathBuf>,
+        ignores: &mut Vec<RqIgnore>,
+    ) -> Result<(), RqError> {
         if !self.fs.is_dir(dir) {
             return Ok(());
         }
+        let ignore_path = dir.join(RQIGNORE_FILE);
+        let has_ignore_file = self.fs.is_file(&ignore_path);
+        if has_ignore_file {
+            let content = self.fs.read(&ignore_path).map_err(RqError::Generic)?;
+            ignores.push(RqIgnore::parse(dir, &content));
+        }
         for path in self.fs.read_dir(dir).map_err(RqError::Generic)? {
-            if self.fs.is_dir(&path) {
-                self.collect_rq_paths(&path, paths)?;
+            let is_dir = self.fs.is_dir(&path);
+            if is_ignored(ignores, &path, is_dir) {
+                continue;
+            }
+            if is_dir {
+                self.collect_rq_paths_with_ignores(&path, paths, ignores)?;
             } else if path.extension().and_then(|s| s.to_str()) == Some("rq") {
                 paths.push(path);
             }
         }
+        if has_ignore_file {
+            ignores.pop();
+        }
         Ok(())
     }
 
@@ -1195,25 +2078,27 @@ impl RqClient {
         &self,
         dir: &Path,
         request_name: &str,
-    ) -> Result<Option<RqFile>, RqError> {
+    ) -> Result<(Option<RqFile>, Vec<RqError>), RqError> {
         let mut paths = Vec::new();
         self.collect_rq_paths(dir, &mut paths)?;
+        paths.sort();
+        let mut parse_errors = Vec::new();
         for path in paths {
             match self.load_rq_file(&path) {
                 Ok(rq_file) => {
                     if rq_file
                         .requests
                         .iter()
-                        .any(|r| r.request.name == request_name)
+                        .any(|r| matches_request_name(&r.request, request_name))
                     {
-                        return Ok(Some(rq_file));
+                        return Ok((Some(rq_file), parse_errors));
                     }
                 }
-                Err(RqError::Syntax(e)) => return Err(RqError::Syntax(e)),
+                Err(RqError::Syntax(e)) => parse_errors.push(RqError::Syntax(e)),
                 Err(_) => {}
             }
         }
-        Ok(None)
+        Ok((None, parse_errors))
     }
 
     fn check_variables(
@@ -1262,6 +2147,7 @@ impl RqClient {
 
         let mut endpoint_reported: std::collections::HashSet<(Option<String>, String)> =
             std::collections::HashSet::new();
+        let mut captured_names: Vec<String> = Vec::new();
 
         for req_with_vars in &rq_file.requests {
             let scoped_vars: Vec<_> = req_with_vars
@@ -1295,6 +2181,7 @@ impl RqClient {
                     .request
                     .required_variables
                     .iter()
+                    .chain(&captured_names)
                     .map(|name| crate::syntax::variable_context::Variable {
                         name: name.clone(),
                         value: crate::syntax::variable_context::VariableValue::String(
@@ -1336,6 +2223,13 @@ impl RqClient {
                 }
                 errors.push(RqError::Syntax(e));
             }
+            captured_names.extend(
+                req_with_vars
+                    .request
+                    .captures
+                    .iter()
+                    .map(|c| c.variable.clone()),
+            );
         }
 
         let base_context = crate::syntax::variable_context::VariableContext::builder()
@@ -1458,18 +2352,8 @@ impl RqClient {
         existing_headers: Vec<(String, String)>,
         ctx: &crate::syntax::variable_context::VariableContext,
     ) -> Result<Vec<(String, String)>, String> {
-        let mut merged = Self::expand_headers_var(header_var, ctx)?;
-        for (ck, cv) in existing_headers {
-            if let Some(i) = merged
-                .iter()
-                .position(|(ek, _)| ek.eq_ignore_ascii_case(&ck))
-            {
-                merged[i] = (ck, cv);
-            } else {
-                merged.push((ck, cv));
-            }
-        }
-        Ok(merged)
+        let var_headers = Self::expand_headers_var(header_var, ctx)?;
+        Ok(header_merge::merge(var_headers, existing_headers))
     }
 
     fn expand_headers_var(
@@ -1492,6 +2376,7 @@ impl RqClient {
         let mut all: Vec<&crate::syntax::variable_context::Variable> = Vec::new();
         all.extend(&ctx.file_variables);
         all.extend(&ctx.environment_variables);
+        all.extend(&ctx.profile_variables);
         all.extend(&ctx.secret_variables);
         all.extend(&ctx.endpoint_variables);
         all.extend(&ctx.request_variables);
@@ -1504,16 +2389,16 @@ impl RqClient {
                     ));
                 }
                 VariableValue::Array(arr) => {
-                    let mut out = Vec::new();
-                    for item in arr {
-                        if let Some((k, v)) = item.split_once(':') {
-                            out.push((
-                                k.trim().trim_matches('"').to_string(),
-                                v.trim().trim_matches('"').to_string(),
-                            ));
-                        }
-                    }
-                    return Ok(out);
+                    return arr
+                        .iter()
+                        .map(|item| {
+                            split_header_entry(item).ok_or_else(|| {
+                                format!(
+                                    "Invalid header entry '{item}' in variable '{name}': expected 'Name: value'"
+                                )
+                            })
+                        })
+                        .collect();
                 }
                 VariableValue::Headers(h) => {
                     return Ok(h.clone());
@@ -1533,27 +2418,20 @@ impl RqClient {
     }
 
     fn parse_cli_variables(variables: &[String]) -> Result<Vec<Variable>, RqError> {
-        let cli_variables: Vec<Variable> = variables
-            .iter()
-            .filter_map(|kv| {
-                if let Some(eq) = kv.find('=') {
-                    let name = kv[..eq].trim();
-                    let value = kv[eq + 1..].to_string();
-                    if name.is_empty() {
-                        eprintln!("Ignoring CLI variable with empty name: {kv}");
-                        None
-                    } else {
-                        Some(Variable {
-                            name: name.to_string(),
-                            value: VariableValue::String(value),
-                        })
-                    }
-                } else {
-                    eprintln!("Ignoring CLI variable without '=': {kv}");
-                    None
-                }
-            })
-            .collect();
+        let mut cli_variables = Vec::new();
+        for kv in variables {
+            let Some((spec, _)) = kv.split_once('=') else {
+                eprintln!("Ignoring CLI variable without '=': {kv}");
+                continue;
+            };
+            if spec.split(':').next().unwrap_or_default().trim().is_empty() {
+                eprintln!("Ignoring CLI variable with empty name: {kv}");
+                continue;
+            }
+            let variable = cli_variables::parse_cli_variable(kv)
+                .map_err(|message| RqError::Syntax(SyntaxError::new(message, 0, 0, 0..0)))?;
+            cli_variables.push(variable);
+        }
 
         Ok(cli_variables)
     }
@@ -1565,7 +2443,7 @@ impl RqClient {
         if let Some(request_name) = request_name {
             requests
                 .into_iter()
-                .filter(|r| r.request.name == request_name)
+                .filter(|r| matches_request_name(&r.request, request_name))
                 .collect()
         } else {
             requests
@@ -1638,17 +2516,97 @@ impl Default for RqClient {
         Self::new(
             Arc::new(native::NativeFs),
             Arc::new(native::NativeSecretProvider),
-            Arc::new(native::ReqwestHttpClient),
+            Arc::new(native::ReqwestHttpClient::new()),
         )
+        .with_auth_context(AuthContext::Cli)
     }
 }
 
+fn explain_injection(request: &Request, auth_name: &str, url: &str, headers: &[(String, String)]) {
+    let injected: Vec<String> = headers
+        .iter()
+        .filter(|header| !request.headers.contains(header))
+        .map(|(name, _)| format!("'{name}'"))
+        .collect();
+    let mut outcome = if injected.is_empty() {
+        "injected no header".to_string()
+    } else {
+        format!("injected header {}", injected.join(", "))
+    };
+    if url != request.url {
+        outcome.push_str(" and rewrote the URL");
+    }
+    eprintln!("Request '{}': auth '{auth_name}' {outcome}", request.name);
+}
+
+fn resolve_conditional_headers(request: &mut Request) {
+    let has_body = request.body.is_some()
+        || request.form.is_some()
+        || request.multipart.is_some()
+        || request.body_lines.is_some()
+        || request.body_bytes.is_some();
+    request.headers = apply_header_conditions(std::mem::take(&mut request.headers), has_body);
+}
+
+fn request_not_found_in_dir(request_name: &str, dir: &Path, parse_errors: &[RqError]) -> RqError {
+    let mut message = format!(
+        "Request '{request_name}' not found in directory: {}",
+        dir.display()
+    );
+    if !parse_errors.is_empty() {
+        message.push_str(&format!(
+            "\nSkipped {} file(s) that failed to parse:",
+            parse_errors.len()
+        ));
+        for error in parse_errors {
+            message.push_str(&format!("\n  {error}"));
+        }
+    }
+    RqError::RequestNotFound(message)
+}
+
+fn unsent_result(request: &Request) -> RequestExecutionResult {
+    RequestExecutionResult {
+        request_name: request.name.clone(),
+        method: request.method.as_str().to_string(),
+        url: request.url.clone(),
+        status: 0,
+        elapsed_ms: 0,
+        request_headers: request.headers.iter().cloned().collect(),
+        response_headers: HashMap::new(),
+        body: String::new(),
+        redirects: Vec::new(),
+        warnings: Vec::new(),
+        output: request.output,
+        assertion_failures: Vec::new(),
+        retries: None,
+    }
+}
+
+fn has_header(headers: &[(String, String)], name: &str) -> bool {
+    headers.iter().any(|(k, _)| k.eq_ignore_ascii_case(name))
+}
+
+fn encode_form(fields: &[(String, String)]) -> String {
+    form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(fields)
+        .finish()
+}
+
+fn encode_ndjson(lines: &[String]) -> String {
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
 fn is_json_body(body: &str) -> bool {
     let trimmed = body.trim();
     (trimmed.starts_with('{') && trimmed.ends_with('}'))
         || (trimmed.starts_with('[') && trimmed.ends_with(']'))
 }
 
+fn matches_request_name(request: &Request, name: &str) -> bool {
+    request.name == name || request.suite.as_deref() == Some(name)
+}
+
 fn extract_unresolved_var_name(message: &str) -> Option<String> {
     for prefix in &["Unresolved variable: '", "Variable '"] {
         if let Some(start) = message.find(prefix).map(|i| i + prefix.len()) {
@@ -1,5 +1,9 @@
+use crate::http::RedirectHop;
+use crate::syntax::parse_result::OutputFormat;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Debug, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AuthListEntry {
@@ -16,6 +20,7 @@ pub struct RequestDetails {
     pub method: String,
     pub body: Option<String>,
     pub timeout: Option<String>,
+    pub description: Option<String>,
     pub required_variables: Vec<String>,
     pub file: String,
     pub line: usize,
@@ -26,6 +31,11 @@ pub struct RequestDetails {
 pub struct RequestInfo {
     pub name: String,
     pub endpoint: Option<String>,
+    pub suite: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub skipped: bool,
     pub file: String,
     pub endpoint_file: Option<String>,
     pub endpoint_line: Option<usize>,
@@ -40,6 +50,31 @@ pub struct EnvironmentEntry {
     pub character: usize,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvironmentFiles {
+    pub name: String,
+    pub files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvironmentCollision {
+    pub name: String,
+    pub message: String,
+    pub file: String,
+    pub line: usize,
+    pub character: usize,
+    pub files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnusedEndpointTemplate {
+    pub name: String,
+    pub message: String,
+    pub file: String,
+    pub line: usize,
+    pub character: usize,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct EndpointEntry {
     pub name: String,
@@ -69,6 +104,181 @@ pub struct RequestExecutionResult {
     pub request_headers: HashMap<String, String>,
     pub response_headers: HashMap<String, String>,
     pub body: String,
+    #[serde(skip)]
+    pub redirects: Vec<RedirectHop>,
+    #[serde(skip)]
+    pub warnings: Vec<String>,
+    #[serde(skip)]
+    pub output: Option<OutputFormat>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assertion_failures: Vec<AssertionFailure>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<RetryTelemetry>,
+}
+
+impl RequestExecutionResult {
+    pub fn succeeded(&self) -> bool {
+        self.status < 400 && self.assertion_failures.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct RetryTelemetry {
+    pub attempts: u32,
+    pub wait_ms: u64,
+    pub rate_limited: bool,
+}
+
+impl std::fmt::Display for RetryTelemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} retry attempt(s), {} ms before the final attempt",
+            self.attempts, self.wait_ms
+        )?;
+        if self.rate_limited {
+            write!(f, ", rate limited (429)")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct AssertionFailure {
+    pub assertion: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl std::fmt::Display for AssertionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (expected {}, got {})",
+            self.assertion, self.expected, self.actual
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct RunSummary {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub elapsed_ms: u64,
+    pub status_codes: BTreeMap<u16, usize>,
+}
+
+impl RunSummary {
+    pub fn from_results(results: &[RequestExecutionResult]) -> Self {
+        let failed = results.iter().filter(|r| r.status >= 400).count();
+        let mut status_codes = BTreeMap::new();
+        for result in results {
+            *status_codes.entry(result.status).or_insert(0) += 1;
+        }
+        Self {
+            total: results.len(),
+            succeeded: results.len() - failed,
+            failed,
+            elapsed_ms: results.iter().map(|r| r.elapsed_ms).sum(),
+            status_codes,
+        }
+    }
+}
+
+impl std::fmt::Display for RunSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let noun = if self.total == 1 {
+            "request"
+        } else {
+            "requests"
+        };
+        write!(
+            f,
+            "Summary: {} {noun}, {} succeeded, {} failed in {} ms",
+            self.total, self.succeeded, self.failed, self.elapsed_ms
+        )?;
+        if !self.status_codes.is_empty() {
+            let histogram = self
+                .status_codes
+                .iter()
+                .map(|(status, count)| format!("{status}: {count}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, " ({histogram})")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthFieldOverride {
+    pub auth: Option<String>,
+    pub field: String,
+    pub value: String,
+}
+
+impl AuthFieldOverride {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let (target, value) = input
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid auth field '{input}': expected [AUTH.]FIELD=VALUE"))?;
+        let (auth, field) = match target.split_once('.') {
+            Some((auth, field)) => (Some(auth.trim().to_string()), field.trim()),
+            None => (None, target.trim()),
+        };
+        if field.is_empty() || auth.as_deref().is_some_and(str::is_empty) {
+            return Err(format!(
+                "Invalid auth field '{input}': expected [AUTH.]FIELD=VALUE"
+            ));
+        }
+        Ok(Self {
+            auth,
+            field: field.to_string(),
+            value: value.to_string(),
+        })
+    }
+
+    pub fn applies_to(&self, auth_name: &str) -> bool {
+        self.auth.as_deref().is_none_or(|auth| auth == auth_name)
+    }
+}
+
+pub type ConfirmMutation = dyn Fn(&str, &str) -> Result<(), String> + Send + Sync;
+pub type SkipRequest = dyn Fn(&Path, &str) -> bool + Send + Sync;
+pub type RequestCompleted = dyn Fn(&Path, &RequestExecutionResult) + Send + Sync;
+
+#[derive(Clone, Default)]
+pub struct RunOptions {
+    pub max_redirects: Option<usize>,
+    pub timeout: Option<String>,
+    pub profiles: Vec<String>,
+    pub variable_files: Vec<PathBuf>,
+    pub confirm_mutation: Option<Arc<ConfirmMutation>>,
+    pub allow_insecure_auth: bool,
+    pub skip_request: Option<Arc<SkipRequest>>,
+    pub on_request_completed: Option<Arc<RequestCompleted>>,
+    pub skip_auth: bool,
+    pub only_auth: bool,
+    pub auth_fields: Vec<AuthFieldOverride>,
+    pub ws_max_frames: Option<usize>,
+    pub reauth: bool,
+    pub require_environment: bool,
+    pub token_cache: Option<PathBuf>,
+    pub dump_tokens_redacted: bool,
+    pub interactive: bool,
+    pub explain_auth: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PingResult {
+    pub request_name: String,
+    pub url: String,
+    pub status: u16,
+    pub protocol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_expires: Option<String>,
+    pub elapsed_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -0,0 +1,211 @@
+use lazy_static::lazy_static;
+use serde_json::Value;
+
+use crate::client::assertions::actual_value;
+use crate::client::models::RequestExecutionResult;
+use crate::error::RqError;
+use crate::syntax::assertion::parse_target;
+use crate::syntax::parse_result::MultipartValue;
+use crate::syntax::Request;
+
+lazy_static! {
+    static ref PREV_PATTERN: regex::Regex =
+        regex::Regex::new(r"\{\{\s*prev\.([^\s{}]+)\s*\}\}").unwrap();
+}
+
+pub fn substitute(
+    request: &mut Request,
+    previous: Option<&RequestExecutionResult>,
+) -> Result<(), RqError> {
+    let name = request.name.clone();
+    let apply = |text: &mut String| -> Result<(), RqError> {
+        if PREV_PATTERN.is_match(text) {
+            *text = replace(text, &name, previous)?;
+        }
+        Ok(())
+    };
+    apply(&mut request.url)?;
+    for (key, value) in &mut request.headers {
+        apply(key)?;
+        apply(value)?;
+    }
+    if let Some(body) = &mut request.body {
+        apply(body)?;
+    }
+    for (key, value) in request.form.iter_mut().flatten() {
+        apply(key)?;
+        apply(value)?;
+    }
+    for part in request.multipart.iter_mut().flatten() {
+        apply(&mut part.name)?;
+        if let MultipartValue::Text(text) = &mut part.value {
+            apply(text)?;
+        }
+    }
+    for line in request.body_lines.iter_mut().flatten() {
+        apply(line)?;
+    }
+    Ok(())
+}
+
+fn replace(
+    text: &str,
+    request_name: &str,
+    previous: Option<&RequestExecutionResult>,
+) -> Result<String, RqError> {
+    let mut output = String::with_capacity(text.len());
+    let mut last = 0;
+    for caps in PREV_PATTERN.captures_iter(text) {
+        let (Some(whole), Some(target)) = (caps.get(0), caps.get(1)) else {
+            continue;
+        };
+        output.push_str(&text[last..whole.start()]);
+        output.push_str(&lookup(target.as_str(), request_name, previous)?);
+        last = whole.end();
+    }
+    output.push_str(&text[last..]);
+    Ok(output)
+}
+
+fn lookup(
+    target: &str,
+    request_name: &str,
+    previous: Option<&RequestExecutionResult>,
+) -> Result<String, RqError> {
+    let parsed = parse_target(target).map_err(|e| {
+        RqError::Validation(format!(
+            "Invalid reference 'prev.{target}' in request '{request_name}': {e}"
+        ))
+    })?;
+    let Some(previous) = previous else {
+        return Err(RqError::Validation(format!(
+            "Request '{request_name}' references 'prev.{target}' but no request ran before it"
+        )));
+    };
+    match actual_value(&parsed, previous) {
+        Some(Value::String(value)) => Ok(value),
+        Some(value) => Ok(value.to_string()),
+        None => Err(RqError::Validation(format!(
+            "'prev.{target}' in request '{request_name}' does not resolve against the response of '{}'",
+            previous.request_name
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::http_method::HttpMethod;
+    use std::collections::HashMap;
+
+    fn previous() -> RequestExecutionResult {
+        RequestExecutionResult {
+            request_name: "create_user".to_string(),
+            method: "POST".to_string(),
+            url: "http://localhost/users".to_string(),
+            status: 201,
+            elapsed_ms: 12,
+            request_headers: HashMap::new(),
+            response_headers: HashMap::from([("location".to_string(), "/users/7".to_string())]),
+            body: r#"{"id": 7, "tags": ["a", "b"], "owner": {"name": "ada"}}"#.to_string(),
+            redirects: Vec::new(),
+            warnings: Vec::new(),
+            output: None,
+            assertion_failures: Vec::new(),
+            retries: None,
+        }
+    }
+
+    fn request(url: &str) -> Request {
+        Request {
+            name: "get_user".to_string(),
+            url: url.to_string(),
+            raw_url: url.to_string(),
+            method: HttpMethod::GET,
+            headers: vec![(
+                "X-Owner".to_string(),
+                "{{prev.body.$.owner.name}}".to_string(),
+            )],
+            body: Some(r#"{"tags": {{ prev.body.$.tags }}}"#.to_string()),
+            form: None,
+            multipart: None,
+            body_lines: None,
+            body_file: None,
+            text_file: None,
+            body_bytes: None,
+            headers_var: None,
+            endpoint: None,
+            suite: None,
+            suite_headers_var: None,
+            auth: Vec::new(),
+            timeout: None,
+            cert: None,
+            retry: None,
+            redirects: None,
+            idempotency_header: None,
+            output: None,
+            unix_socket: None,
+            description: None,
+            reauth: false,
+            skip: false,
+            assertions: Vec::new(),
+            captures: Vec::new(),
+            max_redirects: None,
+            required_variables: Vec::new(),
+            source_path: None,
+            related_files: Vec::new(),
+            line: 0,
+            character: 0,
+        }
+    }
+
+    #[test]
+    fn test_substitute_previous_values() {
+        let mut req = request("http://localhost/users/{{ prev.body.$.id }}?s={{prev.status}}");
+        substitute(&mut req, Some(&previous())).unwrap();
+        assert_eq!(req.url, "http://localhost/users/7?s=201");
+        assert_eq!(req.headers[0].1, "ada");
+        assert_eq!(req.body.as_deref(), Some(r#"{"tags": ["a","b"]}"#));
+
+        let mut req = request("http://localhost{{prev.header.Location}}");
+        substitute(&mut req, Some(&previous())).unwrap();
+        assert_eq!(req.url, "http://localhost/users/7");
+    }
+
+    #[test]
+    fn test_substitute_without_previous_request() {
+        let mut req = request("http://localhost/users/{{prev.body.$.id}}");
+        let err = substitute(&mut req, None).unwrap_err().to_string();
+        assert!(
+            err.contains("'get_user' references 'prev.body.$.id' but no request ran before it"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_substitute_missing_or_invalid_target() {
+        let mut req = request("http://localhost/{{prev.body.$.missing}}");
+        let err = substitute(&mut req, Some(&previous()))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("does not resolve against the response of 'create_user'"),
+            "{err}"
+        );
+
+        let mut req = request("http://localhost/{{prev.cookies}}");
+        let err = substitute(&mut req, Some(&previous()))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown assertion target 'cookies'"), "{err}");
+    }
+
+    #[test]
+    fn test_substitute_leaves_other_templates_untouched() {
+        let mut req = request("http://localhost/{{previous}}/{{ base.url }}");
+        req.headers.clear();
+        req.body = None;
+        substitute(&mut req, None).unwrap();
+        assert_eq!(req.url, "http://localhost/{{previous}}/{{ base.url }}");
+    }
+}
@@ -0,0 +1,351 @@
+use crate::syntax::http_method::HttpMethod;
+use crate::syntax::parse_result::{
+    MultipartPart, MultipartValue, OutputFormat, RedirectPolicy, Request,
+};
+use crate::syntax::parsers::attributes::DEFAULT_IDEMPOTENCY_HEADER;
+use std::path::Path;
+
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+pub fn render(path: &Path, requests: &[Request]) -> String {
+    let mut out = format!("// {}\n", crate::paths::display_path(path));
+    let mut index = 0;
+    while index < requests.len() {
+        out.push('\n');
+        let Some((group, _)) = requests[index].name.rsplit_once('/') else {
+            out.push_str(&render_request(&requests[index], &requests[index].name));
+            index += 1;
+            continue;
+        };
+        let members = requests[index..]
+            .iter()
+            .take_while(|r| r.name.rsplit_once('/').is_some_and(|(g, _)| g == group))
+            .count();
+        out.push_str(&render_group(group, &requests[index..index + members]));
+        index += members;
+    }
+    out
+}
+
+fn render_group(group: &str, requests: &[Request]) -> String {
+    let mut out = if requests[0].suite.is_some() {
+        format!("suite {group} {{\n")
+    } else {
+        format!("ep {group}(\"\") {{\n")
+    };
+    for (i, request) in requests.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let name = request.name.rsplit_once('/').map_or("", |(_, n)| n);
+        for line in render_request(request, name).lines() {
+            out.push_str(&format!("    {line}\n"));
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn render_request(request: &Request, name: &str) -> String {
+    let mut out = String::new();
+    for attribute in attributes(request) {
+        out.push_str(&format!("[{attribute}]\n"));
+    }
+
+    let mut headers = request.headers.clone();
+    if request.body_lines.is_some()
+        && !headers
+            .iter()
+            .any(|(k, _)| k.eq_ignore_ascii_case("content-type"))
+    {
+        headers.push(("content-type".to_string(), NDJSON_CONTENT_TYPE.to_string()));
+    }
+
+    let mut params = vec![quote(&request.url)];
+    if !headers.is_empty() {
+        params.push(format!("headers: {}", render_map("$", &headers)));
+    }
+    if let Some(body) = render_body(request) {
+        params.push(format!("body: {body}"));
+    }
+    out.push_str(&format!("rq {name}({});\n", params.join(", ")));
+    out
+}
+
+fn attributes(request: &Request) -> Vec<String> {
+    let mut attributes = Vec::new();
+    if let Some(description) = &request.description {
+        attributes.push(format!("desc({})", quote(description)));
+    }
+    if request.method != HttpMethod::GET {
+        attributes.push(format!("method({})", request.method.as_str()));
+    }
+    for auth in &request.auth {
+        attributes.push(format!("auth({})", quote(auth)));
+    }
+    if let Some(timeout) = &request.timeout {
+        attributes.push(format!("timeout({timeout})"));
+    }
+    if let Some(cert) = &request.cert {
+        match &cert.password {
+            Some(password) => {
+                attributes.push(format!("cert({}, {})", quote(&cert.path), quote(password)))
+            }
+            None => attributes.push(format!("cert({})", quote(&cert.path))),
+        }
+    }
+    attributes.extend(retry_attribute(request));
+    attributes.extend(redirects_attribute(request));
+    match request.idempotency_header.as_deref() {
+        Some(DEFAULT_IDEMPOTENCY_HEADER) => attributes.push("idempotency".to_string()),
+        Some(header) => attributes.push(format!("idempotency({})", quote(header))),
+        None => {}
+    }
+    if let Some(output) = request.output {
+        let format = match output {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+        };
+        attributes.push(format!("output({format})"));
+    }
+    if let Some(socket) = &request.unix_socket {
+        attributes.push(format!("unix({})", quote(socket)));
+    }
+    if request.reauth {
+        attributes.push("reauth".to_string());
+    }
+    if request.skip {
+        attributes.push("skip".to_string());
+    }
+    for assertion in &request.assertions {
+        attributes.push(format!("expect({})", assertion.expression));
+    }
+    for capture in &request.captures {
+        attributes.push(format!(
+            "capture({} = {})",
+            capture.variable, capture.expression
+        ));
+    }
+    attributes
+}
+
+fn retry_attribute(request: &Request) -> Option<String> {
+    let retry = request.retry.as_ref()?;
+    let mut arguments = vec![format!("count={}", retry.count)];
+    if let Some(statuses) = &retry.on {
+        let statuses: Vec<String> = statuses.iter().map(u16::to_string).collect();
+        arguments.push(format!("on=[{}]", statuses.join(", ")));
+    }
+    if let Some(backoff) = retry.backoff {
+        arguments.push(format!("backoff={}ms", backoff.as_millis()));
+    }
+    Some(format!("retry({})", arguments.join(", ")))
+}
+
+fn redirects_attribute(request: &Request) -> Option<String> {
+    match request.redirects.as_ref()? {
+        RedirectPolicy { follow: false, .. } => Some("redirects(follow=false)".to_string()),
+        RedirectPolicy { max: Some(max), .. } => Some(format!("redirects(max={max})")),
+        RedirectPolicy { max: None, .. } => None,
+    }
+}
+
+fn render_body(request: &Request) -> Option<String> {
+    if let Some(form) = &request.form {
+        return Some(render_map("form", form));
+    }
+    if let Some(parts) = &request.multipart {
+        return Some(render_multipart(parts));
+    }
+    if let Some(lines) = &request.body_lines {
+        let ndjson: String = lines.iter().map(|line| format!("{line}\n")).collect();
+        return Some(quote(&ndjson));
+    }
+    if let Some(path) = &request.body_file {
+        return Some(format!("io.read_bytes({})", quote(path)));
+    }
+    let body = request.body.as_deref()?;
+    let is_object = body.trim_start().starts_with('{')
+        && serde_json::from_str::<serde_json::Value>(body).is_ok();
+    Some(if is_object {
+        format!("${}", body.trim())
+    } else {
+        quote(body)
+    })
+}
+
+fn render_map(prefix: &str, entries: &[(String, String)]) -> String {
+    let entries: Vec<String> = entries
+        .iter()
+        .map(|(key, value)| format!("    {}: {}", quote(key), quote(value)))
+        .collect();
+    format!("{prefix}[\n{}\n]", entries.join(",\n"))
+}
+
+fn render_multipart(parts: &[MultipartPart]) -> String {
+    let entries: Vec<String> = parts
+        .iter()
+        .map(|part| {
+            let value = match &part.value {
+                MultipartValue::Text(text) => quote(text),
+                MultipartValue::File { path, .. } => format!("@{}", quote(path)),
+            };
+            format!("    {}: {value}", quote(&part.name))
+        })
+        .collect();
+    format!("multipart[\n{}\n]", entries.join(",\n"))
+}
+
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::assertion::Assertion;
+    use crate::syntax::parse_result::RetryPolicy;
+    use std::time::Duration;
+
+    fn request() -> Request {
+        Request {
+            name: "create_user".to_string(),
+            url: "https://api.example.com/users".to_string(),
+            raw_url: "{{base}}/users".to_string(),
+            method: HttpMethod::GET,
+            headers: Vec::new(),
+            body: None,
+            form: None,
+            multipart: None,
+            body_lines: None,
+            body_file: None,
+            text_file: None,
+            body_bytes: None,
+            headers_var: None,
+            endpoint: None,
+            suite: None,
+            suite_headers_var: None,
+            auth: Vec::new(),
+            timeout: None,
+            cert: None,
+            retry: None,
+            redirects: None,
+            idempotency_header: None,
+            output: None,
+            unix_socket: None,
+            description: None,
+            reauth: false,
+            skip: false,
+            assertions: Vec::new(),
+            captures: Vec::new(),
+            max_redirects: None,
+            required_variables: Vec::new(),
+            source_path: None,
+            related_files: Vec::new(),
+            line: 0,
+            character: 0,
+        }
+    }
+
+    #[test]
+    fn test_render_plain_get() {
+        assert_eq!(
+            render(Path::new("api.rq"), &[request()]),
+            "// api.rq\n\nrq create_user(\"https://api.example.com/users\");\n"
+        );
+    }
+
+    #[test]
+    fn test_render_attributes_headers_and_json_body() {
+        let mut request = request();
+        request.method = HttpMethod::POST;
+        request.auth = vec!["service".to_string()];
+        request.timeout = Some("5s".to_string());
+        request.retry = Some(RetryPolicy {
+            count: 3,
+            on: Some(vec![502, 503]),
+            backoff: Some(Duration::from_millis(250)),
+        });
+        request.redirects = Some(RedirectPolicy {
+            follow: false,
+            max: None,
+        });
+        request.idempotency_header = Some(DEFAULT_IDEMPOTENCY_HEADER.to_string());
+        request.assertions = vec![Assertion::parse("status == 201").unwrap()];
+        request.headers = vec![
+            ("Accept".to_string(), "application/json".to_string()),
+            ("X-Note".to_string(), "say \"hi\"".to_string()),
+        ];
+        request.body = Some(r#"{"name": "Ann"}"#.to_string());
+
+        assert_eq!(
+            render_request(&request, "create_user"),
+            concat!(
+                "[method(POST)]\n",
+                "[auth(\"service\")]\n",
+                "[timeout(5s)]\n",
+                "[retry(count=3, on=[502, 503], backoff=250ms)]\n",
+                "[redirects(follow=false)]\n",
+                "[idempotency]\n",
+                "[expect(status == 201)]\n",
+                "rq create_user(\"https://api.example.com/users\", headers: $[\n",
+                "    \"Accept\": \"application/json\",\n",
+                "    \"X-Note\": \"say \\\"hi\\\"\"\n",
+                "], body: ${\"name\": \"Ann\"});\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_non_json_bodies() {
+        let mut text = request();
+        text.body = Some("plain {text}".to_string());
+        assert_eq!(render_body(&text).as_deref(), Some("\"plain {text}\""));
+
+        let mut form = request();
+        form.form = Some(vec![("q".to_string(), "a b".to_string())]);
+        assert_eq!(
+            render_body(&form).as_deref(),
+            Some("form[\n    \"q\": \"a b\"\n]")
+        );
+
+        let mut multipart = request();
+        multipart.multipart = Some(vec![
+            MultipartPart {
+                name: "title".to_string(),
+                value: MultipartValue::Text("Report".to_string()),
+            },
+            MultipartPart {
+                name: "file".to_string(),
+                value: MultipartValue::File {
+                    path: "report.pdf".to_string(),
+                    content: Some(vec![1, 2]),
+                },
+            },
+        ]);
+        assert_eq!(
+            render_body(&multipart).as_deref(),
+            Some("multipart[\n    \"title\": \"Report\",\n    \"file\": @\"report.pdf\"\n]")
+        );
+
+        let mut bytes = request();
+        bytes.body_file = Some("payload.bin".to_string());
+        assert_eq!(
+            render_body(&bytes).as_deref(),
+            Some("io.read_bytes(\"payload.bin\")")
+        );
+    }
+}
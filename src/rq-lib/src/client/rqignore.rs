@@ -0,0 +1,174 @@
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+pub const RQIGNORE_FILE: &str = ".rqignore";
+
+#[derive(Debug)]
+struct IgnoreRule {
+    pattern: Regex,
+    negated: bool,
+    dir_only: bool,
+}
+
+#[derive(Debug)]
+pub struct RqIgnore {
+    base: PathBuf,
+    rules: Vec<IgnoreRule>,
+}
+
+impl RqIgnore {
+    pub fn parse(base: &Path, content: &str) -> Self {
+        Self {
+            base: base.to_path_buf(),
+            rules: content.lines().filter_map(parse_rule).collect(),
+        }
+    }
+
+    pub fn matches(&self, path: &Path, is_dir: bool) -> Option<bool> {
+        let relative = path.strip_prefix(&self.base).ok()?;
+        let relative = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| (is_dir || !rule.dir_only) && rule.pattern.is_match(&relative))
+            .map(|rule| !rule.negated)
+    }
+}
+
+pub fn is_ignored(ignores: &[RqIgnore], path: &Path, is_dir: bool) -> bool {
+    ignores
+        .iter()
+        .rev()
+        .find_map(|ignore| ignore.matches(path, is_dir))
+        .unwrap_or(false)
+}
+
+fn parse_rule(line: &str) -> Option<IgnoreRule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (negated, line) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (dir_only, line) = match line.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    if line.is_empty() {
+        return None;
+    }
+    let anchored = line.contains('/');
+    let body = glob_to_regex(line.strip_prefix('/').unwrap_or(line));
+    let prefix = if anchored { "^" } else { "^(?:.*/)?" };
+    Regex::new(&format!("{prefix}{body}$"))
+        .ok()
+        .map(|pattern| IgnoreRule {
+            pattern,
+            negated,
+            dir_only,
+        })
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let chars: Vec<char> = glob.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                let at_segment_start = i == 0 || chars[i - 1] == '/';
+                if at_segment_start && chars.get(i + 2) == Some(&'/') {
+                    out.push_str("(?:.*/)?");
+                    i += 3;
+                } else {
+                    out.push_str(".*");
+                    i += 2;
+                }
+                continue;
+            }
+            '*' => out.push_str("[^/]*"),
+            '?' => out.push_str("[^/]"),
+            '[' => match chars[i + 1..].iter().position(|&c| c == ']') {
+                Some(len) => {
+                    let class: String = chars[i + 1..i + 1 + len].iter().collect();
+                    let class = match class.strip_prefix('!') {
+                        Some(rest) => format!("^{rest}"),
+                        None => class,
+                    };
+                    out.push_str(&format!("[{}]", class.replace('\\', "\\\\")));
+                    i += len + 2;
+                    continue;
+                }
+                None => out.push_str("\\["),
+            },
+            '\\' if i + 1 < chars.len() => {
+                out.push_str(&regex::escape(&chars[i + 1].to_string()));
+                i += 2;
+                continue;
+            }
+            c => out.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignored(content: &str, path: &str, is_dir: bool) -> bool {
+        let rules = RqIgnore::parse(Path::new("root"), content);
+        is_ignored(&[rules], &Path::new("root").join(path), is_dir)
+    }
+
+    #[test]
+    fn unanchored_pattern_matches_at_any_depth() {
+        assert!(ignored("*.wip.rq", "draft.wip.rq", false));
+        assert!(ignored("*.wip.rq", "nested/deep/draft.wip.rq", false));
+        assert!(!ignored("*.wip.rq", "final.rq", false));
+    }
+
+    #[test]
+    fn leading_slash_anchors_to_base() {
+        assert!(ignored("/fixtures", "fixtures", true));
+        assert!(!ignored("/fixtures", "api/fixtures", true));
+    }
+
+    #[test]
+    fn trailing_slash_only_matches_directories() {
+        assert!(ignored("templates/", "templates", true));
+        assert!(!ignored("templates/", "templates", false));
+    }
+
+    #[test]
+    fn negation_re_includes_later_matches() {
+        let content = "*.rq\n!keep.rq";
+        assert!(ignored(content, "drop.rq", false));
+        assert!(!ignored(content, "keep.rq", false));
+    }
+
+    #[test]
+    fn double_star_matches_nested_directories() {
+        assert!(ignored("**/generated", "a/b/generated", true));
+        assert!(ignored("api/**/*.rq", "api/v1/users.rq", false));
+        assert!(ignored("api/**/*.rq", "api/users.rq", false));
+    }
+
+    #[test]
+    fn comments_and_blank_lines_are_skipped() {
+        assert!(!ignored("# *.rq\n\n", "api.rq", false));
+    }
+
+    #[test]
+    fn character_classes_are_supported() {
+        assert!(ignored("v[12].rq", "v1.rq", false));
+        assert!(!ignored("v[!12].rq", "v2.rq", false));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::client::models::UnusedEndpointTemplate;
+use crate::syntax::parse_result::EndpointDefinition;
+use crate::syntax::RqFile;
+use std::collections::{BTreeMap, HashSet};
+
+pub fn find(rq_files: &[RqFile]) -> Vec<UnusedEndpointTemplate> {
+    let parents: HashSet<&str> = rq_files
+        .iter()
+        .flat_map(|f| f.endpoints.values())
+        .filter_map(|ep| ep.parent.as_deref())
+        .collect();
+
+    let mut unused: BTreeMap<(String, usize, usize), UnusedEndpointTemplate> = BTreeMap::new();
+    for rq_file in rq_files {
+        let file = crate::paths::clean_path(&rq_file.path);
+        for ep in rq_file.endpoints.values() {
+            if ep.has_requests || parents.contains(ep.name.as_str()) || !declared_in(ep, &file) {
+                continue;
+            }
+            unused.insert(
+                (file.clone(), ep.line, ep.character),
+                UnusedEndpointTemplate {
+                    name: ep.name.clone(),
+                    message: format!(
+                        "Endpoint template '{}' is never used as a parent by another endpoint",
+                        ep.name
+                    ),
+                    file: file.clone(),
+                    line: ep.line,
+                    character: ep.character,
+                },
+            );
+        }
+    }
+    unused.into_values().collect()
+}
+
+fn declared_in(ep: &EndpointDefinition, file: &str) -> bool {
+    ep.source_path
+        .as_deref()
+        .is_some_and(|source| crate::paths::clean_path_str(source) == file)
+}
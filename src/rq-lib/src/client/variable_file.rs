@@ -0,0 +1,101 @@
+use crate::error::RqError;
+use crate::syntax::{Variable, VariableValue};
+use serde_json::Value;
+use std::path::Path;
+
+pub fn parse_variable_file(path: &Path, content: &str) -> Result<Vec<Variable>, RqError> {
+    let display = path.display();
+    if matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("yaml" | "yml")
+    ) {
+        return Err(RqError::Validation(format!(
+            "Variable file '{display}' is YAML, which is not supported yet. Convert it to JSON"
+        )));
+    }
+    let root: Value = serde_json::from_str(content).map_err(|e| {
+        RqError::Validation(format!("Variable file '{display}' is not valid JSON: {e}"))
+    })?;
+    let Value::Object(entries) = root else {
+        return Err(RqError::Validation(format!(
+            "Variable file '{display}' must contain a JSON object"
+        )));
+    };
+    entries
+        .into_iter()
+        .map(|(name, value)| {
+            let value = to_variable_value(value).ok_or_else(|| {
+                RqError::Validation(format!(
+                    "Variable '{name}' in '{display}' is null; remove it or give it a value"
+                ))
+            })?;
+            Ok(Variable { name, value })
+        })
+        .collect()
+}
+
+fn to_variable_value(value: Value) -> Option<VariableValue> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(VariableValue::String(s)),
+        Value::Bool(_) | Value::Number(_) => Some(VariableValue::String(value.to_string())),
+        Value::Array(items) if items.iter().all(Value::is_string) => Some(VariableValue::Array(
+            items
+                .into_iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect(),
+        )),
+        other => Some(VariableValue::Json(other.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<Vec<Variable>, RqError> {
+        parse_variable_file(Path::new("vars.json"), content)
+    }
+
+    #[test]
+    fn scalars_arrays_and_objects_are_converted() {
+        let vars = parse(
+            r#"{"host": "api.local", "port": 8080, "debug": true, "tags": ["a", "b"], "user": {"id": 1}}"#,
+        )
+        .expect("valid variable file");
+        let value = |name: &str| {
+            vars.iter()
+                .find(|v| v.name == name)
+                .map(|v| v.value.clone())
+        };
+
+        assert_eq!(
+            value("host"),
+            Some(VariableValue::String("api.local".into()))
+        );
+        assert_eq!(value("port"), Some(VariableValue::String("8080".into())));
+        assert_eq!(value("debug"), Some(VariableValue::String("true".into())));
+        assert_eq!(
+            value("tags"),
+            Some(VariableValue::Array(vec!["a".into(), "b".into()]))
+        );
+        assert_eq!(
+            value("user"),
+            Some(VariableValue::Json(r#"{"id":1}"#.into()))
+        );
+    }
+
+    #[test]
+    fn non_object_and_null_values_are_rejected() {
+        assert!(parse("[1, 2]").is_err());
+        assert!(parse(r#"{"token": null}"#).is_err());
+        assert!(parse("{not json").is_err());
+    }
+
+    #[test]
+    fn yaml_files_are_rejected_with_a_hint() {
+        let err = parse_variable_file(Path::new("vars.yaml"), "host: api.local")
+            .expect_err("yaml is not supported");
+        assert!(err.to_string().contains("Convert it to JSON"));
+    }
+}
@@ -22,7 +22,24 @@ struct JsonError {
 }
 
 pub fn error_to_json(error: &(dyn std::error::Error + 'static)) -> String {
-    let detail = if let Some(rq_error) = error.downcast_ref::<RqError>() {
+    serde_json::to_string(&JsonError {
+        error: error_detail(error),
+    })
+    .unwrap_or_else(|_| {
+        format!(
+            r#"{{"error":{{"type":"generic","message":{:?}}}}}"#,
+            error.to_string()
+        )
+    })
+}
+
+pub fn error_to_json_value(error: &(dyn std::error::Error + 'static)) -> serde_json::Value {
+    serde_json::to_value(error_detail(error))
+        .unwrap_or_else(|_| serde_json::json!({ "type": "generic", "message": error.to_string() }))
+}
+
+fn error_detail(error: &(dyn std::error::Error + 'static)) -> JsonErrorDetail {
+    if let Some(rq_error) = error.downcast_ref::<RqError>() {
         match rq_error {
             RqError::Syntax(e) => JsonErrorDetail {
                 error_type: "syntax".to_string(),
@@ -77,6 +94,13 @@ pub fn error_to_json(error: &(dyn std::error::Error + 'static)) -> String {
                 line: None,
                 column: None,
             },
+            RqError::ProfileNotFound(name) => JsonErrorDetail {
+                error_type: "not_found".to_string(),
+                message: format!("Profile not found: {name}"),
+                file: None,
+                line: None,
+                column: None,
+            },
             RqError::Io(e) => JsonErrorDetail {
                 error_type: "io".to_string(),
                 message: e.to_string(),
@@ -91,6 +115,20 @@ pub fn error_to_json(error: &(dyn std::error::Error + 'static)) -> String {
                 line: None,
                 column: None,
             },
+            RqError::DeadlineExceeded(_) => JsonErrorDetail {
+                error_type: "deadline".to_string(),
+                message: rq_error.to_string(),
+                file: None,
+                line: None,
+                column: None,
+            },
+            RqError::AssertionFailed(msg) => JsonErrorDetail {
+                error_type: "assertion".to_string(),
+                message: msg.clone(),
+                file: None,
+                line: None,
+                column: None,
+            },
             RqError::Generic(msg) => JsonErrorDetail {
                 error_type: "generic".to_string(),
                 message: msg.clone(),
@@ -107,14 +145,7 @@ pub fn error_to_json(error: &(dyn std::error::Error + 'static)) -> String {
             line: None,
             column: None,
         }
-    };
-
-    serde_json::to_string(&JsonError { error: detail }).unwrap_or_else(|_| {
-        format!(
-            r#"{{"error":{{"type":"generic","message":{:?}}}}}"#,
-            error.to_string()
-        )
-    })
+    }
 }
 
 #[derive(Debug)]
@@ -127,7 +158,10 @@ pub enum RqError {
     NotADirectory(String),
     RequestNotFound(String),
     EnvironmentNotFound(String),
+    ProfileNotFound(String),
     Network(String),
+    DeadlineExceeded(String),
+    AssertionFailed(String),
     Generic(String),
 }
 
@@ -148,7 +182,12 @@ impl fmt::Display for RqError {
             }
             RqError::RequestNotFound(name) => write!(f, "Request not found: {name}"),
             RqError::EnvironmentNotFound(name) => write!(f, "Environment not found: {name}"),
+            RqError::ProfileNotFound(name) => write!(f, "Profile not found: {name}"),
             RqError::Network(msg) => write!(f, "{msg}"),
+            RqError::DeadlineExceeded(budget) => {
+                write!(f, "Deadline exceeded: run did not finish within {budget}")
+            }
+            RqError::AssertionFailed(msg) => write!(f, "{msg}"),
             RqError::Generic(msg) => write!(f, "{msg}"),
         }
     }
@@ -2,12 +2,35 @@ use crate::error::RqError;
 use crate::syntax::Request;
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct HttpResponse {
     pub status: u16,
     pub headers: std::collections::HashMap<String, String>,
     pub body: String,
+    pub redirects: Vec<RedirectHop>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectHop {
+    pub url: String,
+    pub status: u16,
+    pub location: String,
+}
+
+#[derive(Debug)]
+pub struct PingResponse {
+    pub status: u16,
+    pub protocol: String,
+    pub certificate_expires: Option<String>,
+}
+
+pub fn is_websocket_url(url: &str) -> bool {
+    url.split_once("://").is_some_and(|(scheme, _)| {
+        scheme.eq_ignore_ascii_case("ws") || scheme.eq_ignore_ascii_case("wss")
+    })
 }
 
 pub trait HttpClient: Send + Sync {
@@ -15,4 +38,44 @@ pub trait HttpClient: Send + Sync {
         &'a self,
         request: &'a Request,
     ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, RqError>> + Send + 'a>>;
+
+    fn ping<'a>(
+        &'a self,
+        _request: &'a Request,
+    ) -> Pin<Box<dyn Future<Output = Result<PingResponse, RqError>> + Send + 'a>> {
+        Box::pin(async {
+            Err(RqError::Generic(
+                "Preflight checks are not supported by this HTTP client".to_string(),
+            ))
+        })
+    }
+
+    fn websocket<'a>(
+        &'a self,
+        _request: &'a Request,
+        _max_frames: Option<usize>,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, RqError>> + Send + 'a>> {
+        Box::pin(async {
+            Err(RqError::Generic(
+                "WebSocket requests are not supported by this HTTP client".to_string(),
+            ))
+        })
+    }
+
+    fn sleep<'a>(&'a self, _duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_websocket_url() {
+        assert!(is_websocket_url("ws://localhost:8080/socket"));
+        assert!(is_websocket_url("WSS://example.com"));
+        assert!(!is_websocket_url("https://example.com/ws"));
+        assert!(!is_websocket_url("wsx://example.com"));
+    }
 }
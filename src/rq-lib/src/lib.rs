@@ -9,5 +9,7 @@ pub mod paths;
 pub mod syntax;
 pub mod version;
 
-pub use client::models::RequestExecutionResult;
+pub use client::models::{
+    AssertionFailure, PingResult, RequestExecutionResult, RetryTelemetry, RunOptions, RunSummary,
+};
 pub use client::RqClient;
@@ -22,7 +22,7 @@ lazy_static! {
         |
         (?P<kv_key>(?:password|passwd|token|secret|api_?key))
         (?P<kv_sep>[=:]\s*)
-        (?P<kv_val>\S+)
+        (?P<kv_val>[^\s'"]+)
         |
         (?P<bearer>Bearer\s+)
         (?P<bearer_val>\S+)
@@ -87,3 +87,16 @@ macro_rules! debug_log {
         $crate::logger::Logger::debug_fmt(format_args!($($arg)*))
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_keeps_quotes_around_redacted_values() {
+        assert_eq!(
+            sanitize_message("--data-urlencode 'client_secret=abc' token: xyz"),
+            "--data-urlencode 'client_secret=***' token: ***"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use encoding_rs::{Encoding, UTF_8};
+
+pub struct DecodedBody {
+    pub text: String,
+    pub warning: Option<String>,
+}
+
+pub fn decode_body(bytes: &[u8], content_type: Option<&str>) -> DecodedBody {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return decode(bytes, encoding);
+    }
+    let Some(label) = content_type.and_then(charset_label) else {
+        return decode(bytes, UTF_8);
+    };
+    match Encoding::for_label(label.as_bytes()) {
+        Some(encoding) => decode(bytes, encoding),
+        None => DecodedBody {
+            text: String::from_utf8_lossy(bytes).into_owned(),
+            warning: Some(format!(
+                "Response charset '{label}' is not supported; decoded as UTF-8 and invalid bytes were replaced"
+            )),
+        },
+    }
+}
+
+fn charset_label(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"').to_ascii_lowercase())
+    })
+}
+
+fn decode(bytes: &[u8], encoding: &'static Encoding) -> DecodedBody {
+    let (text, actual, had_errors) = encoding.decode(bytes);
+    DecodedBody {
+        text: text.into_owned(),
+        warning: had_errors.then(|| {
+            format!(
+                "Response body is not valid {}; invalid bytes were replaced",
+                actual.name()
+            )
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latin1_charset_from_content_type() {
+        let decoded = decode_body(b"caf\xE9 \x80", Some("text/plain; charset=\"ISO-8859-1\""));
+        assert_eq!(decoded.text, "café €");
+        assert!(decoded.warning.is_none());
+    }
+
+    #[test]
+    fn test_shift_jis_charset_from_content_type() {
+        let decoded = decode_body(
+            b"\x93\xFA\x96\x7B",
+            Some("application/json; charset=Shift_JIS"),
+        );
+        assert_eq!(decoded.text, "日本");
+        assert!(decoded.warning.is_none());
+    }
+
+    #[test]
+    fn test_bom_overrides_content_type() {
+        let decoded = decode_body(
+            &[0xFF, 0xFE, b'h', 0, b'i', 0],
+            Some("application/json; charset=iso-8859-1"),
+        );
+        assert_eq!(decoded.text, "hi");
+        let decoded = decode_body(b"\xEF\xBB\xBF{}", None);
+        assert_eq!(decoded.text, "{}");
+    }
+
+    #[test]
+    fn test_invalid_utf8_falls_back_with_warning() {
+        let decoded = decode_body(b"ok \xFF", Some("application/json"));
+        assert_eq!(decoded.text, "ok \u{FFFD}");
+        assert!(decoded.warning.is_some());
+    }
+
+    #[test]
+    fn test_unsupported_charset_warns() {
+        let decoded = decode_body(b"plain", Some("text/plain; charset=x-unknown"));
+        assert_eq!(decoded.text, "plain");
+        assert!(decoded
+            .warning
+            .is_some_and(|w| w.contains("'x-unknown' is not supported")));
+    }
+}
@@ -8,6 +8,10 @@ impl Fs for NativeFs {
         std::fs::read_to_string(path).map_err(|e| e.to_string())
     }
 
+    fn read_bytes(&self, path: &Path) -> Result<Vec<u8>, String> {
+        std::fs::read(path).map_err(|e| e.to_string())
+    }
+
     fn resolve_path(&self, base: &Path, relative: &str) -> Result<PathBuf, String> {
         let dir = if base.is_dir() {
             base.to_path_buf()
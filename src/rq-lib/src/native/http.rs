@@ -1,48 +1,159 @@
+use super::charset::decode_body;
 use crate::error::RqError;
-use crate::http::{HttpClient, HttpResponse};
+use crate::http::{HttpClient, HttpResponse, PingResponse, RedirectHop};
+use crate::syntax::duration::parse_duration;
+use crate::syntax::parse_result::{ClientCert, MultipartPart, MultipartValue, RedirectPolicy};
 use crate::syntax::Request;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
 
-pub struct ReqwestHttpClient;
+const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+tokio::task_local! {
+    static REDIRECT_HOPS: RefCell<Vec<RedirectHop>>;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolOptions {
+    pub max_idle_per_host: Option<usize>,
+    pub idle_timeout: Option<Duration>,
+}
+
+impl PoolOptions {
+    fn apply(&self, mut builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        if let Some(max_idle) = self.max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = self.idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+        builder
+    }
+}
+
+#[derive(Default)]
+pub struct ReqwestHttpClient {
+    pool: PoolOptions,
+    clients: Mutex<HashMap<Option<usize>, reqwest::Client>>,
+}
+
+impl ReqwestHttpClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_pool(pool: PoolOptions) -> Self {
+        Self {
+            pool,
+            ..Self::default()
+        }
+    }
+
+    fn client_for(&self, request: &Request) -> Result<reqwest::Client, RqError> {
+        let max_redirects = redirect_limit(request);
+        let builder = self
+            .pool
+            .apply(reqwest::Client::builder())
+            .redirect(max_redirects.map_or_else(reqwest::redirect::Policy::none, redirect_policy));
+        if request.cert.is_some() || request.unix_socket.is_some() {
+            return with_unix_socket(with_client_cert(builder, request)?, request)?
+                .build()
+                .map_err(|e| RqError::Network(error_chain(&e)));
+        }
+        let mut clients = self
+            .clients
+            .lock()
+            .map_err(|_| RqError::Generic("HTTP client pool is poisoned".to_string()))?;
+        if let Some(client) = clients.get(&max_redirects) {
+            return Ok(client.clone());
+        }
+        let client = builder
+            .build()
+            .map_err(|e| RqError::Network(error_chain(&e)))?;
+        clients.insert(max_redirects, client.clone());
+        Ok(client)
+    }
+}
 
 impl HttpClient for ReqwestHttpClient {
     fn execute<'a>(
         &'a self,
         request: &'a Request,
     ) -> Pin<Box<dyn std::future::Future<Output = Result<HttpResponse, RqError>> + Send + 'a>> {
-        Box::pin(async move { execute_with_reqwest(request).await })
+        Box::pin(async move {
+            let client = self.client_for(request)?;
+            REDIRECT_HOPS
+                .scope(
+                    RefCell::new(Vec::new()),
+                    execute_with_reqwest(client, request),
+                )
+                .await
+        })
+    }
+
+    fn ping<'a>(
+        &'a self,
+        request: &'a Request,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<PingResponse, RqError>> + Send + 'a>> {
+        Box::pin(async move { ping_with_reqwest(request).await })
+    }
+
+    fn websocket<'a>(
+        &'a self,
+        request: &'a Request,
+        max_frames: Option<usize>,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<HttpResponse, RqError>> + Send + 'a>> {
+        #[cfg(feature = "websocket")]
+        return Box::pin(super::websocket::execute(request, max_frames));
+        #[cfg(not(feature = "websocket"))]
+        Box::pin(async move {
+            let _ = (request, max_frames);
+            Err(RqError::Generic(
+                "WebSocket requests require rq to be built with the 'websocket' feature"
+                    .to_string(),
+            ))
+        })
+    }
+
+    fn sleep<'a>(
+        &'a self,
+        duration: Duration,
+    ) -> Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>> {
+        Box::pin(tokio::time::sleep(duration))
     }
 }
 
-async fn execute_with_reqwest(request: &Request) -> Result<HttpResponse, RqError> {
-    let client = reqwest::Client::new();
+async fn execute_with_reqwest(
+    client: reqwest::Client,
+    request: &Request,
+) -> Result<HttpResponse, RqError> {
     let method = to_reqwest_method(&request.method);
     let mut req_builder = client.request(method, &request.url);
 
     for (key, value) in &request.headers {
+        if request.multipart.is_some() && key.eq_ignore_ascii_case("content-type") {
+            continue;
+        }
         req_builder = req_builder.header(key, value);
     }
 
-    if let Some(body) = &request.body {
+    if let Some(parts) = &request.multipart {
+        req_builder = req_builder.multipart(multipart_form(parts));
+    } else if let Some(bytes) = &request.body_bytes {
+        req_builder = req_builder.body(bytes.clone());
+    } else if let Some(body) = &request.body {
         req_builder = req_builder.body(body.clone());
     }
 
     if let Some(timeout_str) = &request.timeout {
-        match timeout_str.parse::<f64>() {
-            Ok(secs) if secs.is_finite() && secs >= 0.0 => {
-                req_builder = req_builder.timeout(std::time::Duration::from_secs_f64(secs));
-            }
-            Ok(_) => {
-                return Err(RqError::Generic(format!(
-                    "HTTP Error: Timeout value '{timeout_str}' must be a non-negative finite number"
-                )));
-            }
-            Err(_) => {
-                return Err(RqError::Generic(format!(
-                    "HTTP Error: Timeout value '{timeout_str}' must be a number"
-                )));
-            }
-        }
+        let timeout = parse_duration(timeout_str).map_err(|e| {
+            RqError::Generic(format!("HTTP Error: Invalid timeout '{timeout_str}': {e}"))
+        })?;
+        req_builder = req_builder.timeout(timeout);
     }
 
     let response = req_builder
@@ -58,18 +169,192 @@ async fn execute_with_reqwest(request: &Request) -> Result<HttpResponse, RqError
         }
     }
 
-    let body = response
-        .text()
+    let bytes = response
+        .bytes()
         .await
         .map_err(|e| RqError::Network(error_chain(&e)))?;
+    let decoded = decode_body(&bytes, headers.get("content-type").map(String::as_str));
+
+    let redirects = REDIRECT_HOPS
+        .try_with(|hops| hops.take())
+        .unwrap_or_default();
 
     Ok(HttpResponse {
         status,
         headers,
-        body,
+        body: decoded.text,
+        redirects,
+        warnings: decoded.warning.into_iter().collect(),
+    })
+}
+
+fn redirect_limit(request: &Request) -> Option<usize> {
+    match &request.redirects {
+        Some(policy) if !policy.follow => None,
+        Some(RedirectPolicy { max: Some(max), .. }) => Some(*max),
+        _ => Some(request.max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS)),
+    }
+}
+
+fn redirect_policy(max: usize) -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(move |attempt| {
+        if let Some(from) = attempt.previous().last() {
+            let hop = RedirectHop {
+                url: from.to_string(),
+                status: attempt.status().as_u16(),
+                location: attempt.url().to_string(),
+            };
+            let _ = REDIRECT_HOPS.try_with(|hops| hops.borrow_mut().push(hop));
+        }
+        if attempt.previous().len() > max {
+            attempt.error(format!("too many redirects (max {max})"))
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+async fn ping_with_reqwest(request: &Request) -> Result<PingResponse, RqError> {
+    let builder = with_client_cert(reqwest::Client::builder().tls_info(true), request)?;
+    let client = with_unix_socket(builder, request)?
+        .build()
+        .map_err(|e| RqError::Network(error_chain(&e)))?;
+    let mut req_builder = client.head(&request.url);
+
+    for (key, value) in &request.headers {
+        req_builder = req_builder.header(key, value);
+    }
+
+    if let Some(timeout_str) = &request.timeout {
+        if let Ok(timeout) = parse_duration(timeout_str) {
+            req_builder = req_builder.timeout(timeout);
+        }
+    }
+
+    let response = req_builder
+        .send()
+        .await
+        .map_err(|e| RqError::Network(error_chain(&e)))?;
+
+    let certificate_expires = response
+        .extensions()
+        .get::<reqwest::tls::TlsInfo>()
+        .and_then(|info| info.peer_certificate())
+        .and_then(|der| openssl::x509::X509::from_der(der).ok())
+        .map(|cert| cert.not_after().to_string());
+
+    Ok(PingResponse {
+        status: response.status().as_u16(),
+        protocol: format!("{:?}", response.version()),
+        certificate_expires,
     })
 }
 
+fn with_client_cert(
+    builder: reqwest::ClientBuilder,
+    request: &Request,
+) -> Result<reqwest::ClientBuilder, RqError> {
+    match &request.cert {
+        Some(cert) => Ok(builder.identity(load_identity(request, cert)?)),
+        None => Ok(builder),
+    }
+}
+
+#[cfg(unix)]
+fn with_unix_socket(
+    builder: reqwest::ClientBuilder,
+    request: &Request,
+) -> Result<reqwest::ClientBuilder, RqError> {
+    match &request.unix_socket {
+        Some(path) => {
+            Ok(builder.unix_socket(resolve_relative_path(request.source_path.as_deref(), path)))
+        }
+        None => Ok(builder),
+    }
+}
+
+#[cfg(not(unix))]
+fn with_unix_socket(
+    builder: reqwest::ClientBuilder,
+    request: &Request,
+) -> Result<reqwest::ClientBuilder, RqError> {
+    match &request.unix_socket {
+        Some(_) => Err(RqError::Validation(
+            "Unix sockets are not supported on this platform".to_string(),
+        )),
+        None => Ok(builder),
+    }
+}
+
+fn load_identity(request: &Request, cert: &ClientCert) -> Result<reqwest::Identity, RqError> {
+    let declared_in = cert
+        .source_path
+        .as_deref()
+        .or(request.source_path.as_deref());
+    let path = resolve_relative_path(declared_in, &cert.path);
+    let content = std::fs::read(&path).map_err(|e| {
+        RqError::Validation(format!(
+            "Failed to read client certificate '{}': {e}",
+            cert.path
+        ))
+    })?;
+    let identity = if String::from_utf8_lossy(&content).contains("-----BEGIN") {
+        identity_from_pem(&content)
+    } else {
+        reqwest::Identity::from_pkcs12_der(&content, cert.password.as_deref().unwrap_or(""))
+            .map_err(|e| error_chain(&e))
+    };
+    identity.map_err(|e| {
+        RqError::Validation(format!("Invalid client certificate '{}': {e}", cert.path))
+    })
+}
+
+fn identity_from_pem(content: &[u8]) -> Result<reqwest::Identity, String> {
+    let blocks = pem::parse_many(content).map_err(|e| e.to_string())?;
+    let certs: String = blocks
+        .iter()
+        .filter(|p| p.tag() == "CERTIFICATE")
+        .map(pem::encode)
+        .collect();
+    let key = blocks
+        .iter()
+        .find(|p| p.tag().contains("PRIVATE KEY"))
+        .map(pem::encode)
+        .ok_or_else(|| "no PRIVATE KEY found in PEM file".to_string())?;
+    if certs.is_empty() {
+        return Err("no CERTIFICATE found in PEM file".to_string());
+    }
+    reqwest::Identity::from_pkcs8_pem(certs.as_bytes(), key.as_bytes()).map_err(|e| error_chain(&e))
+}
+
+fn resolve_relative_path(source_path: Option<&str>, relative: &str) -> PathBuf {
+    let path = Path::new(relative);
+    match source_path.and_then(|s| Path::new(s).parent()) {
+        Some(parent) if path.is_relative() => parent.join(path),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn multipart_form(parts: &[MultipartPart]) -> reqwest::multipart::Form {
+    parts
+        .iter()
+        .fold(reqwest::multipart::Form::new(), |form, part| {
+            match &part.value {
+                MultipartValue::Text(text) => form.text(part.name.clone(), text.clone()),
+                MultipartValue::File { path, content } => {
+                    let file_name = Path::new(path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_else(|| path.clone());
+                    let bytes =
+                        reqwest::multipart::Part::bytes(content.clone().unwrap_or_default())
+                            .file_name(file_name);
+                    form.part(part.name.clone(), bytes)
+                }
+            }
+        })
+}
+
 fn to_reqwest_method(method: &crate::syntax::http_method::HttpMethod) -> reqwest::Method {
     use crate::syntax::http_method::HttpMethod;
     match method {
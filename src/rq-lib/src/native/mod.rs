@@ -1,7 +1,12 @@
+mod charset;
 mod fs;
 mod http;
+mod oauth_callback;
 mod secrets;
+#[cfg(feature = "websocket")]
+mod websocket;
 
 pub use fs::NativeFs;
-pub use http::ReqwestHttpClient;
+pub use http::{PoolOptions, ReqwestHttpClient};
+pub use oauth_callback::CallbackListener;
 pub use secrets::NativeSecretProvider;
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+const LOOPBACK_HOSTS: &[&str] = &["127.0.0.1", "localhost", "[::1]"];
+
+pub struct CallbackListener {
+    listener: TcpListener,
+    path: String,
+}
+
+impl CallbackListener {
+    pub fn bind(redirect_uri: &str) -> Result<Self, String> {
+        let url = reqwest::Url::parse(redirect_uri)
+            .map_err(|e| format!("Invalid redirect_uri '{redirect_uri}': {e}"))?;
+        let host = url.host_str().unwrap_or_default();
+        if url.scheme() != "http" || !LOOPBACK_HOSTS.contains(&host) {
+            return Err(format!(
+                "redirect_uri '{redirect_uri}' must be a loopback http URL, such as http://127.0.0.1:8765/oauth-callback, to receive the authorization callback"
+            ));
+        }
+        let port = url.port_or_known_default().unwrap_or(80);
+        let listener = TcpListener::bind((host.trim_matches(['[', ']']), port))
+            .map_err(|e| format!("Failed to listen on {host}:{port} for '{redirect_uri}': {e}"))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| format!("Failed to configure callback listener: {e}"))?;
+        Ok(Self {
+            listener,
+            path: url.path().to_string(),
+        })
+    }
+
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.listener.local_addr().ok()
+    }
+
+    pub fn wait(self, timeout: Duration) -> Result<HashMap<String, String>, String> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    if let Some(params) = self.handle(stream) {
+                        return Ok(params);
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Err(format!(
+                            "Timed out after {}s waiting for the authorization callback",
+                            timeout.as_secs()
+                        ));
+                    }
+                    std::thread::sleep(POLL_INTERVAL);
+                }
+                Err(e) => return Err(format!("Failed to accept authorization callback: {e}")),
+            }
+        }
+    }
+
+    fn handle(&self, mut stream: TcpStream) -> Option<HashMap<String, String>> {
+        let _ = stream.set_nonblocking(false);
+        let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+        let mut request_line = String::new();
+        let mut reader = BufReader::new(&stream);
+        reader.read_line(&mut request_line).ok()?;
+        let mut header = String::new();
+        while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+            header.clear();
+        }
+
+        let target = request_line.split_whitespace().nth(1).unwrap_or("/");
+        let url = reqwest::Url::parse(&format!("http://localhost{target}")).ok()?;
+        if url.path() != self.path {
+            respond(&mut stream, "404 Not Found", "Not found");
+            return None;
+        }
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        let page = if params.contains_key("error") {
+            "Authorization failed. You can close this window and return to rq."
+        } else {
+            "Authorization complete. You can close this window and return to rq."
+        };
+        respond(&mut stream, "200 OK", page);
+        Some(params)
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn get(addr: SocketAddr, target: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {target} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_callback_listener_returns_query_of_callback_path() {
+        let listener = CallbackListener::bind("http://127.0.0.1:0/oauth-callback").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let browser = std::thread::spawn(move || {
+            let missing = get(addr, "/favicon.ico");
+            let callback = get(addr, "/oauth-callback?code=abc%2F1&state=xyz");
+            (missing, callback)
+        });
+
+        let params = listener.wait(Duration::from_secs(5)).unwrap();
+        let (missing, callback) = browser.join().unwrap();
+        assert!(missing.starts_with("HTTP/1.1 404"));
+        assert!(callback.contains("Authorization complete"));
+        assert_eq!(params.get("code").map(String::as_str), Some("abc/1"));
+        assert_eq!(params.get("state").map(String::as_str), Some("xyz"));
+    }
+
+    #[test]
+    fn test_callback_listener_rejects_non_loopback_redirect() {
+        let err = CallbackListener::bind("https://app.example.com/callback")
+            .err()
+            .unwrap();
+        assert!(err.contains("must be a loopback http URL"), "{err}");
+    }
+
+    #[test]
+    fn test_callback_listener_times_out() {
+        let listener = CallbackListener::bind("http://127.0.0.1:0/cb").unwrap();
+        let err = listener.wait(Duration::from_millis(100)).unwrap_err();
+        assert!(err.contains("Timed out"), "{err}");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::RqError;
+use crate::http::HttpResponse;
+use crate::syntax::duration::parse_duration;
+use crate::syntax::Request;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use futures_util::{SinkExt, StreamExt};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::tungstenite::Message;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+const SWITCHING_PROTOCOLS: u16 = 101;
+
+pub async fn execute(
+    request: &Request,
+    max_frames: Option<usize>,
+) -> Result<HttpResponse, RqError> {
+    let timeout = match &request.timeout {
+        Some(timeout_str) => parse_duration(timeout_str).map_err(|e| {
+            RqError::Generic(format!("HTTP Error: Invalid timeout '{timeout_str}': {e}"))
+        })?,
+        None => DEFAULT_TIMEOUT,
+    };
+    let deadline = Instant::now() + timeout;
+
+    let handshake = tokio::time::timeout_at(
+        deadline,
+        tokio_tungstenite::connect_async(handshake_request(request)?),
+    )
+    .await
+    .map_err(|_| RqError::Network(format!("WebSocket handshake timed out after {timeout:?}")))?
+    .map_err(|e| RqError::Network(format!("WebSocket handshake failed: {e}")))?;
+    let (mut stream, response) = handshake;
+
+    let mut headers = HashMap::new();
+    for (key, value) in response.headers() {
+        if let Ok(value_str) = value.to_str() {
+            headers.insert(key.to_string(), value_str.to_string());
+        }
+    }
+
+    if let Some(message) = outgoing_message(request) {
+        stream
+            .send(message)
+            .await
+            .map_err(|e| RqError::Network(format!("WebSocket send failed: {e}")))?;
+    }
+
+    let frames = read_frames(&mut stream, deadline, max_frames).await?;
+    let _ = stream.close(None).await;
+
+    Ok(HttpResponse {
+        status: SWITCHING_PROTOCOLS,
+        headers,
+        body: frames.join("\n"),
+        redirects: Vec::new(),
+        warnings: Vec::new(),
+    })
+}
+
+async fn read_frames<S>(
+    stream: &mut S,
+    deadline: Instant,
+    max_frames: Option<usize>,
+) -> Result<Vec<String>, RqError>
+where
+    S: StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    let mut frames = Vec::new();
+    while max_frames.is_none_or(|max| frames.len() < max) {
+        let Ok(next) = tokio::time::timeout_at(deadline, stream.next()).await else {
+            break;
+        };
+        match next {
+            Some(Ok(Message::Text(text))) => frames.push(text.as_str().to_string()),
+            Some(Ok(Message::Binary(bytes))) => frames.push(STANDARD.encode(&bytes)),
+            Some(Ok(Message::Close(_))) | None => break,
+            Some(Ok(_)) => {}
+            Some(Err(e)) => {
+                return Err(RqError::Network(format!("WebSocket receive failed: {e}")));
+            }
+        }
+    }
+    Ok(frames)
+}
+
+fn handshake_request(
+    request: &Request,
+) -> Result<tokio_tungstenite::tungstenite::handshake::client::Request, RqError> {
+    let mut handshake = request.url.as_str().into_client_request().map_err(|e| {
+        RqError::Validation(format!("Invalid WebSocket URL '{}': {e}", request.url))
+    })?;
+    for (key, value) in &request.headers {
+        let name = HeaderName::from_bytes(key.as_bytes())
+            .map_err(|e| RqError::Validation(format!("Invalid header name '{key}': {e}")))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|e| RqError::Validation(format!("Invalid value for header '{key}': {e}")))?;
+        handshake.headers_mut().insert(name, value);
+    }
+    Ok(handshake)
+}
+
+fn outgoing_message(request: &Request) -> Option<Message> {
+    if let Some(bytes) = &request.body_bytes {
+        return Some(Message::binary(bytes.clone()));
+    }
+    request
+        .body
+        .as_ref()
+        .map(|body| Message::text(body.clone()))
+}
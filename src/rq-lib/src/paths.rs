@@ -14,3 +14,10 @@ pub fn clean_path(path: &Path) -> String {
     let s = path.display().to_string();
     clean_path_str(&s).to_string()
 }
+
+pub fn display_path(path: &Path) -> String {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(|p| p.display().to_string()))
+        .unwrap_or_else(|| clean_path(path))
+}
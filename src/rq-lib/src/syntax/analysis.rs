@@ -1,10 +1,11 @@
 use super::{
     error::SyntaxError,
     fs::Fs,
+    keywords::KW_AUTH,
     parse_result::ParseResult,
     parsers::{
-        AuthParser, EndpointParser, EnvironmentParser, ImportParser, Parse, RequestParser,
-        VariableParser,
+        AuthParser, DefaultRequestParser, EndpointParser, EnvironmentParser, ImportEnvParser,
+        ImportParser, Parse, ProfileParser, RequestParser, SuiteParser, VariableParser,
     },
     reader::TokenReader,
     token::TokenType,
@@ -17,7 +18,7 @@ pub fn analyze(
     source: &str,
     fs: &dyn Fs,
 ) -> Result<ParseResult, SyntaxError> {
-    analyze_impl(tokens, file_path, source, fs, false)
+    analyze_impl(tokens, file_path, source, fs, None)
 }
 
 pub fn analyze_lenient(
@@ -26,10 +27,17 @@ pub fn analyze_lenient(
     source: &str,
     fs: &dyn Fs,
 ) -> ParseResult {
-    analyze_impl(tokens, file_path, source, fs, true).unwrap_or_else(|_| ParseResult {
+    analyze_impl(tokens, file_path, source, fs, Some(&mut Vec::new()))
+        .unwrap_or_else(|_| empty_result())
+}
+
+fn empty_result() -> ParseResult {
+    ParseResult {
         requests: Vec::new(),
         environments: std::collections::HashMap::new(),
         environment_locations: std::collections::HashMap::new(),
+        profiles: std::collections::HashMap::new(),
+        suites: std::collections::HashSet::new(),
         auth_providers: std::collections::HashMap::new(),
         endpoints: std::collections::HashMap::new(),
         file_variables: Vec::new(),
@@ -37,7 +45,29 @@ pub fn analyze_lenient(
         let_variable_locations: std::collections::HashMap::new(),
         env_variable_locations: std::collections::HashMap::new(),
         required_variable_locations: std::collections::HashMap::new(),
-    })
+        default_request: None,
+    }
+}
+
+pub fn analyze_auth_blocks(
+    tokens: &[super::token::Token],
+    file_path: PathBuf,
+    source: &str,
+    fs: &dyn Fs,
+) -> (ParseResult, Vec<SyntaxError>) {
+    let mut failed = Vec::new();
+    let result = analyze_impl(tokens, file_path, source, fs, Some(&mut failed))
+        .unwrap_or_else(|_| empty_result());
+    let auth_errors = failed
+        .into_iter()
+        .filter(|(idx, _)| {
+            tokens
+                .get(*idx)
+                .is_some_and(|t| t.token_type == TokenType::Keyword && t.value == KW_AUTH)
+        })
+        .map(|(_, e)| e)
+        .collect();
+    (result, auth_errors)
 }
 
 fn skip_to_next_statement(r: &mut TokenReader) {
@@ -77,28 +107,22 @@ fn analyze_impl(
     file_path: PathBuf,
     source: &str,
     fs: &dyn Fs,
-    lenient: bool,
+    mut recovered: Option<&mut Vec<(usize, SyntaxError)>>,
 ) -> Result<ParseResult, SyntaxError> {
+    let lenient = recovered.is_some();
     let mut r = TokenReader::new(tokens.to_vec(), file_path, source.to_string());
-    let mut result = ParseResult {
-        requests: Vec::new(),
-        environments: std::collections::HashMap::new(),
-        environment_locations: std::collections::HashMap::new(),
-        auth_providers: std::collections::HashMap::new(),
-        endpoints: std::collections::HashMap::new(),
-        file_variables: Vec::new(),
-        imported_files: Vec::new(),
-        let_variable_locations: std::collections::HashMap::new(),
-        env_variable_locations: std::collections::HashMap::new(),
-        required_variable_locations: std::collections::HashMap::new(),
-    };
+    let mut result = empty_result();
 
     let parsers: Vec<Box<dyn Parse>> = vec![
         Box::new(ImportParser),
+        Box::new(ImportEnvParser),
         Box::new(VariableParser),
         Box::new(EnvironmentParser),
+        Box::new(ProfileParser),
+        Box::new(DefaultRequestParser),
         Box::new(AuthParser),
         Box::new(EndpointParser),
+        Box::new(SuiteParser),
         Box::new(RequestParser),
     ];
 
@@ -114,11 +138,14 @@ fn analyze_impl(
                 let saved_idx = r.idx;
                 match parser.parse(&mut r, &mut result, fs) {
                     Ok(()) => {}
-                    Err(_) if lenient => {
-                        r.idx = saved_idx;
-                        skip_to_next_statement(&mut r);
-                    }
-                    Err(e) => return Err(e),
+                    Err(e) => match recovered.as_deref_mut() {
+                        Some(errors) => {
+                            errors.push((saved_idx, e));
+                            r.idx = saved_idx;
+                            skip_to_next_statement(&mut r);
+                        }
+                        None => return Err(e),
+                    },
                 }
                 parsed = true;
                 break;
@@ -0,0 +1,263 @@
+use serde_json::Value;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+const TARGETS: &str = "status, time_ms, header.<Name>, body or body.$<path>";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssertionTarget {
+    Status,
+    TimeMs,
+    Header(String),
+    Body(Option<Vec<PathSegment>>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Eq,
+    Ne,
+    Lt,
+    Gt,
+    Contains,
+    Matches,
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Comparator::Eq => "==",
+            Comparator::Ne => "!=",
+            Comparator::Lt => "<",
+            Comparator::Gt => ">",
+            Comparator::Contains => "contains",
+            Comparator::Matches => "matches",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assertion {
+    pub expression: String,
+    pub target: AssertionTarget,
+    pub comparator: Comparator,
+    pub expected: Value,
+}
+
+impl Assertion {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let expression = expression.trim();
+        let mut chars = expression.chars().peekable();
+        let target = parse_target(&read_target(&mut chars))?;
+        skip_whitespace(&mut chars);
+        let comparator = read_comparator(&mut chars)?;
+        let literal: String = chars.collect();
+        let expected = parse_expected(literal.trim(), comparator)?;
+        Ok(Self {
+            expression: expression.to_string(),
+            target,
+            comparator,
+            expected,
+        })
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn read_target(chars: &mut Peekable<Chars>) -> String {
+    let mut target = String::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    while let Some(&c) = chars.peek() {
+        let is_boundary = c.is_whitespace() || matches!(c, '=' | '!' | '<' | '>');
+        if depth == 0 && !in_string && is_boundary {
+            break;
+        }
+        match c {
+            '"' => in_string = !in_string,
+            '[' if !in_string => depth += 1,
+            ']' if !in_string => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        target.push(c);
+        chars.next();
+    }
+    target
+}
+
+pub(crate) fn parse_target(target: &str) -> Result<AssertionTarget, String> {
+    match target {
+        "" => Err(format!("Expected an assertion target: {TARGETS}")),
+        "status" => Ok(AssertionTarget::Status),
+        "time_ms" => Ok(AssertionTarget::TimeMs),
+        "body" => Ok(AssertionTarget::Body(None)),
+        other => {
+            if let Some(name) = other.strip_prefix("header.") {
+                if name.is_empty() {
+                    return Err("Expected a header name after 'header.'".to_string());
+                }
+                return Ok(AssertionTarget::Header(name.to_string()));
+            }
+            if let Some(path) = other.strip_prefix("body.$") {
+                return parse_path(path).map(|path| AssertionTarget::Body(Some(path)));
+            }
+            Err(format!(
+                "Unknown assertion target '{other}'; expected one of: {TARGETS}"
+            ))
+        }
+    }
+}
+
+fn parse_path(path: &str) -> Result<Vec<PathSegment>, String> {
+    let invalid = |reason: &str| format!("Invalid body path '${path}': {reason}");
+    let mut segments = Vec::new();
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                let mut key = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                    key.push(c);
+                }
+                if key.is_empty() {
+                    return Err(invalid("expected a field name after '.'"));
+                }
+                segments.push(PathSegment::Key(key));
+            }
+            '[' => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(c) => inner.push(c),
+                        None => return Err(invalid("unclosed '['")),
+                    }
+                }
+                segments.push(parse_bracket(inner.trim()).map_err(|e| invalid(&e))?);
+            }
+            other => return Err(invalid(&format!("unexpected '{other}'"))),
+        }
+    }
+    Ok(segments)
+}
+
+fn parse_bracket(inner: &str) -> Result<PathSegment, String> {
+    if let Some(key) = inner
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        return Ok(PathSegment::Key(key.to_string()));
+    }
+    inner
+        .parse::<usize>()
+        .map(PathSegment::Index)
+        .map_err(|_| format!("expected an index or a quoted key inside '[{inner}]'"))
+}
+
+fn read_comparator(chars: &mut Peekable<Chars>) -> Result<Comparator, String> {
+    let mut op = String::new();
+    if chars.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+        while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+            op.push(c);
+        }
+    } else {
+        while let Some(c) = chars.next_if(|c| matches!(c, '=' | '!' | '<' | '>')) {
+            op.push(c);
+        }
+    }
+    match op.as_str() {
+        "==" => Ok(Comparator::Eq),
+        "!=" => Ok(Comparator::Ne),
+        "<" => Ok(Comparator::Lt),
+        ">" => Ok(Comparator::Gt),
+        "contains" => Ok(Comparator::Contains),
+        "matches" => Ok(Comparator::Matches),
+        "" => Err("Expected a comparator: ==, !=, <, >, contains or matches".to_string()),
+        other => Err(format!(
+            "Unknown comparator '{other}'; expected ==, !=, <, >, contains or matches"
+        )),
+    }
+}
+
+fn parse_expected(literal: &str, comparator: Comparator) -> Result<Value, String> {
+    if literal.is_empty() {
+        return Err(format!("Expected a value after '{comparator}'"));
+    }
+    let value: Value = serde_json::from_str(literal).map_err(|_| {
+        format!("Invalid value '{literal}'; expected a string, number, true, false or null")
+    })?;
+    match (comparator, &value) {
+        (Comparator::Lt | Comparator::Gt, v) if !v.is_number() => {
+            Err(format!("'{comparator}' expects a number, found {literal}"))
+        }
+        (Comparator::Matches, Value::String(pattern)) => regex::Regex::new(pattern)
+            .map(|_| value.clone())
+            .map_err(|e| {
+                let reason = e.to_string();
+                let reason = reason
+                    .lines()
+                    .last()
+                    .unwrap_or_default()
+                    .trim_start_matches("error: ");
+                format!("Invalid regular expression {literal}: {reason}")
+            }),
+        (Comparator::Matches, _) => Err(format!(
+            "'matches' expects a regular expression string, found {literal}"
+        )),
+        _ => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_assertion_targets_and_comparators() {
+        let a = Assertion::parse("status == 200").unwrap();
+        assert_eq!(a.target, AssertionTarget::Status);
+        assert_eq!(a.comparator, Comparator::Eq);
+        assert_eq!(a.expected, Value::from(200));
+
+        let a = Assertion::parse("header.Content-Type contains \"json\"").unwrap();
+        assert_eq!(
+            a.target,
+            AssertionTarget::Header("Content-Type".to_string())
+        );
+        assert_eq!(a.comparator, Comparator::Contains);
+
+        let a = Assertion::parse(r#"body.$.items[0]["first name"]!="x""#).unwrap();
+        assert_eq!(
+            a.target,
+            AssertionTarget::Body(Some(vec![
+                PathSegment::Key("items".to_string()),
+                PathSegment::Index(0),
+                PathSegment::Key("first name".to_string()),
+            ]))
+        );
+        assert_eq!(a.comparator, Comparator::Ne);
+        assert_eq!(
+            Assertion::parse("time_ms<500").unwrap().comparator,
+            Comparator::Lt
+        );
+    }
+
+    #[test]
+    fn test_parse_assertion_rejects_invalid_expressions() {
+        assert!(Assertion::parse("latency == 1").is_err());
+        assert!(Assertion::parse("status = 200").is_err());
+        assert!(Assertion::parse("status ==").is_err());
+        assert!(Assertion::parse("time_ms < \"fast\"").is_err());
+        assert!(Assertion::parse("body matches \"[\"").is_err());
+        assert!(Assertion::parse("body.$.items[x] == 1").is_err());
+    }
+}
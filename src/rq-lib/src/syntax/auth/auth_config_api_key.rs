@@ -0,0 +1,149 @@
+use crate::auth::api_key::{
+    ApiKeyLocation, KEY_FIELD, LOCATION_FIELD, LOCATION_HEADER, LOCATION_QUERY, NAME_FIELD,
+};
+use crate::syntax::auth::{AuthConfig, AuthFuture};
+use crate::syntax::error::SyntaxError;
+use crate::syntax::token::Token;
+use std::collections::HashMap;
+
+pub struct ApiKeyAuthConfig;
+
+impl ApiKeyAuthConfig {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ApiKeyAuthConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthConfig for ApiKeyAuthConfig {
+    fn auth_type(&self) -> &str {
+        "api_key"
+    }
+
+    fn validate(&self, name: &str, fields: &HashMap<String, Token>) -> Result<(), SyntaxError> {
+        for field in [KEY_FIELD, NAME_FIELD] {
+            let Some(token) = fields.get(field) else {
+                return Err(SyntaxError::new(
+                    format!("API key auth '{name}' is missing required field '{field}'"),
+                    0,
+                    0,
+                    0..0,
+                ));
+            };
+            if token.value.trim().is_empty() {
+                return Err(SyntaxError::new(
+                    format!("API key auth '{name}' has empty '{field}' field"),
+                    0,
+                    0,
+                    token.span.clone(),
+                ));
+            }
+        }
+        if let Some(location) = fields.get(LOCATION_FIELD) {
+            let is_placeholder = location.value.starts_with("{{") && location.value.ends_with("}}");
+            if !is_placeholder && ApiKeyLocation::parse(&location.value).is_none() {
+                return Err(SyntaxError::new(
+                    format!(
+                        "API key auth '{name}' has invalid '{LOCATION_FIELD}' '{}'. Expected '{LOCATION_HEADER}' or '{LOCATION_QUERY}'",
+                        location.value
+                    ),
+                    0,
+                    0,
+                    location.span.clone(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn configure<'a>(
+        &'a self,
+        auth_config: &'a crate::syntax::auth::Config,
+        _context: &'a crate::syntax::variable_context::VariableContext,
+        url: String,
+        headers: Vec<(String, String)>,
+    ) -> AuthFuture<'a> {
+        Box::pin(async move {
+            Ok(crate::auth::ApiKeyAuthProvider::apply(
+                auth_config,
+                url,
+                headers,
+            )?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::token::TokenType;
+
+    fn fields(values: &[(&str, &str)]) -> HashMap<String, Token> {
+        values
+            .iter()
+            .map(|(k, v)| {
+                (
+                    k.to_string(),
+                    Token {
+                        token_type: TokenType::String,
+                        value: v.to_string(),
+                        span: 5..10,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_api_key_valid_with_and_without_location() {
+        let config = ApiKeyAuthConfig::new();
+        assert!(config
+            .validate("api", &fields(&[("key", "k"), ("name", "X-API-Key")]))
+            .is_ok());
+        assert!(config
+            .validate(
+                "api",
+                &fields(&[("key", "k"), ("name", "api_key"), ("location", "query")])
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_api_key_missing_or_empty_field() {
+        let config = ApiKeyAuthConfig::new();
+        let err = config
+            .validate("api", &fields(&[("key", "k")]))
+            .unwrap_err();
+        assert!(err.message.contains("missing required field 'name'"));
+        let err = config
+            .validate("api", &fields(&[("key", " "), ("name", "X-API-Key")]))
+            .unwrap_err();
+        assert!(err.message.contains("empty 'key' field"));
+    }
+
+    #[test]
+    fn test_api_key_invalid_location() {
+        let config = ApiKeyAuthConfig::new();
+        let err = config
+            .validate(
+                "api",
+                &fields(&[("key", "k"), ("name", "n"), ("location", "body")]),
+            )
+            .unwrap_err();
+        assert!(err
+            .message
+            .contains("invalid 'location' 'body'. Expected 'header' or 'query'"));
+        assert_eq!(err.span, 5..10);
+        assert!(config
+            .validate(
+                "api",
+                &fields(&[("key", "k"), ("name", "n"), ("location", "{{where}}")]),
+            )
+            .is_ok());
+    }
+}
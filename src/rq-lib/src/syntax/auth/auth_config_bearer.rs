@@ -5,6 +5,9 @@ use crate::syntax::variable_context::Variable;
 use std::collections::HashMap;
 
 const TOKEN_FIELD: &str = "token";
+const TOKEN_FILE_FIELD: &str = "token_file";
+const TOKEN_KEYCHAIN_FIELD: &str = "token_keychain";
+const TOKEN_SOURCES: [&str; 3] = [TOKEN_FIELD, TOKEN_FILE_FIELD, TOKEN_KEYCHAIN_FIELD];
 
 pub struct BearerAuthConfig;
 
@@ -53,15 +56,52 @@ impl AuthConfig for BearerAuthConfig {
     }
 
     fn validate(&self, name: &str, fields: &HashMap<String, Token>) -> Result<(), SyntaxError> {
-        if let Some(token) = fields.get(TOKEN_FIELD) {
-            if token.value.trim().is_empty() {
+        let sources: Vec<&str> = TOKEN_SOURCES
+            .into_iter()
+            .filter(|field| fields.contains_key(*field))
+            .collect();
+        let source = match sources.as_slice() {
+            [] => {
                 return Err(SyntaxError::new(
-                    format!("Bearer auth '{name}' has empty '{TOKEN_FIELD}' field"),
+                    format!(
+                        "Bearer auth '{name}' requires one of '{TOKEN_FIELD}', '{TOKEN_FILE_FIELD}' or '{TOKEN_KEYCHAIN_FIELD}'"
+                    ),
                     0,
                     0,
-                    token.span.clone(),
+                    0..0,
                 ));
             }
+            [first, second, ..] => {
+                return Err(SyntaxError::new(
+                    format!("Bearer auth '{name}' cannot set both '{first}' and '{second}'"),
+                    0,
+                    0,
+                    fields[*second].span.clone(),
+                ));
+            }
+            [source] => *source,
+        };
+        let token = &fields[source];
+        if token.value.trim().is_empty() {
+            return Err(SyntaxError::new(
+                format!("Bearer auth '{name}' has empty '{source}' field"),
+                0,
+                0,
+                token.span.clone(),
+            ));
+        }
+        if source == TOKEN_KEYCHAIN_FIELD
+            && crate::auth::BearerProvider::parse_keychain_entry(&token.value).is_none()
+        {
+            return Err(SyntaxError::new(
+                format!(
+                    "Bearer auth '{name}' has invalid '{TOKEN_KEYCHAIN_FIELD}' value '{}'; expected \"service/account\"",
+                    token.value
+                ),
+                0,
+                0,
+                token.span.clone(),
+            ));
         }
 
         Ok(())
@@ -75,8 +115,8 @@ impl AuthConfig for BearerAuthConfig {
         mut headers: Vec<(String, String)>,
     ) -> AuthFuture<'a> {
         Box::pin(async move {
-            let token = &auth_config.fields[TOKEN_FIELD].value;
-            Self::add_bearer_header(&mut headers, token);
+            let token = crate::auth::BearerProvider::resolve_token(auth_config)?;
+            Self::add_bearer_header(&mut headers, &token);
             Ok((url, headers))
         })
     }
@@ -114,7 +154,96 @@ mod tests {
         let fields = HashMap::new();
 
         let result = config.validate("test_auth", &fields);
-        assert!(result.is_ok());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .message
+            .contains("requires one of 'token', 'token_file' or 'token_keychain'"));
+    }
+
+    #[test]
+    fn test_bearer_auth_token_file() {
+        let config = BearerAuthConfig::new();
+        let mut fields = HashMap::new();
+        fields.insert(
+            "token_file".to_string(),
+            Token {
+                token_type: crate::syntax::token::TokenType::String,
+                value: "token.txt".to_string(),
+                span: 0..0,
+            },
+        );
+
+        assert!(config.validate("test_auth", &fields).is_ok());
+    }
+
+    #[test]
+    fn test_bearer_auth_token_and_token_file() {
+        let config = BearerAuthConfig::new();
+        let mut fields = HashMap::new();
+        for (key, value) in [("token", "abc"), ("token_file", "token.txt")] {
+            fields.insert(
+                key.to_string(),
+                Token {
+                    token_type: crate::syntax::token::TokenType::String,
+                    value: value.to_string(),
+                    span: 20..29,
+                },
+            );
+        }
+
+        let result = config.validate("test_auth", &fields);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().message.contains("cannot set both"));
+    }
+
+    #[test]
+    fn test_bearer_auth_token_keychain() {
+        let config = BearerAuthConfig::new();
+        let keychain_fields = |value: &str| {
+            HashMap::from([(
+                "token_keychain".to_string(),
+                Token {
+                    token_type: crate::syntax::token::TokenType::String,
+                    value: value.to_string(),
+                    span: 20..35,
+                },
+            )])
+        };
+
+        assert!(config
+            .validate("test_auth", &keychain_fields("github/ann"))
+            .is_ok());
+        let err = config
+            .validate("test_auth", &keychain_fields("github"))
+            .unwrap_err();
+        assert!(err.message.contains("expected \"service/account\""));
+        assert_eq!(err.span.start, 20);
+    }
+
+    #[test]
+    fn test_bearer_auth_token_file_and_token_keychain() {
+        let config = BearerAuthConfig::new();
+        let mut fields = HashMap::new();
+        for (key, value, span) in [
+            ("token_file", "token.txt", 10..19),
+            ("token_keychain", "github/ann", 30..42),
+        ] {
+            fields.insert(
+                key.to_string(),
+                Token {
+                    token_type: crate::syntax::token::TokenType::String,
+                    value: value.to_string(),
+                    span,
+                },
+            );
+        }
+
+        let err = config.validate("test_auth", &fields).unwrap_err();
+        assert!(err
+            .message
+            .contains("cannot set both 'token_file' and 'token_keychain'"));
+        assert_eq!(err.span.start, 30);
     }
 
     #[test]
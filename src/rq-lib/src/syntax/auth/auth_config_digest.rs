@@ -0,0 +1,141 @@
+use crate::auth::digest::{PASSWORD_FIELD, USERNAME_FIELD};
+use crate::syntax::auth::{AuthConfig, AuthFuture};
+use crate::syntax::error::SyntaxError;
+use crate::syntax::token::Token;
+use std::collections::HashMap;
+
+const FIELDS: &[&str] = &[USERNAME_FIELD, PASSWORD_FIELD];
+
+pub struct DigestAuthConfig;
+
+impl DigestAuthConfig {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DigestAuthConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthConfig for DigestAuthConfig {
+    fn auth_type(&self) -> &str {
+        "digest"
+    }
+
+    fn validate(&self, name: &str, fields: &HashMap<String, Token>) -> Result<(), SyntaxError> {
+        for field in FIELDS {
+            if !fields.contains_key(*field) {
+                return Err(SyntaxError::new(
+                    format!("Digest auth '{name}' is missing required field '{field}'"),
+                    0,
+                    0,
+                    0..0,
+                ));
+            }
+        }
+        if let Some(token) = fields
+            .get(USERNAME_FIELD)
+            .filter(|t| t.value.trim().is_empty())
+        {
+            return Err(SyntaxError::new(
+                format!("Digest auth '{name}' has empty '{USERNAME_FIELD}' field"),
+                0,
+                0,
+                token.span.clone(),
+            ));
+        }
+        if let Some((field_name, token)) = fields
+            .iter()
+            .find(|(field_name, _)| !FIELDS.contains(&field_name.as_str()))
+        {
+            return Err(SyntaxError::new(
+                format!(
+                    "Digest auth '{name}' has unexpected field '{field_name}'. Expected fields: {}",
+                    FIELDS.join(", ")
+                ),
+                0,
+                0,
+                token.span.clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn configure<'a>(
+        &'a self,
+        auth_config: &'a crate::syntax::auth::Config,
+        context: &'a crate::syntax::variable_context::VariableContext,
+        url: String,
+        headers: Vec<(String, String)>,
+    ) -> AuthFuture<'a> {
+        Box::pin(async move {
+            crate::auth::AuthProvider::configure(
+                &crate::auth::DigestAuthProvider::new(),
+                auth_config,
+                context,
+                url,
+                headers,
+            )
+            .await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::token::TokenType;
+
+    fn fields(values: &[(&str, &str)]) -> HashMap<String, Token> {
+        values
+            .iter()
+            .map(|(k, v)| {
+                (
+                    k.to_string(),
+                    Token {
+                        token_type: TokenType::String,
+                        value: v.to_string(),
+                        span: 0..0,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_digest() {
+        let config = DigestAuthConfig::new();
+        assert!(config
+            .validate("legacy", &fields(&[("username", "u"), ("password", "")]))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_digest_missing_or_empty_username() {
+        let config = DigestAuthConfig::new();
+        let err = config
+            .validate("legacy", &fields(&[("username", "u")]))
+            .unwrap_err();
+        assert!(err.message.contains("missing required field 'password'"));
+
+        let err = config
+            .validate("legacy", &fields(&[("username", " "), ("password", "p")]))
+            .unwrap_err();
+        assert!(err.message.contains("empty 'username' field"));
+    }
+
+    #[test]
+    fn test_digest_unexpected_field() {
+        let config = DigestAuthConfig::new();
+        let err = config
+            .validate(
+                "legacy",
+                &fields(&[("username", "u"), ("password", "p"), ("realm", "r")]),
+            )
+            .unwrap_err();
+        assert!(err.message.contains("unexpected field 'realm'"));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::syntax::auth::{AuthConfig, AuthFuture};
-use crate::syntax::error::{AuthError, SyntaxError};
+use crate::auth::AuthProvider;
+use crate::syntax::auth::{validate_endpoints_or_issuer, AuthConfig, AuthFuture, ISSUER_FIELD};
+use crate::syntax::error::SyntaxError;
 use crate::syntax::token::Token;
 use std::collections::HashMap;
 
@@ -36,10 +37,13 @@ impl AuthConfig for OAuth2AuthorizationCodeConfig {
     }
 
     fn validate(&self, name: &str, fields: &HashMap<String, Token>) -> Result<(), SyntaxError> {
-        const REQUIRED_FIELDS: &[&str] =
-            &[CLIENT_ID_FIELD, AUTHORIZATION_URL_FIELD, TOKEN_URL_FIELD];
+        const REQUIRED_FIELDS: &[&str] = &[CLIENT_ID_FIELD];
+        const ENDPOINT_FIELDS: &[&str] = &[AUTHORIZATION_URL_FIELD, TOKEN_URL_FIELD];
 
         const OPTIONAL_FIELDS: &[&str] = &[
+            AUTHORIZATION_URL_FIELD,
+            TOKEN_URL_FIELD,
+            ISSUER_FIELD,
             CLIENT_SECRET_FIELD,
             REDIRECT_URI_FIELD,
             SCOPE_FIELD,
@@ -61,7 +65,13 @@ impl AuthConfig for OAuth2AuthorizationCodeConfig {
             }
         }
 
-        for field in REQUIRED_FIELDS {
+        validate_endpoints_or_issuer("OAuth2 Authorization Code", name, fields, ENDPOINT_FIELDS)?;
+
+        for field in REQUIRED_FIELDS
+            .iter()
+            .chain(ENDPOINT_FIELDS)
+            .chain(&[ISSUER_FIELD])
+        {
             if let Some(token) = fields.get(*field) {
                 if token.value.trim().is_empty() {
                     return Err(SyntaxError::new(
@@ -147,19 +157,9 @@ impl AuthConfig for OAuth2AuthorizationCodeConfig {
         headers: Vec<(String, String)>,
     ) -> AuthFuture<'a> {
         Box::pin(async move {
-            let variables = context.all_variables();
-            let (modified_headers, applied) =
-                crate::syntax::auth::BearerAuthConfig::apply_from_variables(&variables, headers);
-
-            if applied {
-                return Ok((url, modified_headers));
-            }
-
-            Err(AuthError::new(format!(
-                "OAuth2 Authorization Code auth '{}' requires interactive authentication. This feature is not yet implemented.",
-                auth_config.name
-            ))
-            .into())
+            crate::auth::OAuth2AuthorizationCodeProvider::new()
+                .configure(auth_config, context, url, headers)
+                .await
         })
     }
 }
@@ -423,6 +423,7 @@ mod tests {
                 value: VariableValue::String("fallback-bearer-token".to_string()),
             }],
             environment_variables: vec![],
+            profile_variables: vec![],
             secret_variables: vec![],
             endpoint_variables: vec![],
             request_variables: vec![],
@@ -472,6 +473,7 @@ mod tests {
         let context = crate::syntax::variable_context::VariableContext {
             file_variables: variables,
             environment_variables: vec![],
+            profile_variables: vec![],
             secret_variables: vec![],
             endpoint_variables: vec![],
             request_variables: vec![],
@@ -1,4 +1,4 @@
-use crate::syntax::auth::{AuthConfig, AuthFuture};
+use crate::syntax::auth::{validate_endpoints_or_issuer, AuthConfig, AuthFuture, ISSUER_FIELD};
 use crate::syntax::error::SyntaxError;
 use crate::syntax::token::Token;
 use std::collections::HashMap;
@@ -43,8 +43,11 @@ impl AuthConfig for OAuth2ClientCredentialsConfig {
     }
 
     fn validate(&self, name: &str, fields: &HashMap<String, Token>) -> Result<(), SyntaxError> {
-        const REQUIRED_FIELDS: &[&str] = &[CLIENT_ID_FIELD, TOKEN_URL_FIELD];
+        const REQUIRED_FIELDS: &[&str] = &[CLIENT_ID_FIELD];
+        const ENDPOINT_FIELDS: &[&str] = &[TOKEN_URL_FIELD];
         const OPTIONAL_FIELDS: &[&str] = &[
+            TOKEN_URL_FIELD,
+            ISSUER_FIELD,
             CLIENT_SECRET_FIELD,
             SCOPE_FIELD,
             CERT_FILE_FIELD,
@@ -64,6 +67,8 @@ impl AuthConfig for OAuth2ClientCredentialsConfig {
             }
         }
 
+        validate_endpoints_or_issuer("OAuth2 Client Credentials", name, fields, ENDPOINT_FIELDS)?;
+
         if !fields.contains_key(CLIENT_SECRET_FIELD) && !fields.contains_key(CERT_FILE_FIELD) {
             return Err(SyntaxError::new(
                 format!(
@@ -75,7 +80,11 @@ impl AuthConfig for OAuth2ClientCredentialsConfig {
             ));
         }
 
-        for field in REQUIRED_FIELDS {
+        for field in REQUIRED_FIELDS
+            .iter()
+            .chain(ENDPOINT_FIELDS)
+            .chain(&[ISSUER_FIELD])
+        {
             if let Some(token) = fields.get(*field) {
                 if token.value.trim().is_empty() {
                     return Err(SyntaxError::new(
@@ -1,4 +1,4 @@
-use crate::syntax::auth::{AuthConfig, AuthFuture};
+use crate::syntax::auth::{validate_endpoints_or_issuer, AuthConfig, AuthFuture, ISSUER_FIELD};
 use crate::syntax::error::{AuthError, SyntaxError};
 use crate::syntax::token::Token;
 use std::collections::HashMap;
@@ -8,8 +8,14 @@ const AUTHORIZATION_URL_FIELD: &str = "authorization_url";
 const SCOPE_FIELD: &str = "scope";
 const REDIRECT_URI_FIELD: &str = "redirect_uri";
 
-const REQUIRED_FIELDS: [&str; 2] = [CLIENT_ID_FIELD, AUTHORIZATION_URL_FIELD];
-const OPTIONAL_FIELDS: [&str; 2] = [SCOPE_FIELD, REDIRECT_URI_FIELD];
+const REQUIRED_FIELDS: [&str; 1] = [CLIENT_ID_FIELD];
+const ENDPOINT_FIELDS: [&str; 1] = [AUTHORIZATION_URL_FIELD];
+const OPTIONAL_FIELDS: [&str; 4] = [
+    AUTHORIZATION_URL_FIELD,
+    ISSUER_FIELD,
+    SCOPE_FIELD,
+    REDIRECT_URI_FIELD,
+];
 
 pub struct OAuth2ImplicitConfig;
 
@@ -49,6 +55,8 @@ impl AuthConfig for OAuth2ImplicitConfig {
             }
         }
 
+        validate_endpoints_or_issuer("OAuth2 Implicit", name, fields, &ENDPOINT_FIELDS)?;
+
         for (field_name, token) in fields {
             if !REQUIRED_FIELDS.contains(&field_name.as_str())
                 && !OPTIONAL_FIELDS.contains(&field_name.as_str())
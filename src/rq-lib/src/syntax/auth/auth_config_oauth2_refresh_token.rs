@@ -0,0 +1,195 @@
+use crate::auth::oauth2_refresh_token::{
+    CLIENT_ID_FIELD, CLIENT_SECRET_FIELD, REFRESH_TOKEN_FIELD, SCOPE_FIELD, TOKEN_URL_FIELD,
+};
+use crate::auth::AuthProvider;
+use crate::syntax::auth::{validate_endpoints_or_issuer, AuthConfig, AuthFuture, ISSUER_FIELD};
+use crate::syntax::error::SyntaxError;
+use crate::syntax::token::Token;
+use std::collections::HashMap;
+
+const LABEL: &str = "OAuth2 Refresh Token";
+const REQUIRED_FIELDS: &[&str] = &[CLIENT_ID_FIELD, REFRESH_TOKEN_FIELD];
+const OPTIONAL_FIELDS: &[&str] = &[
+    TOKEN_URL_FIELD,
+    ISSUER_FIELD,
+    CLIENT_SECRET_FIELD,
+    SCOPE_FIELD,
+];
+
+pub struct OAuth2RefreshTokenConfig;
+
+impl OAuth2RefreshTokenConfig {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for OAuth2RefreshTokenConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthConfig for OAuth2RefreshTokenConfig {
+    fn auth_type(&self) -> &str {
+        "oauth2_refresh_token"
+    }
+
+    fn validate(&self, name: &str, fields: &HashMap<String, Token>) -> Result<(), SyntaxError> {
+        if let Some(field) = REQUIRED_FIELDS.iter().find(|f| !fields.contains_key(**f)) {
+            return Err(SyntaxError::new(
+                format!("{LABEL} auth '{name}' missing required field '{field}'"),
+                0,
+                0,
+                0..0,
+            ));
+        }
+        validate_endpoints_or_issuer(LABEL, name, fields, &[TOKEN_URL_FIELD])?;
+
+        for field in REQUIRED_FIELDS
+            .iter()
+            .chain(&[TOKEN_URL_FIELD, ISSUER_FIELD])
+        {
+            if let Some(token) = fields.get(*field).filter(|t| t.value.trim().is_empty()) {
+                return Err(SyntaxError::new(
+                    format!("{LABEL} auth '{name}' has empty '{field}' field"),
+                    0,
+                    0,
+                    token.span.clone(),
+                ));
+            }
+        }
+
+        for (field_name, token) in fields {
+            if !REQUIRED_FIELDS.contains(&field_name.as_str())
+                && !OPTIONAL_FIELDS.contains(&field_name.as_str())
+            {
+                return Err(SyntaxError::new(
+                    format!(
+                        "{LABEL} auth '{name}' has unexpected field '{field_name}'. Expected fields: {}, {}",
+                        REQUIRED_FIELDS.join(", "),
+                        OPTIONAL_FIELDS.join(", ")
+                    ),
+                    0,
+                    0,
+                    token.span.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn configure<'a>(
+        &'a self,
+        auth_config: &'a crate::syntax::auth::Config,
+        context: &'a crate::syntax::variable_context::VariableContext,
+        url: String,
+        headers: Vec<(String, String)>,
+    ) -> AuthFuture<'a> {
+        Box::pin(async move {
+            crate::auth::OAuth2RefreshTokenProvider::new()
+                .configure(auth_config, context, url, headers)
+                .await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::token::TokenType;
+
+    fn fields(values: &[(&str, &str)]) -> HashMap<String, Token> {
+        values
+            .iter()
+            .map(|(k, v)| {
+                (
+                    k.to_string(),
+                    Token {
+                        token_type: TokenType::String,
+                        value: v.to_string(),
+                        span: 0..0,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_refresh_token() {
+        let config = OAuth2RefreshTokenConfig::new();
+        assert!(config
+            .validate(
+                "refresh",
+                &fields(&[
+                    ("client_id", "app"),
+                    ("refresh_token", "r1"),
+                    ("token_url", "https://h/token"),
+                    ("client_secret", "s"),
+                ])
+            )
+            .is_ok());
+        assert!(config
+            .validate(
+                "refresh",
+                &fields(&[
+                    ("client_id", "app"),
+                    ("refresh_token", "r1"),
+                    ("issuer", "https://h")
+                ])
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_refresh_token_missing_or_empty_fields() {
+        let config = OAuth2RefreshTokenConfig::new();
+        let err = config
+            .validate(
+                "refresh",
+                &fields(&[("client_id", "app"), ("token_url", "https://h/token")]),
+            )
+            .unwrap_err();
+        assert!(err
+            .message
+            .contains("missing required field 'refresh_token'"));
+
+        let err = config
+            .validate(
+                "refresh",
+                &fields(&[("client_id", "app"), ("refresh_token", "r1")]),
+            )
+            .unwrap_err();
+        assert!(err.message.contains("missing required field 'token_url'"));
+
+        let err = config
+            .validate(
+                "refresh",
+                &fields(&[
+                    ("client_id", "app"),
+                    ("refresh_token", " "),
+                    ("token_url", "https://h/token"),
+                ]),
+            )
+            .unwrap_err();
+        assert!(err.message.contains("empty 'refresh_token' field"));
+    }
+
+    #[test]
+    fn test_refresh_token_unexpected_field() {
+        let config = OAuth2RefreshTokenConfig::new();
+        let err = config
+            .validate(
+                "refresh",
+                &fields(&[
+                    ("client_id", "app"),
+                    ("refresh_token", "r1"),
+                    ("token_url", "https://h/token"),
+                    ("cert_file", "c.pem"),
+                ]),
+            )
+            .unwrap_err();
+        assert!(err.message.contains("unexpected field 'cert_file'"));
+    }
+}
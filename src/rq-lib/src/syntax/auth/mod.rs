@@ -1,27 +1,40 @@
 mod auth_config;
+mod auth_config_api_key;
 mod auth_config_bearer;
+mod auth_config_digest;
 mod auth_config_oauth2_authorization_code;
 mod auth_config_oauth2_client_credentials;
 mod auth_config_oauth2_implicit;
+mod auth_config_oauth2_refresh_token;
 
 pub use auth_config::AuthConfig;
 pub use auth_config::AuthFuture;
+pub use auth_config_api_key::ApiKeyAuthConfig;
 pub use auth_config_bearer::BearerAuthConfig;
+pub use auth_config_digest::DigestAuthConfig;
 pub use auth_config_oauth2_authorization_code::OAuth2AuthorizationCodeConfig;
 pub use auth_config_oauth2_client_credentials::OAuth2ClientCredentialsConfig;
 pub use auth_config_oauth2_implicit::OAuth2ImplicitConfig;
+pub use auth_config_oauth2_refresh_token::OAuth2RefreshTokenConfig;
 
 use crate::syntax::error::SyntaxError;
 use crate::syntax::token::Token;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+pub const ISSUER_FIELD: &str = "issuer";
+pub const EDITOR_REDIRECT_URI: &str = "vscode://rq-lang.rq-language/oauth-callback";
+pub const CLI_REDIRECT_URI: &str = "http://127.0.0.1:8765/oauth-callback";
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AuthType {
     Bearer,
     OAuth2AuthorizationCode,
     OAuth2ClientCredentials,
     OAuth2Implicit,
+    OAuth2RefreshToken,
+    ApiKey,
+    Digest,
 }
 
 impl AuthType {
@@ -32,6 +45,9 @@ impl AuthType {
             "oauth2_authorization_code" => Ok(AuthType::OAuth2AuthorizationCode),
             "oauth2_client_credentials" => Ok(AuthType::OAuth2ClientCredentials),
             "oauth2_implicit" => Ok(AuthType::OAuth2Implicit),
+            "oauth2_refresh_token" => Ok(AuthType::OAuth2RefreshToken),
+            "api_key" => Ok(AuthType::ApiKey),
+            "digest" => Ok(AuthType::Digest),
             _ => Err(SyntaxError {
                 message: format!("Unknown auth type: {s}"),
                 line: 0,
@@ -49,6 +65,9 @@ impl AuthType {
             AuthType::OAuth2AuthorizationCode => "oauth2_authorization_code",
             AuthType::OAuth2ClientCredentials => "oauth2_client_credentials",
             AuthType::OAuth2Implicit => "oauth2_implicit",
+            AuthType::OAuth2RefreshToken => "oauth2_refresh_token",
+            AuthType::ApiKey => "api_key",
+            AuthType::Digest => "digest",
         }
     }
 
@@ -58,26 +77,31 @@ impl AuthType {
             AuthType::OAuth2AuthorizationCode => Box::new(OAuth2AuthorizationCodeConfig::new()),
             AuthType::OAuth2ClientCredentials => Box::new(OAuth2ClientCredentialsConfig::new()),
             AuthType::OAuth2Implicit => Box::new(OAuth2ImplicitConfig::new()),
+            AuthType::OAuth2RefreshToken => Box::new(OAuth2RefreshTokenConfig::new()),
+            AuthType::ApiKey => Box::new(ApiKeyAuthConfig::new()),
+            AuthType::Digest => Box::new(DigestAuthConfig::new()),
         }
     }
 
     pub fn required_fields(&self) -> Vec<&'static str> {
         match self {
-            AuthType::Bearer => vec!["token"],
-            AuthType::OAuth2AuthorizationCode => {
-                vec!["client_id", "authorization_url", "token_url"]
-            }
-            AuthType::OAuth2ClientCredentials => {
-                vec!["client_id", "token_url"]
-            }
-            AuthType::OAuth2Implicit => vec!["client_id", "authorization_url"],
+            AuthType::Bearer => vec![],
+            AuthType::OAuth2AuthorizationCode => vec!["client_id"],
+            AuthType::OAuth2ClientCredentials => vec!["client_id"],
+            AuthType::OAuth2Implicit => vec!["client_id"],
+            AuthType::OAuth2RefreshToken => vec!["client_id", "refresh_token"],
+            AuthType::ApiKey => vec!["key", "name"],
+            AuthType::Digest => vec!["username", "password"],
         }
     }
 
     pub fn optional_fields(&self) -> Vec<&'static str> {
         match self {
-            AuthType::Bearer => vec![],
+            AuthType::Bearer => vec!["token", "token_file", "token_keychain"],
             AuthType::OAuth2AuthorizationCode => vec![
+                "authorization_url",
+                "token_url",
+                "issuer",
                 "client_secret",
                 "redirect_uri",
                 "scope",
@@ -85,9 +109,37 @@ impl AuthType {
                 "use_state",
             ],
             AuthType::OAuth2ClientCredentials => {
-                vec!["client_secret", "scope", "cert_file", "cert_password"]
+                vec![
+                    "token_url",
+                    "issuer",
+                    "client_secret",
+                    "scope",
+                    "cert_file",
+                    "cert_password",
+                ]
             }
-            AuthType::OAuth2Implicit => vec!["redirect_uri", "scope"],
+            AuthType::OAuth2Implicit => {
+                vec!["authorization_url", "issuer", "redirect_uri", "scope"]
+            }
+            AuthType::OAuth2RefreshToken => vec!["token_url", "issuer", "client_secret", "scope"],
+            AuthType::ApiKey => vec!["location"],
+            AuthType::Digest => vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthContext {
+    #[default]
+    Editor,
+    Cli,
+}
+
+impl AuthContext {
+    pub fn default_redirect_uri(self) -> &'static str {
+        match self {
+            AuthContext::Editor => EDITOR_REDIRECT_URI,
+            AuthContext::Cli => CLI_REDIRECT_URI,
         }
     }
 }
@@ -108,52 +160,61 @@ impl Config {
         auth_config.validate(&self.name, &self.fields)
     }
 
-    /// - redirect_uri: "vscode://rq-lang.rq-language/oauth-callback" (if not present)
-    /// - code_challenge_method: "S256" (if not present)
-    ///   fields.insert("client_id".to_string(), "my-client".to_string());
-    ///   name: "oauth".to_string(),
-    ///   assert_eq!(config.fields.get("redirect_uri").unwrap(), "vscode://rq-lang.rq-language/auth-callback");
-    ///   assert_eq!(config.fields.get("code_challenge_method").unwrap(), "S256");
-    pub fn apply_defaults(&mut self) {
-        match self.auth_type {
-            AuthType::OAuth2AuthorizationCode => {
-                if !self.fields.contains_key("redirect_uri") {
-                    self.fields.insert(
-                        "redirect_uri".to_string(),
-                        Token {
-                            token_type: crate::syntax::token::TokenType::String,
-                            value: "vscode://rq-lang.rq-language/oauth-callback".to_string(),
-                            span: 0..0,
-                        },
-                    );
-                }
-
-                if !self.fields.contains_key("code_challenge_method") {
-                    self.fields.insert(
-                        "code_challenge_method".to_string(),
-                        Token {
-                            token_type: crate::syntax::token::TokenType::String,
-                            value: "S256".to_string(),
-                            span: 0..0,
-                        },
-                    );
-                }
-            }
-            AuthType::OAuth2Implicit => {
-                if !self.fields.contains_key("redirect_uri") {
-                    self.fields.insert(
-                        "redirect_uri".to_string(),
-                        Token {
-                            token_type: crate::syntax::token::TokenType::String,
-                            value: "vscode://rq-lang.rq-language/oauth-callback".to_string(),
-                            span: 0..0,
-                        },
-                    );
-                }
-            }
-            AuthType::Bearer => {}
-            AuthType::OAuth2ClientCredentials => {}
+    pub fn apply_defaults(&mut self, context: AuthContext) -> Vec<&'static str> {
+        let defaults: &[(&'static str, &str)] = match self.auth_type {
+            AuthType::OAuth2AuthorizationCode => &[
+                ("redirect_uri", context.default_redirect_uri()),
+                ("code_challenge_method", "S256"),
+            ],
+            AuthType::OAuth2Implicit => &[("redirect_uri", context.default_redirect_uri())],
+            AuthType::Bearer => &[],
+            AuthType::OAuth2ClientCredentials => &[],
+            AuthType::OAuth2RefreshToken => &[],
+            AuthType::ApiKey => &[],
+            AuthType::Digest => &[],
+        };
+        defaults
+            .iter()
+            .filter(|(field, value)| self.insert_default(field, value))
+            .map(|(field, _)| *field)
+            .collect()
+    }
+
+    fn insert_default(&mut self, field: &str, value: &str) -> bool {
+        if self.fields.contains_key(field) {
+            return false;
         }
+        self.fields.insert(
+            field.to_string(),
+            Token {
+                token_type: crate::syntax::token::TokenType::String,
+                value: value.to_string(),
+                span: 0..0,
+            },
+        );
+        true
+    }
+}
+
+pub(crate) fn validate_endpoints_or_issuer(
+    label: &str,
+    name: &str,
+    fields: &HashMap<String, Token>,
+    endpoint_fields: &[&str],
+) -> Result<(), SyntaxError> {
+    if fields.contains_key(ISSUER_FIELD) {
+        return Ok(());
+    }
+    match endpoint_fields.iter().find(|f| !fields.contains_key(**f)) {
+        Some(field) => Err(SyntaxError::new(
+            format!(
+                "{label} auth '{name}' missing required field '{field}'. Set it explicitly or set '{ISSUER_FIELD}' to discover it"
+            ),
+            0,
+            0,
+            0..0,
+        )),
+        None => Ok(()),
     }
 }
 
@@ -173,6 +234,12 @@ mod tests {
     #[test]
     fn test_auth_type_from_str() {
         assert_eq!(AuthType::from_str("bearer").unwrap(), AuthType::Bearer);
+        assert_eq!(AuthType::from_str("api_key").unwrap(), AuthType::ApiKey);
+        assert_eq!(AuthType::from_str("digest").unwrap(), AuthType::Digest);
+        assert_eq!(
+            AuthType::from_str("oauth2_refresh_token").unwrap(),
+            AuthType::OAuth2RefreshToken
+        );
         assert_eq!(
             AuthType::from_str("oauth2_authorization_code").unwrap(),
             AuthType::OAuth2AuthorizationCode
@@ -187,6 +254,8 @@ mod tests {
     #[test]
     fn test_auth_type_as_str() {
         assert_eq!(AuthType::Bearer.as_str(), "bearer");
+        assert_eq!(AuthType::ApiKey.as_str(), "api_key");
+        assert_eq!(AuthType::Digest.as_str(), "digest");
         assert_eq!(
             AuthType::OAuth2AuthorizationCode.as_str(),
             "oauth2_authorization_code"
@@ -260,8 +329,9 @@ mod tests {
             character: 0,
         };
 
-        config.apply_defaults();
+        let inserted = config.apply_defaults(AuthContext::Editor);
 
+        assert_eq!(inserted, vec!["redirect_uri", "code_challenge_method"]);
         assert_eq!(
             config.fields.get("redirect_uri").unwrap().value,
             "vscode://rq-lang.rq-language/oauth-callback"
@@ -296,8 +366,9 @@ mod tests {
             character: 0,
         };
 
-        config.apply_defaults();
+        let inserted = config.apply_defaults(AuthContext::Cli);
 
+        assert!(inserted.is_empty());
         assert_eq!(
             config.fields.get("redirect_uri").unwrap().value,
             "http://localhost:3000/callback"
@@ -322,8 +393,35 @@ mod tests {
             character: 0,
         };
 
-        config.apply_defaults();
+        config.apply_defaults(AuthContext::Editor);
 
         assert_eq!(config.fields, fields);
     }
+
+    #[test]
+    fn test_config_apply_defaults_cli_uses_loopback_redirect() {
+        let mut fields = HashMap::new();
+        fields.insert("client_id".to_string(), t("my-client"));
+        fields.insert(
+            "authorization_url".to_string(),
+            t("https://auth.example.com/authorize"),
+        );
+
+        let mut config = Config {
+            name: "test_implicit".to_string(),
+            auth_type: AuthType::OAuth2Implicit,
+            fields,
+            file_path: PathBuf::new(),
+            line: 0,
+            character: 0,
+        };
+
+        config.apply_defaults(AuthContext::Cli);
+
+        assert_eq!(
+            config.fields.get("redirect_uri").unwrap().value,
+            CLI_REDIRECT_URI
+        );
+        assert!(!config.fields.contains_key("code_challenge_method"));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::paths::display_path;
 use std::path::Path;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -35,28 +36,10 @@ impl SyntaxError {
     }
 }
 
-fn clean_path(path: &Path) -> String {
-    let s = path.display().to_string();
-    #[cfg(windows)]
-    if let Some(stripped) = s.strip_prefix(r"\\?\") {
-        return stripped.to_string();
-    }
-    s
-}
-
 impl std::fmt::Display for SyntaxError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(file) = &self.file_path {
-            let path = Path::new(file);
-            let display_path = if let Ok(cwd) = std::env::current_dir() {
-                if let Ok(stripped) = path.strip_prefix(&cwd) {
-                    stripped.display().to_string()
-                } else {
-                    clean_path(path)
-                }
-            } else {
-                clean_path(path)
-            };
+            let display_path = display_path(Path::new(file));
             if self.line > 0 {
                 write!(
                     f,
@@ -2,6 +2,9 @@ use std::path::{Path, PathBuf};
 
 pub trait Fs: Send + Sync {
     fn read(&self, path: &Path) -> Result<String, String>;
+    fn read_bytes(&self, path: &Path) -> Result<Vec<u8>, String> {
+        self.read(path).map(String::into_bytes)
+    }
     fn resolve_path(&self, base: &Path, relative: &str) -> Result<PathBuf, String>;
     fn exists(&self, path: &Path) -> bool;
     fn is_file(&self, path: &Path) -> bool;
@@ -0,0 +1,70 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+pub struct CryptoHmacSha256;
+
+impl RqFunction for CryptoHmacSha256 {
+    fn namespace(&self) -> &str {
+        "crypto"
+    }
+
+    fn name(&self) -> &str {
+        "hmac_sha256"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+pub(super) fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(args[0].as_bytes())
+        .map_err(|e| format!("crypto.hmac_sha256: invalid key: {e}"))?;
+    mac.update(args[1].as_bytes());
+    Ok(super::to_hex(&mac.finalize().into_bytes()))
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() != 2 {
+        return Err(format!(
+            "crypto.hmac_sha256 takes exactly two arguments (key, input), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hmac(key: &str, input: &str) -> String {
+        generate(&[key.to_string(), input.to_string()]).unwrap()
+    }
+
+    #[test]
+    fn test_hmac_sha256_known_vectors() {
+        assert_eq!(
+            hmac("key", "The quick brown fox jumps over the lazy dog"),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+        assert_eq!(
+            hmac("Jefe", "what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256_requires_two_arguments() {
+        let err = CryptoHmacSha256
+            .validate_args(&["key".to_string()])
+            .unwrap_err();
+        assert!(err.contains("exactly two arguments"), "{err}");
+    }
+}
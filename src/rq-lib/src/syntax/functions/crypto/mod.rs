@@ -0,0 +1,6 @@
+pub mod hmac_sha256;
+pub mod sha256;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,62 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use sha2::{Digest, Sha256};
+
+pub struct CryptoSha256;
+
+impl RqFunction for CryptoSha256 {
+    fn namespace(&self) -> &str {
+        "crypto"
+    }
+
+    fn name(&self) -> &str {
+        "sha256"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+pub(super) fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    Ok(super::to_hex(&Sha256::digest(args[0].as_bytes())))
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() != 1 {
+        return Err(format!(
+            "crypto.sha256 takes exactly one argument (input), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_known_vectors() {
+        assert_eq!(
+            generate(&[String::new()]).unwrap(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            generate(&["abc".to_string()]).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_sha256_requires_one_argument() {
+        let err = CryptoSha256
+            .validate_args(&["a".to_string(), "b".to_string()])
+            .unwrap_err();
+        assert!(err.contains("exactly one argument"), "{err}");
+    }
+}
@@ -1 +1,2 @@
 pub mod now;
+pub mod timestamp;
@@ -1,6 +1,9 @@
 use super::super::traits::{FunctionContext, RqFunction};
+use chrono::format::{Item, StrftimeItems};
 use chrono::Local;
 
+const DEFAULT_FORMAT: &str = "%Y-%m-%dT%H:%M:%S.%3f%z";
+
 pub struct DateTimeNow;
 
 impl RqFunction for DateTimeNow {
@@ -13,27 +16,90 @@ impl RqFunction for DateTimeNow {
     }
 
     fn validate_args(&self, args: &[String]) -> Result<(), String> {
-        if args.len() > 1 {
-            return Err("datetime.now takes at most one argument (format)".to_string());
+        match args {
+            [] => Ok(()),
+            [format] if format.starts_with("{{") && format.ends_with("}}") => Ok(()),
+            [format] => parse_format(format).map(|_| ()),
+            _ => Err("datetime.now takes at most one argument (format)".to_string()),
         }
-        Ok(())
     }
 
     fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
-        let now = Local::now();
-        if args.is_empty() {
-            Ok(now.format("%Y-%m-%dT%H:%M:%S.%3f%z").to_string())
-        } else {
-            // https://docs.rs/chrono/latest/chrono/format/strftime/index.html
-            // Supports yyyy by replacing it with %Y to make it easier for users
-            let format = args[0]
-                .replace("yyyy", "%Y")
-                .replace("MM", "%m")
-                .replace("dd", "%d")
-                .replace("HH", "%H")
-                .replace("mm", "%M")
-                .replace("ss", "%S");
-            Ok(now.format(&format).to_string())
-        }
+        generate(args)
+    }
+}
+
+fn generate(args: &[String]) -> Result<String, String> {
+    if args.len() > 1 {
+        return Err("datetime.now takes at most one argument (format)".to_string());
+    }
+    let items = parse_format(args.first().map_or(DEFAULT_FORMAT, String::as_str))?;
+    Ok(Local::now()
+        .format_with_items(items.into_iter())
+        .to_string())
+}
+
+fn parse_format(format: &str) -> Result<Vec<Item<'static>>, String> {
+    let pattern = format
+        .replace("yyyy", "%Y")
+        .replace("MM", "%m")
+        .replace("dd", "%d")
+        .replace("HH", "%H")
+        .replace("mm", "%M")
+        .replace("ss", "%S");
+    StrftimeItems::new(&pattern)
+        .parse_to_owned()
+        .map_err(|_| format!("datetime.now format '{format}' is not a valid strftime pattern"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, NaiveDate};
+
+    fn run(args: &[&str]) -> Result<String, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        generate(&args)
+    }
+
+    #[test]
+    fn test_datetime_now_custom_pattern() {
+        let value = run(&["%Y-%m-%d"]).unwrap();
+        assert!(
+            NaiveDate::parse_from_str(&value, "%Y-%m-%d").is_ok(),
+            "{value}"
+        );
+        assert_eq!(
+            run(&["yyyy"]).unwrap(),
+            Local::now().format("%Y").to_string()
+        );
+    }
+
+    #[test]
+    fn test_datetime_now_default_format() {
+        let value = run(&[]).unwrap();
+        assert!(
+            DateTime::parse_from_str(&value, DEFAULT_FORMAT).is_ok(),
+            "{value}"
+        );
+    }
+
+    #[test]
+    fn test_datetime_now_rejects_too_many_args() {
+        let args = ["%Y".to_string(), "%m".to_string()];
+        let err = DateTimeNow.validate_args(&args).unwrap_err();
+        assert!(err.contains("at most one argument"), "{err}");
+        assert!(run(&["%Y", "%m"]).is_err());
+    }
+
+    #[test]
+    fn test_datetime_now_rejects_invalid_pattern() {
+        let err = DateTimeNow.validate_args(&["%Q".to_string()]).unwrap_err();
+        assert!(
+            err.contains("'%Q' is not a valid strftime pattern"),
+            "{err}"
+        );
+        assert!(run(&["%Q"]).is_err());
+        assert!(DateTimeNow.validate_args(&["{{fmt}}".to_string()]).is_ok());
     }
 }
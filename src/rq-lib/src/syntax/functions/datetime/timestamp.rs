@@ -0,0 +1,109 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use chrono::Utc;
+
+pub struct DateTimeTimestamp;
+
+impl RqFunction for DateTimeTimestamp {
+    fn namespace(&self) -> &str {
+        "datetime"
+    }
+
+    fn name(&self) -> &str {
+        "timestamp"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        match args {
+            [] => Ok(()),
+            [unit] if is_unresolved(unit) => Ok(()),
+            [unit] => parse_unit(unit).map(|_| ()),
+            _ => Err(format!(
+                "datetime.timestamp takes at most one argument (unit), got {}",
+                args.len()
+            )),
+        }
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+enum Unit {
+    Seconds,
+    Milliseconds,
+}
+
+fn generate(args: &[String]) -> Result<String, String> {
+    let unit = match args {
+        [] => Unit::Seconds,
+        [unit] => parse_unit(unit)?,
+        _ => {
+            return Err(format!(
+                "datetime.timestamp takes at most one argument (unit), got {}",
+                args.len()
+            ))
+        }
+    };
+    let now = Utc::now();
+    Ok(match unit {
+        Unit::Seconds => now.timestamp().to_string(),
+        Unit::Milliseconds => now.timestamp_millis().to_string(),
+    })
+}
+
+fn is_unresolved(arg: &str) -> bool {
+    arg.starts_with("{{") && arg.ends_with("}}")
+}
+
+fn parse_unit(arg: &str) -> Result<Unit, String> {
+    match arg.trim() {
+        "s" => Ok(Unit::Seconds),
+        "ms" => Ok(Unit::Milliseconds),
+        _ => Err(format!(
+            "datetime.timestamp unit must be 's' or 'ms', got '{arg}'"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<i64, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        generate(&args).map(|value| value.parse::<i64>().unwrap())
+    }
+
+    #[test]
+    fn test_timestamp_returns_epoch_seconds() {
+        let before = Utc::now().timestamp();
+        let value = run(&[]).unwrap();
+        assert!(value >= before && value <= Utc::now().timestamp());
+        assert!(run(&["s"]).unwrap() >= value);
+    }
+
+    #[test]
+    fn test_timestamp_ms_is_about_thousand_times_seconds() {
+        let seconds = run(&["s"]).unwrap();
+        let millis = run(&["ms"]).unwrap();
+        assert!(
+            (millis / 1000 - seconds).abs() <= 1,
+            "{millis} vs {seconds}"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_rejects_unknown_unit_and_extra_args() {
+        let err = DateTimeTimestamp
+            .validate_args(&["us".to_string()])
+            .unwrap_err();
+        assert!(err.contains("'s' or 'ms', got 'us'"), "{err}");
+        assert!(DateTimeTimestamp
+            .validate_args(&["s".to_string(), "ms".to_string()])
+            .is_err());
+        assert!(DateTimeTimestamp
+            .validate_args(&["{{unit}}".to_string()])
+            .is_ok());
+    }
+}
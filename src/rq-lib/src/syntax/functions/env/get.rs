@@ -0,0 +1,97 @@
+use super::super::traits::{FunctionContext, RqFunction};
+
+pub struct EnvGet;
+
+impl RqFunction for EnvGet {
+    fn namespace(&self) -> &str {
+        "env"
+    }
+
+    fn name(&self) -> &str {
+        "get"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_args(args)
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+fn generate(args: &[String]) -> Result<String, String> {
+    check_args(args)?;
+    let name = args[0].trim();
+    match (std::env::var(name), args.get(1)) {
+        (Ok(value), _) => Ok(value),
+        (Err(_), Some(default)) => Ok(default.clone()),
+        (Err(std::env::VarError::NotUnicode(_)), None) => Err(format!(
+            "env.get: environment variable '{name}' is not valid UTF-8"
+        )),
+        (Err(std::env::VarError::NotPresent), None) => {
+            Err(format!("env.get: environment variable '{name}' is not set"))
+        }
+    }
+}
+
+fn check_args(args: &[String]) -> Result<(), String> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(format!(
+            "env.get takes one or two arguments (name, default), got {}",
+            args.len()
+        ));
+    }
+    if args[0].trim().is_empty() {
+        return Err("env.get variable name cannot be empty".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<String, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        generate(&args)
+    }
+
+    #[test]
+    fn test_env_get_present() {
+        std::env::set_var("RQ_TEST_ENV_GET_PRESENT", "from-os");
+        assert_eq!(run(&["RQ_TEST_ENV_GET_PRESENT"]).unwrap(), "from-os");
+        assert_eq!(
+            run(&["RQ_TEST_ENV_GET_PRESENT", "fallback"]).unwrap(),
+            "from-os"
+        );
+    }
+
+    #[test]
+    fn test_env_get_missing() {
+        std::env::remove_var("RQ_TEST_ENV_GET_MISSING");
+        let err = run(&["RQ_TEST_ENV_GET_MISSING"]).unwrap_err();
+        assert!(
+            err.contains("'RQ_TEST_ENV_GET_MISSING' is not set"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_env_get_defaulted() {
+        std::env::remove_var("RQ_TEST_ENV_GET_DEFAULTED");
+        assert_eq!(
+            run(&["RQ_TEST_ENV_GET_DEFAULTED", "fallback"]).unwrap(),
+            "fallback"
+        );
+        assert_eq!(run(&["RQ_TEST_ENV_GET_DEFAULTED", ""]).unwrap(), "");
+    }
+
+    #[test]
+    fn test_env_get_validates_arguments() {
+        assert!(EnvGet.validate_args(&[]).is_err());
+        assert!(EnvGet.validate_args(&[" ".to_string()]).is_err());
+        let args = ["A".to_string(), "b".to_string(), "c".to_string()];
+        assert!(EnvGet.validate_args(&args).is_err());
+    }
+}
@@ -1 +1,3 @@
+pub mod read_bytes;
 pub mod read_file;
+pub mod read_json;
@@ -0,0 +1,45 @@
+use super::super::traits::{FunctionContext, FunctionReturnType, RqFunction};
+use std::path::PathBuf;
+
+pub struct IoReadBytes;
+
+impl RqFunction for IoReadBytes {
+    fn namespace(&self) -> &str {
+        "io"
+    }
+
+    fn name(&self) -> &str {
+        "read_bytes"
+    }
+
+    fn return_type(&self) -> FunctionReturnType {
+        FunctionReturnType::Bytes
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        if args.is_empty() {
+            return Err("io.read_bytes() requires a file path argument".to_string());
+        }
+        Ok(())
+    }
+
+    fn execute(&self, _args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        Err("io.read_bytes() returns raw bytes and can only be used as a request body".to_string())
+    }
+
+    fn execute_bytes(&self, args: &[String], ctx: &FunctionContext) -> Result<Vec<u8>, String> {
+        let file_path = &args[0];
+        let base = ctx
+            .source_files
+            .first()
+            .map(|p| p.as_path())
+            .unwrap_or(std::path::Path::new("."));
+        let resolved = ctx
+            .fs
+            .resolve_path(base, file_path)
+            .unwrap_or_else(|_| PathBuf::from(file_path));
+        ctx.fs
+            .read_bytes(&resolved)
+            .map_err(|e| format!("Error reading file {file_path}: {e}"))
+    }
+}
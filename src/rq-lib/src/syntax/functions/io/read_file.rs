@@ -1,6 +1,8 @@
 use super::super::traits::{FunctionContext, RqFunction};
 use std::path::PathBuf;
 
+const OPTION_TRIM: &str = "trim";
+
 pub struct IoReadFile;
 
 impl RqFunction for IoReadFile {
@@ -16,10 +18,14 @@ impl RqFunction for IoReadFile {
         if args.is_empty() {
             return Err("io.read_file() requires a file path argument".to_string());
         }
-        Ok(())
+        match args.get(1) {
+            Some(option) if option.starts_with("{{") && option.ends_with("}}") => check_arity(args),
+            _ => parse_trim(args).map(|_| ()),
+        }
     }
 
     fn execute(&self, args: &[String], ctx: &FunctionContext) -> Result<String, String> {
+        let trim = parse_trim(args)?;
         let file_path = &args[0];
         let base = ctx
             .source_files
@@ -30,8 +36,74 @@ impl RqFunction for IoReadFile {
             .fs
             .resolve_path(base, file_path)
             .unwrap_or_else(|_| PathBuf::from(file_path));
-        ctx.fs
+        let content = ctx
+            .fs
             .read(&resolved)
-            .map_err(|e| format!("Error reading file {file_path}: {e}"))
+            .map_err(|e| format!("Error reading file {file_path}: {e}"))?;
+        Ok(apply_trim(content, trim))
+    }
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() > 2 {
+        return Err(format!(
+            "io.read_file() takes at most two arguments (path, option), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+fn parse_trim(args: &[String]) -> Result<bool, String> {
+    check_arity(args)?;
+    match args.get(1).map(|option| option.trim()) {
+        None => Ok(false),
+        Some(OPTION_TRIM) => Ok(true),
+        Some(other) => Err(format!(
+            "io.read_file() option must be '{OPTION_TRIM}', got '{other}'"
+        )),
+    }
+}
+
+fn apply_trim(content: String, trim: bool) -> String {
+    if trim {
+        content.trim().to_string()
+    } else {
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_read_file_trim_strips_surrounding_whitespace() {
+        let trim = parse_trim(&args(&["token.txt", "trim"])).unwrap();
+        assert_eq!(apply_trim("  secret\r\n\n".to_string(), trim), "secret");
+    }
+
+    #[test]
+    fn test_read_file_keeps_content_without_option() {
+        let trim = parse_trim(&args(&["token.txt"])).unwrap();
+        assert_eq!(apply_trim("secret\n".to_string(), trim), "secret\n");
+    }
+
+    #[test]
+    fn test_read_file_rejects_unknown_option() {
+        let err = IoReadFile
+            .validate_args(&args(&["token.txt", "strip"]))
+            .unwrap_err();
+        assert!(err.contains("must be 'trim', got 'strip'"), "{err}");
+        assert!(IoReadFile
+            .validate_args(&args(&["token.txt", "trim", "extra"]))
+            .is_err());
+        assert!(IoReadFile
+            .validate_args(&args(&["token.txt", "{{mode}}"]))
+            .is_ok());
     }
 }
@@ -0,0 +1,175 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use serde_json::Value;
+use std::path::PathBuf;
+
+pub struct IoReadJson;
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+impl RqFunction for IoReadJson {
+    fn namespace(&self) -> &str {
+        "io"
+    }
+
+    fn name(&self) -> &str {
+        "read_json"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        if args.len() != 2 {
+            return Err(format!(
+                "io.read_json() requires a file path and a selector, got {} argument(s)",
+                args.len()
+            ));
+        }
+        let selector = &args[1];
+        if selector.starts_with("{{") && selector.ends_with("}}") {
+            return Ok(());
+        }
+        parse_selector(selector).map(|_| ())
+    }
+
+    fn execute(&self, args: &[String], ctx: &FunctionContext) -> Result<String, String> {
+        let file_path = &args[0];
+        let base = ctx
+            .source_files
+            .first()
+            .map(|p| p.as_path())
+            .unwrap_or(std::path::Path::new("."));
+        let resolved = ctx
+            .fs
+            .resolve_path(base, file_path)
+            .unwrap_or_else(|_| PathBuf::from(file_path));
+        let content = ctx
+            .fs
+            .read(&resolved)
+            .map_err(|e| format!("Error reading file {file_path}: {e}"))?;
+        let document: Value = serde_json::from_str(&content)
+            .map_err(|e| format!("io.read_json(): {file_path} is not valid JSON: {e}"))?;
+        extract(&document, &args[1])
+    }
+}
+
+fn extract(document: &Value, selector: &str) -> Result<String, String> {
+    let segments = parse_selector(selector)?;
+    let mut current = document;
+    for (position, segment) in segments.iter().enumerate() {
+        let next = match segment {
+            Segment::Key(key) => current.get(key),
+            Segment::Index(index) => current.get(*index),
+        };
+        current = next.ok_or_else(|| {
+            format!(
+                "io.read_json(): selector '{selector}' does not resolve: nothing at '{}'",
+                render_path(&segments[..=position])
+            )
+        })?;
+    }
+    match current {
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Null => Ok("null".to_string()),
+        Value::Array(_) | Value::Object(_) => Err(format!(
+            "io.read_json(): selector '{selector}' selects an object or array, expected a scalar"
+        )),
+    }
+}
+
+fn parse_selector(selector: &str) -> Result<Vec<Segment>, String> {
+    let invalid = |reason: &str| format!("io.read_json(): invalid selector '{selector}': {reason}");
+    let rest = selector
+        .trim()
+        .strip_prefix('$')
+        .ok_or_else(|| invalid("must start with '$'"))?;
+    let mut segments = Vec::new();
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                let mut key = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next == '.' || next == '[' {
+                        break;
+                    }
+                    key.push(next);
+                    chars.next();
+                }
+                if key.is_empty() {
+                    return Err(invalid("empty key after '.'"));
+                }
+                segments.push(Segment::Key(key));
+            }
+            '[' => {
+                let digits: String = chars.by_ref().take_while(|&next| next != ']').collect();
+                let index = digits
+                    .parse::<usize>()
+                    .map_err(|_| invalid(&format!("'[{digits}]' is not an array index")))?;
+                segments.push(Segment::Index(index));
+            }
+            other => return Err(invalid(&format!("unexpected character '{other}'"))),
+        }
+    }
+    Ok(segments)
+}
+
+fn render_path(segments: &[Segment]) -> String {
+    segments.iter().fold("$".to_string(), |mut path, segment| {
+        match segment {
+            Segment::Key(key) => path.push_str(&format!(".{key}")),
+            Segment::Index(index) => path.push_str(&format!("[{index}]")),
+        }
+        path
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fixture() -> Value {
+        json!({
+            "data": { "token": "abc123", "ttl": 3600, "active": true },
+            "users": [{ "name": "ada" }, { "name": "grace" }]
+        })
+    }
+
+    #[test]
+    fn test_read_json_selects_nested_scalar() {
+        assert_eq!(extract(&fixture(), "$.data.token").unwrap(), "abc123");
+        assert_eq!(extract(&fixture(), "$.data.ttl").unwrap(), "3600");
+        assert_eq!(extract(&fixture(), "$.data.active").unwrap(), "true");
+    }
+
+    #[test]
+    fn test_read_json_indexes_arrays() {
+        assert_eq!(extract(&fixture(), "$.users[1].name").unwrap(), "grace");
+        let err = extract(&fixture(), "$.users[5].name").unwrap_err();
+        assert!(err.contains("nothing at '$.users[5]'"), "{err}");
+    }
+
+    #[test]
+    fn test_read_json_reports_missing_key() {
+        let err = extract(&fixture(), "$.data.missing").unwrap_err();
+        assert!(
+            err.contains("selector '$.data.missing' does not resolve: nothing at '$.data.missing'"),
+            "{err}"
+        );
+        let err = extract(&fixture(), "$.data").unwrap_err();
+        assert!(err.contains("expected a scalar"), "{err}");
+    }
+
+    #[test]
+    fn test_read_json_rejects_malformed_selector() {
+        let args = |s: &str| vec!["fixture.json".to_string(), s.to_string()];
+        assert!(IoReadJson.validate_args(&args("data.token")).is_err());
+        assert!(IoReadJson.validate_args(&args("$.users[x]")).is_err());
+        assert!(IoReadJson.validate_args(&args("$..token")).is_err());
+        assert!(IoReadJson.validate_args(&args("{{selector}}")).is_ok());
+    }
+}
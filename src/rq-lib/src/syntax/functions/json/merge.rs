@@ -0,0 +1,92 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use serde_json::Value;
+
+pub struct JsonMerge;
+
+impl RqFunction for JsonMerge {
+    fn namespace(&self) -> &str {
+        "json"
+    }
+
+    fn name(&self) -> &str {
+        "merge"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        if args.len() < 2 {
+            return Err("json.merge() requires a base and at least one override".to_string());
+        }
+        Ok(())
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        let mut merged = parse_arg(args, 0)?;
+        for index in 1..args.len() {
+            merge_patch(&mut merged, parse_arg(args, index)?);
+        }
+        serde_json::to_string(&merged).map_err(|e| format!("json.merge() failed: {e}"))
+    }
+}
+
+fn parse_arg(args: &[String], index: usize) -> Result<Value, String> {
+    serde_json::from_str(&args[index])
+        .map_err(|e| format!("json.merge() argument {} is not valid JSON: {e}", index + 1))
+}
+
+fn merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(&key);
+            } else {
+                merge_patch(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn merge(base: Value, patch: Value) -> Value {
+        let mut target = base;
+        merge_patch(&mut target, patch);
+        target
+    }
+
+    #[test]
+    fn test_merge_is_deep() {
+        let merged = merge(
+            json!({"name": "a", "meta": {"tags": ["x"], "owner": "ops"}}),
+            json!({"id": 1, "meta": {"owner": "dev"}}),
+        );
+        assert_eq!(
+            merged,
+            json!({"id": 1, "name": "a", "meta": {"tags": ["x"], "owner": "dev"}})
+        );
+    }
+
+    #[test]
+    fn test_merge_null_removes_key() {
+        let merged = merge(json!({"a": 1, "b": 2}), json!({"b": null}));
+        assert_eq!(merged, json!({"a": 1}));
+    }
+
+    #[test]
+    fn test_merge_replaces_arrays_and_scalars() {
+        let merged = merge(
+            json!({"items": [1, 2], "n": 1}),
+            json!({"items": [3], "n": "x"}),
+        );
+        assert_eq!(merged, json!({"items": [3], "n": "x"}));
+    }
+}
@@ -1,6 +1,10 @@
+pub mod crypto;
 pub mod datetime;
+pub mod env;
 pub mod io;
+pub mod json;
 pub mod random;
+pub mod string;
 pub mod traits;
 
 use lazy_static::lazy_static;
@@ -11,8 +15,21 @@ lazy_static! {
     static ref FUNCTIONS: HashMap<String, Box<dyn RqFunction>> = {
         let mut m = HashMap::new();
         register(io::read_file::IoReadFile, &mut m);
+        register(io::read_bytes::IoReadBytes, &mut m);
+        register(io::read_json::IoReadJson, &mut m);
         register(random::guid::RandomGuid, &mut m);
+        register(random::int::RandomInt, &mut m);
+        register(random::string::RandomString, &mut m);
         register(datetime::now::DateTimeNow, &mut m);
+        register(datetime::timestamp::DateTimeTimestamp, &mut m);
+        register(json::merge::JsonMerge, &mut m);
+        register(env::get::EnvGet, &mut m);
+        register(crypto::sha256::CryptoSha256, &mut m);
+        register(crypto::hmac_sha256::CryptoHmacSha256, &mut m);
+        register(string::base64_encode::StringBase64Encode, &mut m);
+        register(string::base64_decode::StringBase64Decode, &mut m);
+        register(string::url_encode::StringUrlEncode, &mut m);
+        register(string::url_decode::StringUrlDecode, &mut m);
         m
     };
 }
@@ -28,5 +45,8 @@ pub fn get_function(namespace: &str, name: &str) -> Option<&'static dyn RqFuncti
 }
 
 pub fn is_known_namespace(namespace: &str) -> bool {
-    matches!(namespace, "random" | "datetime" | "io")
+    matches!(
+        namespace,
+        "random" | "datetime" | "io" | "json" | "string" | "env" | "crypto"
+    )
 }
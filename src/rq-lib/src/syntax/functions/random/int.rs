@@ -0,0 +1,105 @@
+use super::super::traits::{FunctionContext, RqFunction};
+
+pub struct RandomInt;
+
+impl RqFunction for RandomInt {
+    fn namespace(&self) -> &str {
+        "random"
+    }
+
+    fn name(&self) -> &str {
+        "int"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)?;
+        if args.iter().any(|arg| is_unresolved(arg)) {
+            return Ok(());
+        }
+        parse_bounds(args).map(|_| ())
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    let (min, max) = parse_bounds(args)?;
+    Ok(rand::random_range(min..=max).to_string())
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() != 2 {
+        return Err(format!(
+            "random.int takes exactly two arguments (min, max), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+fn is_unresolved(arg: &str) -> bool {
+    arg.starts_with("{{") && arg.ends_with("}}")
+}
+
+fn parse_bounds(args: &[String]) -> Result<(i64, i64), String> {
+    let parse = |arg: &String| {
+        arg.trim()
+            .parse::<i64>()
+            .map_err(|_| format!("random.int expects integer arguments, got '{arg}'"))
+    };
+    let (min, max) = (parse(&args[0])?, parse(&args[1])?);
+    if min > max {
+        return Err(format!(
+            "random.int min ({min}) must be less than or equal to max ({max})"
+        ));
+    }
+    Ok((min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<String, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        generate(&args)
+    }
+
+    #[test]
+    fn test_random_int_stays_within_inclusive_bounds() {
+        for _ in 0..200 {
+            let value: i64 = run(&["-3", "3"]).unwrap().parse().unwrap();
+            assert!((-3..=3).contains(&value));
+        }
+        assert_eq!(run(&["7", "7"]).unwrap(), "7");
+    }
+
+    #[test]
+    fn test_random_int_rejects_single_argument() {
+        let err = run(&["10"]).unwrap_err();
+        assert!(err.contains("exactly two arguments"), "{err}");
+        assert!(RandomInt.validate_args(&["10".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_random_int_rejects_non_numeric_arguments() {
+        let err = run(&["1", "ten"]).unwrap_err();
+        assert!(err.contains("integer arguments, got 'ten'"), "{err}");
+        assert!(RandomInt
+            .validate_args(&["a".to_string(), "2".to_string()])
+            .is_err());
+    }
+
+    #[test]
+    fn test_random_int_validates_min_not_above_max() {
+        assert!(RandomInt
+            .validate_args(&["5".to_string(), "1".to_string()])
+            .is_err());
+        assert!(RandomInt
+            .validate_args(&["{{min}}".to_string(), "1".to_string()])
+            .is_ok());
+    }
+}
@@ -1 +1,3 @@
 pub mod guid;
+pub mod int;
+pub mod string;
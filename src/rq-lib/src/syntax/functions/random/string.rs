@@ -0,0 +1,111 @@
+use super::super::traits::{FunctionContext, RqFunction};
+
+const MAX_LENGTH: usize = 4096;
+const ALPHANUMERIC: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+pub struct RandomString;
+
+impl RqFunction for RandomString {
+    fn namespace(&self) -> &str {
+        "random"
+    }
+
+    fn name(&self) -> &str {
+        "string"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)?;
+        if !is_unresolved(&args[0]) {
+            parse_length(&args[0])?;
+        }
+        match args.get(1) {
+            Some(charset) if !is_unresolved(charset) => parse_charset(charset).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    let length = parse_length(&args[0])?;
+    let charset = parse_charset(args.get(1).map_or(ALPHANUMERIC, String::as_str))?;
+    Ok((0..length)
+        .map(|_| charset[rand::random_range(0..charset.len())])
+        .collect())
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(format!(
+            "random.string takes a length and an optional character set, got {} arguments",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+fn is_unresolved(arg: &str) -> bool {
+    arg.starts_with("{{") && arg.ends_with("}}")
+}
+
+fn parse_length(arg: &str) -> Result<usize, String> {
+    match arg.trim().parse::<usize>() {
+        Ok(length) if (1..=MAX_LENGTH).contains(&length) => Ok(length),
+        _ => Err(format!(
+            "random.string length must be a whole number between 1 and {MAX_LENGTH}, got '{arg}'"
+        )),
+    }
+}
+
+fn parse_charset(arg: &str) -> Result<Vec<char>, String> {
+    let charset: Vec<char> = arg.chars().collect();
+    if charset.is_empty() {
+        return Err("random.string character set cannot be empty".to_string());
+    }
+    Ok(charset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<String, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        generate(&args)
+    }
+
+    #[test]
+    fn test_random_string_has_requested_length_and_alphabet() {
+        let value = run(&["16"]).unwrap();
+        assert_eq!(value.chars().count(), 16);
+        assert!(value.chars().all(|c| c.is_ascii_alphanumeric()), "{value}");
+        assert_eq!(run(&["4096"]).unwrap().len(), 4096);
+    }
+
+    #[test]
+    fn test_random_string_rejects_length_outside_cap() {
+        for length in ["0", "4097", "-1", "ten"] {
+            let err = run(&[length]).unwrap_err();
+            assert!(err.contains("between 1 and 4096"), "{err}");
+            assert!(RandomString.validate_args(&[length.to_string()]).is_err());
+        }
+        assert!(RandomString.validate_args(&[]).is_err());
+        assert!(RandomString
+            .validate_args(&["{{length}}".to_string()])
+            .is_ok());
+    }
+
+    #[test]
+    fn test_random_string_uses_custom_charset() {
+        let value = run(&["32", "ab"]).unwrap();
+        assert_eq!(value.len(), 32);
+        assert!(value.chars().all(|c| c == 'a' || c == 'b'), "{value}");
+        assert_eq!(run(&["3", "é"]).unwrap(), "ééé");
+        assert!(run(&["3", ""]).unwrap_err().contains("cannot be empty"));
+    }
+}
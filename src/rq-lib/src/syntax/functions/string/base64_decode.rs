@@ -0,0 +1,62 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+pub struct StringBase64Decode;
+
+impl RqFunction for StringBase64Decode {
+    fn namespace(&self) -> &str {
+        "string"
+    }
+
+    fn name(&self) -> &str {
+        "base64_decode"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    let bytes = STANDARD
+        .decode(args[0].trim())
+        .map_err(|e| format!("string.base64_decode input is not valid base64: {e}"))?;
+    String::from_utf8(bytes)
+        .map_err(|_| "string.base64_decode result is not valid UTF-8 text".to_string())
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() != 1 {
+        return Err(format!(
+            "string.base64_decode takes exactly one argument (value), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_round_trip() {
+        for value in ["user:pass", "", "héllo wörld", "a?b/c+d"] {
+            let encoded = super::super::base64_encode::generate(&[value.to_string()]).unwrap();
+            assert_eq!(generate(&[encoded]).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_base64_decode_rejects_invalid_input() {
+        let err = generate(&["not base64!".to_string()]).unwrap_err();
+        assert!(err.contains("not valid base64"), "{err}");
+        let err = generate(&["/w==".to_string()]).unwrap_err();
+        assert!(err.contains("not valid UTF-8"), "{err}");
+    }
+}
@@ -0,0 +1,57 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+pub struct StringBase64Encode;
+
+impl RqFunction for StringBase64Encode {
+    fn namespace(&self) -> &str {
+        "string"
+    }
+
+    fn name(&self) -> &str {
+        "base64_encode"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+pub(super) fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    Ok(STANDARD.encode(args[0].as_bytes()))
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() != 1 {
+        return Err(format!(
+            "string.base64_encode takes exactly one argument (value), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode_basic_credentials() {
+        assert_eq!(
+            generate(&["user:pass".to_string()]).unwrap(),
+            "dXNlcjpwYXNz"
+        );
+        assert_eq!(generate(&[String::new()]).unwrap(), "");
+    }
+
+    #[test]
+    fn test_base64_encode_requires_one_argument() {
+        let err = StringBase64Encode.validate_args(&[]).unwrap_err();
+        assert!(err.contains("exactly one argument"), "{err}");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod base64_decode;
+pub mod base64_encode;
+pub mod url_decode;
+pub mod url_encode;
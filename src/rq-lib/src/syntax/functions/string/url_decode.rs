@@ -0,0 +1,95 @@
+use super::super::traits::{FunctionContext, RqFunction};
+
+pub struct StringUrlDecode;
+
+impl RqFunction for StringUrlDecode {
+    fn namespace(&self) -> &str {
+        "string"
+    }
+
+    fn name(&self) -> &str {
+        "url_decode"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    let input = args[0].as_bytes();
+    let mut decoded = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        if input[i] != b'%' {
+            decoded.push(input[i]);
+            i += 1;
+            continue;
+        }
+        let byte = input
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| {
+                format!(
+                    "string.url_decode found an invalid escape at position {i} in '{}'",
+                    args[0]
+                )
+            })?;
+        decoded.push(byte);
+        i += 3;
+    }
+    String::from_utf8(decoded)
+        .map_err(|_| "string.url_decode result is not valid UTF-8 text".to_string())
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() != 1 {
+        return Err(format!(
+            "string.url_decode takes exactly one argument (value), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(value: &str) -> Result<String, String> {
+        generate(&[value.to_string()])
+    }
+
+    #[test]
+    fn test_url_decode_round_trip() {
+        for value in [
+            "a b&c",
+            ":/?#[]@!$&'()*+,;=",
+            "héllo wörld",
+            "plain-text_~.",
+        ] {
+            let encoded = super::super::url_encode::generate(&[value.to_string()]).unwrap();
+            assert_eq!(run(&encoded).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_url_decode_keeps_plus_and_accepts_lowercase_hex() {
+        assert_eq!(run("a+b%2fc").unwrap(), "a+b/c");
+    }
+
+    #[test]
+    fn test_url_decode_rejects_invalid_escapes() {
+        let err = run("100%").unwrap_err();
+        assert!(err.contains("invalid escape at position 3"), "{err}");
+        assert!(run("%zz").is_err());
+        let err = run("%FF").unwrap_err();
+        assert!(err.contains("not valid UTF-8"), "{err}");
+    }
+}
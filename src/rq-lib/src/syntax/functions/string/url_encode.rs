@@ -0,0 +1,80 @@
+use super::super::traits::{FunctionContext, RqFunction};
+use std::fmt::Write as _;
+
+pub struct StringUrlEncode;
+
+impl RqFunction for StringUrlEncode {
+    fn namespace(&self) -> &str {
+        "string"
+    }
+
+    fn name(&self) -> &str {
+        "url_encode"
+    }
+
+    fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        check_arity(args)
+    }
+
+    fn execute(&self, args: &[String], _ctx: &FunctionContext) -> Result<String, String> {
+        generate(args)
+    }
+}
+
+pub(super) fn generate(args: &[String]) -> Result<String, String> {
+    check_arity(args)?;
+    let mut encoded = String::with_capacity(args[0].len());
+    for byte in args[0].bytes() {
+        if is_unreserved(byte) {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    Ok(encoded)
+}
+
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+fn check_arity(args: &[String]) -> Result<(), String> {
+    if args.len() != 1 {
+        return Err(format!(
+            "string.url_encode takes exactly one argument (value), got {}",
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(value: &str) -> String {
+        generate(&[value.to_string()]).unwrap()
+    }
+
+    #[test]
+    fn test_url_encode_spaces() {
+        assert_eq!(run("a b&c"), "a%20b%26c");
+        assert_eq!(run("  "), "%20%20");
+    }
+
+    #[test]
+    fn test_url_encode_reserved_characters() {
+        assert_eq!(
+            run(":/?#[]@!$&'()*+,;="),
+            "%3A%2F%3F%23%5B%5D%40%21%24%26%27%28%29%2A%2B%2C%3B%3D"
+        );
+        assert_eq!(run("AZaz09-._~"), "AZaz09-._~");
+        assert_eq!(run("é"), "%C3%A9");
+    }
+
+    #[test]
+    fn test_url_encode_requires_one_argument() {
+        let err = StringUrlEncode.validate_args(&[]).unwrap_err();
+        assert!(err.contains("exactly one argument"), "{err}");
+    }
+}
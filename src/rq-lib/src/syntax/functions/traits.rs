@@ -11,6 +11,7 @@ pub struct FunctionContext<'a> {
 pub enum FunctionReturnType {
     String,
     Headers,
+    Bytes,
 }
 
 pub trait RqFunction: Send + Sync {
@@ -30,4 +31,8 @@ pub trait RqFunction: Send + Sync {
     }
 
     fn execute(&self, args: &[String], ctx: &FunctionContext) -> Result<String, String>;
+
+    fn execute_bytes(&self, args: &[String], ctx: &FunctionContext) -> Result<Vec<u8>, String> {
+        self.execute(args, ctx).map(String::into_bytes)
+    }
 }
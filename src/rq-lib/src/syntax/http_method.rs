@@ -34,6 +34,9 @@ impl HttpMethod {
             Self::OPTIONS => "OPTIONS",
         }
     }
+    pub fn is_mutating(&self) -> bool {
+        matches!(self, Self::POST | Self::PUT | Self::PATCH | Self::DELETE)
+    }
 }
 
 #[cfg(test)]
@@ -66,4 +69,16 @@ mod tests {
         assert_eq!(HttpMethod::HEAD.as_str(), "HEAD");
         assert_eq!(HttpMethod::OPTIONS.as_str(), "OPTIONS");
     }
+
+    #[test]
+    fn test_is_mutating() {
+        assert!(HttpMethod::POST.is_mutating());
+        assert!(HttpMethod::PUT.is_mutating());
+        assert!(HttpMethod::PATCH.is_mutating());
+        assert!(HttpMethod::DELETE.is_mutating());
+
+        assert!(!HttpMethod::GET.is_mutating());
+        assert!(!HttpMethod::HEAD.is_mutating());
+        assert!(!HttpMethod::OPTIONS.is_mutating());
+    }
 }
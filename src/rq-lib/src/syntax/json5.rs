@@ -0,0 +1,343 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct Json5Error {
+    pub message: String,
+    pub offset: usize,
+}
+
+pub fn to_json(source: &str) -> Result<String, Json5Error> {
+    let mut reader = Json5Reader {
+        source,
+        pos: 0,
+        out: String::with_capacity(source.len()),
+    };
+    reader.parse_value()?;
+    reader.skip_trivia()?;
+    if reader.pos < source.len() {
+        return Err(reader.error("Unexpected content after JSON value"));
+    }
+    Ok(reader.out)
+}
+
+struct Json5Reader<'a> {
+    source: &'a str,
+    pos: usize,
+    out: String,
+}
+
+impl Json5Reader<'_> {
+    fn parse_value(&mut self) -> Result<(), Json5Error> {
+        self.skip_trivia()?;
+        match self.peek() {
+            Some('{') if self.rest().starts_with("{{") => self.parse_placeholder(),
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') | Some('\'') => self.parse_string(),
+            Some(c) if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => self.parse_number(),
+            Some(c) if is_identifier_start(c) => self.parse_literal(),
+            Some(c) => Err(self.error(&format!("Unexpected character '{c}'"))),
+            None => Err(self.error("Unexpected end of JSON5 body")),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<(), Json5Error> {
+        self.bump();
+        self.out.push('{');
+        loop {
+            self.skip_trivia()?;
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    self.out.push('}');
+                    return Ok(());
+                }
+                Some('"') | Some('\'') => self.parse_string()?,
+                Some('{') if self.rest().starts_with("{{") => self.parse_placeholder()?,
+                Some(c) if is_identifier_start(c) => {
+                    let key = self.read_identifier();
+                    self.out.push('"');
+                    self.out.push_str(&key);
+                    self.out.push('"');
+                }
+                _ => return Err(self.error("Expected object key or '}'")),
+            }
+            self.skip_trivia()?;
+            if self.peek() != Some(':') {
+                return Err(self.error("Expected ':' after object key"));
+            }
+            self.bump();
+            self.out.push(':');
+            self.parse_value()?;
+            if !self.parse_separator('}')? {
+                return Err(self.error("Expected ',' or '}'"));
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<(), Json5Error> {
+        self.bump();
+        self.out.push('[');
+        loop {
+            self.skip_trivia()?;
+            if self.peek() == Some(']') {
+                self.bump();
+                self.out.push(']');
+                return Ok(());
+            }
+            self.parse_value()?;
+            if !self.parse_separator(']')? {
+                return Err(self.error("Expected ',' or ']'"));
+            }
+        }
+    }
+
+    fn parse_separator(&mut self, close: char) -> Result<bool, Json5Error> {
+        self.skip_trivia()?;
+        match self.peek() {
+            Some(',') => {
+                self.bump();
+                self.skip_trivia()?;
+                if self.peek() != Some(close) {
+                    self.out.push(',');
+                }
+                Ok(true)
+            }
+            Some(c) if c == close => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<(), Json5Error> {
+        let start = self.pos;
+        let quote = self.bump().unwrap_or('"');
+        self.out.push('"');
+        loop {
+            match self.bump() {
+                Some('\\') => match self.bump() {
+                    Some('\'') => self.out.push('\''),
+                    Some('\n') => {}
+                    Some(c) => {
+                        self.out.push('\\');
+                        self.out.push(c);
+                    }
+                    None => break,
+                },
+                Some('"') if quote == '\'' => self.out.push_str("\\\""),
+                Some(c) if c == quote => {
+                    self.out.push('"');
+                    return Ok(());
+                }
+                Some('\n') => break,
+                Some(c) => self.out.push(c),
+                None => break,
+            }
+        }
+        Err(Json5Error {
+            message: "Unterminated string".to_string(),
+            offset: start,
+        })
+    }
+
+    fn parse_number(&mut self) -> Result<(), Json5Error> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        let raw = &self.source[start..self.pos];
+        normalize_number(raw)
+            .map(|n| self.out.push_str(&n))
+            .ok_or(Json5Error {
+                message: format!("Invalid number '{raw}'"),
+                offset: start,
+            })
+    }
+
+    fn parse_literal(&mut self) -> Result<(), Json5Error> {
+        let start = self.pos;
+        let word = self.read_identifier();
+        match word.as_str() {
+            "true" | "false" | "null" => {
+                self.out.push_str(&word);
+                Ok(())
+            }
+            "Infinity" | "NaN" => Err(Json5Error {
+                message: format!("'{word}' cannot be represented in JSON"),
+                offset: start,
+            }),
+            _ => Err(Json5Error {
+                message: format!("Unexpected identifier '{word}'"),
+                offset: start,
+            }),
+        }
+    }
+
+    fn parse_placeholder(&mut self) -> Result<(), Json5Error> {
+        let start = self.pos;
+        match self.rest().find("}}") {
+            Some(end) => {
+                self.out.push_str(&self.source[start..start + end + 2]);
+                self.pos += end + 2;
+                Ok(())
+            }
+            None => Err(Json5Error {
+                message: "Unterminated variable placeholder".to_string(),
+                offset: start,
+            }),
+        }
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), Json5Error> {
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("//") {
+                self.pos += rest.find('\n').unwrap_or(rest.len());
+            } else if rest.starts_with("/*") {
+                let end = rest
+                    .find("*/")
+                    .ok_or_else(|| self.error("Unterminated comment"))?;
+                self.pos += end + 2;
+            } else if self.peek().map(char::is_whitespace).unwrap_or(false) {
+                self.bump();
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if is_identifier_start(c) || c.is_ascii_digit() {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        self.source[start..self.pos].to_string()
+    }
+
+    fn rest(&self) -> &str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self, message: &str) -> Json5Error {
+        Json5Error {
+            message: message.to_string(),
+            offset: self.pos,
+        }
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn normalize_number(raw: &str) -> Option<String> {
+    let (sign, digits) = match raw.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", raw.strip_prefix('+').unwrap_or(raw)),
+    };
+    if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        return u64::from_str_radix(hex, 16)
+            .ok()
+            .map(|n| format!("{sign}{n}"));
+    }
+    let mut number = digits.to_string();
+    if number.starts_with('.') {
+        number.insert(0, '0');
+    }
+    if number.ends_with('.') {
+        number.pop();
+    }
+    number = number.replace(".e", "e").replace(".E", "E");
+    number.parse::<f64>().ok()?;
+    if number
+        .chars()
+        .any(|c| c.is_alphabetic() && c != 'e' && c != 'E')
+    {
+        return None;
+    }
+    Some(format!("{sign}{number}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_json_is_unchanged() {
+        assert_eq!(
+            to_json(r#"{"a": 1, "b": [true, null]}"#).unwrap(),
+            r#"{"a":1,"b":[true,null]}"#
+        );
+    }
+
+    #[test]
+    fn unquoted_keys_are_quoted() {
+        assert_eq!(to_json("{name: 'rq'}").unwrap(), r#"{"name":"rq"}"#);
+    }
+
+    #[test]
+    fn trailing_commas_are_removed() {
+        assert_eq!(to_json("{a: [1, 2,],}").unwrap(), r#"{"a":[1,2]}"#);
+    }
+
+    #[test]
+    fn comments_are_stripped() {
+        assert_eq!(
+            to_json("{\n  // line\n  a: /* inline */ 1\n}").unwrap(),
+            r#"{"a":1}"#
+        );
+    }
+
+    #[test]
+    fn single_quoted_strings_escape_double_quotes() {
+        assert_eq!(
+            to_json(r#"{a: 'say "hi"'}"#).unwrap(),
+            r#"{"a":"say \"hi\""}"#
+        );
+    }
+
+    #[test]
+    fn relaxed_numbers_are_normalized() {
+        assert_eq!(
+            to_json("[+1, .5, 5., 0x1F, -2e3]").unwrap(),
+            "[1,0.5,5,31,-2e3]"
+        );
+    }
+
+    #[test]
+    fn placeholders_are_preserved() {
+        assert_eq!(
+            to_json("{count: {{count}}, name: '{{name}}'}").unwrap(),
+            r#"{"count":{{count}},"name":"{{name}}"}"#
+        );
+    }
+
+    #[test]
+    fn missing_colon_reports_offset() {
+        let err = to_json("{a 1}").unwrap_err();
+        assert_eq!(err.offset, 3);
+    }
+
+    #[test]
+    fn infinity_is_rejected() {
+        assert!(to_json("{a: Infinity}").is_err());
+    }
+}
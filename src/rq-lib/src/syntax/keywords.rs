@@ -1,11 +1,19 @@
 pub const KW_LET: &str = "let";
+pub const KW_EXPORT: &str = "export";
 pub const KW_RQ: &str = "rq";
 pub const KW_EP: &str = "ep";
 pub const KW_ENV: &str = "env";
 pub const KW_AUTH: &str = "auth";
 pub const KW_IMPORT: &str = "import";
+pub const KW_IMPORT_ENV: &str = "import_env";
+pub const KW_SUITE: &str = "suite";
+pub const KW_PROFILE: &str = "profile";
 pub const ALL_KEYWORDS: &[&str] = &[KW_LET, KW_RQ, KW_EP, KW_ENV, KW_AUTH, KW_IMPORT];
 
+pub const BODY_FORM: &str = "form";
+pub const BODY_MULTIPART: &str = "multipart";
+pub const HEADER_WHEN_BODY: &str = "when_body";
+
 pub const PUNC_LBRACE: &str = "{";
 pub const PUNC_RBRACE: &str = "}";
 #[allow(dead_code)]
@@ -19,6 +27,7 @@ pub const PUNC_SEMI: &str = ";";
 pub const PUNC_COMMA: &str = ",";
 pub const PUNC_DOT: &str = ".";
 pub const PUNC_DOLLAR: &str = "$";
+pub const PUNC_AT: &str = "@";
 
 pub const OP_ASSIGN: &str = "=";
 #[allow(dead_code)]
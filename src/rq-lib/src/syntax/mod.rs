@@ -1,9 +1,12 @@
 pub mod analysis;
+pub mod assertion;
 pub mod auth;
+pub mod duration;
 pub mod error;
 pub mod fs;
 pub mod functions;
 pub mod http_method;
+pub mod json5;
 pub mod keywords;
 pub mod parse_result;
 pub mod parsers;
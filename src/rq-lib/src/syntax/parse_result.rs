@@ -1,6 +1,11 @@
+use crate::syntax::assertion::{Assertion, AssertionTarget};
 use crate::syntax::auth::Config as AuthConfig;
 use crate::syntax::http_method::HttpMethod;
 use crate::syntax::variable_context::Variable;
+use std::time::Duration;
+
+pub const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct EndpointDefinition {
@@ -9,11 +14,13 @@ pub struct EndpointDefinition {
     pub headers: Vec<(String, String)>,
     pub headers_var: Option<String>,
     pub qs: Option<String>,
-    pub auth: Option<String>,
+    pub auth: Vec<String>,
     pub timeout: Option<String>,
+    pub cert: Option<ClientCert>,
     pub variables: Vec<Variable>,
     pub has_requests: bool,
     pub is_template: bool,
+    pub parent: Option<String>,
     pub source_path: Option<String>,
     pub related_files: Vec<String>,
     pub line: usize,
@@ -28,10 +35,30 @@ pub struct Request {
     pub method: HttpMethod,
     pub headers: Vec<(String, String)>,
     pub body: Option<String>,
+    pub form: Option<Vec<(String, String)>>,
+    pub multipart: Option<Vec<MultipartPart>>,
+    pub body_lines: Option<Vec<String>>,
+    pub body_file: Option<String>,
+    pub text_file: Option<BodyFile>,
+    pub body_bytes: Option<Vec<u8>>,
     pub headers_var: Option<String>,
     pub endpoint: Option<String>,
-    pub auth: Option<String>,
+    pub suite: Option<String>,
+    pub suite_headers_var: Option<String>,
+    pub auth: Vec<String>,
     pub timeout: Option<String>,
+    pub cert: Option<ClientCert>,
+    pub retry: Option<RetryPolicy>,
+    pub redirects: Option<RedirectPolicy>,
+    pub idempotency_header: Option<String>,
+    pub output: Option<OutputFormat>,
+    pub unix_socket: Option<String>,
+    pub description: Option<String>,
+    pub reauth: bool,
+    pub skip: bool,
+    pub assertions: Vec<Assertion>,
+    pub captures: Vec<Capture>,
+    pub max_redirects: Option<usize>,
     pub required_variables: Vec<String>,
     pub source_path: Option<String>,
     pub related_files: Vec<String>,
@@ -39,6 +66,89 @@ pub struct Request {
     pub character: usize,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct BodyFile {
+    pub path: String,
+    pub raw: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultipartPart {
+    pub name: String,
+    pub value: MultipartValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MultipartValue {
+    Text(String),
+    File {
+        path: String,
+        content: Option<Vec<u8>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedirectPolicy {
+    pub follow: bool,
+    pub max: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientCert {
+    pub path: String,
+    pub password: Option<String>,
+    pub source_path: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Capture {
+    pub variable: String,
+    pub expression: String,
+    pub target: AssertionTarget,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub count: u32,
+    pub on: Option<Vec<u16>>,
+    pub backoff: Option<Duration>,
+}
+
+impl RetryPolicy {
+    pub fn should_retry(&self, status: u16) -> bool {
+        match &self.on {
+            Some(statuses) => statuses.contains(&status),
+            None => status == 429 || (500..600).contains(&status),
+        }
+    }
+
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .backoff
+            .unwrap_or(DEFAULT_RETRY_BACKOFF)
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(MAX_RETRY_BACKOFF);
+        exponential + exponential.mul_f64(rand::random::<f64>() / 2.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RequestWithVariables {
     pub request: Request,
@@ -51,6 +161,8 @@ pub struct ParseResult {
     pub requests: Vec<RequestWithVariables>,
     pub environments: std::collections::HashMap<String, Vec<Variable>>,
     pub environment_locations: std::collections::HashMap<String, (String, usize, usize)>,
+    pub profiles: std::collections::HashMap<String, Vec<Variable>>,
+    pub suites: std::collections::HashSet<String>,
     pub auth_providers: std::collections::HashMap<String, AuthConfig>,
     pub endpoints: std::collections::HashMap<String, EndpointDefinition>,
     pub file_variables: Vec<Variable>,
@@ -61,4 +173,36 @@ pub struct ParseResult {
         std::collections::HashMap<String, (String, usize, usize)>,
     >,
     pub required_variable_locations: std::collections::HashMap<String, (String, usize, usize)>,
+    pub default_request: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(backoff: Option<Duration>) -> RetryPolicy {
+        RetryPolicy {
+            count: 5,
+            on: None,
+            backoff,
+        }
+    }
+
+    #[test]
+    fn test_retry_delay_without_backoff_uses_default() {
+        let delay = policy(None).delay(1);
+        assert!(delay >= DEFAULT_RETRY_BACKOFF, "{delay:?}");
+        assert!(delay <= DEFAULT_RETRY_BACKOFF.mul_f64(1.5), "{delay:?}");
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_with_jitter_and_cap() {
+        let retry = policy(Some(Duration::from_millis(100)));
+        for (attempt, base) in [(1, 100), (2, 200), (3, 400)] {
+            let delay = retry.delay(attempt);
+            assert!(delay >= Duration::from_millis(base), "{delay:?}");
+            assert!(delay <= Duration::from_millis(base * 3 / 2), "{delay:?}");
+        }
+        assert!(retry.delay(20) <= MAX_RETRY_BACKOFF.mul_f64(1.5));
+    }
 }
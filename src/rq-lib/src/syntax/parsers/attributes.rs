@@ -1,12 +1,21 @@
-use super::utils::unescape_string;
+use std::time::Duration;
+
+use super::utils::{parse_bool, unescape_string};
 use crate::syntax::{
+    assertion::{parse_target, Assertion},
+    duration::parse_duration,
     error::SyntaxError,
     http_method::HttpMethod,
-    keywords::{PUNC_DOLLAR, PUNC_LBRACKET, PUNC_LPAREN, PUNC_RBRACKET, PUNC_RPAREN},
+    keywords::{
+        OP_ASSIGN, PUNC_COMMA, PUNC_DOLLAR, PUNC_LBRACKET, PUNC_LPAREN, PUNC_RBRACKET, PUNC_RPAREN,
+    },
+    parse_result::{Capture, ClientCert, OutputFormat, RedirectPolicy, RetryPolicy},
     reader::{expect, TokenReader},
-    token::TokenType,
+    token::{Token, TokenType},
 };
 
+pub const DEFAULT_IDEMPOTENCY_HEADER: &str = "Idempotency-Key";
+
 pub struct RequiredVariable {
     pub name: String,
     pub line: usize,
@@ -16,8 +25,19 @@ pub struct RequiredVariable {
 #[derive(Default)]
 pub struct AttributeContext {
     pub method: Option<HttpMethod>,
-    pub auth: Option<String>,
+    pub auth: Vec<String>,
     pub timeout: Option<String>,
+    pub cert: Option<ClientCert>,
+    pub retry: Option<RetryPolicy>,
+    pub redirects: Option<RedirectPolicy>,
+    pub idempotency_header: Option<String>,
+    pub output: Option<OutputFormat>,
+    pub unix_socket: Option<String>,
+    pub description: Option<String>,
+    pub reauth: bool,
+    pub skip: bool,
+    pub assertions: Vec<Assertion>,
+    pub captures: Vec<Capture>,
     pub required_variables: Vec<RequiredVariable>,
 }
 
@@ -29,11 +49,11 @@ impl AttributeContext {
         self.method = Some(method);
         Ok(())
     }
-    pub fn set_auth(&mut self, auth: String) -> Result<(), String> {
-        if self.auth.is_some() {
-            return Err("Duplicate attribute 'auth'".to_string());
+    pub fn add_auth(&mut self, auth: String) -> Result<(), String> {
+        if self.auth.contains(&auth) {
+            return Err(format!("Duplicate auth '{auth}'"));
         }
-        self.auth = Some(auth);
+        self.auth.push(auth);
         Ok(())
     }
     pub fn set_timeout(&mut self, timeout: String) -> Result<(), String> {
@@ -43,6 +63,79 @@ impl AttributeContext {
         self.timeout = Some(timeout);
         Ok(())
     }
+    pub fn set_cert(&mut self, cert: ClientCert) -> Result<(), String> {
+        if self.cert.is_some() {
+            return Err("Duplicate attribute 'cert'".to_string());
+        }
+        self.cert = Some(cert);
+        Ok(())
+    }
+    pub fn set_retry(&mut self, retry: RetryPolicy) -> Result<(), String> {
+        if self.retry.is_some() {
+            return Err("Duplicate attribute 'retry'".to_string());
+        }
+        self.retry = Some(retry);
+        Ok(())
+    }
+    pub fn set_redirects(&mut self, redirects: RedirectPolicy) -> Result<(), String> {
+        if self.redirects.is_some() {
+            return Err("Duplicate attribute 'redirects'".to_string());
+        }
+        self.redirects = Some(redirects);
+        Ok(())
+    }
+    pub fn set_idempotency_header(&mut self, header: String) -> Result<(), String> {
+        if self.idempotency_header.is_some() {
+            return Err("Duplicate attribute 'idempotency'".to_string());
+        }
+        self.idempotency_header = Some(header);
+        Ok(())
+    }
+    pub fn set_output(&mut self, output: OutputFormat) -> Result<(), String> {
+        if self.output.is_some() {
+            return Err("Duplicate attribute 'output'".to_string());
+        }
+        self.output = Some(output);
+        Ok(())
+    }
+    pub fn set_unix_socket(&mut self, path: String) -> Result<(), String> {
+        if self.unix_socket.is_some() {
+            return Err("Duplicate attribute 'unix'".to_string());
+        }
+        self.unix_socket = Some(path);
+        Ok(())
+    }
+    pub fn set_description(&mut self, description: String) -> Result<(), String> {
+        if self.description.is_some() {
+            return Err("Duplicate attribute 'desc'".to_string());
+        }
+        self.description = Some(description);
+        Ok(())
+    }
+    pub fn set_reauth(&mut self) -> Result<(), String> {
+        if self.reauth {
+            return Err("Duplicate attribute 'reauth'".to_string());
+        }
+        self.reauth = true;
+        Ok(())
+    }
+    pub fn set_skip(&mut self) -> Result<(), String> {
+        if self.skip {
+            return Err("Duplicate attribute 'skip'".to_string());
+        }
+        self.skip = true;
+        Ok(())
+    }
+    pub fn add_assertion(&mut self, assertion: Assertion) {
+        self.assertions.push(assertion);
+    }
+    pub fn add_capture(&mut self, capture: Capture) -> Result<(), String> {
+        if self.captures.iter().any(|c| c.variable == capture.variable) {
+            return Err(format!("Duplicate capture of '{}'", capture.variable));
+        }
+        self.captures.push(capture);
+        Ok(())
+    }
     pub fn add_required_variable(&mut self, name: String, line: usize, character: usize) {
         self.required_variables.push(RequiredVariable {
             name,
@@ -234,12 +327,34 @@ impl AttributeParser for AuthAttributeParser {
         )?;
         r.advance();
 
-        ctx.set_auth(auth_name)
+        ctx.add_auth(auth_name)
             .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
         Ok(())
     }
 }
 
+fn parse_duration_literal(
+    r: &mut TokenReader,
+    number: &Token,
+    what: &str,
+) -> Result<String, SyntaxError> {
+    r.advance();
+    let mut literal = number.value.clone();
+    let mut span = number.span.clone();
+    if let Some(unit) = r
+        .cur()
+        .filter(|t| t.token_type == TokenType::Identifier && t.span.start == span.end)
+        .cloned()
+    {
+        literal.push_str(&unit.value);
+        span.end = unit.span.end;
+        r.advance();
+    }
+    parse_duration(&literal)
+        .map_err(|e| r.create_error_with_file(format!("Invalid {what} '{literal}': {e}"), span))?;
+    Ok(literal)
+}
+
 pub struct TimeoutAttributeParser;
 impl AttributeParser for TimeoutAttributeParser {
     fn name(&self) -> &str {
@@ -278,8 +393,7 @@ impl AttributeParser for TimeoutAttributeParser {
         })?;
 
         let timeout_str = if val_token.token_type == TokenType::Number {
-            r.advance();
-            val_token.value
+            parse_duration_literal(r, &val_token, "timeout")?
         } else if val_token.token_type == TokenType::Punctuation && val_token.value == PUNC_DOLLAR {
             r.advance(); // consume $
             let ident = expect(
@@ -379,3 +493,849 @@ impl AttributeParser for RequiredAttributeParser {
         Ok(())
     }
 }
+
+fn parse_string_or_identifier(r: &mut TokenReader, what: &str) -> Result<String, SyntaxError> {
+    let tok = expect(
+        r,
+        |t| t.token_type == TokenType::String || t.token_type == TokenType::Identifier,
+        format!("Expected string literal or identifier for {what}"),
+    )?
+    .clone();
+    r.advance();
+    Ok(if tok.token_type == TokenType::Identifier {
+        format!("{{{{{}}}}}", tok.value)
+    } else {
+        unescape_string(&tok.value[1..tok.value.len() - 1])
+    })
+}
+
+pub struct CertAttributeParser;
+impl AttributeParser for CertAttributeParser {
+    fn name(&self) -> &str {
+        "cert"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "cert"
+            },
+            "Expected 'cert'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let path = parse_string_or_identifier(r, "certificate path")?;
+        r.skip_ignorable();
+        let mut password = None;
+        if r.cur()
+            .is_some_and(|t| t.token_type == TokenType::Punctuation && t.value == PUNC_COMMA)
+        {
+            r.advance();
+            r.skip_ignorable();
+            password = Some(parse_string_or_identifier(r, "certificate password")?);
+            r.skip_ignorable();
+        }
+
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+            "Expected ')'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_cert(ClientCert {
+            path,
+            password,
+            source_path: Some(r.file_path.to_string_lossy().to_string()),
+        })
+        .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+fn is_punctuation(r: &TokenReader, value: &str) -> bool {
+    r.cur()
+        .is_some_and(|t| t.token_type == TokenType::Punctuation && t.value == value)
+}
+
+fn parse_integer<T: std::str::FromStr>(
+    r: &mut TokenReader,
+    what: &str,
+    valid: impl Fn(&T) -> bool,
+) -> Result<T, SyntaxError> {
+    let tok = expect(
+        r,
+        |t| t.token_type == TokenType::Number,
+        format!("Expected number for {what}"),
+    )?
+    .clone();
+    let value = tok
+        .value
+        .parse::<T>()
+        .ok()
+        .filter(valid)
+        .ok_or_else(|| r.create_error(format!("Invalid {what} '{}'", tok.value), tok.span))?;
+    r.advance();
+    Ok(value)
+}
+
+fn parse_status_list(r: &mut TokenReader) -> Result<Vec<u16>, SyntaxError> {
+    expect(
+        r,
+        |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LBRACKET,
+        "Expected '[' for retry status list",
+    )?;
+    r.advance();
+    let mut statuses = Vec::new();
+    loop {
+        r.skip_ignorable();
+        if is_punctuation(r, PUNC_RBRACKET) {
+            r.advance();
+            break;
+        }
+        statuses.push(parse_integer(r, "retry status code", |s: &u16| {
+            (100..600).contains(s)
+        })?);
+        r.skip_ignorable();
+        if is_punctuation(r, PUNC_COMMA) {
+            r.advance();
+        } else if !is_punctuation(r, PUNC_RBRACKET) {
+            let span = r
+                .cur()
+                .map_or(r.source.len()..r.source.len(), |t| t.span.clone());
+            return Err(r.create_error("Expected ',' or ']'".into(), span));
+        }
+    }
+    Ok(statuses)
+}
+
+fn parse_retry_arguments(r: &mut TokenReader) -> Result<RetryPolicy, SyntaxError> {
+    let mut count = None;
+    let mut on = None;
+    let mut backoff = None;
+    while !is_punctuation(r, PUNC_RPAREN) {
+        let key = expect(
+            r,
+            |t| t.token_type == TokenType::Identifier,
+            "Expected retry argument 'count', 'on' or 'backoff'",
+        )?
+        .clone();
+        r.advance();
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Operator && t.value == OP_ASSIGN,
+            format!("Expected '{OP_ASSIGN}' after '{}'", key.value),
+        )?;
+        r.advance();
+        r.skip_ignorable();
+        let duplicate = match key.value.as_str() {
+            "count" => count
+                .replace(parse_integer(r, "retry count", |c: &u32| *c > 0)?)
+                .is_some(),
+            "on" => on.replace(parse_status_list(r)?).is_some(),
+            "backoff" => backoff.replace(parse_backoff(r)?).is_some(),
+            other => {
+                return Err(r.create_error(
+                    format!(
+                        "Unknown retry argument '{other}'; expected 'count', 'on' or 'backoff'"
+                    ),
+                    key.span,
+                ))
+            }
+        };
+        if duplicate {
+            return Err(r.create_error(
+                format!("Duplicate retry argument '{}'", key.value),
+                key.span,
+            ));
+        }
+        r.skip_ignorable();
+        if is_punctuation(r, PUNC_COMMA) {
+            r.advance();
+            r.skip_ignorable();
+        }
+    }
+    let count = count.ok_or_else(|| {
+        let span = r
+            .cur()
+            .map_or(r.source.len()..r.source.len(), |t| t.span.clone());
+        r.create_error("Missing retry argument 'count'".into(), span)
+    })?;
+    Ok(RetryPolicy { count, on, backoff })
+}
+
+fn parse_backoff(r: &mut TokenReader) -> Result<Duration, SyntaxError> {
+    let number = expect(
+        r,
+        |t| t.token_type == TokenType::Number,
+        "Expected duration for retry backoff",
+    )?
+    .clone();
+    let literal = parse_duration_literal(r, &number, "retry backoff")?;
+    parse_duration(&literal).map_err(|e| r.create_error(e, number.span))
+}
+
+pub struct RetryAttributeParser;
+impl AttributeParser for RetryAttributeParser {
+    fn name(&self) -> &str {
+        "retry"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "retry"
+            },
+            "Expected 'retry'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let retry = parse_retry_arguments(r)?;
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+            "Expected ')'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_retry(retry)
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+fn parse_redirect_arguments(r: &mut TokenReader) -> Result<RedirectPolicy, SyntaxError> {
+    let mut follow = None;
+    let mut max = None;
+    let start = r
+        .cur()
+        .map_or(r.source.len()..r.source.len(), |t| t.span.clone());
+    while !is_punctuation(r, PUNC_RPAREN) {
+        let key = expect(
+            r,
+            |t| t.token_type == TokenType::Identifier,
+            "Expected redirects argument 'follow' or 'max'",
+        )?
+        .clone();
+        r.advance();
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Operator && t.value == OP_ASSIGN,
+            format!("Expected '{OP_ASSIGN}' after '{}'", key.value),
+        )?;
+        r.advance();
+        r.skip_ignorable();
+        let duplicate = match key.value.as_str() {
+            "follow" => follow.replace(parse_bool(r, "redirects follow")?).is_some(),
+            "max" => max
+                .replace(parse_integer(r, "redirects max", |_: &usize| true)?)
+                .is_some(),
+            other => {
+                return Err(r.create_error(
+                    format!("Unknown redirects argument '{other}'; expected 'follow' or 'max'"),
+                    key.span,
+                ))
+            }
+        };
+        if duplicate {
+            return Err(r.create_error(
+                format!("Duplicate redirects argument '{}'", key.value),
+                key.span,
+            ));
+        }
+        r.skip_ignorable();
+        if is_punctuation(r, PUNC_COMMA) {
+            r.advance();
+            r.skip_ignorable();
+        }
+    }
+    match (follow, max) {
+        (None, None) => Err(r.create_error(
+            "Expected redirects argument 'follow' or 'max'".into(),
+            start,
+        )),
+        (Some(false), Some(_)) => Err(r.create_error(
+            "Redirects argument 'max' cannot be combined with 'follow=false'".into(),
+            start,
+        )),
+        (follow, max) => Ok(RedirectPolicy {
+            follow: follow.unwrap_or(true),
+            max,
+        }),
+    }
+}
+
+pub struct RedirectsAttributeParser;
+impl AttributeParser for RedirectsAttributeParser {
+    fn name(&self) -> &str {
+        "redirects"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "redirects"
+            },
+            "Expected 'redirects'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let redirects = parse_redirect_arguments(r)?;
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+            "Expected ')'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_redirects(redirects)
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+pub struct IdempotencyAttributeParser;
+impl AttributeParser for IdempotencyAttributeParser {
+    fn name(&self) -> &str {
+        "idempotency"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "idempotency"
+            },
+            "Expected 'idempotency'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let mut header = DEFAULT_IDEMPOTENCY_HEADER.to_string();
+        if is_punctuation(r, PUNC_LPAREN) {
+            r.advance();
+            r.skip_ignorable();
+            let tok = expect(
+                r,
+                |t| t.token_type == TokenType::String,
+                "Expected string literal for idempotency header name",
+            )?
+            .clone();
+            header = unescape_string(&tok.value[1..tok.value.len() - 1]);
+            if header.trim().is_empty() {
+                return Err(
+                    r.create_error("Idempotency header name cannot be empty".into(), tok.span)
+                );
+            }
+            r.advance();
+
+            r.skip_ignorable();
+            expect(
+                r,
+                |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+                "Expected ')'",
+            )?;
+            r.advance();
+            r.skip_ignorable();
+        }
+
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_idempotency_header(header)
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+pub struct OutputAttributeParser;
+impl AttributeParser for OutputAttributeParser {
+    fn name(&self) -> &str {
+        "output"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "output"
+            },
+            "Expected 'output'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let tok = expect(
+            r,
+            |t| t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword,
+            "Expected output format 'text' or 'json'",
+        )?
+        .clone();
+        let output = OutputFormat::from_str(&tok.value).ok_or_else(|| {
+            r.create_error(
+                format!(
+                    "Unknown output format '{}'; expected 'text' or 'json'",
+                    tok.value
+                ),
+                tok.span.clone(),
+            )
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+            "Expected ')'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_output(output)
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+pub struct UnixAttributeParser;
+impl AttributeParser for UnixAttributeParser {
+    fn name(&self) -> &str {
+        "unix"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "unix"
+            },
+            "Expected 'unix'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let path_span = r.cur().map(|t| t.span.clone()).unwrap_or_default();
+        let path = parse_string_or_identifier(r, "unix socket path")?;
+        if path.trim().is_empty() {
+            return Err(r.create_error("Unix socket path cannot be empty".into(), path_span));
+        }
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+            "Expected ')'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_unix_socket(path)
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+pub struct DescAttributeParser;
+impl AttributeParser for DescAttributeParser {
+    fn name(&self) -> &str {
+        "desc"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "desc"
+            },
+            "Expected 'desc'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let desc_tok = expect(
+            r,
+            |t| t.token_type == TokenType::String,
+            "Expected string literal for description",
+        )?
+        .clone();
+        let description = unescape_string(&desc_tok.value[1..desc_tok.value.len() - 1]);
+        if description.trim().is_empty() {
+            return Err(r.create_error("Description cannot be empty".into(), desc_tok.span));
+        }
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+            "Expected ')'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_description(description.trim().to_string())
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+pub struct ReauthAttributeParser;
+impl AttributeParser for ReauthAttributeParser {
+    fn name(&self) -> &str {
+        "reauth"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "reauth"
+            },
+            "Expected 'reauth'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_reauth()
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+pub struct SkipAttributeParser;
+impl AttributeParser for SkipAttributeParser {
+    fn name(&self) -> &str {
+        "skip"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        let start_token = r.cur().cloned().ok_or_else(|| {
+            r.create_error("Unexpected EOF".into(), r.source.len()..r.source.len())
+        })?;
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "skip"
+            },
+            "Expected 'skip'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.set_skip()
+            .map_err(|msg| r.create_error_with_file(msg, start_token.span.clone()))?;
+        Ok(())
+    }
+}
+
+pub struct ExpectAttributeParser;
+impl AttributeParser for ExpectAttributeParser {
+    fn name(&self) -> &str {
+        "expect"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| {
+                (t.token_type == TokenType::Identifier || t.token_type == TokenType::Keyword)
+                    && t.value == "expect"
+            },
+            "Expected 'expect'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let lparen = expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        let start = lparen.span.end;
+        r.advance();
+
+        let end = skip_to_closing_paren(r)?;
+        let assertion = Assertion::parse(&r.source[start..end])
+            .map_err(|msg| r.create_error(msg, start..end))?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.add_assertion(assertion);
+        Ok(())
+    }
+}
+
+pub struct CaptureAttributeParser;
+impl AttributeParser for CaptureAttributeParser {
+    fn name(&self) -> &str {
+        "capture"
+    }
+
+    fn parse(&self, r: &mut TokenReader, ctx: &mut AttributeContext) -> Result<(), SyntaxError> {
+        r.advance();
+
+        r.skip_ignorable();
+        let _ = expect(
+            r,
+            |t| t.token_type == TokenType::Identifier && t.value == "capture",
+            "Expected 'capture'",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN,
+            "Expected '('",
+        )?;
+        r.advance();
+
+        r.skip_ignorable();
+        let name_tok = expect(
+            r,
+            |t| t.token_type == TokenType::Identifier,
+            "Expected variable name for capture",
+        )?
+        .clone();
+        r.advance();
+        r.skip_ignorable();
+        let assign = expect(
+            r,
+            |t| t.token_type == TokenType::Operator && t.value == OP_ASSIGN,
+            format!("Expected '{OP_ASSIGN}' after '{}'", name_tok.value),
+        )?;
+        let start = assign.span.end;
+        r.advance();
+
+        let end = skip_to_closing_paren(r)?;
+        let expression = r.source[start..end].trim().to_string();
+        let target = parse_target(&expression).map_err(|msg| r.create_error(msg, start..end))?;
+        r.advance();
+
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACKET,
+            "Expected ']'",
+        )?;
+        r.advance();
+
+        ctx.add_capture(Capture {
+            variable: name_tok.value,
+            expression,
+            target,
+        })
+        .map_err(|msg| r.create_error_with_file(msg, name_tok.span))
+    }
+}
+
+fn skip_to_closing_paren(r: &mut TokenReader) -> Result<usize, SyntaxError> {
+    let mut depth = 0usize;
+    while let Some(t) = r.cur() {
+        if t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN {
+            if depth == 0 {
+                return Ok(t.span.start);
+            }
+            depth -= 1;
+        } else if t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN {
+            depth += 1;
+        } else if t.token_type == TokenType::Newline {
+            break;
+        }
+        r.advance();
+    }
+    let span = r
+        .cur()
+        .map(|t| t.span.clone())
+        .unwrap_or(r.source.len()..r.source.len());
+    Err(r.create_error("Expected ')'".into(), span))
+}
@@ -259,7 +259,12 @@ fn validate_field_is_allowed(
                 auth_type.as_str(),
                 auth_name,
                 key,
-                required_fields.join(", ")
+                required_fields
+                    .iter()
+                    .chain(optional_fields)
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", ")
             ),
             token.span.clone(),
         ));
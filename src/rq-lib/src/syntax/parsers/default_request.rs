@@ -0,0 +1,74 @@
+use super::parse_trait::Parse;
+use crate::syntax::fs::Fs;
+use crate::syntax::{
+    error::SyntaxError,
+    keywords::{PUNC_DOT, PUNC_SEMI},
+    parse_result::ParseResult,
+    reader::{expect, TokenReader},
+    token::TokenType,
+};
+
+const DIRECTIVE_DEFAULT: &str = "default";
+const DIRECTIVE_REQUEST: &str = "request";
+
+pub struct DefaultRequestParser;
+impl Parse for DefaultRequestParser {
+    fn can_parse(&self, r: &TokenReader) -> bool {
+        r.cur()
+            .is_some_and(|t| t.token_type == TokenType::Identifier && t.value == DIRECTIVE_DEFAULT)
+    }
+    fn parse(
+        &self,
+        r: &mut TokenReader,
+        result: &mut ParseResult,
+        _fs: &dyn Fs,
+    ) -> Result<(), SyntaxError> {
+        let directive_span = r.cur().map(|t| t.span.clone()).unwrap_or_default();
+        r.advance();
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Identifier && t.value == DIRECTIVE_REQUEST,
+            format!("Expected '{DIRECTIVE_REQUEST}'"),
+        )?;
+        r.advance();
+        r.skip_ignorable();
+        let name = parse_request_path(r)?;
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_SEMI,
+            format!("Expected '{PUNC_SEMI}'"),
+        )?;
+        r.advance();
+
+        if let Some(existing) = &result.default_request {
+            return Err(r.create_error_with_file(
+                format!("Duplicate default request: '{existing}' is already the default"),
+                directive_span,
+            ));
+        }
+        result.default_request = Some(name);
+        Ok(())
+    }
+}
+
+fn parse_request_path(r: &mut TokenReader) -> Result<String, SyntaxError> {
+    let mut segments = Vec::new();
+    loop {
+        let segment = expect(
+            r,
+            |t| t.token_type == TokenType::Identifier,
+            "Expected request name",
+        )?;
+        segments.push(segment.value);
+        r.advance();
+        if !r
+            .cur()
+            .is_some_and(|t| t.token_type == TokenType::Punctuation && t.value == PUNC_DOT)
+        {
+            return Ok(segments.join(PUNC_DOT));
+        }
+        r.advance();
+    }
+}
@@ -1,13 +1,13 @@
 use super::{
     attributes::{
         parse_attributes, AttributeContext, AttributeParser, AuthAttributeParser,
-        TimeoutAttributeParser,
+        CertAttributeParser, TimeoutAttributeParser,
     },
     parse_trait::Parse,
     request::parse_request_with_context,
     utils::{
-        can_parse_attributed, check_variable_type, is_headers_like, is_string_like,
-        parse_headers_array, parse_string_value,
+        can_parse_attributed, check_variable_type, claim_parameter, is_headers_like,
+        is_string_like, parse_headers_array, parse_string_value,
     },
     variable::parse_variable_declaration,
 };
@@ -15,8 +15,8 @@ use crate::syntax::fs::Fs;
 use crate::syntax::{
     error::SyntaxError,
     keywords::{
-        KW_EP, KW_LET, KW_RQ, OP_GT, OP_LT, PUNC_COLON, PUNC_COMMA, PUNC_LBRACE, PUNC_LBRACKET,
-        PUNC_LPAREN, PUNC_RBRACE, PUNC_RPAREN, PUNC_SEMI,
+        KW_EP, KW_EXPORT, KW_LET, KW_RQ, OP_GT, OP_LT, PUNC_COLON, PUNC_COMMA, PUNC_LBRACE,
+        PUNC_LBRACKET, PUNC_LPAREN, PUNC_RBRACE, PUNC_RPAREN, PUNC_SEMI,
     },
     parse_result::{EndpointDefinition, ParseResult},
     reader::{expect, TokenReader},
@@ -36,12 +36,19 @@ impl Parse for EndpointParser {
         result: &mut ParseResult,
         _fs: &dyn Fs,
     ) -> Result<(), SyntaxError> {
-        let (mut ep, ep_def, ep_locs) = parse_endpoint_with_context(
+        let (mut ep, ep_def, ep_locs, exported) = parse_endpoint_with_context(
             r,
             &result.file_variables,
             &result.requests,
             &result.endpoints,
         )?;
+        let file = r.file_path.to_string_lossy().to_string();
+        for (var, line, character) in exported {
+            result
+                .let_variable_locations
+                .insert(var.name.clone(), (file.clone(), line, character));
+            result.file_variables.push(var);
+        }
         for (name, file, line, character) in ep_locs {
             result
                 .required_variable_locations
@@ -70,6 +77,7 @@ pub fn parse_endpoint_constructor_params(
     let mut headers_var: Option<String> = None;
     let mut qs: Option<String> = None;
     let mut positional_index = 0;
+    let mut seen_params = Vec::new();
     loop {
         r.skip_ignorable();
         if let Some(t) = r.cur() {
@@ -104,6 +112,9 @@ pub fn parse_endpoint_constructor_params(
             false
         };
         if is_named {
+            if let Some(name) = r.cur().map(|t| t.value.clone()) {
+                claim_parameter(r, &mut seen_params, &name)?;
+            }
             let name_tok = expect(
                 r,
                 |t| t.token_type == TokenType::Identifier,
@@ -162,6 +173,9 @@ pub fn parse_endpoint_constructor_params(
                 }
             }
         } else {
+            if let Some(name) = ["url", "headers", "qs"].get(positional_index) {
+                claim_parameter(r, &mut seen_params, name)?;
+            }
             match positional_index {
                 0 => {
                     if let Some(t) = r.cur() {
@@ -225,6 +239,7 @@ type EndpointParseResult = (
     Vec<crate::syntax::parse_result::RequestWithVariables>,
     EndpointDefinition,
     Vec<(String, String, usize, usize)>,
+    Vec<(Variable, usize, usize)>,
 );
 
 pub(crate) fn parse_endpoint_with_context(
@@ -234,8 +249,30 @@ pub(crate) fn parse_endpoint_with_context(
     existing_endpoints: &std::collections::HashMap<String, EndpointDefinition>,
 ) -> Result<EndpointParseResult, SyntaxError> {
     let mut ctx = AttributeContext::default();
-    let parsers: Vec<&dyn AttributeParser> = vec![&AuthAttributeParser, &TimeoutAttributeParser];
-    parse_attributes(r, &parsers, &["method", "required"], &mut ctx)?;
+    let parsers: Vec<&dyn AttributeParser> = vec![
+        &AuthAttributeParser,
+        &TimeoutAttributeParser,
+        &CertAttributeParser,
+    ];
+    parse_attributes(
+        r,
+        &parsers,
+        &[
+            "method",
+            "required",
+            "retry",
+            "redirects",
+            "idempotency",
+            "output",
+            "unix",
+            "expect",
+            "capture",
+            "desc",
+            "reauth",
+            "skip",
+        ],
+        &mut ctx,
+    )?;
 
     expect(
         r,
@@ -324,6 +361,7 @@ pub(crate) fn parse_endpoint_with_context(
     let mut endpoint_variables = Vec::new();
     let mut related_files = Vec::new();
 
+    let parent_name = parent_ep.as_ref().map(|p| p.name.clone());
     if let Some(parent) = parent_ep {
         if !parent.url.is_empty() {
             let is_absolute = base_url.to_lowercase().starts_with("http://")
@@ -363,12 +401,15 @@ pub(crate) fn parse_endpoint_with_context(
                 None => ep_qs = Some(p_qs.clone()),
             }
         }
-        if ctx.auth.is_none() {
+        if ctx.auth.is_empty() {
             ctx.auth = parent.auth;
         }
         if ctx.timeout.is_none() {
             ctx.timeout = parent.timeout;
         }
+        if ctx.cert.is_none() {
+            ctx.cert = parent.cert;
+        }
         endpoint_variables = parent.variables.clone();
 
         if let Some(src) = &parent.source_path {
@@ -385,6 +426,7 @@ pub(crate) fn parse_endpoint_with_context(
 
     let mut children = Vec::new();
     let mut required_locations: Vec<(String, String, usize, usize)> = Vec::new();
+    let mut exported_variables = Vec::new();
 
     if let Some(t) = r.cur() {
         if t.token_type == TokenType::Punctuation && t.value == PUNC_SEMI {
@@ -398,15 +440,17 @@ pub(crate) fn parse_endpoint_with_context(
                 qs: ep_qs,
                 auth: ctx.auth,
                 timeout: ctx.timeout,
+                cert: ctx.cert,
                 variables: endpoint_variables,
                 has_requests: false,
                 is_template: true,
+                parent: parent_name,
                 source_path: Some(r.file_path.to_string_lossy().to_string()),
                 related_files,
                 line: ep_line,
                 character: ep_character,
             };
-            return Ok((children, ep_def, required_locations));
+            return Ok((children, ep_def, required_locations, Vec::new()));
         }
     }
 
@@ -430,6 +474,12 @@ pub(crate) fn parse_endpoint_with_context(
             endpoint_variables.push(var);
             continue;
         }
+        if r.is_contextual_keyword(KW_EXPORT) {
+            let (var, line, character) = parse_exported_variable(r)?;
+            endpoint_variables.push(var.clone());
+            exported_variables.push((var, line, character));
+            continue;
+        }
         if r.is_keyword(KW_RQ)
             || (r
                 .cur()
@@ -498,16 +548,17 @@ pub(crate) fn parse_endpoint_with_context(
                     req.headers_var = Some(hv.clone());
                 }
             }
-            if req.auth.is_none() {
-                if let Some(ref ea) = ctx.auth {
-                    req.auth = Some(ea.clone());
-                }
+            if req.auth.is_empty() {
+                req.auth = ctx.auth.clone();
             }
             if req.timeout.is_none() {
                 if let Some(ref et) = ctx.timeout {
                     req.timeout = Some(et.clone());
                 }
             }
+            if req.cert.is_none() {
+                req.cert = ctx.cert.clone();
+            }
 
             let current_src = r.file_path.to_string_lossy().to_string();
             if !req.related_files.contains(&current_src) {
@@ -546,14 +597,33 @@ pub(crate) fn parse_endpoint_with_context(
         qs: ep_qs,
         auth: ctx.auth,
         timeout: ctx.timeout,
+        cert: ctx.cert,
         variables: endpoint_variables,
         has_requests: !children.is_empty(),
         is_template: false,
+        parent: parent_name,
         source_path: Some(r.file_path.to_string_lossy().to_string()),
         related_files,
         line: ep_line,
         character: ep_character,
     };
 
-    Ok((children, ep_def, required_locations))
+    Ok((children, ep_def, required_locations, exported_variables))
+}
+
+fn parse_exported_variable(r: &mut TokenReader) -> Result<(Variable, usize, usize), SyntaxError> {
+    expect(
+        r,
+        |t| t.token_type == TokenType::Identifier && t.value == KW_EXPORT,
+        format!("Expected '{KW_EXPORT}'"),
+    )?;
+    r.advance();
+    r.skip_ignorable();
+    if !r.is_keyword(KW_LET) {
+        let span = r
+            .cur()
+            .map_or(r.source.len()..r.source.len(), |t| t.span.clone());
+        return Err(r.create_error(format!("Expected '{KW_LET}' after '{KW_EXPORT}'"), span));
+    }
+    parse_variable_declaration(r)
 }
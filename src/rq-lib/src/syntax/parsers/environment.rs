@@ -52,11 +52,22 @@ impl Parse for EnvironmentParser {
 pub(crate) fn parse_environment_definition(
     r: &mut TokenReader,
     existing_environments: &HashMap<String, Vec<Variable>>,
+) -> Result<EnvDefinition, SyntaxError> {
+    parse_variable_block(r, KW_ENV, "environment", existing_environments)
+}
+
+pub(crate) fn parse_variable_block(
+    r: &mut TokenReader,
+    keyword: &str,
+    kind: &str,
+    existing: &HashMap<String, Vec<Variable>>,
 ) -> Result<EnvDefinition, SyntaxError> {
     expect(
         r,
-        |t| t.token_type == TokenType::Keyword && t.value == KW_ENV,
-        format!("Expected '{KW_ENV}'"),
+        |t| {
+            matches!(t.token_type, TokenType::Keyword | TokenType::Identifier) && t.value == keyword
+        },
+        format!("Expected '{keyword}'"),
     )?;
     r.advance();
     r.skip_ignorable();
@@ -70,9 +81,9 @@ pub(crate) fn parse_environment_definition(
     let line = line_1.saturating_sub(1);
     let character = col_1.saturating_sub(1);
 
-    if existing_environments.contains_key(&env_name) {
+    if existing.contains_key(&env_name) {
         return Err(r.create_error_with_file(
-            format!("Duplicate environment definition: '{env_name}'"),
+            format!("Duplicate {kind} definition: '{env_name}'"),
             name_tok.span.clone(),
         ));
     }
@@ -1,12 +1,18 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
 use super::parse_trait::Parse;
 use super::utils::unescape_string;
+use crate::client::variable_file::parse_variable_file;
+use crate::error::RqError;
 use crate::syntax::{
     error::SyntaxError,
     fs::Fs,
-    keywords::{KW_IMPORT, PUNC_SEMI},
+    keywords::{KW_IMPORT, KW_IMPORT_ENV, PUNC_SEMI},
     parse_result::ParseResult,
     reader::{expect, TokenReader},
     token::TokenType,
+    variable_context::Variable,
 };
 
 pub struct ImportParser;
@@ -88,6 +94,8 @@ impl Parse for ImportParser {
         result.requests.extend(imported_result.requests);
         result.file_variables.extend(imported_result.file_variables);
         result.environments.extend(imported_result.environments);
+        result.profiles.extend(imported_result.profiles);
+        result.suites.extend(imported_result.suites);
         result
             .environment_locations
             .extend(imported_result.environment_locations);
@@ -113,3 +121,106 @@ impl Parse for ImportParser {
         Ok(())
     }
 }
+
+pub struct ImportEnvParser;
+impl Parse for ImportEnvParser {
+    fn can_parse(&self, r: &TokenReader) -> bool {
+        r.is_contextual_keyword(KW_IMPORT_ENV)
+    }
+    fn parse(
+        &self,
+        r: &mut TokenReader,
+        result: &mut ParseResult,
+        fs: &dyn Fs,
+    ) -> Result<(), SyntaxError> {
+        expect(
+            r,
+            |t| t.token_type == TokenType::Identifier && t.value == KW_IMPORT_ENV,
+            format!("Expected '{KW_IMPORT_ENV}'"),
+        )?;
+        r.advance();
+        r.skip_ignorable();
+        let path_tok = expect(
+            r,
+            |t| t.token_type == TokenType::String,
+            "Expected string literal with the path of a JSON file",
+        )?
+        .clone();
+        let path = unescape_string(&path_tok.value[1..path_tok.value.len() - 1]);
+        r.advance();
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Identifier && t.value == "as",
+            "Expected 'as'",
+        )?;
+        r.advance();
+        r.skip_ignorable();
+        let name_tok = expect(
+            r,
+            |t| t.token_type == TokenType::Identifier,
+            "Expected environment name",
+        )?
+        .clone();
+        r.advance();
+        r.skip_ignorable();
+        expect(
+            r,
+            |t| t.token_type == TokenType::Punctuation && t.value == PUNC_SEMI,
+            format!("Expected '{PUNC_SEMI}'"),
+        )?;
+        r.advance();
+
+        let env_name = name_tok.value;
+        if result.environments.contains_key(&env_name) {
+            return Err(r.create_error_with_file(
+                format!("Duplicate environment definition: '{env_name}'"),
+                name_tok.span,
+            ));
+        }
+
+        let (json_path, vars) = load_env_file(r, fs, &path, &path_tok.span)?;
+
+        let file = r.file_path.to_string_lossy().to_string();
+        let json_file = json_path.to_string_lossy().to_string();
+        let (line, character) = r.get_line_col(name_tok.span.start);
+        result.environment_locations.insert(
+            env_name.clone(),
+            (file, line.saturating_sub(1), character.saturating_sub(1)),
+        );
+        let env_var_map = result
+            .env_variable_locations
+            .entry(env_name.clone())
+            .or_default();
+        for var in &vars {
+            env_var_map.insert(var.name.clone(), (json_file.clone(), 0, 0));
+        }
+        result.environments.insert(env_name, vars);
+        Ok(())
+    }
+}
+
+fn load_env_file(
+    r: &TokenReader,
+    fs: &dyn Fs,
+    path: &str,
+    span: &Range<usize>,
+) -> Result<(PathBuf, Vec<Variable>), SyntaxError> {
+    let json_path = fs.resolve_path(&r.file_path, path).map_err(|_| {
+        r.create_error_with_file(
+            format!("Environment file not found: '{path}'"),
+            span.clone(),
+        )
+    })?;
+    let vars = fs
+        .read(&json_path)
+        .map_err(|e| format!("Failed to read environment file '{path}': {e}"))
+        .and_then(|content| {
+            parse_variable_file(Path::new(path), &content).map_err(|e| match e {
+                RqError::Validation(msg) => msg,
+                other => other.to_string(),
+            })
+        })
+        .map_err(|msg| r.create_error_with_file(msg, span.clone()))?;
+    Ok((json_path, vars))
+}
@@ -1,17 +1,24 @@
 pub mod attributes;
 pub mod auth;
+pub mod default_request;
 pub mod endpoint;
 pub mod environment;
 pub mod import;
 pub mod parse_trait;
+pub mod profile;
 pub mod request;
+pub mod suite;
 pub mod utils;
 pub mod variable;
+pub mod yaml;
 
 pub use auth::AuthParser;
+pub use default_request::DefaultRequestParser;
 pub use endpoint::EndpointParser;
 pub use environment::EnvironmentParser;
-pub use import::ImportParser;
+pub use import::{ImportEnvParser, ImportParser};
 pub use parse_trait::Parse;
+pub use profile::ProfileParser;
 pub use request::RequestParser;
+pub use suite::SuiteParser;
 pub use variable::VariableParser;
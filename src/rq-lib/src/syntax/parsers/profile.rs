@@ -0,0 +1,24 @@
+use super::environment::parse_variable_block;
+use super::parse_trait::Parse;
+use crate::syntax::fs::Fs;
+use crate::syntax::{
+    error::SyntaxError, keywords::KW_PROFILE, parse_result::ParseResult, reader::TokenReader,
+};
+
+pub struct ProfileParser;
+impl Parse for ProfileParser {
+    fn can_parse(&self, r: &TokenReader) -> bool {
+        r.is_contextual_keyword(KW_PROFILE)
+    }
+    fn parse(
+        &self,
+        r: &mut TokenReader,
+        result: &mut ParseResult,
+        _fs: &dyn Fs,
+    ) -> Result<(), SyntaxError> {
+        let (profile_name, vars, _, _, _) =
+            parse_variable_block(r, KW_PROFILE, "profile", &result.profiles)?;
+        result.profiles.insert(profile_name, vars);
+        Ok(())
+    }
+}
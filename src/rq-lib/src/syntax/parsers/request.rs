@@ -1,23 +1,31 @@
+use std::ops::Range;
+
 use super::{
     attributes::{
         parse_attributes, AttributeContext, AttributeParser, AuthAttributeParser,
-        MethodAttributeParser, RequiredAttributeParser, TimeoutAttributeParser,
+        CaptureAttributeParser, CertAttributeParser, DescAttributeParser, ExpectAttributeParser,
+        IdempotencyAttributeParser, MethodAttributeParser, OutputAttributeParser,
+        ReauthAttributeParser, RedirectsAttributeParser, RequiredAttributeParser,
+        RetryAttributeParser, SkipAttributeParser, TimeoutAttributeParser, UnixAttributeParser,
     },
     parse_trait::Parse,
     utils::{
-        can_parse_attributed, check_variable_type, is_headers_like, is_string_like,
-        parse_headers_array, parse_string_value,
+        can_parse_attributed, check_variable_type, claim_parameter, is_bytes_body, is_form_body,
+        is_headers_like, is_lines_like, is_multipart_body, is_string_like, parse_bool,
+        parse_bytes_body, parse_form_body, parse_headers_array, parse_json_body,
+        parse_multipart_body, parse_string_value,
     },
+    yaml::parse_yaml_body,
 };
 use crate::syntax::fs::Fs;
 use crate::syntax::{
     error::SyntaxError,
     http_method::HttpMethod,
     keywords::{
-        KW_RQ, PUNC_COLON, PUNC_COMMA, PUNC_DOLLAR, PUNC_LBRACE, PUNC_LPAREN, PUNC_RBRACE,
-        PUNC_RPAREN, PUNC_SEMI,
+        KW_RQ, PUNC_COLON, PUNC_COMMA, PUNC_DOLLAR, PUNC_LBRACE, PUNC_LPAREN, PUNC_RPAREN,
+        PUNC_SEMI,
     },
-    parse_result::{ParseResult, Request},
+    parse_result::{BodyFile, MultipartPart, ParseResult, Request},
     reader::{expect, TokenReader},
     token::TokenType,
     variable_context::Variable,
@@ -34,6 +42,7 @@ impl Parse for RequestParser {
         result: &mut ParseResult,
         _fs: &dyn Fs,
     ) -> Result<(), SyntaxError> {
+        let start = r.idx;
         let (req, req_vars, req_locs) = parse_request_with_context(
             r,
             &result.file_variables,
@@ -41,6 +50,16 @@ impl Parse for RequestParser {
             None,
             &result.requests,
         )?;
+        if result.suites.contains(&req.name) {
+            let span = r.tokens[start..r.idx]
+                .iter()
+                .find(|t| t.token_type == TokenType::Identifier && t.value == req.name)
+                .map_or(0..0, |t| t.span.clone());
+            return Err(r.create_error_with_file(
+                format!("Duplicate request definition: '{}'", req.name),
+                span,
+            ));
+        }
         for (name, file, line, character) in req_locs {
             result
                 .required_variable_locations
@@ -62,36 +81,7 @@ pub fn parse_body_value(r: &mut TokenReader) -> Result<String, SyntaxError> {
     if let Some(val) = r.cur() {
         match val.token_type {
             TokenType::String | TokenType::Identifier => parse_string_value(r, " "),
-            TokenType::Punctuation if val.value == PUNC_DOLLAR => {
-                r.advance();
-                r.skip_ignorable();
-                let _ = expect(
-                    r,
-                    |tk| tk.token_type == TokenType::Punctuation && tk.value == PUNC_LBRACE,
-                    format!("Expected '{PUNC_LBRACE}'"),
-                )?;
-                let mut depth = 0;
-                let mut collected = String::new();
-                while let Some(tok) = r.cur() {
-                    if tok.token_type == TokenType::Punctuation {
-                        if tok.value == PUNC_LBRACE {
-                            depth += 1;
-                        }
-                        if tok.value == PUNC_RBRACE {
-                            depth -= 1;
-                        }
-                        collected.push_str(&tok.value);
-                        r.advance();
-                        if depth == 0 {
-                            break;
-                        }
-                    } else {
-                        collected.push_str(&tok.value);
-                        r.advance();
-                    }
-                }
-                Ok(collected)
-            }
+            TokenType::Punctuation if val.value == PUNC_DOLLAR => parse_json_body(r),
             TokenType::Punctuation if val.value == PUNC_LBRACE => Err(r.create_error_with_file(
                 "Bare '{' syntax is not supported. Use '${' prefix.".into(),
                 val.span.clone(),
@@ -106,14 +96,41 @@ pub fn parse_body_value(r: &mut TokenReader) -> Result<String, SyntaxError> {
     }
 }
 
+pub type FormFields = Vec<(String, String)>;
+pub type MultipartParts = Vec<MultipartPart>;
+
 pub type ConstructorParams = (
-    String,                // url
-    Vec<(String, String)>, // headers
-    Option<String>,        // body (rq only; endpoints will reject)
-    Option<String>,        // headers_var
-    Vec<Variable>,         // variables (from attributes)
+    String,                 // url
+    Vec<(String, String)>,  // headers
+    Option<String>,         // body (rq only; endpoints will reject)
+    Option<FormFields>,     // form body (rq only)
+    Option<MultipartParts>, // multipart body (rq only)
+    Option<Vec<String>>,    // ndjson body lines (rq only)
+    Option<String>,         // raw bytes body file (rq only)
+    Option<BodyFile>,       // text body file (rq only)
+    Option<String>,         // headers_var
+    Vec<Variable>,          // variables (from attributes)
 );
 
+fn parse_request_body(
+    r: &mut TokenReader,
+    body: &mut Option<String>,
+    form: &mut Option<FormFields>,
+    multipart: &mut Option<MultipartParts>,
+    body_file: &mut Option<String>,
+) -> Result<(), SyntaxError> {
+    if is_form_body(r) {
+        *form = Some(parse_form_body(r)?);
+    } else if is_multipart_body(r) {
+        *multipart = Some(parse_multipart_body(r)?);
+    } else if is_bytes_body(r) {
+        *body_file = Some(parse_bytes_body(r)?);
+    } else {
+        *body = Some(parse_body_value(r)?);
+    }
+    Ok(())
+}
+
 pub fn parse_constructor_params(
     r: &mut TokenReader,
     file_vars: &[Variable],
@@ -121,9 +138,17 @@ pub fn parse_constructor_params(
     let mut url = String::new();
     let mut headers = Vec::new();
     let mut body = None;
+    let mut form = None;
+    let mut multipart = None;
+    let mut body_file = None;
+    let mut body_lines: Option<(Vec<String>, Range<usize>)> = None;
+    let mut body_yaml: Option<(String, Range<usize>)> = None;
+    let mut text_file: Option<(String, Range<usize>)> = None;
+    let mut raw: Option<(bool, Range<usize>)> = None;
     let mut headers_var: Option<String> = None;
     let request_variables = Vec::new();
     let mut positional_index = 0;
+    let mut seen_params = Vec::new();
     loop {
         r.skip_ignorable();
         if let Some(t) = r.cur() {
@@ -158,6 +183,9 @@ pub fn parse_constructor_params(
             false
         };
         if is_named {
+            if let Some(name) = r.cur().map(|t| t.value.clone()) {
+                claim_parameter(r, &mut seen_params, &name)?;
+            }
             let name_tok = expect(
                 r,
                 |t| t.token_type == TokenType::Identifier,
@@ -199,7 +227,22 @@ pub fn parse_constructor_params(
                     }
                 }
                 "body" => {
-                    body = Some(parse_body_value(r)?);
+                    parse_request_body(r, &mut body, &mut form, &mut multipart, &mut body_file)?;
+                }
+                "body_lines" => {
+                    body_lines = Some(parse_body_lines(r, file_vars)?);
+                }
+                "body_yaml" => {
+                    let span = name_tok.span.clone();
+                    body_yaml = Some((parse_yaml_body(r)?, span));
+                }
+                "body_file" => {
+                    let span = name_tok.span.clone();
+                    text_file = Some((parse_string_value(r, "")?, span));
+                }
+                "raw" => {
+                    let span = name_tok.span.clone();
+                    raw = Some((parse_bool(r, "'raw'")?, span));
                 }
                 _ => {
                     return Err(r.create_error(
@@ -209,6 +252,9 @@ pub fn parse_constructor_params(
                 }
             }
         } else {
+            if let Some(name) = ["url", "headers", "body"].get(positional_index) {
+                claim_parameter(r, &mut seen_params, name)?;
+            }
             match positional_index {
                 0 => {
                     if let Some(t) = r.cur() {
@@ -235,7 +281,7 @@ pub fn parse_constructor_params(
                     }
                 }
                 2 => {
-                    body = Some(parse_body_value(r)?);
+                    parse_request_body(r, &mut body, &mut form, &mut multipart, &mut body_file)?;
                 }
                 _ => {
                     let span = if let Some(t) = r.cur() {
@@ -258,7 +304,89 @@ pub fn parse_constructor_params(
             }
         }
     }
-    Ok((url, headers, body, headers_var, request_variables))
+    if let Some((_, span)) = body_lines
+        .as_ref()
+        .filter(|_| body.is_some() || form.is_some() || multipart.is_some() || body_file.is_some())
+    {
+        return Err(r.create_error(
+            "Cannot combine 'body_lines' with 'body'; use one body per request".into(),
+            span.clone(),
+        ));
+    }
+    if let Some((yaml, span)) = body_yaml {
+        if body.is_some()
+            || form.is_some()
+            || multipart.is_some()
+            || body_file.is_some()
+            || body_lines.is_some()
+        {
+            return Err(r.create_error(
+                "Cannot combine 'body_yaml' with another body; use one body per request".into(),
+                span,
+            ));
+        }
+        body = Some(yaml);
+    }
+    let has_other_body = body.is_some()
+        || form.is_some()
+        || multipart.is_some()
+        || body_file.is_some()
+        || body_lines.is_some();
+    let text_file = body_file_param(r, text_file, raw, has_other_body)?;
+    Ok((
+        url,
+        headers,
+        body,
+        form,
+        multipart,
+        body_lines.map(|(lines, _)| lines),
+        body_file,
+        text_file,
+        headers_var,
+        request_variables,
+    ))
+}
+
+fn body_file_param(
+    r: &TokenReader,
+    path: Option<(String, Range<usize>)>,
+    raw: Option<(bool, Range<usize>)>,
+    has_other_body: bool,
+) -> Result<Option<BodyFile>, SyntaxError> {
+    let Some((path, span)) = path else {
+        return match raw {
+            Some((_, span)) => Err(r.create_error(
+                "'raw' can only be used together with 'body_file'".into(),
+                span,
+            )),
+            None => Ok(None),
+        };
+    };
+    if has_other_body {
+        return Err(r.create_error(
+            "Cannot combine 'body_file' with another body; use one body per request".into(),
+            span,
+        ));
+    }
+    Ok(Some(BodyFile {
+        path,
+        raw: raw.is_some_and(|(raw, _)| raw),
+    }))
+}
+
+fn parse_body_lines(
+    r: &mut TokenReader,
+    file_vars: &[Variable],
+) -> Result<(Vec<String>, Range<usize>), SyntaxError> {
+    let tk = expect(
+        r,
+        |t| t.token_type == TokenType::Identifier,
+        "Expected array variable name for 'body_lines'",
+    )?;
+    check_variable_type(&tk.value, &[is_lines_like], file_vars, &tk, r)?;
+    let parsed = (vec![format!("{{{{{}}}}}", tk.value)], tk.span.clone());
+    r.advance();
+    Ok(parsed)
 }
 
 type RequiredVarLocation = (String, String, usize, usize);
@@ -276,6 +404,17 @@ pub(crate) fn parse_request_with_context(
         &AuthAttributeParser,
         &TimeoutAttributeParser,
         &RequiredAttributeParser,
+        &CertAttributeParser,
+        &RetryAttributeParser,
+        &RedirectsAttributeParser,
+        &IdempotencyAttributeParser,
+        &OutputAttributeParser,
+        &UnixAttributeParser,
+        &ExpectAttributeParser,
+        &CaptureAttributeParser,
+        &DescAttributeParser,
+        &ReauthAttributeParser,
+        &SkipAttributeParser,
     ];
     parse_attributes(r, &parsers, &[], &mut ctx)?;
 
@@ -327,8 +466,18 @@ pub(crate) fn parse_request_with_context(
     )?;
     r.advance();
     r.skip_ignorable();
-    let (url, headers, body, headers_var, request_variables) =
-        parse_constructor_params(r, file_vars)?;
+    let (
+        url,
+        headers,
+        body,
+        form,
+        multipart,
+        body_lines,
+        body_file,
+        text_file,
+        headers_var,
+        request_variables,
+    ) = parse_constructor_params(r, file_vars)?;
     expect(
         r,
         |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
@@ -358,10 +507,30 @@ pub(crate) fn parse_request_with_context(
         method,
         headers,
         body,
+        form,
+        multipart,
+        body_lines,
+        body_file,
+        text_file,
+        body_bytes: None,
         headers_var,
         endpoint: endpoint_name.map(|s| s.to_string()),
+        suite: None,
+        suite_headers_var: None,
         auth: ctx.auth,
         timeout: ctx.timeout,
+        cert: ctx.cert,
+        retry: ctx.retry,
+        redirects: ctx.redirects,
+        idempotency_header: ctx.idempotency_header,
+        output: ctx.output,
+        unix_socket: ctx.unix_socket,
+        description: ctx.description,
+        reauth: ctx.reauth,
+        skip: ctx.skip,
+        assertions: ctx.assertions,
+        captures: ctx.captures,
+        max_redirects: None,
         required_variables: ctx.required_variables.into_iter().map(|v| v.name).collect(),
         source_path: Some(file),
         related_files: Vec::new(),
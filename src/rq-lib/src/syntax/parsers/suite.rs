@@ -0,0 +1,241 @@
+use super::{
+    attributes::{
+        parse_attributes, AttributeContext, AttributeParser, AuthAttributeParser,
+        TimeoutAttributeParser,
+    },
+    parse_trait::Parse,
+    request::parse_request_with_context,
+    utils::{can_parse_attributed, check_variable_type, is_headers_like, parse_headers_array},
+    variable::parse_variable_declaration,
+};
+use crate::syntax::fs::Fs;
+use crate::syntax::{
+    error::SyntaxError,
+    keywords::{
+        KW_LET, KW_RQ, KW_SUITE, PUNC_COLON, PUNC_LBRACE, PUNC_LBRACKET, PUNC_LPAREN, PUNC_RBRACE,
+        PUNC_RPAREN,
+    },
+    parse_result::{ParseResult, Request, RequestWithVariables},
+    reader::{expect, TokenReader},
+    token::TokenType,
+    variable_context::Variable,
+};
+
+pub struct SuiteParser;
+impl Parse for SuiteParser {
+    fn can_parse(&self, r: &TokenReader) -> bool {
+        can_parse_attributed(r, KW_SUITE)
+    }
+    fn parse(
+        &self,
+        r: &mut TokenReader,
+        result: &mut ParseResult,
+        _fs: &dyn Fs,
+    ) -> Result<(), SyntaxError> {
+        let (mut children, locations) = parse_suite_with_context(r, result)?;
+        for (name, file, line, character) in locations {
+            result
+                .required_variable_locations
+                .entry(name)
+                .or_insert((file, line, character));
+        }
+        result.requests.append(&mut children);
+        Ok(())
+    }
+}
+
+type SuiteHeaders = (Vec<(String, String)>, Option<String>);
+type SuiteParseResult = (
+    Vec<RequestWithVariables>,
+    Vec<(String, String, usize, usize)>,
+);
+
+fn parse_suite_with_context(
+    r: &mut TokenReader,
+    result: &mut ParseResult,
+) -> Result<SuiteParseResult, SyntaxError> {
+    let mut ctx = AttributeContext::default();
+    let parsers: Vec<&dyn AttributeParser> = vec![&AuthAttributeParser, &TimeoutAttributeParser];
+    parse_attributes(r, &parsers, &[], &mut ctx)?;
+
+    expect(
+        r,
+        |t| t.token_type == TokenType::Identifier && t.value == KW_SUITE,
+        format!("Expected '{KW_SUITE}'"),
+    )?;
+    r.advance();
+    r.skip_ignorable();
+    let name_tok = expect(
+        r,
+        |t| matches!(t.token_type, TokenType::Identifier),
+        "Expected identifier",
+    )?;
+    let suite_name = name_tok.value.clone();
+
+    if result.endpoints.contains_key(&suite_name)
+        || result.suites.contains(&suite_name)
+        || result
+            .requests
+            .iter()
+            .any(|req| req.request.name == suite_name)
+    {
+        return Err(r.create_error_with_file(
+            format!("Duplicate suite definition: '{suite_name}'"),
+            name_tok.span.clone(),
+        ));
+    }
+    result.suites.insert(suite_name.clone());
+
+    r.advance();
+    r.skip_ignorable();
+    let suite_headers = parse_suite_params(r, &result.file_variables)?;
+
+    expect(
+        r,
+        |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LBRACE,
+        format!("Expected '{PUNC_LBRACE}'"),
+    )?;
+    r.advance();
+
+    parse_suite_body(r, result, &suite_name, &ctx, &suite_headers)
+}
+
+fn parse_suite_body(
+    r: &mut TokenReader,
+    result: &ParseResult,
+    suite_name: &str,
+    ctx: &AttributeContext,
+    suite_headers: &SuiteHeaders,
+) -> Result<SuiteParseResult, SyntaxError> {
+    let mut suite_variables: Vec<Variable> = Vec::new();
+    let mut children: Vec<RequestWithVariables> = Vec::new();
+    let mut required_locations = Vec::new();
+
+    loop {
+        r.skip_ignorable();
+        let Some(t) = r.cur() else {
+            return Err(r.create_error("Expected '}'".into(), r.source.len()..r.source.len()));
+        };
+        if t.token_type == TokenType::Punctuation && t.value == PUNC_RBRACE {
+            r.advance();
+            break;
+        }
+        if r.is_keyword(KW_LET) {
+            let (var, _, _) = parse_variable_declaration(r)?;
+            suite_variables.push(var);
+            continue;
+        }
+        let starts_request = r.is_keyword(KW_RQ)
+            || (t.token_type == TokenType::Punctuation && t.value == PUNC_LBRACKET);
+        if !starts_request {
+            return Err(r.create_error(format!("Unexpected token '{}'", t.value), t.span.clone()));
+        }
+
+        let mut all_requests = result.requests.clone();
+        all_requests.extend(children.iter().cloned());
+        let (mut req, req_vars, req_locs) = parse_request_with_context(
+            r,
+            &result.file_variables,
+            &suite_variables,
+            Some(suite_name),
+            &all_requests,
+        )?;
+        required_locations.extend(req_locs);
+        apply_suite_context(&mut req, suite_name, ctx, suite_headers);
+        children.push(RequestWithVariables {
+            request: req,
+            endpoint_variables: suite_variables.clone(),
+            request_variables: req_vars,
+        });
+    }
+
+    Ok((children, required_locations))
+}
+
+fn parse_suite_params(
+    r: &mut TokenReader,
+    file_vars: &[Variable],
+) -> Result<SuiteHeaders, SyntaxError> {
+    let is_lparen = r
+        .cur()
+        .map(|t| t.token_type == TokenType::Punctuation && t.value == PUNC_LPAREN)
+        .unwrap_or(false);
+    if !is_lparen {
+        return Ok((Vec::new(), None));
+    }
+    r.advance();
+    r.skip_ignorable();
+
+    let is_named = r
+        .cur()
+        .map(|t| t.token_type == TokenType::Identifier && t.value == "headers")
+        .unwrap_or(false)
+        && r.tokens
+            .iter()
+            .skip(r.idx + 1)
+            .find(|t| {
+                !matches!(
+                    t.token_type,
+                    TokenType::Whitespace | TokenType::Newline | TokenType::Comment
+                )
+            })
+            .map(|t| t.token_type == TokenType::Punctuation && t.value == PUNC_COLON)
+            .unwrap_or(false);
+    if is_named {
+        r.advance();
+        r.skip_ignorable();
+        r.advance();
+        r.skip_ignorable();
+    }
+
+    let mut headers = Vec::new();
+    let mut headers_var = None;
+    if let Some(tk) = r.cur() {
+        if tk.token_type == TokenType::Identifier {
+            check_variable_type(&tk.value, &[is_headers_like], file_vars, tk, r)?;
+            headers_var = Some(tk.value.clone());
+            r.advance();
+        } else if !(tk.token_type == TokenType::Punctuation && tk.value == PUNC_RPAREN) {
+            headers = parse_headers_array(r)?;
+        }
+    }
+
+    r.skip_ignorable();
+    expect(
+        r,
+        |t| t.token_type == TokenType::Punctuation && t.value == PUNC_RPAREN,
+        format!("Expected '{PUNC_RPAREN}'"),
+    )?;
+    r.advance();
+    r.skip_ignorable();
+    Ok((headers, headers_var))
+}
+
+fn apply_suite_context(
+    req: &mut Request,
+    suite_name: &str,
+    ctx: &AttributeContext,
+    (suite_headers, suite_headers_var): &SuiteHeaders,
+) {
+    req.name = format!("{suite_name}/{}", req.name);
+    req.endpoint = None;
+    req.suite = Some(suite_name.to_string());
+
+    let mut merged = suite_headers.to_vec();
+    for (k, v) in req.headers.iter() {
+        if let Some(i) = merged.iter().position(|(sk, _)| sk.eq_ignore_ascii_case(k)) {
+            merged[i] = (k.clone(), v.clone());
+        } else {
+            merged.push((k.clone(), v.clone()));
+        }
+    }
+    req.headers = merged;
+
+    req.suite_headers_var = suite_headers_var.clone();
+    if req.auth.is_empty() {
+        req.auth = ctx.auth.clone();
+    }
+    if req.timeout.is_none() {
+        req.timeout = ctx.timeout.clone();
+    }
+}
@@ -1,13 +1,17 @@
 use crate::syntax::{
     error::SyntaxError,
     keywords::{
-        PUNC_COLON, PUNC_COMMA, PUNC_DOLLAR, PUNC_LBRACKET, PUNC_LPAREN, PUNC_RBRACKET, PUNC_RPAREN,
+        BODY_FORM, BODY_MULTIPART, HEADER_WHEN_BODY, PUNC_AT, PUNC_COLON, PUNC_COMMA, PUNC_DOLLAR,
+        PUNC_LBRACE, PUNC_LBRACKET, PUNC_LPAREN, PUNC_RBRACE, PUNC_RBRACKET, PUNC_RPAREN,
     },
+    parse_result::{MultipartPart, MultipartValue},
     reader::{expect, make_error, TokenReader},
     token::TokenType,
     variable_context::{Variable, VariableValue},
 };
 
+const WHEN_BODY_MARKER: &str = "\u{0}when_body\u{0}";
+
 fn resolve_reference_type<'a>(
     value: &'a VariableValue,
     vars: &'a [Variable],
@@ -57,6 +61,21 @@ pub fn check_variable_type(
     Ok(())
 }
 
+pub fn claim_parameter(
+    r: &TokenReader,
+    seen: &mut Vec<String>,
+    name: &str,
+) -> Result<(), SyntaxError> {
+    if seen.iter().any(|s| s == name) {
+        let span = r
+            .cur()
+            .map_or(r.source.len()..r.source.len(), |t| t.span.clone());
+        return Err(r.create_error(format!("Parameter '{name}' specified twice"), span));
+    }
+    seen.push(name.to_string());
+    Ok(())
+}
+
 pub fn is_string_like(v: &VariableValue) -> bool {
     matches!(
         v,
@@ -64,8 +83,69 @@ pub fn is_string_like(v: &VariableValue) -> bool {
     )
 }
 
+pub fn is_lines_like(v: &VariableValue) -> bool {
+    match v {
+        VariableValue::Array(_) => true,
+        VariableValue::Json(json) => json.trim_start().starts_with('['),
+        _ => false,
+    }
+}
+
 pub fn is_headers_like(v: &VariableValue) -> bool {
-    matches!(v, VariableValue::Headers(_))
+    match v {
+        VariableValue::Headers(_) => true,
+        VariableValue::Array(items) => items.iter().all(|i| split_header_entry(i).is_some()),
+        _ => false,
+    }
+}
+
+pub fn parse_header_condition(r: &mut TokenReader, value: String) -> String {
+    r.skip_ignorable();
+    if r.cur()
+        .is_some_and(|t| t.token_type == TokenType::Identifier && t.value == HEADER_WHEN_BODY)
+    {
+        r.advance();
+        return format!("{WHEN_BODY_MARKER}{value}");
+    }
+    value
+}
+
+pub fn split_header_condition(value: &str) -> (&str, bool) {
+    match value.strip_prefix(WHEN_BODY_MARKER) {
+        Some(rest) => (rest, true),
+        None => (value, false),
+    }
+}
+
+pub fn apply_header_conditions(
+    headers: Vec<(String, String)>,
+    has_body: bool,
+) -> Vec<(String, String)> {
+    headers
+        .into_iter()
+        .filter_map(|(key, value)| match value.strip_prefix(WHEN_BODY_MARKER) {
+            Some(rest) => has_body.then(|| (key, rest.to_string())),
+            None => Some((key, value)),
+        })
+        .collect()
+}
+
+pub fn split_header_entry(entry: &str) -> Option<(String, String)> {
+    let (key, value) = entry.split_once(':')?;
+    let key = strip_matching_quotes(key.trim());
+    if key.is_empty() {
+        return None;
+    }
+    Some((
+        key.to_string(),
+        strip_matching_quotes(value.trim()).to_string(),
+    ))
+}
+
+fn strip_matching_quotes(s: &str) -> &str {
+    s.strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+        .unwrap_or(s)
 }
 
 pub fn parse_system_function(
@@ -109,30 +189,19 @@ pub fn parse_system_function(
                 let arg = unescape_string(&normalize_multiline_string(raw, " "));
                 args.push(arg);
                 r.advance();
-                r.skip_ignorable();
-                if let Some(comma) = r.cur() {
-                    if comma.token_type == TokenType::Punctuation && comma.value == PUNC_COMMA {
-                        r.advance();
-                        r.skip_ignorable();
-                    }
-                }
             } else if t.token_type == TokenType::Identifier {
                 let var_name = t.value.clone();
                 args.push(format!("{{{{{var_name}}}}}"));
                 r.advance();
-                r.skip_ignorable();
-                if let Some(comma) = r.cur() {
-                    if comma.token_type == TokenType::Punctuation && comma.value == PUNC_COMMA {
-                        r.advance();
-                        r.skip_ignorable();
-                    }
-                }
+            } else if t.token_type == TokenType::Punctuation && t.value == PUNC_DOLLAR {
+                args.push(parse_json_body(r)?);
             } else {
                 return Err(r.create_error_no_file(
-                    "Expected string literal or variable name".into(),
+                    "Expected string literal, variable name, or JSON object".into(),
                     t.span.clone(),
                 ));
             }
+            skip_argument_separator(r);
         } else {
             return Err(r.create_error(
                 "Unexpected end of input in system function call".into(),
@@ -150,6 +219,16 @@ pub fn parse_system_function(
     })
 }
 
+fn skip_argument_separator(r: &mut TokenReader) {
+    r.skip_ignorable();
+    if let Some(comma) = r.cur() {
+        if comma.token_type == TokenType::Punctuation && comma.value == PUNC_COMMA {
+            r.advance();
+            r.skip_ignorable();
+        }
+    }
+}
+
 pub(crate) fn unescape_string(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
     let mut chars = s.chars();
@@ -207,42 +286,18 @@ pub fn parse_string_value(r: &mut TokenReader, separator: &str) -> Result<String
             }
             TokenType::Identifier => {
                 let ident = t.value.clone();
-                if crate::syntax::functions::is_known_namespace(&ident) {
-                    let saved = r.idx;
-                    r.advance();
-                    r.skip_ignorable();
-                    if let Some(dot) = r.cur() {
-                        if dot.token_type == TokenType::Punctuation && dot.value == "." {
-                            r.advance();
-                            r.skip_ignorable();
-                            let func_span = r
-                                .cur()
-                                .map(|t| t.span.clone())
-                                .unwrap_or(r.source.len()..r.source.len());
-                            let sys_func = parse_system_function(r, &ident)?;
-                            if let VariableValue::SystemFunction { name, args } = sys_func {
-                                let func = crate::syntax::functions::get_function(
-                                    &ident,
-                                    &name[ident.len() + 1..],
-                                );
-                                if func.map(|f| f.return_type())
-                                    != Some(crate::syntax::functions::traits::FunctionReturnType::String)
-                                {
-                                    return Err(r.create_error_no_file(
-                                        format!("{name}() cannot be used here: expected a string-returning function"),
-                                        func_span,
-                                    ));
-                                }
-                                let args_str = args.join("\x1F");
-                                return Ok(format!("{{{{${name}\x1E{args_str}}}}}"));
-                            }
-                        }
-                    }
-                    r.idx = saved;
+                if let Some(call) = parse_string_function_call(r, &ident)? {
+                    return Ok(call);
                 }
                 r.advance();
                 Ok(format!("{{{{{ident}}}}}"))
             }
+            TokenType::Keyword if crate::syntax::functions::is_known_namespace(&t.value) => {
+                let (ident, span) = (t.value.clone(), t.span.clone());
+                parse_string_function_call(r, &ident)?.ok_or_else(|| {
+                    r.create_error_no_file("Expected string literal or identifier".into(), span)
+                })
+            }
             _ => Err(r.create_error_no_file(
                 "Expected string literal or identifier".into(),
                 t.span.clone(),
@@ -253,6 +308,57 @@ pub fn parse_string_value(r: &mut TokenReader, separator: &str) -> Result<String
     }
 }
 
+fn parse_string_function_call(
+    r: &mut TokenReader,
+    ident: &str,
+) -> Result<Option<String>, SyntaxError> {
+    if !crate::syntax::functions::is_known_namespace(ident) {
+        return Ok(None);
+    }
+    let saved = r.idx;
+    r.advance();
+    r.skip_ignorable();
+    if !r
+        .cur()
+        .is_some_and(|dot| dot.token_type == TokenType::Punctuation && dot.value == ".")
+    {
+        r.idx = saved;
+        return Ok(None);
+    }
+    r.advance();
+    r.skip_ignorable();
+    let func_span = r
+        .cur()
+        .map(|t| t.span.clone())
+        .unwrap_or(r.source.len()..r.source.len());
+    let VariableValue::SystemFunction { name, args } = parse_system_function(r, ident)? else {
+        r.idx = saved;
+        return Ok(None);
+    };
+    let func = crate::syntax::functions::get_function(ident, &name[ident.len() + 1..]);
+    if func.map(|f| f.return_type())
+        != Some(crate::syntax::functions::traits::FunctionReturnType::String)
+    {
+        return Err(r.create_error_no_file(
+            format!("{name}() cannot be used here: expected a string-returning function"),
+            func_span,
+        ));
+    }
+    let args_str = args.join("\x1F");
+    Ok(Some(format!("{{{{${name}\x1E{args_str}\x1E}}}}")))
+}
+
+pub fn parse_bool(r: &mut TokenReader, what: &str) -> Result<bool, SyntaxError> {
+    let tok = expect(
+        r,
+        |t| t.token_type == TokenType::Identifier && (t.value == "true" || t.value == "false"),
+        format!("Expected 'true' or 'false' for {what}"),
+    )?;
+    let value = tok.value == "true";
+    r.advance();
+    Ok(value)
+}
+
 pub fn parse_headers_array(r: &mut TokenReader) -> Result<Vec<(String, String)>, SyntaxError> {
     expect(
         r,
@@ -267,7 +373,125 @@ pub fn parse_headers_array(r: &mut TokenReader) -> Result<Vec<(String, String)>,
         format!("Expected '{PUNC_LBRACKET}'"),
     )?;
     r.advance();
-    let mut headers = Vec::new();
+    parse_key_value_entries(r, true)
+}
+
+pub fn is_form_body(r: &TokenReader) -> bool {
+    is_bracketed_body(r, BODY_FORM)
+}
+
+pub fn is_multipart_body(r: &TokenReader) -> bool {
+    is_bracketed_body(r, BODY_MULTIPART)
+}
+
+fn is_bracketed_body(r: &TokenReader, name: &str) -> bool {
+    if !r
+        .cur()
+        .is_some_and(|t| t.token_type == TokenType::Identifier && t.value == name)
+    {
+        return false;
+    }
+    let mut lookahead = 1;
+    while let Some(next) = r.peek(lookahead) {
+        if !matches!(next.token_type, TokenType::Whitespace | TokenType::Newline) {
+            return next.token_type == TokenType::Punctuation && next.value == PUNC_LBRACKET;
+        }
+        lookahead += 1;
+    }
+    false
+}
+
+pub fn is_bytes_body(r: &TokenReader) -> bool {
+    let mut significant = (0..)
+        .map_while(|offset| r.peek(offset))
+        .filter(|t| !matches!(t.token_type, TokenType::Whitespace | TokenType::Newline));
+    match (significant.next(), significant.next(), significant.next()) {
+        (Some(namespace), Some(dot), Some(name))
+            if namespace.token_type == TokenType::Identifier
+                && dot.token_type == TokenType::Punctuation
+                && dot.value == "."
+                && name.token_type == TokenType::Identifier =>
+        {
+            crate::syntax::functions::get_function(&namespace.value, &name.value).is_some_and(|f| {
+                f.return_type() == crate::syntax::functions::traits::FunctionReturnType::Bytes
+            })
+        }
+        _ => false,
+    }
+}
+
+pub fn parse_bytes_body(r: &mut TokenReader) -> Result<String, SyntaxError> {
+    let namespace = r.cur().map(|t| t.value.clone()).unwrap_or_default();
+    let span = r.cur().map(|t| t.span.clone()).unwrap_or_default();
+    r.advance();
+    r.skip_ignorable();
+    r.advance();
+    r.skip_ignorable();
+    match parse_system_function(r, &namespace)? {
+        VariableValue::SystemFunction { args, .. } if !args.is_empty() => {
+            Ok(args.into_iter().next().unwrap_or_default())
+        }
+        _ => Err(r.create_error("Expected a file path for the request body".into(), span)),
+    }
+}
+
+pub fn parse_form_body(r: &mut TokenReader) -> Result<Vec<(String, String)>, SyntaxError> {
+    open_bracketed_body(r)?;
+    parse_key_value_entries(r, false)
+}
+
+pub fn parse_multipart_body(r: &mut TokenReader) -> Result<Vec<MultipartPart>, SyntaxError> {
+    open_bracketed_body(r)?;
+    let entries = parse_entries(r, |r| {
+        let is_file = r
+            .cur()
+            .is_some_and(|t| t.token_type == TokenType::Punctuation && t.value == PUNC_AT);
+        if !is_file {
+            return Ok(MultipartValue::Text(parse_string_value(r, " ")?));
+        }
+        r.advance();
+        Ok(MultipartValue::File {
+            path: parse_string_value(r, "")?,
+            content: None,
+        })
+    })?;
+    Ok(entries
+        .into_iter()
+        .map(|(name, value)| MultipartPart { name, value })
+        .collect())
+}
+
+fn open_bracketed_body(r: &mut TokenReader) -> Result<(), SyntaxError> {
+    r.advance();
+    r.skip_ignorable();
+    expect(
+        r,
+        |t| t.token_type == TokenType::Punctuation && t.value == PUNC_LBRACKET,
+        format!("Expected '{PUNC_LBRACKET}'"),
+    )?;
+    r.advance();
+    Ok(())
+}
+
+fn parse_key_value_entries(
+    r: &mut TokenReader,
+    allow_conditions: bool,
+) -> Result<Vec<(String, String)>, SyntaxError> {
+    parse_entries(r, |r| {
+        let val = parse_string_value(r, " ")?;
+        Ok(if allow_conditions {
+            parse_header_condition(r, val)
+        } else {
+            val
+        })
+    })
+}
+
+fn parse_entries<T>(
+    r: &mut TokenReader,
+    mut parse_value: impl FnMut(&mut TokenReader) -> Result<T, SyntaxError>,
+) -> Result<Vec<(String, T)>, SyntaxError> {
+    let mut entries = Vec::new();
     loop {
         r.skip_ignorable();
         if let Some(ct) = r.cur() {
@@ -287,8 +511,7 @@ pub fn parse_headers_array(r: &mut TokenReader) -> Result<Vec<(String, String)>,
                 )?;
                 r.advance();
                 r.skip_ignorable();
-                let val = parse_string_value(r, " ")?;
-                headers.push((key, val));
+                entries.push((key, parse_value(r)?));
                 r.skip_ignorable();
                 if let Some(com) = r.cur() {
                     if com.token_type == TokenType::Punctuation && com.value == PUNC_COMMA {
@@ -313,11 +536,161 @@ pub fn parse_headers_array(r: &mut TokenReader) -> Result<Vec<(String, String)>,
             r.advance();
         }
     }
-    Ok(headers)
+    Ok(entries)
+}
+
+pub fn parse_json_body(r: &mut TokenReader) -> Result<String, SyntaxError> {
+    r.advance();
+    r.skip_ignorable();
+    let is_json5 = r
+        .cur()
+        .map(|t| t.token_type == TokenType::Identifier && t.value == "json5")
+        .unwrap_or(false);
+    if is_json5 {
+        r.advance();
+        r.skip_ignorable();
+    }
+    let start = expect(
+        r,
+        |tk| tk.token_type == TokenType::Punctuation && tk.value == PUNC_LBRACE,
+        format!("Expected '{PUNC_LBRACE}'"),
+    )?
+    .span
+    .start;
+    let mut depth = 0;
+    let mut collected = String::new();
+    while let Some(tok) = r.cur() {
+        if tok.token_type == TokenType::Punctuation {
+            if tok.value == PUNC_LBRACE {
+                depth += 1;
+            }
+            if tok.value == PUNC_RBRACE {
+                depth -= 1;
+            }
+            collected.push_str(&tok.value);
+            r.advance();
+            if depth == 0 {
+                break;
+            }
+        } else {
+            collected.push_str(&tok.value);
+            r.advance();
+        }
+    }
+    if !is_json5 {
+        let collected = blank_trailing_commas(&collected);
+        return validate_json_body(&collected)
+            .map(|()| collected)
+            .map_err(|(message, offset)| {
+                let pos = start + offset;
+                r.create_error_with_file(format!("Invalid JSON body: {message}"), pos..pos + 1)
+            });
+    }
+    crate::syntax::json5::to_json(&collected).map_err(|e| {
+        let pos = start + e.offset;
+        r.create_error_with_file(format!("Invalid JSON5 body: {}", e.message), pos..pos + 1)
+    })
+}
+
+fn validate_json_body(body: &str) -> Result<(), (String, usize)> {
+    let masked = mask_for_validation(body);
+    match serde_json::from_str::<serde_json::Value>(&masked) {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            let line_start: usize = masked
+                .split_inclusive('\n')
+                .take(e.line().saturating_sub(1))
+                .map(str::len)
+                .sum();
+            let offset = (line_start + e.column().saturating_sub(1)).min(body.len());
+            let message = e.to_string();
+            let message = message
+                .split(" at line ")
+                .next()
+                .unwrap_or(&message)
+                .to_string();
+            Err((message, offset))
+        }
+    }
+}
+
+fn blank_trailing_commas(body: &str) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut rest = body;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") && !escaped {
+            if let Some(end) = rest.find("}}") {
+                out.push_str(&rest[..end + 2]);
+                rest = &rest[end + 2..];
+                continue;
+            }
+        }
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' && rest[1..].trim_start().starts_with(['}', ']']) {
+            out.push(' ');
+            rest = &rest[1..];
+            continue;
+        }
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+fn mask_for_validation(body: &str) -> String {
+    let mut masked = String::with_capacity(body.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut rest = body;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") && !escaped {
+            if let Some(end) = rest.find("}}") {
+                let len = end + 2;
+                let is_key = !in_string && rest[len..].trim_start().starts_with(':');
+                if in_string {
+                    masked.push_str(&"_".repeat(len));
+                } else if is_key {
+                    masked.push('"');
+                    masked.push_str(&"_".repeat(len - 2));
+                    masked.push('"');
+                } else {
+                    masked.push_str("null");
+                    masked.push_str(&" ".repeat(len - 4));
+                }
+                rest = &rest[len..];
+                continue;
+            }
+        }
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        }
+        masked.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    masked
 }
 
 pub fn can_parse_attributed(r: &TokenReader, keyword: &str) -> bool {
-    if r.is_keyword(keyword) {
+    if r.is_keyword(keyword) || r.is_contextual_keyword(keyword) {
         return true;
     }
     if let Some(t) = r.cur() {
@@ -349,7 +722,9 @@ pub fn can_parse_attributed(r: &TokenReader, keyword: &str) -> bool {
                             continue;
                         }
 
-                        if next.token_type == TokenType::Keyword && next.value == keyword {
+                        if matches!(next.token_type, TokenType::Keyword | TokenType::Identifier)
+                            && next.value == keyword
+                        {
                             return true;
                         }
 
@@ -373,6 +748,99 @@ pub fn can_parse_attributed(r: &TokenReader, keyword: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn split_header_entry_splits_on_first_colon() {
+        assert_eq!(
+            split_header_entry("X-Trace: abc:def"),
+            Some(("X-Trace".to_string(), "abc:def".to_string()))
+        );
+    }
+
+    #[test]
+    fn split_header_entry_strips_quotes_around_value() {
+        assert_eq!(
+            split_header_entry("X-Quoted: \"hello world\""),
+            Some(("X-Quoted".to_string(), "hello world".to_string()))
+        );
+    }
+
+    #[test]
+    fn split_header_entry_rejects_missing_colon_or_name() {
+        assert_eq!(split_header_entry("Authorization"), None);
+        assert_eq!(split_header_entry(": value"), None);
+    }
+
+    #[test]
+    fn array_of_header_entries_is_headers_like() {
+        let value = VariableValue::Array(vec![
+            "Authorization: Bearer x".to_string(),
+            "X-Trace: abc:def".to_string(),
+        ]);
+        assert!(is_headers_like(&value));
+        assert!(!is_headers_like(&VariableValue::Array(vec![
+            "Authorization".to_string()
+        ])));
+    }
+
+    #[test]
+    fn when_body_headers_only_apply_with_body() {
+        let headers = vec![
+            (
+                "Content-Type".to_string(),
+                format!("{WHEN_BODY_MARKER}application/json"),
+            ),
+            ("Accept".to_string(), "*/*".to_string()),
+        ];
+        assert_eq!(
+            apply_header_conditions(headers.clone(), false),
+            vec![("Accept".to_string(), "*/*".to_string())]
+        );
+        assert_eq!(
+            apply_header_conditions(headers, true)[0],
+            ("Content-Type".to_string(), "application/json".to_string())
+        );
+    }
+
+    #[test]
+    fn mask_placeholder_outside_string_becomes_null() {
+        assert_eq!(
+            mask_for_validation(r#"{"a": {{count}}}"#),
+            r#"{"a": null     }"#
+        );
+    }
+
+    #[test]
+    fn mask_placeholder_inside_string_keeps_string_valid() {
+        assert_eq!(
+            mask_for_validation(r#"{"y": "{{ datetime.now("yyyy") }}"}"#),
+            r#"{"y": "__________________________"}"#
+        );
+    }
+
+    #[test]
+    fn mask_placeholder_in_key_position_becomes_string() {
+        assert_eq!(mask_for_validation(r#"{ {{k}}: 1 }"#), r#"{ "___": 1 }"#);
+    }
+
+    #[test]
+    fn trailing_commas_are_blanked_outside_strings() {
+        assert_eq!(
+            blank_trailing_commas(r#"{"a": [1,], "b": ",}", "c": "{{ f(x,) }}", }"#),
+            r#"{"a": [1 ], "b": ",}", "c": "{{ f(x,) }}"  }"#
+        );
+    }
+
+    #[test]
+    fn validate_json_body_accepts_placeholders() {
+        assert!(validate_json_body(r#"{"a": {{count}}, "b": "{{name}}"}"#).is_ok());
+    }
+
+    #[test]
+    fn validate_json_body_reports_offset_of_error() {
+        let (_, offset) = validate_json_body("{\n  \"a\": 1,\n  \"b\" 2\n}").unwrap_err();
+        assert_eq!(offset, 18);
+    }
+
     #[test]
     fn unescape_double_quote() {
         assert_eq!(unescape_string(r#"hello \"world\""#), r#"hello "world""#);
@@ -1,13 +1,16 @@
 use super::{
     parse_trait::Parse,
-    utils::{normalize_multiline_string, parse_system_function, unescape_string},
+    utils::{
+        normalize_multiline_string, parse_header_condition, parse_json_body, parse_system_function,
+        unescape_string,
+    },
 };
 use crate::syntax::fs::Fs;
 use crate::syntax::{
     error::SyntaxError,
     keywords::{
         KW_LET, OP_ASSIGN, PUNC_COLON, PUNC_COMMA, PUNC_DOLLAR, PUNC_DOT, PUNC_LBRACE,
-        PUNC_LBRACKET, PUNC_RBRACE, PUNC_RBRACKET, PUNC_SEMI,
+        PUNC_LBRACKET, PUNC_RBRACKET, PUNC_SEMI,
     },
     parse_result::ParseResult,
     reader::{expect, TokenReader},
@@ -89,6 +92,9 @@ fn parse_variable_value(r: &mut TokenReader) -> Result<VariableValue, SyntaxErro
 
     match token.token_type {
         TokenType::Identifier => parse_identifier_value(r, &token),
+        TokenType::Keyword if crate::syntax::functions::is_known_namespace(&token.value) => {
+            parse_keyword_namespace_value(r, &token)
+        }
         TokenType::String => {
             let raw = &token.value[1..token.value.len() - 1];
             let s = unescape_string(&normalize_multiline_string(raw, " "));
@@ -150,6 +156,22 @@ fn parse_identifier_value(
     }
 }
 
+fn parse_keyword_namespace_value(
+    r: &mut TokenReader,
+    t: &crate::syntax::token::Token,
+) -> Result<VariableValue, SyntaxError> {
+    r.advance();
+    r.skip_ignorable();
+    expect(
+        r,
+        |t| t.token_type == TokenType::Punctuation && t.value == PUNC_DOT,
+        format!("Expected '{PUNC_DOT}'"),
+    )?;
+    r.advance();
+    r.skip_ignorable();
+    parse_system_function(r, &t.value)
+}
+
 fn parse_headers_variable(r: &mut TokenReader) -> Result<VariableValue, SyntaxError> {
     r.advance(); // consume $
     r.skip_ignorable();
@@ -189,6 +211,7 @@ fn parse_headers_variable(r: &mut TokenReader) -> Result<VariableValue, SyntaxEr
                     val_tok.value.clone()
                 };
                 r.advance();
+                let val = parse_header_condition(r, val);
                 headers.push((key, val));
                 r.skip_ignorable();
                 if let Some(com) = r.cur() {
@@ -241,32 +264,5 @@ fn parse_array_variable(r: &mut TokenReader) -> Result<VariableValue, SyntaxErro
 }
 
 fn parse_json_value(r: &mut TokenReader) -> Result<VariableValue, SyntaxError> {
-    r.advance();
-    r.skip_ignorable();
-    let _ = expect(
-        r,
-        |tk| tk.token_type == TokenType::Punctuation && tk.value == PUNC_LBRACE,
-        format!("Expected '{PUNC_LBRACE}'"),
-    )?;
-    let mut depth = 0;
-    let mut collected = String::new();
-    while let Some(tok) = r.cur() {
-        if tok.token_type == TokenType::Punctuation {
-            if tok.value == PUNC_LBRACE {
-                depth += 1;
-            }
-            if tok.value == PUNC_RBRACE {
-                depth -= 1;
-            }
-            collected.push_str(&tok.value);
-            r.advance();
-            if depth == 0 {
-                break;
-            }
-        } else {
-            collected.push_str(&tok.value);
-            r.advance();
-        }
-    }
-    Ok(VariableValue::Json(collected))
+    parse_json_body(r).map(VariableValue::Json)
 }
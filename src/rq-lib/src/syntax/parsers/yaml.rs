@@ -0,0 +1,97 @@
+use serde_json::Value;
+
+use super::utils::unescape_string;
+use crate::syntax::{
+    error::SyntaxError,
+    reader::{expect, TokenReader},
+    token::TokenType,
+};
+
+pub fn parse_yaml_body(r: &mut TokenReader) -> Result<String, SyntaxError> {
+    let tok = expect(
+        r,
+        |t| t.token_type == TokenType::String,
+        "Expected string literal for 'body_yaml'",
+    )?
+    .clone();
+    let content_start = tok.span.start + 1;
+    let (text, line_offsets) = dedent(&tok.value[1..tok.value.len() - 1]);
+    let value: Value = serde_yaml::from_str(&unescape_string(&text)).map_err(|e| {
+        let position = e
+            .location()
+            .and_then(|loc| {
+                line_offsets
+                    .get(loc.line().saturating_sub(1))
+                    .map(|offset| content_start + offset + loc.column().saturating_sub(1))
+            })
+            .unwrap_or(tok.span.start);
+        r.create_error(
+            format!("Invalid YAML body: {}", yaml_message(&e)),
+            position..position + 1,
+        )
+    })?;
+    if !value.is_object() && !value.is_array() {
+        return Err(r.create_error("YAML body must be a mapping or a sequence".into(), tok.span));
+    }
+    r.advance();
+    serde_json::to_string(&value)
+        .map_err(|e| r.create_error(format!("Invalid YAML body: {e}"), tok.span))
+}
+
+fn yaml_message(error: &serde_yaml::Error) -> String {
+    let message = error.to_string();
+    match message.split_once(" at line ") {
+        Some((head, _)) => head.to_string(),
+        None => message,
+    }
+}
+
+fn dedent(raw: &str) -> (String, Vec<usize>) {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in raw.split('\n') {
+        lines.push((start, line.trim_end_matches('\r')));
+        start += line.len() + 1;
+    }
+    if lines.len() > 1 && lines[0].1.trim().is_empty() {
+        lines.remove(0);
+    }
+    if lines.len() > 1 && lines.last().is_some_and(|(_, l)| l.trim().is_empty()) {
+        lines.pop();
+    }
+    let indent = lines
+        .iter()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(_, l)| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut text = String::new();
+    let mut offsets = Vec::new();
+    for (offset, line) in lines {
+        let cut = indent.min(line.len() - line.trim_start().len());
+        offsets.push(offset + cut);
+        text.push_str(&line[cut..]);
+        text.push('\n');
+    }
+    (text, offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedent_strips_common_indentation_and_blank_edges() {
+        let (text, offsets) = dedent("\n    name: rq\n    tags:\n      - a\n  ");
+        assert_eq!(text, "name: rq\ntags:\n  - a\n");
+        assert_eq!(offsets, vec![5, 18, 28]);
+    }
+
+    #[test]
+    fn dedent_keeps_single_line_content() {
+        let (text, offsets) = dedent("a: 1");
+        assert_eq!(text, "a: 1\n");
+        assert_eq!(offsets, vec![0]);
+    }
+}
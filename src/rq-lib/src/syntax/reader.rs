@@ -95,6 +95,11 @@ impl TokenReader {
             .map(|t| t.token_type == TokenType::Keyword && t.value == kw)
             .unwrap_or(false)
     }
+    pub fn is_contextual_keyword(&self, kw: &str) -> bool {
+        self.cur()
+            .map(|t| t.token_type == TokenType::Identifier && t.value == kw)
+            .unwrap_or(false)
+    }
 }
 
 pub fn make_error<S: Into<String>>(r: &TokenReader, t: &Token, msg: S) -> SyntaxError {
@@ -1,20 +1,24 @@
 use super::{
     error::SyntaxError,
     fs::Fs,
-    functions::{self, traits::FunctionContext},
-    parse_result::Request,
-    parsers::utils::parse_system_function,
+    functions::{
+        self,
+        traits::{FunctionContext, RqFunction},
+    },
+    parse_result::{BodyFile, MultipartPart, MultipartValue, Request},
+    parsers::utils::{parse_system_function, split_header_condition},
     reader::TokenReader,
     token::TokenType,
     tokenizer::tokenize,
     variable_context::{VariableContext, VariableValue},
 };
 use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 lazy_static! {
     static ref FUNC_PATTERN: regex::Regex =
-        regex::Regex::new(r"\{\{\$([a-zA-Z0-9_]+)\.([a-zA-Z0-9_]+)\x1E(.*?)\}\}").unwrap();
+        regex::Regex::new(r"(?s)\{\{\$([a-zA-Z0-9_]+)\.([a-zA-Z0-9_]+)\x1E(.*?)\x1E\}\}").unwrap();
     static ref USER_FUNC_PATTERN: regex::Regex =
         regex::Regex::new(r"\{\{\s*([a-zA-Z0-9_]+)\.([a-zA-Z0-9_]+)\s*\((.*?)\)\s*\}\}").unwrap();
     static ref UNRESOLVED_PATTERN: regex::Regex =
@@ -808,18 +812,264 @@ pub fn resolve_variables(
         *k = resolve_string(k, context, source_files, fs)?;
         *v = resolve_string(v, context, source_files, fs)?;
     }
+    check_header_injection(&request)?;
     if let Some(body) = &request.body {
         request.body = Some(resolve_string(body, context, source_files, fs)?);
     }
+    if let Some(form) = &request.form {
+        request.form = Some(resolve_form_fields(form, context, source_files, fs)?);
+    }
+    if let Some(parts) = request.multipart.take() {
+        request.multipart = Some(resolve_multipart_parts(
+            &request,
+            parts,
+            context,
+            source_files,
+            fs,
+        )?);
+    }
+    if let Some(lines) = &request.body_lines {
+        request.body_lines = Some(resolve_body_lines(lines, context, source_files, fs)?);
+    }
+    if let Some(path) = &request.body_file {
+        let path = resolve_string(path, context, source_files, fs)?;
+        request.body_bytes = Some(read_body_bytes(&path, source_files, fs)?);
+        request.body_file = Some(path);
+    }
+    if let Some(file) = request.text_file.take() {
+        request.text_file = Some(resolve_text_file(
+            &mut request,
+            file,
+            context,
+            source_files,
+            fs,
+        )?);
+    }
     if let Some(timeout) = &request.timeout {
         request.timeout = Some(resolve_string(timeout, context, source_files, fs)?);
     }
-    if let Some(auth) = &request.auth {
-        request.auth = Some(resolve_string(auth, context, source_files, fs)?);
+    request.auth = request
+        .auth
+        .iter()
+        .map(|auth| resolve_string(auth, context, source_files, fs))
+        .collect::<Result<_, _>>()?;
+    if let Some(path) = &request.unix_socket {
+        request.unix_socket = Some(resolve_string(path, context, source_files, fs)?);
+    }
+    if let Some(cert) = &mut request.cert {
+        cert.path = resolve_string(&cert.path, context, source_files, fs)?;
+        if let Some(password) = &cert.password {
+            cert.password = Some(resolve_string(password, context, source_files, fs)?);
+        }
     }
     Ok(request)
 }
 
+fn check_header_injection(request: &Request) -> Result<(), SyntaxError> {
+    let message = request.headers.iter().find_map(|(name, value)| {
+        let (value, _) = split_header_condition(value);
+        if name.chars().any(|c| c.is_control()) {
+            Some(format!(
+                "Header name '{}' in request '{}' contains control characters after variable resolution; CR, LF and other control characters are not allowed in header names",
+                name.escape_debug(),
+                request.name
+            ))
+        } else if value.chars().any(|c| c.is_control() && c != '\t') {
+            Some(format!(
+                "Header '{name}' in request '{}' resolves to a value containing control characters; CR, LF and other control characters are not allowed in header values",
+                request.name
+            ))
+        } else {
+            None
+        }
+    });
+    let Some(message) = message else {
+        return Ok(());
+    };
+    let mut error = SyntaxError::new(message, request.line + 1, request.character + 1, 0..0);
+    error.file_path = request.source_path.clone();
+    Err(error)
+}
+
+fn resolve_form_fields(
+    form: &[(String, String)],
+    context: &VariableContext,
+    source_files: &[PathBuf],
+    fs: &dyn Fs,
+) -> Result<Vec<(String, String)>, SyntaxError> {
+    let map = context.as_map();
+    let mut fields = Vec::new();
+    for (key, value) in form {
+        let key = resolve_string(key, context, source_files, fs)?;
+        match form_array_values(value, &map) {
+            Some(items) => {
+                for item in items {
+                    fields.push((
+                        key.clone(),
+                        resolve_string(item, context, source_files, fs)?,
+                    ));
+                }
+            }
+            None => fields.push((key, resolve_string(value, context, source_files, fs)?)),
+        }
+    }
+    Ok(fields)
+}
+
+fn form_array_values<'a>(
+    value: &str,
+    map: &HashMap<&str, &'a VariableValue>,
+) -> Option<&'a [String]> {
+    match whole_reference(value, map)? {
+        VariableValue::Array(items) => Some(items),
+        _ => None,
+    }
+}
+
+fn whole_reference<'a>(
+    value: &str,
+    map: &HashMap<&str, &'a VariableValue>,
+) -> Option<&'a VariableValue> {
+    let mut name = UNRESOLVED_PATTERN
+        .captures(value)
+        .filter(|caps| caps[0].len() == value.len())
+        .map(|caps| caps[1].to_string())?;
+    let mut visited = HashSet::new();
+    while visited.insert(name.clone()) {
+        match map.get(name.as_str())? {
+            VariableValue::Reference(next) => name = next.clone(),
+            other => return Some(other),
+        }
+    }
+    None
+}
+
+fn resolve_body_lines(
+    lines: &[String],
+    context: &VariableContext,
+    source_files: &[PathBuf],
+    fs: &dyn Fs,
+) -> Result<Vec<String>, SyntaxError> {
+    let map = context.as_map();
+    let mut resolved = Vec::new();
+    for line in lines {
+        match whole_reference(line, &map) {
+            Some(VariableValue::Array(items)) => {
+                for item in items {
+                    resolved.push(resolve_string(item, context, source_files, fs)?);
+                }
+            }
+            Some(VariableValue::Json(json)) => resolved.extend(json_array_lines(line, json)?),
+            _ => resolved.push(resolve_string(line, context, source_files, fs)?),
+        }
+    }
+    Ok(resolved)
+}
+
+fn resolve_multipart_parts(
+    request: &Request,
+    parts: Vec<MultipartPart>,
+    context: &VariableContext,
+    source_files: &[PathBuf],
+    fs: &dyn Fs,
+) -> Result<Vec<MultipartPart>, SyntaxError> {
+    let ctx = FunctionContext { source_files, fs };
+    let mut resolved = Vec::with_capacity(parts.len());
+    for part in parts {
+        let name = resolve_string(&part.name, context, source_files, fs)?;
+        let value = match part.value {
+            MultipartValue::Text(text) => {
+                MultipartValue::Text(resolve_string(&text, context, source_files, fs)?)
+            }
+            MultipartValue::File { path, .. } => {
+                let path = resolve_string(&path, context, source_files, fs)?;
+                let args = [path.clone()];
+                let content = functions::io::read_bytes::IoReadBytes
+                    .execute_bytes(&args, &ctx)
+                    .map_err(|e| {
+                        let mut error = SyntaxError::new(
+                            format!("Multipart part '{name}': {e}"),
+                            request.line + 1,
+                            request.character + 1,
+                            0..0,
+                        );
+                        error.file_path = request.source_path.clone();
+                        error
+                    })?;
+                MultipartValue::File {
+                    path,
+                    content: Some(content),
+                }
+            }
+        };
+        resolved.push(MultipartPart { name, value });
+    }
+    Ok(resolved)
+}
+
+fn resolve_text_file(
+    request: &mut Request,
+    file: BodyFile,
+    context: &VariableContext,
+    source_files: &[PathBuf],
+    fs: &dyn Fs,
+) -> Result<BodyFile, SyntaxError> {
+    let path = resolve_string(&file.path, context, source_files, fs)?;
+    let ctx = FunctionContext { source_files, fs };
+    let args = [path.clone()];
+    let content = functions::io::read_file::IoReadFile
+        .execute(&args, &ctx)
+        .map_err(|e| {
+            let (line, col, file) = find_sys_call_location(fs, source_files, "body_file", &args);
+            SyntaxError::with_file(e, line, col, 0..0, format_path(&file))
+        })?;
+    request.body = Some(if file.raw {
+        content
+    } else {
+        resolve_string(&content, context, source_files, fs)?
+    });
+    Ok(BodyFile {
+        path,
+        raw: file.raw,
+    })
+}
+
+fn read_body_bytes(
+    path: &str,
+    source_files: &[PathBuf],
+    fs: &dyn Fs,
+) -> Result<Vec<u8>, SyntaxError> {
+    let ctx = FunctionContext { source_files, fs };
+    let args = [path.to_string()];
+    functions::io::read_bytes::IoReadBytes
+        .execute_bytes(&args, &ctx)
+        .map_err(|e| {
+            let (line, col, file) =
+                find_sys_call_location(fs, source_files, "io.read_bytes", &args);
+            SyntaxError::with_file(e, line, col, 0..0, format_path(&file))
+        })
+}
+
+fn json_array_lines(reference: &str, json: &str) -> Result<Vec<String>, SyntaxError> {
+    let not_array = || {
+        SyntaxError::new(
+            format!("'body_lines' expects an array, but {reference} is not a JSON array"),
+            0,
+            0,
+            0..0,
+        )
+    };
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|_| not_array())?;
+    let items = value.as_array().ok_or_else(not_array)?;
+    Ok(items
+        .iter()
+        .map(|item| match item {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect())
+}
+
 pub fn collect_variable_errors(
     request: &Request,
     context: &VariableContext,
@@ -848,7 +1098,7 @@ pub fn collect_variable_errors(
         try_resolve(k);
         try_resolve(v);
     }
-    if let Some(ref var_name) = request.headers_var {
+    for var_name in request.suite_headers_var.iter().chain(&request.headers_var) {
         let is_defined_headers = matches!(
             context.as_map().get(var_name.as_str()),
             Some(super::variable_context::VariableValue::Headers(_))
@@ -860,12 +1110,51 @@ pub fn collect_variable_errors(
     if let Some(ref body) = request.body {
         try_resolve(body);
     }
+    if let Some(ref form) = request.form {
+        let map = context.as_map();
+        for (k, v) in form {
+            try_resolve(k);
+            if form_array_values(v, &map).is_none() {
+                try_resolve(v);
+            }
+        }
+    }
+    for part in request.multipart.iter().flatten() {
+        try_resolve(&part.name);
+        match &part.value {
+            MultipartValue::Text(text) => try_resolve(text),
+            MultipartValue::File { path, .. } => try_resolve(path),
+        }
+    }
+    if let Some(ref lines) = request.body_lines {
+        let map = context.as_map();
+        for line in lines {
+            if whole_reference(line, &map).is_none() {
+                try_resolve(line);
+            }
+        }
+    }
+    if let Some(ref path) = request.body_file {
+        try_resolve(path);
+    }
+    if let Some(ref file) = request.text_file {
+        try_resolve(&file.path);
+    }
     if let Some(ref timeout) = request.timeout {
         try_resolve(timeout);
     }
-    if let Some(ref auth) = request.auth {
+    for auth in &request.auth {
         try_resolve(auth);
     }
+    if let Some(ref path) = request.unix_socket {
+        try_resolve(path);
+    }
+    if let Some(ref cert) = request.cert {
+        try_resolve(&cert.path);
+        if let Some(ref password) = cert.password {
+            try_resolve(password);
+        }
+    }
     errors
 }
 
@@ -965,8 +1254,27 @@ pub fn resolve_auth_provider(
     source_files: &[PathBuf],
     fs: &dyn Fs,
 ) -> Result<crate::syntax::auth::Config, SyntaxError> {
-    for (_, token) in auth_config.fields.iter_mut() {
+    for (field, token) in auth_config.fields.iter_mut() {
         token.value = resolve_string(&token.value, context, source_files, fs)?;
+        if token.value.contains(['\r', '\n']) {
+            let (line, col) = match fs.read(&auth_config.file_path) {
+                Ok(content) if token.span.end > 0 => {
+                    let (line, col) = zero_based_line_col(&content, token.span.start);
+                    (line + 1, col + 1)
+                }
+                _ => (auth_config.line + 1, auth_config.character + 1),
+            };
+            return Err(SyntaxError::with_file(
+                format!(
+                    "Auth '{}' field '{field}' resolves to a value containing a line break; CR and LF are not allowed in auth values",
+                    auth_config.name
+                ),
+                line,
+                col,
+                token.span.clone(),
+                format_path(&auth_config.file_path),
+            ));
+        }
     }
     Ok(auth_config)
 }
@@ -1009,6 +1317,7 @@ mod tests {
         VariableContext {
             file_variables: vars,
             environment_variables: Vec::new(),
+            profile_variables: Vec::new(),
             secret_variables: Vec::new(),
             endpoint_variables: Vec::new(),
             request_variables: Vec::new(),
@@ -1060,4 +1369,27 @@ mod tests {
         let result = resolve_auth_provider(config, &make_context(vec![]), &[], &NoopReader);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_resolve_auth_provider_rejects_line_breaks() {
+        let mut fields = HashMap::new();
+        fields.insert("token".to_string(), t("{{token_value}}"));
+        let config = Config {
+            name: "test".to_string(),
+            auth_type: AuthType::Bearer,
+            fields,
+            file_path: PathBuf::new(),
+            line: 2,
+            character: 4,
+        };
+        let env_vars = vec![Variable {
+            name: "token_value".to_string(),
+            value: VariableValue::String("abc\r\nX-Injected: 1".to_string()),
+        }];
+        let err = resolve_auth_provider(config, &make_context(env_vars), &[], &NoopReader)
+            .expect_err("line breaks must be rejected");
+        assert!(err.message.contains("field 'token'"));
+        assert!(!err.message.contains("X-Injected"));
+        assert_eq!((err.line, err.column), (3, 5));
+    }
 }
@@ -4,7 +4,7 @@ use super::{
     variable_context::Variable,
 };
 use crate::syntax::auth::Config as AuthConfig;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 pub struct RqFile {
@@ -12,6 +12,7 @@ pub struct RqFile {
     pub requests: Vec<RequestWithVariables>,
     pub environments: HashMap<String, Vec<Variable>>,
     pub environment_locations: HashMap<String, (String, usize, usize)>,
+    pub profiles: HashMap<String, Vec<Variable>>,
     pub auth_providers: HashMap<String, AuthConfig>,
     pub endpoints: HashMap<String, EndpointDefinition>,
     pub file_variables: Vec<Variable>,
@@ -19,6 +20,7 @@ pub struct RqFile {
     pub let_variable_locations: HashMap<String, (String, usize, usize)>,
     pub env_variable_locations: HashMap<String, HashMap<String, (String, usize, usize)>>,
     pub required_variable_locations: HashMap<String, (String, usize, usize)>,
+    pub default_request: Option<String>,
 }
 
 impl RqFile {
@@ -52,6 +54,8 @@ impl RqFile {
                 requests: Vec::new(),
                 environments: HashMap::new(),
                 environment_locations: HashMap::new(),
+                profiles: HashMap::new(),
+                suites: HashSet::new(),
                 auth_providers: HashMap::new(),
                 endpoints: HashMap::new(),
                 file_variables: Vec::new(),
@@ -59,6 +63,7 @@ impl RqFile {
                 let_variable_locations: HashMap::new(),
                 env_variable_locations: HashMap::new(),
                 required_variable_locations: HashMap::new(),
+                default_request: None,
             },
         )
     }
@@ -69,6 +74,7 @@ impl RqFile {
             requests: parse_result.requests,
             environments: parse_result.environments,
             environment_locations: parse_result.environment_locations,
+            profiles: parse_result.profiles,
             auth_providers: parse_result.auth_providers,
             endpoints: parse_result.endpoints,
             file_variables: parse_result.file_variables,
@@ -76,6 +82,7 @@ impl RqFile {
             let_variable_locations: parse_result.let_variable_locations,
             env_variable_locations: parse_result.env_variable_locations,
             required_variable_locations: parse_result.required_variable_locations,
+            default_request: parse_result.default_request,
         }
     }
 }
@@ -1,3 +1,6 @@
+use crate::syntax::keywords::HEADER_WHEN_BODY;
+use crate::syntax::parsers::utils::split_header_condition;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum VariableValue {
     String(String),
@@ -18,6 +21,7 @@ pub struct Variable {
 pub struct VariableContext {
     pub file_variables: Vec<Variable>,
     pub environment_variables: Vec<Variable>,
+    pub profile_variables: Vec<Variable>,
     pub secret_variables: Vec<Variable>,
     pub endpoint_variables: Vec<Variable>,
     pub request_variables: Vec<Variable>,
@@ -28,6 +32,7 @@ pub struct VariableContext {
 pub struct VariableContextBuilder {
     file_variables: Vec<Variable>,
     environment_variables: Vec<Variable>,
+    profile_variables: Vec<Variable>,
     secret_variables: Vec<Variable>,
     endpoint_variables: Vec<Variable>,
     request_variables: Vec<Variable>,
@@ -45,6 +50,11 @@ impl VariableContextBuilder {
         self
     }
 
+    pub fn profile_variables(mut self, v: Vec<Variable>) -> Self {
+        self.profile_variables = v;
+        self
+    }
+
     pub fn secret_variables(mut self, v: Vec<Variable>) -> Self {
         self.secret_variables = v;
         self
@@ -69,6 +79,7 @@ impl VariableContextBuilder {
         VariableContext {
             file_variables: self.file_variables,
             environment_variables: self.environment_variables,
+            profile_variables: self.profile_variables,
             secret_variables: self.secret_variables,
             endpoint_variables: self.endpoint_variables,
             request_variables: self.request_variables,
@@ -94,7 +105,10 @@ impl VariableValue {
             VariableValue::Headers(pairs) => {
                 let inner = pairs
                     .iter()
-                    .map(|(k, v)| format!("\"{k}\": \"{v}\""))
+                    .map(|(k, v)| match split_header_condition(v) {
+                        (v, true) => format!("\"{k}\": \"{v}\" {HEADER_WHEN_BODY}"),
+                        (v, false) => format!("\"{k}\": \"{v}\""),
+                    })
                     .collect::<Vec<_>>()
                     .join(", ");
                 format!("$[{inner}]")
@@ -128,6 +142,9 @@ impl VariableContext {
         for var in &self.environment_variables {
             map.insert(var.name.as_str(), &var.value);
         }
+        for var in &self.profile_variables {
+            map.insert(var.name.as_str(), &var.value);
+        }
         for var in &self.secret_variables {
             map.insert(var.name.as_str(), &var.value);
         }
@@ -147,6 +164,7 @@ impl VariableContext {
         let mut all = Vec::new();
         all.extend(self.file_variables.clone());
         all.extend(self.environment_variables.clone());
+        all.extend(self.profile_variables.clone());
         all.extend(self.secret_variables.clone());
         all.extend(self.endpoint_variables.clone());
         all.extend(self.request_variables.clone());
@@ -1,8 +1,9 @@
 use crate::{WasmFs, WasmHttpClient, WasmSecretProvider};
 use rq_lib::client::models::{
-    AuthListEntry, EndpointEntry, EnvironmentEntry, ReferenceLocation, VariableEntry,
+    AuthListEntry, EndpointEntry, EnvironmentEntry, ReferenceLocation, RunOptions, VariableEntry,
 };
 use rq_lib::error::RqError;
+use rq_lib::syntax::auth::AuthContext;
 use rq_lib::RqClient;
 use serde::Serialize;
 use std::collections::HashMap;
@@ -16,6 +17,7 @@ fn make_client(files: HashMap<String, String>, secrets: WasmSecretProvider) -> R
         Arc::new(secrets),
         Arc::new(WasmHttpClient),
     )
+    .with_auth_context(AuthContext::Editor)
 }
 
 fn parse_files(files_json: &str) -> Result<HashMap<String, String>, JsError> {
@@ -64,6 +66,8 @@ struct RequestDetailsJson {
     body: Option<String>,
     #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none")]
     timeout: Option<String>,
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
     #[serde(rename = "Auth", skip_serializing_if = "Option::is_none")]
     auth: Option<AuthRef>,
     #[serde(rename = "RequiredVariables", skip_serializing_if = "Vec::is_empty")]
@@ -90,6 +94,8 @@ struct AuthDetailsJson {
     environment: Option<String>,
     #[serde(rename = "Fields")]
     fields: HashMap<String, String>,
+    #[serde(rename = "Defaulted Fields")]
+    defaulted_fields: Vec<String>,
     file: String,
     line: usize,
     character: usize,
@@ -221,6 +227,7 @@ pub fn get_request_details(
         headers,
         body: details.body,
         timeout: details.timeout,
+        description: details.description,
         auth,
         required_variables: details.required_variables,
         file: details.file,
@@ -239,7 +246,7 @@ pub fn get_auth_details(
     env: Option<String>,
     interpolate: bool,
 ) -> Result<String, JsError> {
-    let (auth_name, auth_type, fields, file, line, character) = make_client(
+    let (auth_name, auth_type, fields, defaulted_fields, file, line, character) = make_client(
         parse_files(files_json)?,
         parse_secrets(secrets_json),
     )
@@ -250,6 +257,7 @@ pub fn get_auth_details(
         auth_type,
         environment: env,
         fields,
+        defaulted_fields,
         file,
         line,
         character,
@@ -356,6 +364,7 @@ pub async fn run_request(
             Some(request_name),
             env.as_deref(),
             &variables,
+            &RunOptions::default(),
         )
         .await
         .map_err(rq_err)?;
@@ -1,11 +1,13 @@
 use js_sys::{Function, Object, Promise, Reflect};
 use rq_lib::error::RqError;
 use rq_lib::http::{HttpClient, HttpResponse};
+use rq_lib::syntax::duration::parse_duration;
 use rq_lib::syntax::Request;
 use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
@@ -33,8 +35,58 @@ impl HttpClient for WasmHttpClient {
         let headers = request.headers.clone();
         let body = request.body.clone();
         let timeout = request.timeout.clone();
+        let has_cert = request.cert.is_some();
+        let has_bytes = request.body_bytes.is_some();
+        let has_multipart = request.multipart.is_some();
+        let has_unix_socket = request.unix_socket.is_some();
+        let follow_redirects = request.redirects.as_ref().is_none_or(|r| r.follow);
         Box::pin(SendFuture(async move {
-            fetch(&url, &method, &headers, body.as_deref(), timeout.as_deref()).await
+            if has_cert {
+                return Err(RqError::Validation(
+                    "Client certificates are not supported in WASM builds".to_string(),
+                ));
+            }
+            if has_unix_socket {
+                return Err(RqError::Validation(
+                    "Unix sockets are not supported in WASM builds".to_string(),
+                ));
+            }
+            if has_bytes {
+                return Err(RqError::Validation(
+                    "Binary request bodies are not supported in WASM builds".to_string(),
+                ));
+            }
+            if has_multipart {
+                return Err(RqError::Validation(
+                    "Multipart request bodies are not supported in WASM builds".to_string(),
+                ));
+            }
+            fetch(
+                &url,
+                &method,
+                &headers,
+                body.as_deref(),
+                timeout.as_deref(),
+                follow_redirects,
+            )
+            .await
+        }))
+    }
+
+    fn sleep<'a>(&'a self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(SendFuture(async move {
+            let ms = duration.as_millis() as f64;
+            let promise = Promise::new(&mut |resolve, _| {
+                let set_timeout_fn =
+                    Reflect::get(&js_sys::global(), &JsValue::from_str("setTimeout"))
+                        .ok()
+                        .and_then(|v| v.dyn_into::<Function>().ok());
+                if let Some(set_timeout_fn) = set_timeout_fn {
+                    let _ =
+                        set_timeout_fn.call2(&JsValue::UNDEFINED, &resolve, &JsValue::from_f64(ms));
+                }
+            });
+            let _ = JsFuture::from(promise).await;
         }))
     }
 }
@@ -45,6 +97,7 @@ async fn fetch(
     headers: &[(String, String)],
     body: Option<&str>,
     timeout: Option<&str>,
+    follow_redirects: bool,
 ) -> Result<HttpResponse, RqError> {
     let global = js_sys::global();
     let fetch_val = Reflect::get(&global, &JsValue::from_str("fetch"))
@@ -56,15 +109,12 @@ async fn fetch(
         ));
     }
 
-    let abort_controller = timeout
-        .and_then(|t| t.parse::<f64>().ok())
-        .filter(|secs| secs.is_finite() && *secs >= 0.0)
-        .map(|secs| {
-            let controller = js_sys::eval("new AbortController()")
-                .ok()
-                .filter(|v| !v.is_undefined() && !v.is_null());
-            (controller, (secs * 1000.0) as i32)
-        });
+    let abort_controller = timeout.and_then(|t| parse_duration(t).ok()).map(|timeout| {
+        let controller = js_sys::eval("new AbortController()")
+            .ok()
+            .filter(|v| !v.is_undefined() && !v.is_null());
+        (controller, timeout.as_millis() as i32)
+    });
 
     if matches!(&abort_controller, Some((None, _))) {
         return Err(RqError::Generic(
@@ -80,6 +130,15 @@ async fn fetch(
     )
     .map_err(|_| RqError::Generic("Failed to set request method".to_string()))?;
 
+    if !follow_redirects {
+        Reflect::set(
+            &opts,
+            &JsValue::from_str("redirect"),
+            &JsValue::from_str("manual"),
+        )
+        .map_err(|_| RqError::Generic("Failed to set redirect mode".to_string()))?;
+    }
+
     let headers_obj = Object::new();
     for (k, v) in headers {
         Reflect::set(&headers_obj, &JsValue::from_str(k), &JsValue::from_str(v))
@@ -162,6 +221,8 @@ async fn fetch(
         status,
         headers: response_headers,
         body: text_val.as_string().unwrap_or_default(),
+        redirects: Vec::new(),
+        warnings: Vec::new(),
     })
 }
 
@@ -55,6 +55,8 @@ export async function performOAuth2Flow(
         console.log('  Scope:', authConfig.fields.scope);
         console.log('  Code Challenge Method:', authConfig.fields.code_challenge_method);
         
+        await rqClient.applyIssuerDiscovery(authConfig.fields);
+
         // Validate URLs before creating config
         if (!authConfig.fields.authorization_url) {
             throw new Error('Missing authorization_url in auth configuration');
@@ -237,9 +237,12 @@ export const authTypeHandler: CompletionHandler = {
     async provide(_ctx) {
         return [
             { name: 'bearer', detail: 'Bearer Token Authentication', description: 'Simple bearer token authentication. Requires: token' },
+            { name: 'api_key', detail: 'API Key Authentication', description: 'API key sent as a header or query parameter. Requires: key, name' },
+            { name: 'digest', detail: 'HTTP Digest Authentication', description: 'Answers the server\'s Digest challenge. Requires: username, password' },
             { name: 'oauth2_authorization_code', detail: 'OAuth2 Authorization Code with PKCE', description: 'OAuth2 authorization code flow with PKCE' },
             { name: 'oauth2_client_credentials', detail: 'OAuth2 Client Credentials', description: 'OAuth2 client credentials flow' },
             { name: 'oauth2_implicit', detail: 'OAuth2 Implicit Flow', description: 'OAuth2 implicit flow' },
+            { name: 'oauth2_refresh_token', detail: 'OAuth2 Refresh Token', description: 'Exchanges a stored refresh token for an access token. Requires: client_id, refresh_token, token_url' },
         ].map(t => {
             const item = new vscode.CompletionItem(t.name, vscode.CompletionItemKind.EnumMember);
             item.detail = t.detail;
@@ -347,7 +350,7 @@ function buildRqEpHandler(
 export const rqBlockHandler = buildRqEpHandler(
     /\brq\s+\w+\s*\([^;]*$/s,
     REQUEST_PROPERTIES,
-    ['url', 'headers', 'body', 'method'],
+    ['url', 'headers', 'body', 'body_lines', 'body_yaml', 'body_file', 'raw', 'method'],
     true
 );
 
@@ -417,7 +420,62 @@ export const attributeHandler: CompletionHandler = {
         requiredItem.documentation = new vscode.MarkdownString('Marks a variable as required.\n\n**Example:** `[required(user_id)]`');
         requiredItem.insertText = new vscode.SnippetString('required(${1:var_name})');
 
-        return [methodItem, timeoutItem, authItem, requiredItem];
+        const certItem = new vscode.CompletionItem('cert', vscode.CompletionItemKind.Keyword);
+        certItem.detail = 'Client certificate for mutual TLS';
+        certItem.documentation = new vscode.MarkdownString('Presents a PKCS#12 or PEM client certificate for the next `rq` statement, or for every request of the next `ep`. The path is relative to the `.rq` file that declares it.\n\n**Example:** `[cert("certs/client.p12", "password")]`');
+        certItem.insertText = new vscode.SnippetString('cert("${1:client.p12}")');
+
+        const retryItem = new vscode.CompletionItem('retry', vscode.CompletionItemKind.Keyword);
+        retryItem.detail = 'Retry on transient status codes';
+        retryItem.documentation = new vscode.MarkdownString('Re-sends the next `rq` statement up to `count` more times while the status is in `on` (default: any 5xx and 429) or the connection fails. `backoff` sets the first wait, doubled on every retry.\n\n**Example:** `[retry(count=3, on=[502, 503, 429], backoff=500ms)]`');
+        retryItem.insertText = new vscode.SnippetString('retry(count=${1:3})');
+
+        const redirectsItem = new vscode.CompletionItem('redirects', vscode.CompletionItemKind.Keyword);
+        redirectsItem.detail = 'Control redirect following';
+        redirectsItem.documentation = new vscode.MarkdownString('With `follow=false`, returns the `3xx` response of the next `rq` statement instead of following it. `max` sets how many redirects are followed, overriding `--max-redirects`.\n\n**Example:** `[redirects(follow=false)]`');
+        redirectsItem.insertText = new vscode.SnippetString('redirects(${1|follow=false,max=5|})');
+
+        const idempotencyItem = new vscode.CompletionItem('idempotency', vscode.CompletionItemKind.Keyword);
+        idempotencyItem.detail = 'Send a generated idempotency key';
+        idempotencyItem.documentation = new vscode.MarkdownString('Adds an `Idempotency-Key` header with a UUID that stays the same across retries of the next `rq` statement. Pass a string to use a different header name.\n\n**Example:** `[idempotency("X-Request-Id")]`');
+        idempotencyItem.insertText = new vscode.SnippetString('idempotency');
+
+        const outputItem = new vscode.CompletionItem('output', vscode.CompletionItemKind.Keyword);
+        outputItem.detail = 'Output format for this request';
+        outputItem.documentation = new vscode.MarkdownString('Prints the result of the next `rq` statement as `json` or `text`, overriding the `-o` CLI option.\n\n**Example:** `[output(json)]`');
+        outputItem.insertText = new vscode.SnippetString('output(${1|json,text|})');
+
+        const unixItem = new vscode.CompletionItem('unix', vscode.CompletionItemKind.Keyword);
+        unixItem.detail = 'Send over a Unix domain socket';
+        unixItem.documentation = new vscode.MarkdownString('Connects to a Unix domain socket instead of the URL host for the next `rq` statement. The URL still sets the request path and `Host` header.\n\n**Example:** `[unix("/var/run/docker.sock")]`');
+        unixItem.insertText = new vscode.SnippetString('unix("${1:/var/run/docker.sock}")');
+
+        const expectItem = new vscode.CompletionItem('expect', vscode.CompletionItemKind.Keyword);
+        expectItem.detail = 'Assert on the response';
+        expectItem.documentation = new vscode.MarkdownString('Checks the response of the next `rq` statement. Targets: `status`, `time_ms`, `header.<Name>`, `body`, `body.$<path>`. Comparators: `==`, `!=`, `<`, `>`, `contains`, `matches`. A failed assertion makes the run exit with code 10.\n\n**Example:** `[expect(body.$.id == 1)]`');
+        expectItem.insertText = new vscode.SnippetString('expect(${1:status} ${2:==} ${3:200})');
+
+        const captureItem = new vscode.CompletionItem('capture', vscode.CompletionItemKind.Keyword);
+        captureItem.detail = 'Store a response value in a variable';
+        captureItem.documentation = new vscode.MarkdownString('Stores a value from the response of the next `rq` statement in a variable for the requests that follow. Targets are the same as for `expect`. A missing target stops the run.\n\n**Example:** `[capture(order_url = header.Location)]`');
+        captureItem.insertText = new vscode.SnippetString('capture(${1:name} = ${2:header.Location})');
+
+        const descItem = new vscode.CompletionItem('desc', vscode.CompletionItemKind.Keyword);
+        descItem.detail = 'Describe the request';
+        descItem.documentation = new vscode.MarkdownString('Attaches a human-readable description to the next `rq` statement. It is shown by `rq request list` and `rq request show`.\n\n**Example:** `[desc("Fetches the current user")]`');
+        descItem.insertText = new vscode.SnippetString('desc("${1:description}")');
+
+        const reauthItem = new vscode.CompletionItem('reauth', vscode.CompletionItemKind.Keyword);
+        reauthItem.detail = 'Re-run auth on 401';
+        reauthItem.documentation = new vscode.MarkdownString('When the next `rq` statement returns `401`, runs its auth configuration again and retries the request once with the new credentials.\n\n**Example:** `[reauth]`');
+        reauthItem.insertText = new vscode.SnippetString('reauth');
+
+        const skipItem = new vscode.CompletionItem('skip', vscode.CompletionItemKind.Keyword);
+        skipItem.detail = 'Exclude the request from runs';
+        skipItem.documentation = new vscode.MarkdownString('Keeps the next `rq` statement in the file and in `rq request list`, but never sends it. Runs report it as skipped.\n\n**Example:** `[skip]`');
+        skipItem.insertText = new vscode.SnippetString('skip');
+
+        return [methodItem, timeoutItem, authItem, requiredItem, certItem, retryItem, redirectsItem, idempotencyItem, outputItem, unixItem, expectItem, captureItem, descItem, reauthItem, skipItem];
     },
 };
 
@@ -455,7 +513,7 @@ export const headerKeyHandler: CompletionHandler = {
 
 export const functionArgHandler: CompletionHandler = {
     canHandle: ({ linePrefix }) => {
-        const match = /\b(io\.read_file|datetime\.now)\(([^)]*)$/.exec(linePrefix);
+        const match = /\b(io\.read_file|io\.read_bytes|io\.read_json|datetime\.now)\(([^)]*)$/.exec(linePrefix);
         if (!match) { return false; }
         const quoteCount = (match[2].match(/"/g) ?? []).length;
         return quoteCount % 2 === 0;
@@ -472,9 +530,9 @@ export const functionArgHandler: CompletionHandler = {
 };
 
 export const namespaceHandler: CompletionHandler = {
-    canHandle: ({ linePrefix }) => /\b(io|random|datetime)\.[a-zA-Z_]*$/.test(linePrefix),
+    canHandle: ({ linePrefix }) => /\b(io|random|datetime|string|env|crypto)\.[a-zA-Z_]*$/.test(linePrefix),
     async provide({ linePrefix, position, document }) {
-        const match = linePrefix.match(/\b(io|random|datetime)\.([a-zA-Z_]*)$/);
+        const match = linePrefix.match(/\b(io|random|datetime|string|env|crypto)\.([a-zA-Z_]*)$/);
         if (!match) { return undefined; }
         const ns = match[1];
         const partial = match[2];
@@ -491,11 +549,25 @@ export const namespaceHandler: CompletionHandler = {
         if (ns === 'io') {
             return [applyRange((() => {
                 const i = new vscode.CompletionItem('read_file', vscode.CompletionItemKind.Function);
-                i.detail = 'io.read_file(path: string)';
-                i.documentation = new vscode.MarkdownString('Imports the contents of a file relative to the current .rq file\n\n**Parameters:**\n- path: string - Relative or absolute path to the file to import');
+                i.detail = 'io.read_file(path: string, option?: string)';
+                i.documentation = new vscode.MarkdownString('Imports the contents of a file relative to the current .rq file\n\n**Parameters:**\n- path: string - Relative or absolute path to the file to import\n- option: string (optional) - "trim" to strip surrounding whitespace and newlines');
                 i.insertText = new vscode.SnippetString('read_file($1)');
                 i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
                 return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('read_bytes', vscode.CompletionItemKind.Function);
+                i.detail = 'io.read_bytes(path: string)';
+                i.documentation = new vscode.MarkdownString('Sends the raw bytes of a file as the request body, without text decoding\n\n**Parameters:**\n- path: string - Relative or absolute path to the file to send');
+                i.insertText = new vscode.SnippetString('read_bytes($1)');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('read_json', vscode.CompletionItemKind.Function);
+                i.detail = 'io.read_json(path: string, selector: string)';
+                i.documentation = new vscode.MarkdownString('Reads a JSON file relative to the current .rq file and returns the scalar at a JSONPath-like selector\n\n**Parameters:**\n- path: string - Relative or absolute path to the JSON file\n- selector: string - Path to a scalar value, e.g. "$.data.token" or "$.items[0].id"');
+                i.insertText = new vscode.SnippetString('read_json($1)');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
             })())];
         }
         if (ns === 'random') {
@@ -505,6 +577,78 @@ export const namespaceHandler: CompletionHandler = {
                 i.documentation = new vscode.MarkdownString('Generates a random GUID (UUID v4)');
                 i.insertText = new vscode.SnippetString('guid()');
                 return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('int', vscode.CompletionItemKind.Function);
+                i.detail = 'random.int(min: string, max: string) → string';
+                i.documentation = new vscode.MarkdownString('Generates a random integer between min and max, both inclusive\n\n**Parameters:**\n- min: string - Lower bound, e.g. "1"\n- max: string - Upper bound, e.g. "100"');
+                i.insertText = new vscode.SnippetString('int("${1:1}", "${2:100}")');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('string', vscode.CompletionItemKind.Function);
+                i.detail = 'random.string(length: string, charset?: string) → string';
+                i.documentation = new vscode.MarkdownString('Generates a random string of the given length from A-Z, a-z and 0-9, or from a custom character set\n\n**Parameters:**\n- length: string - Number of characters, from "1" to "4096"\n- charset: string (optional) - Characters to pick from');
+                i.insertText = new vscode.SnippetString('string("${1:16}")');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })())];
+        }
+        if (ns === 'string') {
+            return [applyRange((() => {
+                const i = new vscode.CompletionItem('base64_encode', vscode.CompletionItemKind.Function);
+                i.detail = 'string.base64_encode(value: string) → string';
+                i.documentation = new vscode.MarkdownString('Encodes a string as standard base64, e.g. for Basic auth headers\n\n**Parameters:**\n- value: string - The text to encode');
+                i.insertText = new vscode.SnippetString('base64_encode("${1}")');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('base64_decode', vscode.CompletionItemKind.Function);
+                i.detail = 'string.base64_decode(value: string) → string';
+                i.documentation = new vscode.MarkdownString('Decodes a standard base64 string into text\n\n**Parameters:**\n- value: string - The base64 text to decode');
+                i.insertText = new vscode.SnippetString('base64_decode("${1}")');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('url_encode', vscode.CompletionItemKind.Function);
+                i.detail = 'string.url_encode(value: string) → string';
+                i.documentation = new vscode.MarkdownString('Percent-encodes every character outside A-Z, a-z, 0-9 and - . _ ~, e.g. for query string values\n\n**Parameters:**\n- value: string - The text to encode');
+                i.insertText = new vscode.SnippetString('url_encode("${1}")');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('url_decode', vscode.CompletionItemKind.Function);
+                i.detail = 'string.url_decode(value: string) → string';
+                i.documentation = new vscode.MarkdownString('Decodes %XX escapes back into text\n\n**Parameters:**\n- value: string - The percent-encoded text to decode');
+                i.insertText = new vscode.SnippetString('url_decode("${1}")');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })())];
+        }
+        if (ns === 'env') {
+            return [applyRange((() => {
+                const i = new vscode.CompletionItem('get', vscode.CompletionItemKind.Function);
+                i.detail = 'env.get(name: string, default?: string) → string';
+                i.documentation = new vscode.MarkdownString('Returns the value of an OS environment variable, or the default when it is not set\n\n**Parameters:**\n- name: string - Name of the OS environment variable\n- default: string (optional) - Value to use when the variable is not set');
+                i.insertText = new vscode.SnippetString('get("${1:HOME}")');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })())];
+        }
+        if (ns === 'crypto') {
+            return [applyRange((() => {
+                const i = new vscode.CompletionItem('sha256', vscode.CompletionItemKind.Function);
+                i.detail = 'crypto.sha256(input: string) → string';
+                i.documentation = new vscode.MarkdownString('Returns the SHA-256 digest of the text as lowercase hex\n\n**Parameters:**\n- input: string - The text to hash');
+                i.insertText = new vscode.SnippetString('sha256($1)');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('hmac_sha256', vscode.CompletionItemKind.Function);
+                i.detail = 'crypto.hmac_sha256(key: string, input: string) → string';
+                i.documentation = new vscode.MarkdownString('Returns the HMAC-SHA256 of the text with the given key as lowercase hex, e.g. for request signatures\n\n**Parameters:**\n- key: string - The secret key\n- input: string - The text to sign');
+                i.insertText = new vscode.SnippetString('hmac_sha256($1)');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
             })())];
         }
         if (ns === 'datetime') {
@@ -515,6 +659,13 @@ export const namespaceHandler: CompletionHandler = {
                 i.insertText = new vscode.SnippetString('now(${1:})');
                 i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
                 return i;
+            })()), applyRange((() => {
+                const i = new vscode.CompletionItem('timestamp', vscode.CompletionItemKind.Function);
+                i.detail = 'datetime.timestamp(unit?: string) → string';
+                i.documentation = new vscode.MarkdownString('Returns the current Unix time as a decimal string.\n\n**Parameters:**\n- unit: string (optional) - "s" for seconds (default) or "ms" for milliseconds');
+                i.insertText = new vscode.SnippetString('timestamp(${1:})');
+                i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+                return i;
             })())];
         }
         return [];
@@ -564,6 +715,20 @@ export const topLevelKeywordHandler: CompletionHandler = {
                 i.sortText = 'auth_2bearer';
                 return i;
             })(),
+            (() => {
+                const i = new vscode.CompletionItem('auth api_key', vscode.CompletionItemKind.Module);
+                i.detail = 'Auth block — API Key';
+                i.insertText = new vscode.SnippetString('auth ${1:my_auth}(auth_type.api_key) {\n\tkey: "${2:}",\n\tname: "${3:X-API-Key}",\n\tlocation: "${4|header,query|}"\n}');
+                i.sortText = 'auth_2api_key';
+                return i;
+            })(),
+            (() => {
+                const i = new vscode.CompletionItem('auth digest', vscode.CompletionItemKind.Module);
+                i.detail = 'Auth block — HTTP Digest';
+                i.insertText = new vscode.SnippetString('auth ${1:my_auth}(auth_type.digest) {\n\tusername: "${2:}",\n\tpassword: "${3:}"\n}');
+                i.sortText = 'auth_2digest';
+                return i;
+            })(),
             (() => {
                 const i = new vscode.CompletionItem('auth oauth2_authorization_code', vscode.CompletionItemKind.Module);
                 i.detail = 'Auth block — OAuth2 Authorization Code with PKCE';
@@ -592,6 +757,13 @@ export const topLevelKeywordHandler: CompletionHandler = {
                 i.sortText = 'auth_2oauth_impl';
                 return i;
             })(),
+            (() => {
+                const i = new vscode.CompletionItem('auth oauth2_refresh_token', vscode.CompletionItemKind.Module);
+                i.detail = 'Auth block — OAuth2 Refresh Token';
+                i.insertText = new vscode.SnippetString('auth ${1:my_auth}(auth_type.oauth2_refresh_token) {\n\tclient_id: "${2:}",\n\trefresh_token: "${3:}",\n\ttoken_url: "${4:}"\n}');
+                i.sortText = 'auth_2oauth_refresh';
+                return i;
+            })(),
             kw('ep', 'Endpoint block keyword', 'ep_0kw'),
             (() => {
                 const i = new vscode.CompletionItem('ep …', vscode.CompletionItemKind.Module);
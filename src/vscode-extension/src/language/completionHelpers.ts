@@ -10,11 +10,23 @@ export const COMMON_HEADERS = [
 
 export const AUTH_PROPERTIES: Record<string, { name: string; required: boolean }[]> = {
     bearer: [
-        { name: 'token', required: true },
+        { name: 'token', required: false },
+        { name: 'token_file', required: false },
+        { name: 'token_keychain', required: false },
+    ],
+    api_key: [
+        { name: 'key', required: true },
+        { name: 'name', required: true },
+        { name: 'location', required: false },
+    ],
+    digest: [
+        { name: 'username', required: true },
+        { name: 'password', required: true },
     ],
     oauth2_client_credentials: [
         { name: 'client_id', required: true },
-        { name: 'token_url', required: true },
+        { name: 'token_url', required: false },
+        { name: 'issuer', required: false },
         { name: 'client_secret', required: false },
         { name: 'cert_file', required: false },
         { name: 'cert_password', required: false },
@@ -22,8 +34,9 @@ export const AUTH_PROPERTIES: Record<string, { name: string; required: boolean }
     ],
     oauth2_authorization_code: [
         { name: 'client_id', required: true },
-        { name: 'authorization_url', required: true },
-        { name: 'token_url', required: true },
+        { name: 'authorization_url', required: false },
+        { name: 'token_url', required: false },
+        { name: 'issuer', required: false },
         { name: 'redirect_uri', required: false },
         { name: 'client_secret', required: false },
         { name: 'scope', required: false },
@@ -32,10 +45,19 @@ export const AUTH_PROPERTIES: Record<string, { name: string; required: boolean }
     ],
     oauth2_implicit: [
         { name: 'client_id', required: true },
-        { name: 'authorization_url', required: true },
+        { name: 'authorization_url', required: false },
+        { name: 'issuer', required: false },
         { name: 'redirect_uri', required: false },
         { name: 'scope', required: false },
     ],
+    oauth2_refresh_token: [
+        { name: 'client_id', required: true },
+        { name: 'refresh_token', required: true },
+        { name: 'token_url', required: false },
+        { name: 'issuer', required: false },
+        { name: 'client_secret', required: false },
+        { name: 'scope', required: false },
+    ],
 };
 
 export function builtinFunctionItems(): vscode.CompletionItem[] {
@@ -46,6 +68,20 @@ export function builtinFunctionItems(): vscode.CompletionItem[] {
             i.insertText = 'random.guid()';
             return i;
         })(),
+        (() => {
+            const i = new vscode.CompletionItem('random.int()', vscode.CompletionItemKind.Function);
+            i.detail = 'random.int(min: string, max: string) → string';
+            i.insertText = new vscode.SnippetString('random.int("${1:1}", "${2:100}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('random.string()', vscode.CompletionItemKind.Function);
+            i.detail = 'random.string(length: string, charset?: string) → string';
+            i.insertText = new vscode.SnippetString('random.string("${1:16}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
         (() => {
             const i = new vscode.CompletionItem('datetime.now()', vscode.CompletionItemKind.Function);
             i.detail = 'datetime.now(format?: string) → string';
@@ -53,13 +89,76 @@ export function builtinFunctionItems(): vscode.CompletionItem[] {
             i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
             return i;
         })(),
+        (() => {
+            const i = new vscode.CompletionItem('datetime.timestamp()', vscode.CompletionItemKind.Function);
+            i.detail = 'datetime.timestamp(unit?: string) → string';
+            i.insertText = new vscode.SnippetString('datetime.timestamp(${1:})');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('string.base64_encode()', vscode.CompletionItemKind.Function);
+            i.detail = 'string.base64_encode(value: string) → string';
+            i.insertText = new vscode.SnippetString('string.base64_encode("${1}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('string.base64_decode()', vscode.CompletionItemKind.Function);
+            i.detail = 'string.base64_decode(value: string) → string';
+            i.insertText = new vscode.SnippetString('string.base64_decode("${1}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('string.url_encode()', vscode.CompletionItemKind.Function);
+            i.detail = 'string.url_encode(value: string) → string';
+            i.insertText = new vscode.SnippetString('string.url_encode("${1}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('string.url_decode()', vscode.CompletionItemKind.Function);
+            i.detail = 'string.url_decode(value: string) → string';
+            i.insertText = new vscode.SnippetString('string.url_decode("${1}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('env.get()', vscode.CompletionItemKind.Function);
+            i.detail = 'env.get(name: string, default?: string) → string';
+            i.insertText = new vscode.SnippetString('env.get("${1:HOME}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('crypto.sha256()', vscode.CompletionItemKind.Function);
+            i.detail = 'crypto.sha256(input: string) → string';
+            i.insertText = new vscode.SnippetString('crypto.sha256(${1})');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
+        (() => {
+            const i = new vscode.CompletionItem('crypto.hmac_sha256()', vscode.CompletionItemKind.Function);
+            i.detail = 'crypto.hmac_sha256(key: string, input: string) → string';
+            i.insertText = new vscode.SnippetString('crypto.hmac_sha256(${1:key}, ${2:input})');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
         (() => {
             const i = new vscode.CompletionItem('io.read_file()', vscode.CompletionItemKind.Function);
-            i.detail = 'io.read_file(path: string) → string';
+            i.detail = 'io.read_file(path: string, option?: string) → string';
             i.insertText = new vscode.SnippetString('io.read_file($1)');
             i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
             return i;
         })(),
+        (() => {
+            const i = new vscode.CompletionItem('io.read_json()', vscode.CompletionItemKind.Function);
+            i.detail = 'io.read_json(path: string, selector: string) → string';
+            i.insertText = new vscode.SnippetString('io.read_json("${1}", "\\$.${2}")');
+            i.command = { command: 'editor.action.triggerParameterHints', title: 'Trigger parameter hints' };
+            return i;
+        })(),
     ];
 }
 
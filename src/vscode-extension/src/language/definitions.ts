@@ -16,9 +16,21 @@ export const SYSTEM_FUNCTIONS: FunctionDefinition[] = [
 export const IO_FUNCTIONS = [
     {
         name: 'read_file',
-        signature: 'io.read_file(path: string)',
+        signature: 'io.read_file(path: string, option?: string)',
         description: 'Imports the contents of a file relative to the current .rq file',
-        parameters: ['path: string - Relative or absolute path to the file to import']
+        parameters: ['path: string - Relative or absolute path to the file to import', 'option: string (optional) - "trim" to strip surrounding whitespace and newlines']
+    },
+    {
+        name: 'read_bytes',
+        signature: 'io.read_bytes(path: string)',
+        description: 'Sends the raw bytes of a file as the request body, without text decoding',
+        parameters: ['path: string - Relative or absolute path to the file to send']
+    },
+    {
+        name: 'read_json',
+        signature: 'io.read_json(path: string, selector: string)',
+        description: 'Reads a JSON file relative to the current .rq file and returns the scalar at a JSONPath-like selector',
+        parameters: ['path: string - Relative or absolute path to the JSON file', 'selector: string - Path to a scalar value, e.g. "$.data.token" or "$.items[0].id"']
     }
 ];
 
@@ -29,6 +41,18 @@ export const RANDOM_FUNCTIONS = [
         signature: 'random.guid()',
         description: 'Generates a random GUID (UUID v4)',
         parameters: []
+    },
+    {
+        name: 'int',
+        signature: 'random.int(min: string, max: string)',
+        description: 'Generates a random integer between min and max, both inclusive',
+        parameters: ['min: string - Lower bound, e.g. "1"', 'max: string - Upper bound, e.g. "100"']
+    },
+    {
+        name: 'string',
+        signature: 'random.string(length: string, charset?: string)',
+        description: 'Generates a random string of the given length from A-Z, a-z and 0-9, or from a custom character set',
+        parameters: ['length: string - Number of characters, from "1" to "4096"', 'charset: string (optional) - Characters to pick from']
     }
 ];
 
@@ -39,6 +63,63 @@ export const DATETIME_FUNCTIONS = [
         signature: 'datetime.now(format?: string)',
         description: 'Returns the current date and time. If format is provided, it formats the date according to the format string. Otherwise it returns ISO 8601 format.',
         parameters: ['format: string (optional) - The format string (e.g. "yyyy-MM-dd HH:mm:ss")']
+    },
+    {
+        name: 'timestamp',
+        signature: 'datetime.timestamp(unit?: string)',
+        description: 'Returns the current Unix time as a decimal string, in seconds by default or in milliseconds with "ms".',
+        parameters: ['unit: string (optional) - "s" for seconds or "ms" for milliseconds']
+    }
+];
+
+export const STRING_FUNCTIONS = [
+    {
+        name: 'base64_encode',
+        signature: 'string.base64_encode(value: string)',
+        description: 'Encodes a string as standard base64, e.g. for Basic auth headers',
+        parameters: ['value: string - The text to encode']
+    },
+    {
+        name: 'base64_decode',
+        signature: 'string.base64_decode(value: string)',
+        description: 'Decodes a standard base64 string into text',
+        parameters: ['value: string - The base64 text to decode']
+    },
+    {
+        name: 'url_encode',
+        signature: 'string.url_encode(value: string)',
+        description: 'Percent-encodes every character outside A-Z, a-z, 0-9 and - . _ ~, e.g. for query string values',
+        parameters: ['value: string - The text to encode']
+    },
+    {
+        name: 'url_decode',
+        signature: 'string.url_decode(value: string)',
+        description: 'Decodes %XX escapes back into text',
+        parameters: ['value: string - The percent-encoded text to decode']
+    }
+];
+
+export const ENV_FUNCTIONS = [
+    {
+        name: 'get',
+        signature: 'env.get(name: string, default?: string)',
+        description: 'Returns the value of an OS environment variable, or the default when it is not set',
+        parameters: ['name: string - Name of the OS environment variable', 'default: string (optional) - Value to use when the variable is not set']
+    }
+];
+
+export const CRYPTO_FUNCTIONS = [
+    {
+        name: 'sha256',
+        signature: 'crypto.sha256(input: string)',
+        description: 'Returns the SHA-256 digest of the text as lowercase hex',
+        parameters: ['input: string - The text to hash']
+    },
+    {
+        name: 'hmac_sha256',
+        signature: 'crypto.hmac_sha256(key: string, input: string)',
+        description: 'Returns the HMAC-SHA256 of the text with the given key as lowercase hex, e.g. for request signatures',
+        parameters: ['key: string - The secret key', 'input: string - The text to sign']
     }
 ];
 
@@ -61,6 +142,30 @@ export const REQUEST_PROPERTIES = [
         signature: 'body: string | ${}',
         description: 'Request body content. Can be a string or JSON object (JSON must start with $)',
         example: 'body: ${"key": "value"} or body: sys.import_file("data.json") or body: "string"'
+    },
+    {
+        name: 'body_lines',
+        signature: 'body_lines: array',
+        description: 'Newline-delimited (ndjson) body built from an array variable, one element per line. Sent as application/x-ndjson',
+        example: 'body_lines: records'
+    },
+    {
+        name: 'body_yaml',
+        signature: 'body_yaml: string',
+        description: 'Request body written as YAML and converted to JSON. Sent as application/json',
+        example: 'body_yaml: "name: rq"'
+    },
+    {
+        name: 'body_file',
+        signature: 'body_file: string',
+        description: 'Reads the request body from a file relative to the .rq file when the request runs. {{variable}} placeholders in the file are interpolated',
+        example: 'body_file: "bodies/user.json"'
+    },
+    {
+        name: 'raw',
+        signature: 'raw: true | false',
+        description: 'Sends the body_file content exactly as stored, without interpolating {{variable}} placeholders',
+        example: 'body_file: "bodies/page.hbs", raw: true'
     }
 ];
 
@@ -5,6 +5,9 @@ import {
     IO_FUNCTIONS,
     RANDOM_FUNCTIONS,
     DATETIME_FUNCTIONS,
+    STRING_FUNCTIONS,
+    ENV_FUNCTIONS,
+    CRYPTO_FUNCTIONS,
     FunctionDefinition,
     REQUEST_PROPERTIES,
     ENDPOINT_PROPERTIES,
@@ -39,9 +42,12 @@ function resolveRequestMethod(document: vscode.TextDocument, lineNum: number, na
 function formatAuthType(authType: string): string {
     const types: Record<string, string> = {
         bearer: 'Bearer Token',
+        api_key: 'API Key',
+        digest: 'HTTP Digest',
         oauth2_client_credentials: 'OAuth2 Client Credentials',
         oauth2_authorization_code: 'OAuth2 Authorization Code (PKCE)',
         oauth2_implicit: 'OAuth2 Implicit Flow',
+        oauth2_refresh_token: 'OAuth2 Refresh Token',
     };
     return types[authType] ?? authType;
 }
@@ -237,6 +243,24 @@ export const hoverProvider = vscode.languages.registerHoverProvider('rq', {
             if (func) { return new vscode.Hover(buildFunctionHover(func)); }
         }
 
+        const stringRange = document.getWordRangeAtPosition(position, /string\.\w+/);
+        if (stringRange) {
+            const func = STRING_FUNCTIONS.find(f => f.name === document.getText(stringRange).replace('string.', ''));
+            if (func) { return new vscode.Hover(buildFunctionHover(func)); }
+        }
+
+        const envRange = document.getWordRangeAtPosition(position, /env\.\w+/);
+        if (envRange) {
+            const func = ENV_FUNCTIONS.find(f => f.name === document.getText(envRange).replace('env.', ''));
+            if (func) { return new vscode.Hover(buildFunctionHover(func)); }
+        }
+
+        const cryptoRange = document.getWordRangeAtPosition(position, /crypto\.\w+/);
+        if (cryptoRange) {
+            const func = CRYPTO_FUNCTIONS.find(f => f.name === document.getText(cryptoRange).replace('crypto.', ''));
+            if (func) { return new vscode.Hover(buildFunctionHover(func)); }
+        }
+
         const sysRange = document.getWordRangeAtPosition(position, /sys\.\w+/);
         if (sysRange) {
             const func = SYSTEM_FUNCTIONS.find(f => f.name === document.getText(sysRange).replace('sys.', ''));
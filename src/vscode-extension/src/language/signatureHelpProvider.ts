@@ -113,11 +113,21 @@ export const signatureHelpProvider = vscode.languages.registerSignatureHelpProvi
 
             const ioMatch = textBeforeCursor.match(/\bio\.read_file\(([^)]*)$/s);
             if (ioMatch) {
-                const sig = buildFunctionSignature('io.read_file(path: string)', ['path: string']);
+                const sig = buildFunctionSignature('io.read_file(path: string, option?: string)', ['path: string', 'option?: string']);
                 const help = new vscode.SignatureHelp();
                 help.signatures = [sig];
                 help.activeSignature = 0;
-                help.activeParameter = 0;
+                help.activeParameter = ioMatch[1].includes(',') ? 1 : 0;
+                return help;
+            }
+
+            const readJsonMatch = textBeforeCursor.match(/\bio\.read_json\(([^)]*)$/s);
+            if (readJsonMatch) {
+                const sig = buildFunctionSignature('io.read_json(path: string, selector: string)', ['path: string', 'selector: string']);
+                const help = new vscode.SignatureHelp();
+                help.signatures = [sig];
+                help.activeSignature = 0;
+                help.activeParameter = readJsonMatch[1].includes(',') ? 1 : 0;
                 return help;
             }
 
@@ -6,6 +6,8 @@ import { normalizePath, applyTreeItemLoading } from './utils';
 export interface RequestInfo {
     name: string;
     endpoint: string | null;
+    description?: string;
+    skipped?: boolean;
     file: string;
     endpoint_file?: string;
     endpoint_line?: number;
@@ -400,8 +402,10 @@ export class RequestTreeItem extends vscode.TreeItem {
         if (request) {
             // This is a request item
             this.contextValue = 'request';
-            this.tooltip = `${request.name}\nFile: ${request.file}`;
-            this.description = undefined;
+            this.tooltip = request.description
+                ? `${request.name}\n${request.description}\nFile: ${request.file}`
+                : `${request.name}\nFile: ${request.file}`;
+            this.description = request.skipped ? 'skipped' : undefined;
             
             // Set icon
             this.iconPath = new vscode.ThemeIcon('symbol-interface');
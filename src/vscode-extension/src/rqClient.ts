@@ -69,6 +69,8 @@ export interface AuthShowOutput {
 export interface RequestInfo {
     name: string;
     endpoint: string | null;
+    description?: string;
+    skipped?: boolean;
     file: string;
     endpoint_file?: string;
     endpoint_line?: number;
@@ -399,7 +401,7 @@ export async function executeRequest(options: ExecuteRequestOptions): Promise<Ex
     );
     const raw = JSON.parse(detailsRaw) as RequestShowRaw;
 
-    const url = raw.URL;
+    let url = raw.URL;
     const method = raw.Method;
     const body = raw.Body;
     const headers: Record<string, string> = { ...raw.Headers };
@@ -437,13 +439,45 @@ export async function executeRequest(options: ExecuteRequestOptions): Promise<Ex
             }
         } else if (authType === 'oauth2_client_credentials') {
             const authDetails = await showAuthConfig(authName, options.sourceDirectory, options.environment);
+            await applyIssuerDiscovery(authDetails.fields);
             const token = await fetchClientCredentialsToken(authDetails.fields, path.dirname(authDetails.file));
             headers['authorization'] = `Bearer ${token}`;
+        } else if (authType === 'oauth2_refresh_token') {
+            const authDetails = await showAuthConfig(authName, options.sourceDirectory, options.environment);
+            await applyIssuerDiscovery(authDetails.fields);
+            const token = await fetchRefreshedToken(authDetails.fields);
+            headers['authorization'] = `Bearer ${token}`;
+        } else if (authType === 'api_key') {
+            const authDetails = await showAuthConfig(authName, options.sourceDirectory, options.environment);
+            const { key, name, location } = authDetails.fields;
+            if (key && name) {
+                if (location === 'query') {
+                    const target = new URL(url);
+                    target.searchParams.append(name, key);
+                    url = target.toString();
+                } else {
+                    for (const existing of Object.keys(headers)) {
+                        if (existing.toLowerCase() === name.toLowerCase()) { delete headers[existing]; }
+                    }
+                    headers[name] = key;
+                }
+            }
         }
     }
 
     const startTime = Date.now();
-    const response = await nodeHttpRequest(url, method, headers, body, timeoutMs);
+    let response = await nodeHttpRequest(url, method, headers, body, timeoutMs);
+    if (response.status === 401 && raw.Auth?.type === 'digest') {
+        const authDetails = await showAuthConfig(raw.Auth.name, options.sourceDirectory, options.environment);
+        const challenge = Object.entries(response.headers).find(([k]) => k.toLowerCase() === 'www-authenticate')?.[1];
+        const authorization = challenge
+            ? digestAuthorization(challenge, authDetails.fields['username'] ?? '', authDetails.fields['password'] ?? '', method, url)
+            : undefined;
+        if (authorization) {
+            headers['authorization'] = authorization;
+            response = await nodeHttpRequest(url, method, headers, body, timeoutMs);
+        }
+    }
     const elapsed = Date.now() - startTime;
 
     return {
@@ -461,6 +495,38 @@ export async function executeRequest(options: ExecuteRequestOptions): Promise<Ex
     };
 }
 
+function digestAuthorization(challenge: string, username: string, password: string, method: string, url: string): string | undefined {
+    const start = challenge.toLowerCase().indexOf('digest ');
+    if (start < 0) { return undefined; }
+    const params: Record<string, string> = {};
+    for (const match of challenge.slice(start + 7).matchAll(/([\w-]+)\s*=\s*(?:"([^"]*)"|([^,\s]*))/g)) {
+        params[match[1].toLowerCase()] = match[2] ?? match[3];
+    }
+    const algorithm = params['algorithm'] ?? 'MD5';
+    const hashName = algorithm.toUpperCase().replace(/-SESS$/, '') === 'SHA-256' ? 'sha256' : 'md5';
+    const h = (data: string) => crypto.createHash(hashName).update(data).digest('hex');
+    const target = new URL(url);
+    const uri = target.pathname + target.search;
+    const cnonce = crypto.randomBytes(16).toString('hex');
+    const nc = '00000001';
+    const qop = params['qop']?.split(',').map(q => q.trim()).includes('auth') ? 'auth' : undefined;
+
+    let ha1 = h(`${username}:${params['realm'] ?? ''}:${password}`);
+    if (algorithm.toLowerCase().endsWith('-sess')) { ha1 = h(`${ha1}:${params['nonce']}:${cnonce}`); }
+    const ha2 = h(`${method}:${uri}`);
+    const response = qop
+        ? h(`${ha1}:${params['nonce']}:${nc}:${cnonce}:${qop}:${ha2}`)
+        : h(`${ha1}:${params['nonce']}:${ha2}`);
+
+    const parts = [
+        `username="${username}"`, `realm="${params['realm'] ?? ''}"`, `nonce="${params['nonce']}"`,
+        `uri="${uri}"`, `algorithm=${algorithm}`, `response="${response}"`,
+    ];
+    if (qop) { parts.push(`qop=${qop}`, `nc=${nc}`, `cnonce="${cnonce}"`); }
+    if (params['opaque'] !== undefined) { parts.push(`opaque="${params['opaque']}"`); }
+    return `Digest ${parts.join(', ')}`;
+}
+
 interface NodeHttpResponse {
     status: number;
     headers: Record<string, string>;
@@ -518,6 +584,27 @@ function nodeHttpRequest(url: string, method: string, reqHeaders: Record<string,
     });
 }
 
+const discoveryDocuments = new Map<string, Record<string, string>>();
+
+export async function applyIssuerDiscovery(fields: Record<string, string>): Promise<void> {
+    const issuer = fields.issuer;
+    if (!issuer || (fields.authorization_url && fields.token_url)) {
+        return;
+    }
+    let document = discoveryDocuments.get(issuer);
+    if (!document) {
+        const url = `${issuer.replace(/\/+$/, '')}/.well-known/openid-configuration`;
+        const response = await fetch(url);
+        if (!response.ok) {
+            throw new Error(`Discovery document '${url}' returned status ${response.status}`);
+        }
+        document = (await response.json()) as Record<string, string>;
+        discoveryDocuments.set(issuer, document);
+    }
+    fields.authorization_url = fields.authorization_url || document.authorization_endpoint;
+    fields.token_url = fields.token_url || document.token_endpoint;
+}
+
 async function fetchClientCredentialsToken(fields: Record<string, string>, authFileDir?: string): Promise<string> {
     const { client_id, client_secret, token_url, scope, cert_file, cert_password } = fields;
 
@@ -537,28 +624,33 @@ async function fetchClientCredentialsToken(fields: Record<string, string>, authF
             client_assertion: assertion,
         });
         if (scope) { params.set('scope', scope); }
-        const response = await nodeHttpRequest(token_url, 'POST', {
-            'content-type': 'application/x-www-form-urlencoded',
-        }, params.toString());
-        if (response.status < 200 || response.status >= 300) {
-            throw new Error(`Token request failed with status ${response.status}: ${response.body}`);
-        }
-        const data = JSON.parse(response.body) as { access_token?: string };
-        if (!data.access_token) {
-            throw new Error(`Token response missing access_token: ${response.body}`);
-        }
-        return data.access_token;
+        return postTokenRequest(token_url, params);
     }
 
     const params = new URLSearchParams({ grant_type: 'client_credentials', client_id, client_secret });
     if (scope) { params.set('scope', scope); }
-    const response = await nodeHttpRequest(token_url, 'POST', {
+    return postTokenRequest(token_url, params);
+}
+
+async function fetchRefreshedToken(fields: Record<string, string>): Promise<string> {
+    const { client_id, client_secret, token_url, scope, refresh_token } = fields;
+    const params = new URLSearchParams({ grant_type: 'refresh_token', client_id, refresh_token });
+    if (client_secret) { params.set('client_secret', client_secret); }
+    if (scope) { params.set('scope', scope); }
+    return postTokenRequest(token_url, params);
+}
+
+async function postTokenRequest(tokenUrl: string, params: URLSearchParams): Promise<string> {
+    const response = await nodeHttpRequest(tokenUrl, 'POST', {
         'content-type': 'application/x-www-form-urlencoded',
     }, params.toString());
     if (response.status < 200 || response.status >= 300) {
         throw new Error(`Token request failed with status ${response.status}: ${response.body}`);
     }
-    const data = JSON.parse(response.body) as { access_token?: string };
+    const data = JSON.parse(response.body) as { access_token?: string; error?: string; error_description?: string };
+    if (data.error) {
+        throw new Error(data.error_description ? `Token request failed: ${data.error}: ${data.error_description}` : `Token request failed: ${data.error}`);
+    }
     if (!data.access_token) {
         throw new Error(`Token response missing access_token: ${response.body}`);
     }
@@ -95,7 +95,7 @@ describe('auth block property name completion', () => {
         const items = await provideCompletionItems(doc, position);
 
         expect(items.find((i: any) => i.label === 'client_id')?.detail).toBe('required');
-        expect(items.find((i: any) => i.label === 'token_url')?.detail).toBe('required');
+        expect(items.find((i: any) => i.label === 'token_url')?.detail).toBe('optional');
         expect(items.find((i: any) => i.label === 'client_secret')?.detail).toBe('optional');
         expect(items.find((i: any) => i.label === 'scope')?.detail).toBe('optional');
     });